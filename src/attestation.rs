@@ -0,0 +1,76 @@
+// This file is part of Allfeat.
+
+// Copyright (C) Allfeat (FR) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Artists verification attestations.
+//!
+//! With the `evm-attestation` feature, every verification change writes a compact
+//! [`VerificationAttestation`] record of the artist into the `Artists::VerificationAttestations`
+//! storage map, removed once the artist gets unregistered. The record is keyed by the artist
+//! account with `Blake2_128Concat`, so an EVM system contract (or precompile) can read it at:
+//!
+//! `twox_128("Artists") ++ twox_128("VerificationAttestations") ++ blake2_128(id) ++ id`
+//!
+//! the pallet prefix being the name of the pallet in the runtime. Solidity dApps can then trust
+//! the verification of an artist without any off-chain oracle, checking its `verified_at`
+//! against the `VerificationValidity` as a lapsed record only goes away with the idle cleanup.
+
+use crate::types::AccountIdOf;
+use crate::{Artist, Config, Pallet};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::Blake2_128Concat;
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// The attestation of a verified artist, as read by the EVM.
+///
+/// The layout is SCALE encoded and only ever extended at its end.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct VerificationAttestation<BlockNumber, Hash> {
+    /// The block the artist got verified at.
+    pub verified_at: BlockNumber,
+    /// The fingerprint of the KYC evidence held off-chain by the verifying entity, if any.
+    pub kyc_evidence_hash: Option<Hash>,
+}
+
+/// The attestations of the verified artists, consumed by the EVM.
+#[frame_support::storage_alias]
+pub type VerificationAttestations<T: Config<I>, I: 'static = ()> = StorageMap<
+    Pallet<T, I>,
+    Blake2_128Concat,
+    AccountIdOf<T>,
+    VerificationAttestation<BlockNumberFor<T>, <T as frame_system::Config>::Hash>,
+>;
+
+/// Write the attestation of the passed artist, removing it if the artist isn't verified.
+pub(crate) fn attest<T: Config<I>, I: 'static>(artist: &Artist<T, I>) {
+    match artist.verified_at {
+        Some(verified_at) => VerificationAttestations::<T, I>::insert(
+            &artist.owner,
+            VerificationAttestation {
+                verified_at,
+                kyc_evidence_hash: artist.kyc_evidence_hash,
+            },
+        ),
+        None => VerificationAttestations::<T, I>::remove(&artist.owner),
+    }
+}
+
+/// Remove the attestation of the passed unregistered artist, if any.
+pub(crate) fn revoke<T: Config<I>, I: 'static>(id: &AccountIdOf<T>) {
+    VerificationAttestations::<T, I>::remove(id);
+}
@@ -22,7 +22,7 @@
 use super::*;
 use crate::Pallet as Artists;
 
-use crate::types::ArtistAliasOf;
+use crate::types::{ArtistAliasOf, RoyaltySplitsOf, UpdatableDataOf, UpdatableLinks};
 use codec::alloc::string::ToString;
 use frame_benchmarking::v2::*;
 use frame_support::dispatch::RawOrigin;
@@ -30,20 +30,51 @@ use frame_support::traits::fungible::Mutate;
 use frame_system::Pallet as System;
 use genres_registry::ElectronicSubtype;
 use genres_registry::MusicGenre::Electronic;
+use sp_runtime::traits::Hash;
 use sp_runtime::Saturating;
 
 const MINIMUM_BALANCE: u128 = 1000000000000000000;
 
-fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::RuntimeEvent) {
     frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
-fn dumb_name_with_capacity<T: Config>(capacity: u32) -> ArtistAliasOf<T> {
+/// A main name of the passed length, lengthened to `T::MinNameLen` if shorter.
+fn dumb_name_with_capacity<T: Config<I>, I: 'static>(capacity: u32) -> ArtistAliasOf<T, I> {
+    let capacity = capacity.max(T::MinNameLen::get());
     let vec: Vec<u8> = sp_std::iter::repeat(b'X').take(capacity as usize).collect();
-    vec.try_into().unwrap()
+    T::Name::try_from_bytes(vec).expect("benchmarking name")
 }
 
-fn dumb_genres_with_capacity<T: Config>(capacity: u32) -> BoundedVec<MusicGenre, T::MaxGenres> {
+/// An alias never colliding with the names of `dumb_name_with_capacity`.
+fn dumb_alias_with_capacity<T: Config<I>, I: 'static>(capacity: u32) -> ArtistAliasOf<T, I> {
+    let vec: Vec<u8> = sp_std::iter::repeat(b'A').take(capacity as usize).collect();
+    T::Name::try_from_bytes(vec).expect("benchmarking alias")
+}
+
+/// A `MaxNameLen` long alias, unique for each passed `index` and never colliding with the names
+/// of `dumb_name_with_capacity`.
+fn dumb_alias_with_index<T: Config<I>, I: 'static>(index: u32) -> ArtistAliasOf<T, I> {
+    let mut alias = index.to_le_bytes().to_vec();
+    alias.resize(T::MaxNameLen::get() as usize, b'A');
+    T::Name::try_from_bytes(alias).expect("benchmarking alias")
+}
+
+fn add_test_aliases<T: Config<I>, I: 'static>(id: T::AccountId, aliases_count: u32) {
+    for i in 0..aliases_count {
+        Artists::<T, I>::update(
+            RawOrigin::Signed(id.clone()).into(),
+            UpdatableDataOf::<T, I>::Aliases(UpdatableAliases::Add(dumb_alias_with_index::<T, I>(
+                i,
+            ))),
+        )
+        .expect("benchmark test should not fail");
+    }
+}
+
+fn dumb_genres_with_capacity<T: Config<I>, I: 'static>(
+    capacity: u32,
+) -> BoundedVec<MusicGenre, T::MaxGenres> {
     let mut b_vec: BoundedVec<MusicGenre, T::MaxGenres> = vec![
         Electronic(Some(ElectronicSubtype::House)),
         Electronic(Some(ElectronicSubtype::Ambient)),
@@ -65,63 +96,194 @@ fn dumb_genres_with_capacity<T: Config>(capacity: u32) -> BoundedVec<MusicGenre,
     b_vec
 }
 
-fn dumb_assets_with_capacity<T: Config>(capacity: u32) -> BoundedVec<Vec<u8>, T::MaxAssets> {
-    let mut b_vec: BoundedVec<Vec<u8>, T::MaxAssets> = Default::default();
+fn dumb_assets_with_capacity<T: Config<I>, I: 'static>(
+    capacity: u32,
+) -> BoundedVec<(AssetType, Vec<u8>), T::MaxAssets> {
+    let mut b_vec: BoundedVec<(AssetType, Vec<u8>), T::MaxAssets> = Default::default();
 
     for i in 0..capacity {
         let mut buffer = Vec::new();
         buffer.extend_from_slice("asset".as_bytes());
         buffer.extend_from_slice(i.to_string().as_bytes());
-        b_vec.try_push(buffer).unwrap();
+        b_vec.try_push((AssetType::Image, buffer)).unwrap();
     }
 
     b_vec
 }
 
-fn register_test_artist<T: Config>(
+/// A `MaxLinkLen` long link, unique for each passed `index`.
+fn dumb_link_with_index<T: Config<I>, I: 'static>(index: u32) -> Vec<u8> {
+    let mut link = index.to_le_bytes().to_vec();
+    link.resize(T::MaxLinkLen::get() as usize, b'X');
+    link
+}
+
+fn add_test_links<T: Config<I>, I: 'static>(id: T::AccountId, links_count: u32) {
+    for i in 0..links_count {
+        Artists::<T, I>::update(
+            RawOrigin::Signed(id.clone()).into(),
+            UpdatableDataOf::<T, I>::Links(UpdatableLinks::Add(
+                LinkType::Website,
+                dumb_link_with_index::<T, I>(i),
+            )),
+        )
+        .expect("benchmark test should not fail");
+    }
+}
+
+/// A description of `len` bytes, to be hashed.
+fn dumb_description_with_len<T: Config<I>, I: 'static>(
+    len: u32,
+) -> BoundedVec<u8, T::MaxHashedDescriptionLen> {
+    sp_std::iter::repeat(b'X')
+        .take(len as usize)
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("benchmarking bounded vec")
+}
+
+fn register_test_artist<T: Config<I>, I: 'static>(
     id: T::AccountId,
     name_length: u32,
     genres_count: u32,
     assets_count: u32,
 ) {
-    let name: ArtistAliasOf<T> = dumb_name_with_capacity::<T>(name_length);
-    let alias: ArtistAliasOf<T> = dumb_name_with_capacity::<T>(name_length);
-    let genres: BoundedVec<MusicGenre, T::MaxGenres> = dumb_genres_with_capacity::<T>(genres_count);
-    let description = Some("test".as_bytes().to_vec());
-    let assets: BoundedVec<Vec<u8>, T::MaxAssets> = dumb_assets_with_capacity::<T>(assets_count);
+    let alias = dumb_alias_with_capacity::<T, I>(name_length);
+    register_test_artist_with_alias::<T, I>(
+        id,
+        name_length,
+        genres_count,
+        assets_count,
+        Some(alias),
+    );
+}
 
-    Artists::<T>::register(
+/// Register a test artist with the passed alias, aliases being unique across artists.
+fn register_test_artist_with_alias<T: Config<I>, I: 'static>(
+    id: T::AccountId,
+    name_length: u32,
+    genres_count: u32,
+    assets_count: u32,
+    alias: Option<ArtistAliasOf<T, I>>,
+) {
+    let name: ArtistAliasOf<T, I> = dumb_name_with_capacity::<T, I>(name_length);
+    let genres: BoundedVec<MusicGenre, T::MaxGenres> =
+        dumb_genres_with_capacity::<T, I>(genres_count);
+    let description = Some(dumb_description_with_len::<T, I>(4));
+    let assets: BoundedVec<(AssetType, Vec<u8>), T::MaxAssets> =
+        dumb_assets_with_capacity::<T, I>(assets_count);
+
+    Artists::<T, I>::register(
         RawOrigin::Signed(id).into(),
         name,
-        Some(alias),
+        alias,
         genres,
         description,
         assets,
     )
     .expect("benchmark test should not fail");
+
+    // Keep the registrations cap out of the way of the benchmarks setup
+    RegistrationsUsage::<T, I>::kill();
+}
+
+/// `count` distinct royalty beneficiaries sharing 100%, the last one taking the rounding rest.
+fn royalty_splits<T: Config<I>, I: 'static>(count: u32) -> RoyaltySplitsOf<T, I> {
+    let share = Perbill::one().deconstruct() / count;
+    let rest = Perbill::one().deconstruct() - share * count;
+    (0..count)
+        .map(|i| {
+            let share = if i == count - 1 { share + rest } else { share };
+            (account("beneficiary", i, 0), Perbill::from_parts(share))
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("benchmarking bounded vec")
+}
+
+/// Register the passed account as a group artist with `count` members.
+fn register_test_group<T: Config<I>, I: 'static>(id: T::AccountId, count: u32) {
+    register_test_artist::<T, I>(id.clone(), 1, 0, 0);
+    Artists::<T, I>::set_artist_kind(RawOrigin::Signed(id.clone()).into(), ArtistKind::Group)
+        .expect("benchmark test should not fail");
+
+    for i in 0..count {
+        let member: T::AccountId = account("member", i, 0);
+        Artists::<T, I>::add_member(RawOrigin::Signed(id.clone()).into(), member.clone())
+            .expect("benchmark test should not fail");
+        Artists::<T, I>::accept_membership(RawOrigin::Signed(member).into(), id.clone())
+            .expect("benchmark test should not fail");
+    }
+}
+
+/// The co-ownership share offered to the group members.
+fn test_share<T: Config<I>, I: 'static>() -> BalanceOf<T, I> {
+    T::MinCoOwnerShare::get().max(MINIMUM_BALANCE.saturated_into())
+}
+
+/// Make the passed group member a co-owner of the group.
+fn join_test_ownership<T: Config<I>, I: 'static>(group: T::AccountId, member: T::AccountId) {
+    T::Currency::set_balance(&member, (MINIMUM_BALANCE * 100000u128).saturated_into());
+    Artists::<T, I>::offer_co_ownership(
+        RawOrigin::Signed(group.clone()).into(),
+        member.clone(),
+        test_share::<T, I>(),
+    )
+    .expect("benchmark test should not fail");
+    Artists::<T, I>::join_ownership(
+        RawOrigin::Signed(member).into(),
+        group,
+        test_share::<T, I>(),
+    )
+    .expect("benchmark test should not fail");
 }
 
-#[benchmarks]
+/// Fill the pending verification queue with `count` artists each submitting `evidence_count`
+/// evidence.
+fn fill_verification_queue<T: Config<I>, I: 'static>(count: u32, evidence_count: u32) {
+    let evidence: BoundedVec<T::Hash, T::MaxEvidence> = (0..evidence_count)
+        .map(|i| T::Hashing::hash(&i.to_le_bytes()))
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("benchmarking bounded vec");
+
+    for i in 0..count {
+        let id: T::AccountId = account("pending", i, 0);
+        T::Currency::set_balance(&id, (MINIMUM_BALANCE * 100000u128).saturated_into());
+        register_test_artist_with_alias::<T, I>(id.clone(), 1, 0, 0, None);
+
+        Artists::<T, I>::submit_verification_evidence(
+            RawOrigin::Signed(id).into(),
+            evidence.clone(),
+        )
+        .expect("benchmark test should not fail");
+    }
+}
+
+#[instance_benchmarks]
 mod benchmarks {
     use super::*;
-    use crate::types::{UpdatableAssets, UpdatableData, UpdatableGenres};
+    use crate::types::{UpdatableAssets, UpdatableGenres};
     use genres_registry::ClassicalSubtype;
 
+    /// `d` is the length of the hashed description.
     #[benchmark]
     fn register(
         n: Linear<1, { T::MaxNameLen::get() }>,
         g: Linear<0, { T::MaxGenres::get() }>,
         a: Linear<0, { T::MaxAssets::get() }>,
+        d: Linear<0, { T::MaxHashedDescriptionLen::get() }>,
     ) -> Result<(), BenchmarkError> {
         let caller: T::AccountId = whitelisted_caller();
 
         T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
 
-        let name: ArtistAliasOf<T> = dumb_name_with_capacity::<T>(n);
-        let alias: ArtistAliasOf<T> = dumb_name_with_capacity::<T>(n);
-        let genres: BoundedVec<MusicGenre, T::MaxGenres> = dumb_genres_with_capacity::<T>(g);
-        let description = Some("test".as_bytes().to_vec());
-        let assets: BoundedVec<Vec<u8>, T::MaxAssets> = dumb_assets_with_capacity::<T>(a);
+        let name: ArtistAliasOf<T, I> = dumb_name_with_capacity::<T, I>(n);
+        let alias: ArtistAliasOf<T, I> = dumb_name_with_capacity::<T, I>(n);
+        let genres: BoundedVec<MusicGenre, T::MaxGenres> = dumb_genres_with_capacity::<T, I>(g);
+        let description = Some(dumb_description_with_len::<T, I>(d));
+        let assets: BoundedVec<(AssetType, Vec<u8>), T::MaxAssets> =
+            dumb_assets_with_capacity::<T, I>(a);
 
         #[extrinsic_call]
         _(
@@ -133,7 +295,7 @@ mod benchmarks {
             assets,
         );
 
-        assert_last_event::<T>(Event::ArtistRegistered { id: caller, name }.into());
+        assert_last_event::<T, I>(Event::ArtistRegistered { id: caller, name }.into());
 
         Ok(())
     }
@@ -148,18 +310,36 @@ mod benchmarks {
 
         T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
 
-        register_test_artist::<T>(caller.clone(), n, g, a);
+        register_test_artist::<T, I>(caller.clone(), n, g, a);
+        let deposit = *Artists::<T, I>::get_artist_by_id(&caller)
+            .expect("benchmark test should not fail")
+            .deposit();
+
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 
         #[extrinsic_call]
-        _(RawOrigin::Root, caller.clone());
+        _(
+            origin as T::RuntimeOrigin,
+            caller.clone(),
+            DepositPolicy::Slash,
+        );
 
-        assert_last_event::<T>(Event::ArtistForceUnregistered { id: caller }.into());
+        assert_last_event::<T, I>(
+            Event::ArtistForceUnregistered {
+                id: caller.clone(),
+                policy: DepositPolicy::Slash,
+                deposit,
+                depositor: caller,
+            }
+            .into(),
+        );
 
         Ok(())
     }
 
     #[benchmark]
-    fn unregister(
+    fn confirm_unregister(
         n: Linear<1, { T::MaxNameLen::get() }>,
         g: Linear<0, { T::MaxGenres::get() }>,
         a: Linear<0, { T::MaxAssets::get() }>,
@@ -168,7 +348,12 @@ mod benchmarks {
 
         T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
 
-        register_test_artist::<T>(caller.clone(), n, g, a);
+        register_test_artist::<T, I>(caller.clone(), n, g, a);
+        Artists::<T, I>::request_unregister(RawOrigin::Signed(caller.clone()).into())
+            .expect("benchmark test should not fail");
+        let deposit = *Artists::<T, I>::get_artist_by_id(&caller)
+            .expect("benchmark test should not fail")
+            .deposit();
 
         System::<T>::set_block_number(
             System::<T>::block_number().saturating_add(T::UnregisterPeriod::get().into()),
@@ -177,7 +362,14 @@ mod benchmarks {
         #[extrinsic_call]
         _(RawOrigin::Signed(caller.clone().into()));
 
-        assert_last_event::<T>(Event::ArtistUnregistered { id: caller }.into());
+        assert_last_event::<T, I>(
+            Event::ArtistUnregistered {
+                id: caller.clone(),
+                deposit,
+                depositor: caller,
+            }
+            .into(),
+        );
 
         Ok(())
     }
@@ -192,15 +384,14 @@ mod benchmarks {
 
         T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
 
-        register_test_artist::<T>(caller.clone(), n, 0, 0);
+        register_test_artist::<T, I>(caller.clone(), n, 0, 0);
 
-        let new_data =
-            UpdatableData::<ArtistAliasOf<T>>::Alias(Some(dumb_name_with_capacity::<T>(x)));
+        let new_data = UpdatableDataOf::<T, I>::Alias(Some(dumb_name_with_capacity::<T, I>(x)));
 
         #[extrinsic_call]
         update(RawOrigin::Signed(caller.clone().into()), new_data.clone());
 
-        assert_last_event::<T>(
+        assert_last_event::<T, I>(
             Event::ArtistUpdated {
                 id: caller,
                 new_data,
@@ -220,16 +411,20 @@ mod benchmarks {
 
         T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
 
-        register_test_artist::<T>(caller.clone(), 1, n, 0);
+        register_test_artist::<T, I>(caller.clone(), 1, n, 0);
 
-        let new_data = UpdatableData::<ArtistAliasOf<T>>::Genres(UpdatableGenres::Add(
+        let new_data = UpdatableDataOf::<T, I>::Genres(UpdatableGenres::Add(
             MusicGenre::Classical(Some(ClassicalSubtype::Symphony)),
         ));
 
         #[extrinsic_call]
         update(RawOrigin::Signed(caller.clone().into()), new_data.clone());
 
-        assert_last_event::<T>(
+        assert!(ArtistsOfGenre::<T, I>::contains_key(
+            StoredGenre::from(MusicGenre::Classical(Some(ClassicalSubtype::Symphony))),
+            &caller
+        ));
+        assert_last_event::<T, I>(
             Event::ArtistUpdated {
                 id: caller,
                 new_data,
@@ -247,18 +442,46 @@ mod benchmarks {
 
         T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
 
-        register_test_artist::<T>(caller.clone(), 1, n, 0);
+        register_test_artist::<T, I>(caller.clone(), 1, n, 0);
 
         // Always remove what we are sure this is the first element so there is always something
         // to remove even with only one genre existing in the benchmarking artist.
-        let new_data = UpdatableData::<ArtistAliasOf<T>>::Genres(UpdatableGenres::Remove(
-            Electronic(Some(ElectronicSubtype::House)),
-        ));
+        let new_data = UpdatableDataOf::<T, I>::Genres(UpdatableGenres::Remove(Electronic(Some(
+            ElectronicSubtype::House,
+        ))));
+
+        #[extrinsic_call]
+        update(RawOrigin::Signed(caller.clone().into()), new_data.clone());
+
+        assert_last_event::<T, I>(
+            Event::ArtistUpdated {
+                id: caller,
+                new_data,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    /// `n` is the existing artist data.
+    #[benchmark]
+    fn update_remove_genres_at(
+        n: Linear<1, { T::MaxGenres::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, n, 0);
+
+        // The first genre shifts every other one.
+        let new_data = UpdatableDataOf::<T, I>::Genres(UpdatableGenres::RemoveAt(0));
 
         #[extrinsic_call]
         update(RawOrigin::Signed(caller.clone().into()), new_data.clone());
 
-        assert_last_event::<T>(
+        assert_last_event::<T, I>(
             Event::ArtistUpdated {
                 id: caller,
                 new_data,
@@ -276,14 +499,94 @@ mod benchmarks {
 
         T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
 
-        register_test_artist::<T>(caller.clone(), 1, n, 0);
+        register_test_artist::<T, I>(caller.clone(), 1, n, 0);
+
+        let new_data = UpdatableDataOf::<T, I>::Genres(UpdatableGenres::Clear);
+
+        #[extrinsic_call]
+        update(RawOrigin::Signed(caller.clone().into()), new_data.clone());
+
+        // Every genre has been removed from the genres index
+        assert!(ArtistsOfGenre::<T, I>::iter_keys().all(|(_, id)| id != caller));
+        assert_last_event::<T, I>(
+            Event::ArtistUpdated {
+                id: caller,
+                new_data,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    /// The genres index entries removed along with `n` cleared genres, charged on top of an
+    /// empty `update_clear_genres`.
+    #[benchmark]
+    fn clear_genre_index(n: Linear<0, { T::MaxGenres::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, n, 0);
+        let genres = ArtistOf::<T, I>::get(&caller)
+            .expect("benchmark test should not fail")
+            .genres;
+
+        #[block]
+        {
+            Artists::<T, I>::reindex_genres(&caller, &genres, &[]);
+        }
+
+        assert!(ArtistsOfGenre::<T, I>::iter_keys().all(|(_, id)| id != caller));
+
+        Ok(())
+    }
+
+    /// The description is stored as a fixed size hash, only its hashing scales with its length.
+    #[benchmark]
+    fn update_description(
+        n: Linear<0, { T::MaxHashedDescriptionLen::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+
+        let description = dumb_description_with_len::<T, I>(n).into_inner();
+        let new_data = UpdatableDataOf::<T, I>::Description(Some(description));
+
+        #[extrinsic_call]
+        update(RawOrigin::Signed(caller.clone().into()), new_data.clone());
+
+        assert_last_event::<T, I>(
+            Event::ArtistUpdated {
+                id: caller,
+                new_data,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn update_raw_description(
+        n: Linear<1, { T::MaxDescriptionLen::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
 
-        let new_data = UpdatableData::<ArtistAliasOf<T>>::Genres(UpdatableGenres::Clear);
+        let description: Vec<u8> = sp_std::iter::repeat(b'X').take(n as usize).collect();
+        let new_data = UpdatableDataOf::<T, I>::RawDescription(Some(description));
 
         #[extrinsic_call]
         update(RawOrigin::Signed(caller.clone().into()), new_data.clone());
 
-        assert_last_event::<T>(
+        assert_last_event::<T, I>(
             Event::ArtistUpdated {
                 id: caller,
                 new_data,
@@ -294,22 +597,88 @@ mod benchmarks {
         Ok(())
     }
 
-    /// Description is a hashed data so the length is fixed, we don't need to benchmark multiple lengths.
+    /// The availability flags have a fixed size.
     #[benchmark]
-    fn update_description() -> Result<(), BenchmarkError> {
+    fn update_open_to() -> Result<(), BenchmarkError> {
         let caller: T::AccountId = whitelisted_caller();
 
         T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
 
-        register_test_artist::<T>(caller.clone(), 1, 0, 0);
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+
+        let new_data = UpdatableDataOf::<T, I>::OpenTo(AvailabilityFlags(
+            Availability::Collaboration | Availability::Booking,
+        ));
+
+        #[extrinsic_call]
+        update(RawOrigin::Signed(caller.clone().into()), new_data.clone());
+
+        assert_last_event::<T, I>(
+            Event::ArtistUpdated {
+                id: caller,
+                new_data,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    /// The worst case replaces a main genre, moving the artist in the genres index.
+    #[benchmark]
+    fn update_main_genre() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+        Artists::<T, I>::update(
+            RawOrigin::Signed(caller.clone()).into(),
+            UpdatableDataOf::<T, I>::MainGenre(Some(MusicGenre::Classical(None))),
+        )
+        .expect("benchmark test should not fail");
+
+        let genre = MusicGenre::Classical(Some(ClassicalSubtype::Symphony));
+        let new_data = UpdatableDataOf::<T, I>::MainGenre(Some(genre));
+
+        #[extrinsic_call]
+        update(RawOrigin::Signed(caller.clone().into()), new_data.clone());
+
+        assert!(ArtistsOfGenre::<T, I>::contains_key(
+            StoredGenre::from(genre),
+            &caller
+        ));
+        assert!(!ArtistsOfGenre::<T, I>::contains_key(
+            StoredGenre::from(MusicGenre::Classical(None)),
+            &caller
+        ));
+        assert_last_event::<T, I>(
+            Event::ArtistUpdated {
+                id: caller,
+                new_data,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    /// The contact commitment is a hash so the length is fixed.
+    #[benchmark]
+    fn update_contact_hash() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
 
         let new_data =
-            UpdatableData::<ArtistAliasOf<T>>::Description(Some(b"new_description".to_vec()));
+            UpdatableDataOf::<T, I>::ContactHash(Some(T::Hashing::hash(b"contact@artist.com")));
 
         #[extrinsic_call]
         update(RawOrigin::Signed(caller.clone().into()), new_data.clone());
 
-        assert_last_event::<T>(
+        assert_last_event::<T, I>(
             Event::ArtistUpdated {
                 id: caller,
                 new_data,
@@ -329,15 +698,17 @@ mod benchmarks {
 
         T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
 
-        register_test_artist::<T>(caller.clone(), 1, 0, n);
+        register_test_artist::<T, I>(caller.clone(), 1, 0, n);
 
-        let new_data =
-            UpdatableData::<ArtistAliasOf<T>>::Assets(UpdatableAssets::Add(b"test asset".to_vec()));
+        let new_data = UpdatableDataOf::<T, I>::Assets(UpdatableAssets::Add(
+            AssetType::Audio,
+            b"test asset".to_vec(),
+        ));
 
         #[extrinsic_call]
         update(RawOrigin::Signed(caller.clone().into()), new_data.clone());
 
-        assert_last_event::<T>(
+        assert_last_event::<T, I>(
             Event::ArtistUpdated {
                 id: caller,
                 new_data,
@@ -355,17 +726,75 @@ mod benchmarks {
 
         T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
 
-        register_test_artist::<T>(caller.clone(), 1, 0, n);
+        register_test_artist::<T, I>(caller.clone(), 1, 0, n);
 
         // Always remove what we are sure this is the first element so there is always something
         // to remove even with only one genre existing in the benchmarking artist.
-        let new_data =
-            UpdatableData::<ArtistAliasOf<T>>::Assets(UpdatableAssets::Remove(b"asset0".to_vec()));
+        let new_data = UpdatableDataOf::<T, I>::Assets(UpdatableAssets::Remove(b"asset0".to_vec()));
+
+        #[extrinsic_call]
+        update(RawOrigin::Signed(caller.clone().into()), new_data.clone());
+
+        assert_last_event::<T, I>(
+            Event::ArtistUpdated {
+                id: caller,
+                new_data,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    /// `n` is the existing artist data.
+    #[benchmark]
+    fn update_remove_assets_at(
+        n: Linear<1, { T::MaxAssets::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, n);
+
+        // The first asset shifts every other one.
+        let new_data = UpdatableDataOf::<T, I>::Assets(UpdatableAssets::RemoveAt(0));
+
+        #[extrinsic_call]
+        update(RawOrigin::Signed(caller.clone().into()), new_data.clone());
+
+        assert_last_event::<T, I>(
+            Event::ArtistUpdated {
+                id: caller,
+                new_data,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    /// `n` is the existing artist data.
+    #[benchmark]
+    fn update_remove_assets_fingerprint(
+        n: Linear<1, { T::MaxAssets::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, n);
+
+        // The last asset is the furthest to look up.
+        let hash = Artists::<T, I>::get_artist_by_id(&caller)
+            .and_then(|artist| artist.assets.last().map(|asset| asset.hash.clone()))
+            .expect("benchmark test should not fail");
+        let new_data = UpdatableDataOf::<T, I>::Assets(UpdatableAssets::RemoveFingerprint(hash));
 
         #[extrinsic_call]
         update(RawOrigin::Signed(caller.clone().into()), new_data.clone());
 
-        assert_last_event::<T>(
+        assert_last_event::<T, I>(
             Event::ArtistUpdated {
                 id: caller,
                 new_data,
@@ -383,14 +812,45 @@ mod benchmarks {
 
         T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
 
-        register_test_artist::<T>(caller.clone(), 1, 0, n);
+        register_test_artist::<T, I>(caller.clone(), 1, 0, n);
+
+        let new_data = UpdatableDataOf::<T, I>::Assets(UpdatableAssets::Clear);
+
+        #[extrinsic_call]
+        update(RawOrigin::Signed(caller.clone().into()), new_data.clone());
+
+        assert_last_event::<T, I>(
+            Event::ArtistUpdated {
+                id: caller,
+                new_data,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    /// `n` is the existing artist data.
+    #[benchmark]
+    fn update_add_links(
+        n: Linear<0, { T::MaxLinks::get().saturating_sub(1) }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+        add_test_links::<T, I>(caller.clone(), n);
 
-        let new_data = UpdatableData::<ArtistAliasOf<T>>::Assets(UpdatableAssets::Clear);
+        let new_data = UpdatableDataOf::<T, I>::Links(UpdatableLinks::Add(
+            LinkType::Website,
+            dumb_link_with_index::<T, I>(n),
+        ));
 
         #[extrinsic_call]
         update(RawOrigin::Signed(caller.clone().into()), new_data.clone());
 
-        assert_last_event::<T>(
+        assert_last_event::<T, I>(
             Event::ArtistUpdated {
                 id: caller,
                 new_data,
@@ -401,6 +861,1686 @@ mod benchmarks {
         Ok(())
     }
 
+    /// `n` is the existing artist data.
+    #[benchmark]
+    fn update_remove_links(n: Linear<1, { T::MaxLinks::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+        add_test_links::<T, I>(caller.clone(), n);
+
+        // The last link is the worst case to look up.
+        let new_data = UpdatableDataOf::<T, I>::Links(UpdatableLinks::Remove(
+            LinkType::Website,
+            dumb_link_with_index::<T, I>(n - 1),
+        ));
+
+        #[extrinsic_call]
+        update(RawOrigin::Signed(caller.clone().into()), new_data.clone());
+
+        assert_last_event::<T, I>(
+            Event::ArtistUpdated {
+                id: caller,
+                new_data,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    /// `n` is the existing artist data.
+    #[benchmark]
+    fn update_clear_links(n: Linear<0, { T::MaxLinks::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+        add_test_links::<T, I>(caller.clone(), n);
+
+        let new_data = UpdatableDataOf::<T, I>::Links(UpdatableLinks::Clear);
+
+        #[extrinsic_call]
+        update(RawOrigin::Signed(caller.clone().into()), new_data.clone());
+
+        assert_last_event::<T, I>(
+            Event::ArtistUpdated {
+                id: caller,
+                new_data,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    /// `n` is the existing artist data.
+    #[benchmark]
+    fn update_add_aliases(
+        n: Linear<0, { T::MaxAliases::get().saturating_sub(1) }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist_with_alias::<T, I>(caller.clone(), 1, 0, 0, None);
+        add_test_aliases::<T, I>(caller.clone(), n);
+
+        let alias = dumb_alias_with_index::<T, I>(n);
+        let new_data = UpdatableDataOf::<T, I>::Aliases(UpdatableAliases::Add(alias.clone()));
+
+        #[extrinsic_call]
+        update(RawOrigin::Signed(caller.clone().into()), new_data.clone());
+
+        assert_eq!(ArtistAliasIndex::<T, I>::get(&alias), Some(caller.clone()));
+        assert_last_event::<T, I>(
+            Event::ArtistUpdated {
+                id: caller,
+                new_data,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    /// `n` is the existing artist data, the worst case removing the last alias.
+    #[benchmark]
+    fn update_remove_aliases(n: Linear<1, { T::MaxAliases::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist_with_alias::<T, I>(caller.clone(), 1, 0, 0, None);
+        add_test_aliases::<T, I>(caller.clone(), n);
+
+        let alias = dumb_alias_with_index::<T, I>(n - 1);
+        let new_data = UpdatableDataOf::<T, I>::Aliases(UpdatableAliases::Remove(alias.clone()));
+
+        #[extrinsic_call]
+        update(RawOrigin::Signed(caller.clone().into()), new_data.clone());
+
+        assert!(!ArtistAliasIndex::<T, I>::contains_key(&alias));
+        assert_last_event::<T, I>(
+            Event::ArtistUpdated {
+                id: caller,
+                new_data,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    /// `n` is the existing artist data.
+    #[benchmark]
+    fn update_clear_aliases(n: Linear<0, { T::MaxAliases::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist_with_alias::<T, I>(caller.clone(), 1, 0, 0, None);
+        add_test_aliases::<T, I>(caller.clone(), n);
+
+        let new_data = UpdatableDataOf::<T, I>::Aliases(UpdatableAliases::Clear);
+
+        #[extrinsic_call]
+        update(RawOrigin::Signed(caller.clone().into()), new_data.clone());
+
+        // Every alias has been removed from the aliases index
+        assert!(ArtistAliasIndex::<T, I>::iter_values().all(|id| id != caller));
+        assert_last_event::<T, I>(
+            Event::ArtistUpdated {
+                id: caller,
+                new_data,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn verify_artist() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+
+        let origin =
+            T::VerifierOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let kyc_evidence_hash = Some(T::Hashing::hash(b"kyc evidence"));
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            caller.clone(),
+            kyc_evidence_hash,
+        );
+
+        assert_last_event::<T, I>(
+            Event::ArtistVerified {
+                id: caller,
+                kyc_evidence_hash,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn update_kyc_evidence() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+
+        let origin =
+            T::VerifierOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        Artists::<T, I>::verify_artist(origin.clone(), caller.clone(), None)
+            .expect("benchmark test should not fail");
+
+        let kyc_evidence_hash = Some(T::Hashing::hash(b"new kyc evidence"));
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            caller.clone(),
+            kyc_evidence_hash,
+        );
+
+        assert_last_event::<T, I>(
+            Event::KycEvidenceUpdated {
+                id: caller,
+                kyc_evidence_hash,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_deposit_exemption() -> Result<(), BenchmarkError> {
+        let account: T::AccountId = whitelisted_caller();
+
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, account.clone(), true);
+
+        assert_last_event::<T, I>(
+            Event::DepositExemptionSet {
+                id: account,
+                exempt: true,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn freeze_contracts() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, caller.clone());
+
+        assert_last_event::<T, I>(Event::ContractsFrozen { id: caller }.into());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unfreeze_contracts() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        Artists::<T, I>::freeze_contracts(origin.clone(), caller.clone())
+            .expect("benchmark test should not fail");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, caller.clone());
+
+        assert_last_event::<T, I>(Event::ContractsUnfrozen { id: caller }.into());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn reserve_name() -> Result<(), BenchmarkError> {
+        let name: ArtistAliasOf<T, I> = dumb_name_with_capacity::<T, I>(T::MaxNameLen::get());
+
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, name.clone(), None);
+
+        assert_last_event::<T, I>(
+            Event::NameReserved {
+                name,
+                claimant: None,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unreserve_name() -> Result<(), BenchmarkError> {
+        let name: ArtistAliasOf<T, I> = dumb_name_with_capacity::<T, I>(T::MaxNameLen::get());
+
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        Artists::<T, I>::reserve_name(origin.clone(), name.clone(), None)
+            .expect("benchmark test should not fail");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, name.clone());
+
+        assert_last_event::<T, I>(Event::NameUnreserved { name }.into());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn ban_name() -> Result<(), BenchmarkError> {
+        let name: ArtistAliasOf<T, I> = dumb_name_with_capacity::<T, I>(T::MaxNameLen::get());
+
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, name.clone());
+
+        assert_last_event::<T, I>(Event::NameBanned { name }.into());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unban_name() -> Result<(), BenchmarkError> {
+        let name: ArtistAliasOf<T, I> = dumb_name_with_capacity::<T, I>(T::MaxNameLen::get());
+
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        Artists::<T, I>::ban_name(origin.clone(), name.clone())
+            .expect("benchmark test should not fail");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, name.clone());
+
+        assert_last_event::<T, I>(Event::NameUnbanned { name }.into());
+
+        Ok(())
+    }
+
+    /// `e` is the amount of submitted evidence.
+    ///
+    /// The pending queue is filled so the request gets the worst case of the overflow policy:
+    /// evicting the oldest request, or being the last one fitting in the queue.
+    #[benchmark]
+    fn submit_verification_evidence(
+        e: Linear<1, { T::MaxEvidence::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+
+        let queued = match T::VerificationQueueOverflow::get() {
+            QueueOverflowPolicy::EvictOldest => T::MaxPendingVerifications::get(),
+            QueueOverflowPolicy::Reject => T::MaxPendingVerifications::get().saturating_sub(1),
+        };
+        fill_verification_queue::<T, I>(queued, T::MaxEvidence::get());
+
+        let evidence: BoundedVec<T::Hash, T::MaxEvidence> = (0..e)
+            .map(|i| T::Hashing::hash(&i.to_le_bytes()))
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("benchmarking bounded vec");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone().into()), evidence.clone());
+
+        assert_last_event::<T, I>(
+            Event::VerificationEvidenceSubmitted {
+                id: caller,
+                evidence,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    /// `a` is the amount of assets, `l` of links and `c` of contracts moved from the duplicate.
+    #[benchmark]
+    fn merge_artists(
+        a: Linear<0, { T::MaxAssets::get() }>,
+        l: Linear<0, { T::MaxLinks::get() }>,
+        c: Linear<0, { T::MaxContracts::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let primary: T::AccountId = whitelisted_caller();
+        let duplicate: T::AccountId = account("duplicate", 0, 0);
+
+        T::Currency::set_balance(&primary, (MINIMUM_BALANCE * 100000u128).saturated_into());
+        T::Currency::set_balance(&duplicate, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(primary.clone(), 1, 0, 0);
+        register_test_artist_with_alias::<T, I>(duplicate.clone(), 1, 0, a, None);
+        add_test_links::<T, I>(duplicate.clone(), l);
+        for i in 0..c {
+            Artists::<T, I>::link_contract(&duplicate, account("contract", i, 0))
+                .expect("benchmark test should not fail");
+        }
+
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            primary.clone(),
+            duplicate.clone(),
+        );
+
+        assert_last_event::<T, I>(Event::ArtistsMerged { primary, duplicate }.into());
+
+        Ok(())
+    }
+
+    /// `n` is the length of the new main name.
+    #[benchmark]
+    fn rename(n: Linear<1, { T::MaxNameLen::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+
+        let old_name: ArtistAliasOf<T, I> = dumb_name_with_capacity::<T, I>(1);
+        let new_name: ArtistAliasOf<T, I> =
+            T::Name::try_from_bytes(vec![b'Y'; n.max(T::MinNameLen::get()) as usize])
+                .expect("benchmarking name");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone().into()), new_name.clone());
+
+        assert_last_event::<T, I>(
+            Event::ArtistRenamed {
+                id: caller,
+                old_name,
+                new_name,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    /// `n` is the length of the new main name.
+    #[benchmark]
+    fn approve_rename(n: Linear<1, { T::MaxNameLen::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+
+        let verifier =
+            T::VerifierOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        Artists::<T, I>::verify_artist(verifier, caller.clone(), None)
+            .expect("benchmark test should not fail");
+
+        let old_name: ArtistAliasOf<T, I> = dumb_name_with_capacity::<T, I>(1);
+        let new_name: ArtistAliasOf<T, I> =
+            T::Name::try_from_bytes(vec![b'Y'; n.max(T::MinNameLen::get()) as usize])
+                .expect("benchmarking name");
+        Artists::<T, I>::rename(RawOrigin::Signed(caller.clone()).into(), new_name.clone())
+            .expect("benchmark test should not fail");
+
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, caller.clone());
+
+        assert_last_event::<T, I>(
+            Event::ArtistRenamed {
+                id: caller,
+                old_name,
+                new_name,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn reject_rename() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+
+        let verifier =
+            T::VerifierOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        Artists::<T, I>::verify_artist(verifier, caller.clone(), None)
+            .expect("benchmark test should not fail");
+
+        let new_name: ArtistAliasOf<T, I> = dumb_name_with_capacity::<T, I>(T::MaxNameLen::get());
+        Artists::<T, I>::rename(RawOrigin::Signed(caller.clone()).into(), new_name)
+            .expect("benchmark test should not fail");
+
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, caller.clone());
+
+        assert_last_event::<T, I>(Event::RenameRejected { id: caller }.into());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn request_unregister() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone().into()));
+
+        let period: u32 = T::UnregisterPeriod::get();
+        assert_last_event::<T, I>(
+            Event::UnregisterRequested {
+                id: caller,
+                confirmable_at: System::<T>::block_number().saturating_add(period.into()),
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn cancel_unregister() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+        Artists::<T, I>::request_unregister(RawOrigin::Signed(caller.clone()).into())
+            .expect("benchmark test should not fail");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone().into()));
+
+        assert_last_event::<T, I>(Event::UnregisterCancelled { id: caller }.into());
+
+        Ok(())
+    }
+
+    /// `w` is the works already claimed.
+    #[benchmark]
+    fn claim_work(
+        w: Linear<0, { T::MaxWorks::get().saturating_sub(1) }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+        for i in 0..w {
+            Artists::<T, I>::claim_work(
+                RawOrigin::Signed(caller.clone()).into(),
+                T::Hashing::hash(&i.to_le_bytes()),
+            )
+            .expect("benchmark test should not fail");
+        }
+        let work = T::Hashing::hash(&w.to_le_bytes());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone().into()), work);
+
+        assert_last_event::<T, I>(Event::WorkClaimed { id: caller, work }.into());
+
+        Ok(())
+    }
+
+    /// `w` is the works already claimed, the disclaimed one being the last of them.
+    #[benchmark]
+    fn disclaim_work(w: Linear<1, { T::MaxWorks::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+        for i in 0..w {
+            Artists::<T, I>::claim_work(
+                RawOrigin::Signed(caller.clone()).into(),
+                T::Hashing::hash(&i.to_le_bytes()),
+            )
+            .expect("benchmark test should not fail");
+        }
+        let work = T::Hashing::hash(&(w - 1).to_le_bytes());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone().into()), work);
+
+        assert_last_event::<T, I>(Event::WorkDisclaimed { id: caller, work }.into());
+
+        Ok(())
+    }
+
+    /// `d` is the existing delegates.
+    #[benchmark]
+    fn add_delegate(
+        d: Linear<0, { T::MaxDelegates::get().saturating_sub(1) }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+        for i in 0..d {
+            Artists::<T, I>::add_delegate(
+                RawOrigin::Signed(caller.clone()).into(),
+                account("delegate", i, 0),
+            )
+            .expect("benchmark test should not fail");
+        }
+        let delegate: T::AccountId = account("delegate", d, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone().into()), delegate.clone());
+
+        assert_last_event::<T, I>(
+            Event::DelegateAdded {
+                id: caller,
+                delegate,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    /// `d` is the existing delegates.
+    #[benchmark]
+    fn remove_delegate(d: Linear<1, { T::MaxDelegates::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+        for i in 0..d {
+            Artists::<T, I>::add_delegate(
+                RawOrigin::Signed(caller.clone()).into(),
+                account("delegate", i, 0),
+            )
+            .expect("benchmark test should not fail");
+        }
+        // The last delegate is the worst case to look up.
+        let delegate: T::AccountId = account("delegate", d - 1, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone().into()), delegate.clone());
+
+        assert_last_event::<T, I>(
+            Event::DelegateRemoved {
+                id: caller,
+                delegate,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    /// `n` is the counted artists.
+    #[benchmark]
+    fn update_stats(n: Linear<0, 1000>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        for i in 0..n {
+            let id: T::AccountId = account("artist", i, 0);
+            T::Currency::set_balance(&id, (MINIMUM_BALANCE * 100000u128).saturated_into());
+            register_test_artist_with_alias::<T, I>(id, 1, 0, 0, None);
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), n);
+
+        assert_last_event::<T, I>(
+            Event::RegistryStatsUpdated {
+                total: n,
+                verified: 0,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn request_verification(e: Linear<1, { T::MaxEvidence::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+
+        let queued = match T::VerificationQueueOverflow::get() {
+            QueueOverflowPolicy::EvictOldest => T::MaxPendingVerifications::get(),
+            QueueOverflowPolicy::Reject => T::MaxPendingVerifications::get().saturating_sub(1),
+        };
+        fill_verification_queue::<T, I>(queued, T::MaxEvidence::get());
+
+        let evidence: BoundedVec<T::Hash, T::MaxEvidence> = (0..e)
+            .map(|i| T::Hashing::hash(&i.to_le_bytes()))
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("benchmarking bounded vec");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone().into()), evidence.clone());
+
+        assert_last_event::<T, I>(
+            Event::VerificationEvidenceSubmitted {
+                id: caller,
+                evidence,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn approve_verification() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+
+        let evidence: BoundedVec<T::Hash, T::MaxEvidence> = (0..T::MaxEvidence::get())
+            .map(|i| T::Hashing::hash(&i.to_le_bytes()))
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("benchmarking bounded vec");
+        Artists::<T, I>::request_verification(RawOrigin::Signed(caller.clone()).into(), evidence)
+            .expect("benchmark test should not fail");
+
+        let origin =
+            T::VerifierOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let kyc_evidence_hash = Some(T::Hashing::hash(b"kyc evidence"));
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            caller.clone(),
+            kyc_evidence_hash,
+        );
+
+        assert_last_event::<T, I>(
+            Event::ArtistVerified {
+                id: caller,
+                kyc_evidence_hash,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn reject_verification() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+
+        let evidence: BoundedVec<T::Hash, T::MaxEvidence> = (0..T::MaxEvidence::get())
+            .map(|i| T::Hashing::hash(&i.to_le_bytes()))
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("benchmarking bounded vec");
+        Artists::<T, I>::request_verification(RawOrigin::Signed(caller.clone()).into(), evidence)
+            .expect("benchmark test should not fail");
+
+        let origin =
+            T::VerifierOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            caller.clone(),
+            DepositPolicy::Slash,
+        );
+
+        assert_last_event::<T, I>(
+            Event::VerificationRejected {
+                id: caller,
+                policy: DepositPolicy::Slash,
+                deposit: T::VerificationDeposit::get(),
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn suspend_artist() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, caller.clone(), false);
+
+        assert_last_event::<T, I>(
+            Event::ArtistStatusChanged {
+                id: caller,
+                status: ArtistStatus::Suspended,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn reinstate_artist() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        Artists::<T, I>::suspend_artist(origin.clone(), caller.clone(), false)
+            .expect("benchmark test should not fail");
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, caller.clone());
+
+        assert_last_event::<T, I>(
+            Event::ArtistStatusChanged {
+                id: caller,
+                status: ArtistStatus::Active,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn export_artist() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+        let artist = ArtistOf::<T, I>::get(&caller).expect("benchmark test should not fail");
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, caller.clone());
+
+        assert_last_event::<T, I>(
+            Event::ArtistExported {
+                id: caller,
+                record: ArtistRecord::new(artist),
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn force_restore_artist(g: Linear<0, { T::MaxGenres::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, g, 0);
+
+        // Restoring the artist without its genres drops them all from the genres index
+        let mut artist = ArtistOf::<T, I>::get(&caller).expect("benchmark test should not fail");
+        artist.genres = Default::default();
+        let record = ArtistRecord::new(artist);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, record);
+
+        assert_last_event::<T, I>(Event::ArtistRestored { id: caller }.into());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn claim_profile() -> Result<(), BenchmarkError> {
+        let profile: T::AccountId = account("profile", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&profile, (MINIMUM_BALANCE * 100000u128).saturated_into());
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(
+            profile.clone(),
+            T::MaxNameLen::get(),
+            T::MaxGenres::get(),
+            0,
+        );
+        ClaimableProfiles::<T, I>::insert(&profile, T::ClaimWindow::get());
+        let voucher = T::BenchmarkHelper::sign_claim_voucher(
+            &Artists::<T, I>::claim_voucher_payload(&profile, &caller),
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), profile.clone(), voucher);
+
+        assert_last_event::<T, I>(
+            Event::ProfileClaimed {
+                profile,
+                claimant: caller,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn approve_profile_claim() -> Result<(), BenchmarkError> {
+        let profile: T::AccountId = account("profile", 0, 0);
+        let claimant: T::AccountId = account("claimant", 0, 0);
+
+        T::Currency::set_balance(&profile, (MINIMUM_BALANCE * 100000u128).saturated_into());
+        T::Currency::set_balance(&claimant, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(
+            profile.clone(),
+            T::MaxNameLen::get(),
+            T::MaxGenres::get(),
+            0,
+        );
+        ClaimableProfiles::<T, I>::insert(&profile, T::ClaimWindow::get());
+
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            profile.clone(),
+            claimant.clone(),
+        );
+
+        assert_last_event::<T, I>(Event::ProfileClaimed { profile, claimant }.into());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn release_expired_profile() -> Result<(), BenchmarkError> {
+        let profile: T::AccountId = account("profile", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&profile, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(
+            profile.clone(),
+            T::MaxNameLen::get(),
+            T::MaxGenres::get(),
+            0,
+        );
+        let expiry = System::<T>::block_number();
+        ClaimableProfiles::<T, I>::insert(&profile, expiry);
+        System::<T>::set_block_number(expiry + 1u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), profile.clone());
+
+        assert!(ArtistOf::<T, I>::get(&profile).is_none());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn register_for(
+        n: Linear<1, { T::MaxNameLen::get() }>,
+        g: Linear<0, { T::MaxGenres::get() }>,
+        a: Linear<0, { T::MaxAssets::get() }>,
+        d: Linear<0, { T::MaxHashedDescriptionLen::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::LabelOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let label = T::LabelOrigin::ensure_origin(origin.clone())
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let target: T::AccountId = account("target", 0, 0);
+
+        T::Currency::set_balance(&label, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        let name: ArtistAliasOf<T, I> = dumb_name_with_capacity::<T, I>(n);
+        let alias = Some(dumb_alias_with_capacity::<T, I>(n));
+        let genres: BoundedVec<MusicGenre, T::MaxGenres> = dumb_genres_with_capacity::<T, I>(g);
+        let description = Some(dumb_description_with_len::<T, I>(d));
+        let assets: BoundedVec<(AssetType, Vec<u8>), T::MaxAssets> =
+            dumb_assets_with_capacity::<T, I>(a);
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            target.clone(),
+            name.clone(),
+            alias,
+            genres,
+            description,
+            assets,
+        );
+
+        assert_last_event::<T, I>(
+            Event::ArtistRegistered {
+                id: target.clone(),
+                name,
+            }
+            .into(),
+        );
+        assert_eq!(
+            ArtistOf::<T, I>::get(&target)
+                .expect("benchmark test should not fail")
+                .depositor,
+            label
+        );
+
+        Ok(())
+    }
+
+    /// `b` is the beneficiaries.
+    #[benchmark]
+    fn set_royalty_splits(
+        b: Linear<1, { T::MaxRoyaltyBeneficiaries::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+        let splits = royalty_splits::<T, I>(b);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone().into()), splits.clone());
+
+        assert_last_event::<T, I>(Event::RoyaltySplitsSet { id: caller, splits }.into());
+
+        Ok(())
+    }
+
+    /// `b` is the existing beneficiaries.
+    #[benchmark]
+    fn clear_royalty_splits(
+        b: Linear<1, { T::MaxRoyaltyBeneficiaries::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+        Artists::<T, I>::set_royalty_splits(
+            RawOrigin::Signed(caller.clone()).into(),
+            royalty_splits::<T, I>(b),
+        )
+        .expect("benchmark test should not fail");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone().into()));
+
+        assert_last_event::<T, I>(Event::RoyaltySplitsCleared { id: caller }.into());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_artist_kind() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone().into()), ArtistKind::Group);
+
+        assert_last_event::<T, I>(
+            Event::ArtistKindChanged {
+                id: caller,
+                kind: ArtistKind::Group,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    /// `m` is the existing members.
+    #[benchmark]
+    fn add_member(
+        m: Linear<0, { T::MaxMembers::get().saturating_sub(1) }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_group::<T, I>(caller.clone(), m);
+        let member: T::AccountId = account("member", m, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone().into()), member.clone());
+
+        assert_last_event::<T, I>(Event::MemberInvited { id: caller, member }.into());
+
+        Ok(())
+    }
+
+    /// `m` is the existing members.
+    #[benchmark]
+    fn accept_membership(
+        m: Linear<0, { T::MaxMembers::get().saturating_sub(1) }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_group::<T, I>(caller.clone(), m);
+        let member: T::AccountId = account("member", m, 0);
+        Artists::<T, I>::add_member(RawOrigin::Signed(caller.clone()).into(), member.clone())
+            .expect("benchmark test should not fail");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(member.clone().into()), caller.clone());
+
+        assert_last_event::<T, I>(Event::MemberAdded { id: caller, member }.into());
+
+        Ok(())
+    }
+
+    /// `m` is the existing members.
+    #[benchmark]
+    fn remove_member(m: Linear<1, { T::MaxMembers::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_group::<T, I>(caller.clone(), m);
+        // The last member is the worst case to look up, settling its co-ownership share.
+        let member: T::AccountId = account("member", m - 1, 0);
+        join_test_ownership::<T, I>(caller.clone(), member.clone());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone().into()), member.clone());
+
+        assert_last_event::<T, I>(Event::MemberRemoved { id: caller, member }.into());
+
+        Ok(())
+    }
+
+    /// `m` is the existing members.
+    #[benchmark]
+    fn leave_group(m: Linear<1, { T::MaxMembers::get() }>) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_group::<T, I>(caller.clone(), m);
+        let member: T::AccountId = account("member", m - 1, 0);
+        join_test_ownership::<T, I>(caller.clone(), member.clone());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(member.clone().into()), caller.clone());
+
+        assert_last_event::<T, I>(Event::MemberRemoved { id: caller, member }.into());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn register_v2(
+        n: Linear<1, { T::MaxNameLen::get() }>,
+        g: Linear<0, { T::MaxGenres::get() }>,
+        a: Linear<0, { T::MaxAssets::get() }>,
+        l: Linear<0, { T::MaxLinks::get() }>,
+        d: Linear<0, { T::MaxHashedDescriptionLen::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        let name: ArtistAliasOf<T, I> = dumb_name_with_capacity::<T, I>(n);
+        let mut data = ArtistRegistrationData::<T, I>::new(
+            name.clone(),
+            Some(dumb_name_with_capacity::<T, I>(n)),
+            dumb_genres_with_capacity::<T, I>(g),
+            Some(dumb_description_with_len::<T, I>(d)),
+            dumb_assets_with_capacity::<T, I>(a),
+        );
+        data.contact_hash = Some(T::Hashing::hash(b"contact"));
+        for i in 0..l {
+            data.links
+                .try_push((LinkType::Website, dumb_link_with_index::<T, I>(i)))
+                .expect("benchmark test should not fail");
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone().into()), data);
+
+        assert_last_event::<T, I>(Event::ArtistRegistered { id: caller, name }.into());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn join_ownership() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let group: T::AccountId = account("group", 0, 0);
+
+        T::Currency::set_balance(&group, (MINIMUM_BALANCE * 100000u128).saturated_into());
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_group::<T, I>(group.clone(), 0);
+        Artists::<T, I>::add_member(RawOrigin::Signed(group.clone()).into(), caller.clone())
+            .expect("benchmark test should not fail");
+        Artists::<T, I>::accept_membership(RawOrigin::Signed(caller.clone()).into(), group.clone())
+            .expect("benchmark test should not fail");
+        let share = test_share::<T, I>();
+        Artists::<T, I>::offer_co_ownership(
+            RawOrigin::Signed(group.clone()).into(),
+            caller.clone(),
+            share,
+        )
+        .expect("benchmark test should not fail");
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone().into()),
+            group.clone(),
+            share,
+        );
+
+        assert_last_event::<T, I>(
+            Event::CoOwnerJoined {
+                id: group,
+                member: caller,
+                share,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn offer_co_ownership() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let member: T::AccountId = account("member", 0, 0);
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_group::<T, I>(caller.clone(), 1);
+        let share = test_share::<T, I>();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone().into()),
+            member.clone(),
+            share,
+        );
+
+        assert_last_event::<T, I>(
+            Event::CoOwnershipOffered {
+                id: caller,
+                member,
+                share,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn prune_pending_verification() -> Result<(), BenchmarkError> {
+        let lifetime = T::VerificationRequestLifetime::get();
+        if lifetime.is_zero() {
+            return Err(BenchmarkError::Weightless);
+        }
+
+        fill_verification_queue::<T, I>(1, T::MaxEvidence::get());
+        let id: T::AccountId = account("pending", 0, 0);
+        System::<T>::set_block_number(
+            System::<T>::block_number()
+                .saturating_add(lifetime)
+                .saturating_add(1u32.into()),
+        );
+        let mut meter = WeightMeter::new();
+
+        #[block]
+        {
+            Artists::<T, I>::prune_stale(&mut meter);
+        }
+
+        assert!(PendingVerifications::<T, I>::get(&id).is_none());
+        assert_last_event::<T, I>(
+            Event::StalePruned {
+                entry: StaleEntry::Verification(id),
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn prune_retired_name() -> Result<(), BenchmarkError> {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let name_hash = T::Hashing::hash(b"retired name");
+        RetiredNames::<T, I>::insert(name_hash, (owner, System::<T>::block_number()));
+        System::<T>::set_block_number(System::<T>::block_number().saturating_add(1u32.into()));
+        let mut meter = WeightMeter::new();
+
+        #[block]
+        {
+            Artists::<T, I>::prune_stale(&mut meter);
+        }
+
+        assert!(RetiredNames::<T, I>::get(name_hash).is_none());
+        assert_last_event::<T, I>(
+            Event::StalePruned {
+                entry: StaleEntry::RetiredName(name_hash),
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn transfer_ownership() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let new_owner: T::AccountId = account("new_owner", 0, 0);
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), T::MaxNameLen::get(), T::MaxGenres::get(), 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), new_owner.clone());
+
+        assert_eq!(
+            OwnershipOffers::<T, I>::get(&caller),
+            Some(new_owner.clone())
+        );
+        assert_last_event::<T, I>(
+            Event::OwnershipOffered {
+                id: caller,
+                new_owner,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn accept_ownership() -> Result<(), BenchmarkError> {
+        let artist: T::AccountId = account("artist", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&artist, (MINIMUM_BALANCE * 100000u128).saturated_into());
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(artist.clone(), T::MaxNameLen::get(), T::MaxGenres::get(), 0);
+        Artists::<T, I>::transfer_ownership(
+            RawOrigin::Signed(artist.clone()).into(),
+            caller.clone(),
+        )
+        .expect("benchmark test should not fail");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), artist.clone());
+
+        assert!(ArtistOf::<T, I>::contains_key(&caller));
+        assert_last_event::<T, I>(
+            Event::OwnershipTransferred {
+                from: artist,
+                to: caller,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_evidence_delegate() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let delegate: T::AccountId = account("agency", 0, 0);
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), Some(delegate.clone()));
+
+        assert_last_event::<T, I>(
+            Event::EvidenceDelegateSet {
+                id: caller,
+                delegate: Some(delegate),
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    /// `e` is the amount of submitted evidence.
+    ///
+    /// The pending queue is filled as for `submit_verification_evidence`.
+    #[benchmark]
+    fn submit_verification_evidence_as_delegate(
+        e: Linear<1, { T::MaxEvidence::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let artist: T::AccountId = account("artist", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&artist, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(artist.clone(), 1, 0, 0);
+        Artists::<T, I>::set_evidence_delegate(
+            RawOrigin::Signed(artist.clone()).into(),
+            Some(caller.clone()),
+        )
+        .expect("benchmark test should not fail");
+
+        let queued = match T::VerificationQueueOverflow::get() {
+            QueueOverflowPolicy::EvictOldest => T::MaxPendingVerifications::get(),
+            QueueOverflowPolicy::Reject => T::MaxPendingVerifications::get().saturating_sub(1),
+        };
+        fill_verification_queue::<T, I>(queued, T::MaxEvidence::get());
+
+        let evidence: BoundedVec<T::Hash, T::MaxEvidence> = (0..e)
+            .map(|i| T::Hashing::hash(&i.to_le_bytes()))
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("benchmarking bounded vec");
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            artist.clone(),
+            evidence.clone(),
+        );
+
+        assert_last_event::<T, I>(
+            Event::VerificationEvidenceSubmitted {
+                id: artist,
+                evidence,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    /// The route is the last of the `MaxContracts` contracts linked to the artist.
+    #[benchmark]
+    fn set_payout_route() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+        for i in 0..T::MaxContracts::get() {
+            Artists::<T, I>::link_contract(&caller, account("contract", i, 0))
+                .expect("benchmark test should not fail");
+        }
+        let route = Some(PayoutRoute::Contract(account(
+            "contract",
+            T::MaxContracts::get().saturating_sub(1),
+            0,
+        )));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), route.clone());
+
+        assert_last_event::<T, I>(Event::PayoutRouteSet { id: caller, route }.into());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn renew_verification() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+
+        let origin =
+            T::VerifierOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        Artists::<T, I>::verify_artist(origin.clone(), caller.clone(), None)
+            .expect("benchmark test should not fail");
+        System::<T>::set_block_number(System::<T>::block_number().saturating_add(1u32.into()));
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, caller.clone());
+
+        assert_eq!(
+            ArtistOf::<T, I>::get(&caller).and_then(|artist| artist.verified_at),
+            Some(System::<T>::block_number())
+        );
+        assert_last_event::<T, I>(Event::VerificationRenewed { id: caller }.into());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn expire_verification() -> Result<(), BenchmarkError> {
+        let validity = T::VerificationValidity::get();
+        if validity.is_zero() {
+            return Err(BenchmarkError::Weightless);
+        }
+
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), T::MaxNameLen::get(), 0, 0);
+
+        let origin =
+            T::VerifierOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        Artists::<T, I>::verify_artist(origin, caller.clone(), None)
+            .expect("benchmark test should not fail");
+        let verified_at = System::<T>::block_number();
+        System::<T>::set_block_number(verified_at.saturating_add(validity));
+        let mut meter = WeightMeter::new();
+
+        #[block]
+        {
+            Artists::<T, I>::expire_verifications(&mut meter);
+        }
+
+        assert_eq!(
+            ArtistOf::<T, I>::get(&caller).and_then(|artist| artist.verified_at),
+            None
+        );
+        assert!(PendingVerifications::<T, I>::contains_key(&caller));
+        assert_last_event::<T, I>(
+            Event::VerificationExpired {
+                id: caller,
+                verified_at,
+                requeued: true,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn poke_deposit() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(caller.clone(), 1, 0, 0);
+        // As if registered for free, the whole deposit being held by the poke
+        ArtistOf::<T, I>::mutate(&caller, |maybe_artist| {
+            if let Some(artist) = maybe_artist {
+                artist.deposit = Zero::zero();
+            }
+        });
+        let new_deposit = ArtistOf::<T, I>::get(&caller)
+            .expect("benchmark test should not fail")
+            .required_deposit();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), caller.clone());
+
+        assert_last_event::<T, I>(
+            Event::DepositUpdated {
+                id: caller,
+                old_deposit: Zero::zero(),
+                new_deposit,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn reap_expired(
+        n: Linear<1, { T::MaxNameLen::get() }>,
+        g: Linear<0, { T::MaxGenres::get() }>,
+        a: Linear<0, { T::MaxAssets::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let lifetime = T::UnverifiedLifetime::get().ok_or(BenchmarkError::Weightless)?;
+        let artist_id: T::AccountId = account("artist", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+
+        T::Currency::set_balance(&artist_id, (MINIMUM_BALANCE * 100000u128).saturated_into());
+        T::Currency::set_balance(&caller, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        register_test_artist::<T, I>(artist_id.clone(), n, g, a);
+        let deposit = *Artists::<T, I>::get_artist_by_id(&artist_id)
+            .expect("benchmark test should not fail")
+            .deposit();
+        let reward = T::ReapReward::get().mul_floor(deposit);
+
+        System::<T>::set_block_number(System::<T>::block_number().saturating_add(lifetime));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), artist_id.clone());
+
+        assert_last_event::<T, I>(
+            Event::ArtistReaped {
+                id: artist_id.clone(),
+                reaper: caller,
+                reward,
+                deposit: deposit.saturating_sub(reward),
+                depositor: artist_id,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn register_remote(
+        n: Linear<1, { T::MaxNameLen::get() }>,
+        g: Linear<0, { T::MaxGenres::get() }>,
+        a: Linear<0, { T::MaxAssets::get() }>,
+        l: Linear<0, { T::MaxLinks::get() }>,
+        d: Linear<0, { T::MaxHashedDescriptionLen::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let origin = T::RemoteRegistrationOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let (who, origin_chain) = T::RemoteRegistrationOrigin::ensure_origin(origin.clone())
+            .map_err(|_| BenchmarkError::Weightless)?;
+
+        T::Currency::set_balance(&who, (MINIMUM_BALANCE * 100000u128).saturated_into());
+
+        let mut data = ArtistRegistrationData::<T, I>::new(
+            dumb_name_with_capacity::<T, I>(n),
+            Some(dumb_name_with_capacity::<T, I>(n)),
+            dumb_genres_with_capacity::<T, I>(g),
+            Some(dumb_description_with_len::<T, I>(d)),
+            dumb_assets_with_capacity::<T, I>(a),
+        );
+        data.contact_hash = Some(T::Hashing::hash(b"contact"));
+        for i in 0..l {
+            data.links
+                .try_push((LinkType::Website, dumb_link_with_index::<T, I>(i)))
+                .expect("benchmark test should not fail");
+        }
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, data);
+
+        assert_last_event::<T, I>(
+            Event::RemoteArtistRegistered {
+                id: who,
+                origin_chain,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
     impl_benchmark_test_suite! {
         Artists,
         crate::mock::new_test_ext(),
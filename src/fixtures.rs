@@ -0,0 +1,138 @@
+// This file is part of Allfeat.
+
+// Copyright (C) Allfeat (FR) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deterministic artist fixtures, setting up registry states straight into the storage.
+//!
+//! ```ignore
+//! use pallet_artists::fixtures::ArtistFixture;
+//!
+//! let artist = ArtistFixture::<Runtime>::new()
+//!     .name("The Weeknd")
+//!     .verified()
+//!     .with_assets(3)
+//!     .insert(account);
+//! ```
+
+use super::*;
+use codec::Encode;
+use sp_std::marker::PhantomData;
+use sp_std::prelude::Vec;
+
+/// Builds an artist to insert into the storage as a genesis artist would be, with its indexes and
+/// without holding any deposit.
+pub struct ArtistFixture<T: Config<I>, I: 'static = ()> {
+    main_name: Vec<u8>,
+    alias: Option<Vec<u8>>,
+    genres: Vec<MusicGenre>,
+    description: Option<Vec<u8>>,
+    assets: u32,
+    verified: bool,
+    status: ArtistStatus,
+    _phantom: PhantomData<(T, I)>,
+}
+
+impl<T: Config<I>, I: 'static> Default for ArtistFixture<T, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Config<I>, I: 'static> ArtistFixture<T, I> {
+    /// An active and unverified artist named "Fixture", without any other data.
+    pub fn new() -> Self {
+        Self {
+            main_name: b"Fixture".to_vec(),
+            alias: None,
+            genres: Vec::new(),
+            description: None,
+            assets: 0,
+            verified: false,
+            status: ArtistStatus::Active,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Set the main name of the artist.
+    pub fn name(mut self, name: &str) -> Self {
+        self.main_name = name.as_bytes().to_vec();
+        self
+    }
+
+    /// Set the alias of the artist.
+    pub fn alias(mut self, alias: &str) -> Self {
+        self.alias = Some(alias.as_bytes().to_vec());
+        self
+    }
+
+    /// Add the passed genre to the artist.
+    pub fn genre(mut self, genre: MusicGenre) -> Self {
+        self.genres.push(genre);
+        self
+    }
+
+    /// Set the description of the artist, stored as a hash.
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.as_bytes().to_vec());
+        self
+    }
+
+    /// Give `count` distinct assets to the artist, the same for every fixture.
+    pub fn with_assets(mut self, count: u32) -> Self {
+        self.assets = count;
+        self
+    }
+
+    /// Verify the artist, indexing it by its main name.
+    pub fn verified(mut self) -> Self {
+        self.verified = true;
+        self
+    }
+
+    /// Set the moderation status of the artist.
+    pub fn status(mut self, status: ArtistStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Insert the artist under the passed account, returning it as stored.
+    ///
+    /// Panics if the artist is invalid or conflicts with the registry (already registered,
+    /// verified name or alias taken...), as a genesis artist would.
+    pub fn insert(self, id: T::AccountId) -> Artist<T, I> {
+        let assets: Vec<(AssetType, Vec<u8>)> = (0..self.assets)
+            .map(|i| (AssetType::Other, (b"fixture asset", i).encode()))
+            .collect();
+        let artist: GenesisArtistOf<T> = (
+            id.clone(),
+            self.main_name,
+            self.alias,
+            self.genres,
+            self.description,
+            assets,
+            self.verified,
+        );
+        Pallet::<T, I>::seed_artist(&artist, true);
+
+        ArtistOf::<T, I>::mutate(&id, |maybe_artist| {
+            let artist = maybe_artist
+                .as_mut()
+                .expect("fixture artist just got inserted");
+            artist.status = self.status;
+            artist.clone()
+        })
+    }
+}
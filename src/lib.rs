@@ -29,12 +29,15 @@
 //! ### Key Features
 //!
 //! 1. **Artist Registration**: Users can register themselves as artists, providing details like their main
-//! name, an alias, music genres, a description, and related assets.
+//! name, an alias, music genres, a description, and related assets, bundled in an `ArtistRegistrationData`
+//! along with their availability, contact hash and links.
 //!
 //! 2. **Storage**: Artist data is securely stored on-chain. Artists can be retrieved by their account
-//! ID.
+//! ID, and verified artists by their main name.
 //!
-//! 3. **Asset Handling**: Artist assets undergo hashing to ensure data integrity.
+//! 3. **Asset Handling**: Artist assets undergo hashing to ensure data integrity, and are typed
+//! (image, audio, video, document...) so consumers can tell them apart. Artists can also declare
+//! the `Fingerprint` of their assets and description computed off-chain (SHA-256, IPFS CID).
 //!
 //! 4. **Error Management**: Several error cases are covered, like when an artist tries to unregister while verified.
 //!
@@ -43,29 +46,175 @@
 //! This pallet offers multiple configurable constants:
 //! - `BaseDeposit`: The base deposit for registering as an artist.
 //! - `ByteDeposit`: The per-byte deposit for hashing data on-chain.
-//! - `UnregisterPeriod`: The time a registered artist must wait before being allowed to unregister.
+//! - `VerificationDeposit`: The deposit escrowed while a verification request is pending.
+//! - `UnregisterPeriod`: The time an artist must wait after requesting to unregister before confirming it.
+//! - `NameRetentionPeriod`: How long the name of an unregistered artist can only be registered back by it.
+//! - `AdminOrigin`: The origin allowed to administrate the registry (e.g: deposit exemptions).
 //! - `MaxNameLen`: Maximum allowable length for an artist's name.
+//! - `Name`: The type of the artists names, such as a `BoundedVec<u8, MaxNameLen>`.
+//! - `NameValidator`: The rules of the main names, such as `Utf8NameValidator` comparing them regardless of their case.
+//! - `MinNameLen`: Minimum length of an artist's main name.
+//! - `NamePrefixLen`: Length of the name prefixes the artists are indexed under for the searches, `0` disabling it.
 //! - `MaxGenres`: Maximum number of genres an artist can associate with.
+//! - `GenreCapacity`: Maximum number of genres an artist can have under each top-level genre.
+//! - `AliasCapacity`: Maximum number of aliases an artist can hold depending on its verification level.
 //! - `MaxAssets`: Maximum assets an artist can have.
+//! - `AssetBudget` / `AssetBudgetPeriod`: Bytes of new asset fingerprints an artist can add per period.
+//! - `MaxRegistrationsPerPeriod` / `RegistrationPeriod`: New registrations the registry accepts per period.
 //! - `MaxContracts`: Maximum contracts an artist can have.
+//! - `MaxWorks`: Maximum works an artist can claim.
+//! - `MaxArtistProofSize`: Proof size budget of reading a single artist, checked by the integrity test.
+//! - `MaxLinks`: Maximum external links an artist can have.
+//! - `MaxLinkLen`: Maximum length of an artist external link.
+//! - `MaxDescriptionLen`: Maximum length of an artist description stored on-chain.
+//! - `MaxHashedDescriptionLen`: Maximum length of an artist description passed to be stored as a hash.
+//! - `MaxDelegates`: Maximum delegates allowed to manage an artist profile.
+//! - `MaxUpdatesPerCall`: Maximum updates an artist can apply at once with `update_many`.
+//! - `MaxMembers`: Maximum members, accepted or invited, a group artist can have.
+//! - `MinCoOwnerShare`: Minimum share a group can offer one of its members to co-own it.
+//! - `MaxRoyaltyBeneficiaries`: Maximum beneficiaries an artist can split its royalties between.
+//! - `MaxEvidence`: Maximum evidence fingerprints attached to a verification request.
+//! - `MaxPendingVerifications`: Maximum verification requests waiting in the pending queue.
+//! - `VerificationQueueOverflow`: Whether a full pending queue rejects new requests or evicts the oldest one.
+//! - `VerificationRequestLifetime`: How long a verification request stays pending before getting pruned.
+//! - `VerificationValidity`: How long a verification lasts before lapsing, zero never expiring it.
+//! - `UnverifiedLifetime`: How long an artist can stay unverified before anyone can reap it, `None` never expiring it.
+//! - `ReapReward`: The share of the deposit of a reaped artist given to its reaper.
+//! - `MaxCleanupPerBlock`: Maximum stale entries the idle cleanup prunes, or artists it examines for an expired verification, in a block.
+//! - `OnArtistLifecycle`: Handlers called when an artist gets registered, unregistered or verified.
+//! - `MaxLifecycleHookWeight`: The weight limit of a lifecycle handler, charged by the calls triggering it.
+//! - `LifecycleHookFailure`: Whether a failing lifecycle handler is ignored or fails the artist call.
+//! - `LabelOrigin`: The origin of the labels allowed to register artists for other accounts, paying their deposit.
+//! - `RemoteRegistrationOrigin`: The origin of the accounts registering from another chain through XCM, returning the derived account and its `OriginChain`.
+//! - `Proxies`: Which accounts can register an artist profile on behalf of another one.
+//! - `AbuseFilter`: The anti-abuse filter rejecting or surcharging registrations and updates.
+//! - `ContractNotifier` / `MaxContractNotificationWeight`: Notifies the linked contracts of the artist status changes,
+//! within a weight limit.
+//! - `ClaimWindow`: How long the genesis profiles can be claimed by their real-world artist.
+//! - `ClaimSignature` / `ClaimAuthority`: The signature of the profile claim vouchers and the key signing them.
 //!
 //! ### Events
 //!
 //! - `ArtistRegistered`: Triggered when a new artist gets registered. Carries the artist's account ID and name.
+//! - `ArtistVerified`: Triggered when an artist gets verified. Carries the KYC evidence fingerprint, if any.
+//! - `VerificationRenewed` / `VerificationExpired`: Triggered when a verification gets renewed, or expires past the `VerificationValidity`.
+//! - `VerificationRequested` / `VerificationRejected`: Triggered when an artist requests its verification or gets rejected. Carries the escrowed, refunded or slashed deposit.
+//! - `ArtistExported` / `ArtistRestored`: Triggered when the root exports or restores an artist. The export carries the portable record.
+//! - `ProfileClaimed` / `ProfileReleased`: Triggered when a genesis profile gets claimed, or released unclaimed.
+//! - `SubmissionSurcharged`: Triggered when the `AbuseFilter` surcharges a registration or an update.
+//! - `ArtistBatchUpdated`: Triggered when an artist applies a batch of updates with `update_many`, instead of one
+//! `ArtistUpdated` per update.
+//! - `DepositUpdated`: Triggered when `poke_deposit` reprices the deposit of an artist. Carries the deposit before and after it.
+//! - `ArtistReaped`: Triggered when an expired unverified artist gets reaped. Carries the reward of the reaper and the refunded deposit.
+//! - `RemoteArtistRegistered`: Triggered when an artist registers from another chain. Carries the chain it comes from.
+//! - `ArtistDataChanged`: Triggered along with any update event, carrying the updated field value before and after it.
+//! - `GenreAdded` / `GenreRemoved` / `AssetAdded` / `AssetRemoved` / `AliasAdded` / `AliasRemoved`: Triggered along
+//! with the updates changing the genres, assets or aliases of an artist, one per added or removed item.
+//! - `ArtistStatusChanged`: Triggered when an artist gets suspended, banned or reinstated.
+//! - `ArtistKindChanged`: Triggered when an artist turns into a group or back into a solo artist.
+//! - `MemberInvited` / `MemberAdded` / `MemberRemoved`: Triggered when a group invites a member, the member accepts, or leaves the group.
+//! - `RoyaltySplitsSet` / `RoyaltySplitsCleared`: Triggered when an artist sets or clears its royalty beneficiaries.
+//! - `WorkClaimed` / `WorkDisclaimed`: Triggered when an artist claims a work or drops its claim.
+//! - `ArtistUnregistered` / `ArtistForceUnregistered`: Triggered when an artist gets removed. Carries the deposit amount refunded (or slashed) and the account it was held from.
 //!
 //! ### Errors
 //!
 //! A few of the potential errors include:
 //! - `NotUniqueGenre`: Raised when a genre appears multiple times in an artist's data.
-//! - `NameUnavailable`: Raised if the artist's name is already taken by a verified artist.
+//! - `GenreCapacityExceeded`: Raised when too many genres are tagged under the same top-level genre.
+//! - `UnsupportedGenre`: Raised when a genre can't be stored by its registry tag and subtag.
+//! - `TooManyAliases`: Raised when an artist sets more aliases than `MaxAliases`, or than allowed at its verification level.
+//! - `NotUniqueAlias`: Raised when an alias appears multiple times in an artist's data.
+//! - `NotUniqueLink` / `LinkTooLong`: Raised when an external link appears multiple times or exceeds `MaxLinkLen`.
+//! - `DescriptionTooLong`: Raised when a description to store on-chain exceeds `MaxDescriptionLen`.
+//! - `IndexOutOfBounds`: Raised when removing a genre or an asset past the end of the list.
+//! - `GenresFull` / `AssetsFull` / `ContractsFull` / `LinksFull` / `DelegatesFull` / `MembersFull`: Raised when the
+//! artist data would exceed the bound of the named field.
+//! - `GenreNotFound` / `AssetNotFound` / `AliasNotFound` / `ContractNotFound` / `LinkNotFound` / `RoyaltySplitsNotFound`: Raised when
+//! removing an element the artist doesn't have.
+//! - `NameNotReserved` / `NameNotBanned` / `RenameNotRequested`: Raised when lifting a name reservation or ban, or
+//! ruling on a rename, that doesn't exist.
+//! - `RenameAlreadyRequested`: Raised when a verified artist requests a rename while another one is pending.
+//! - `MainGenreDuplicated`: Raised when the main genre of an artist would also be one of its secondary genres.
+//! - `NameConfusable` / `NameTooShort`: Raised when a main name or an alias is confusable with the name of a verified artist, or
+//! shorter than `MinNameLen`.
+//! - `WorkAlreadyClaimed` / `WorkNotClaimed` / `WorksFull`: Raised when claiming a work twice, disclaiming a work
+//! the artist doesn't claim, or claiming more than `MaxWorks` works.
+//! - `NotGroup` / `GroupHasMembers`: Raised when adding members to a solo artist, or turning a group with members solo.
+//! - `AlreadyMember` / `NotMember` / `AlreadyInvited` / `NotInvited`: Raised on invalid group membership changes.
+//! - `NoCoOwnershipOffer` / `ShareTooLow`: Raised when joining a group ownership without a matching offer, or offering a share below `MinCoOwnerShare`.
+//! - `NoOwnershipOffer`: Raised when accepting a profile which wasn't offered to the caller.
+//! - `InvalidName`: Raised when the `NameValidator` rejects a main name or an alias, or when previewing a registration or normalizing a name with bytes that don't form a valid name.
+//! - `InvalidRoyaltySplits` / `DuplicateBeneficiary`: Raised when the royalty shares don't sum to 100% or a beneficiary appears twice.
+//! - `SubmissionRejected`: Raised when the `AbuseFilter` rejects a registration or an update.
+//! - `AssetBudgetExceeded`: Raised when an artist already added its `AssetBudget` of assets in the current period.
+//! - `TooManyRegistrations`: Raised when the registry already accepted its `MaxRegistrationsPerPeriod` registrations in the current period.
+//! - `StaleUpdate`: Raised when the artist got updated since the update nonce passed to `update_v2`.
+//! - `NoUpdates`: Raised when the batch passed to `update_many` is empty.
+//! - `PrefixTooShort`: Raised when searching the artists by a name prefix shorter than `NamePrefixLen`.
+//! - `NotExpired`: Raised when reaping an artist which got verified or whose `UnverifiedLifetime` hasn't elapsed yet.
+//! - `NameUnavailable`: Raised if the artist's name is already taken by a verified artist or is another artist alias.
+//! - `AliasUnavailable`: Raised if the artist's alias is already used by another artist.
+//! - `BannedName` / `ReservedName`: Raised if the artist's name is banned or reserved for someone else.
 //! - `NotRegistered`: If an account isn't registered as an artist.
 //! - `AlreadyRegistered`: If the account ID is already registered as an artist.
 //! - `IsVerified`: If the artist is verified and therefore cannot unregister.
 //! - `PeriodNotPassed`: If the unregister period isn't fully elapsed yet.
+//! - `UnregisterNotRequested`: If the artist confirms an unregistration it never requested.
+//! - `ArtistSuspended` / `ArtistBanned`: If the artist is suspended or banned by the moderation.
 //!
 //! ### Extrinsics
 //!
 //! - `register`: Allows a user to register as an artist by mapping the Account ID.
+//! - `register_for`: Allows a label or a proxy to register an artist for another account, paying its deposit.
+//! - `register_remote`: Allows an account of another chain to register as an artist through XCM, its derived account paying the deposit.
+//! - `request_unregister` / `cancel_unregister`: Allows an artist to start or abort its unregistration.
+//! - `confirm_unregister`: Allows an artist to unregister once the `UnregisterPeriod` passed.
+//! - `force_unregister`: Allows the `AdminOrigin` to remove an artist, slashing all or part of its deposit, or refunding it.
+//! - `update_v2`: Allows an artist to update its data, optionally checking it wasn't updated since the expected update nonce.
+//! - `update_many`: Allows an artist to apply several updates to its data at once, all-or-nothing.
+//! - `poke_deposit`: Allows anyone to reprice the deposit of an artist at the current deposit constants.
+//! - `reap_expired`: Allows anyone to unregister an artist left unverified past the `UnverifiedLifetime`, for a share of its deposit.
+//! - `force_update`: Allows the `AdminOrigin` to correct the data of an artist.
+//! - `reserve_name` / `unreserve_name`: Allows the `AdminOrigin` to reserve a name for a later verified claim.
+//! - `ban_name` / `unban_name`: Allows the `AdminOrigin` to ban a name from the registry.
+//! - `freeze_contracts` / `unfreeze_contracts`: Allows the `AdminOrigin` to block contracts linking for an artist.
+//! - `verify_artist`: Allows the `VerifierOrigin` to verify an artist, attaching an opaque KYC evidence hash.
+//! - `merge_artists`: Allows the `AdminOrigin` to merge a duplicate artist into its primary one.
+//! - `rename`: Allows an artist to change its main name, verified artists needing the `AdminOrigin` approval.
+//! - `approve_rename` / `reject_rename`: Allows the `AdminOrigin` to rule on the renames of verified artists.
+//! - `set_deposit_exemption`: Allows the `AdminOrigin` to exempt an account from deposits.
+//! - `submit_verification_evidence`: Allows an artist to attach evidence fingerprints to its pending verification.
+//! - `request_verification`: Allows an artist to request its verification, escrowing the `VerificationDeposit`.
+//! - `approve_verification` / `reject_verification`: Allows the `VerifierOrigin` to rule on a verification request, slashing the escrow of abusive ones.
+//! - `add_delegate` / `remove_delegate`: Allows an artist to let other accounts, such as its label, manage its profile.
+//! - `update_as_delegate`: Allows a delegate to update the data of its artist.
+//! - `offer_co_ownership` / `join_ownership`: Allows a group to offer one of its members a co-ownership share, which the member accepts.
+//! - `claim_work` / `disclaim_work`: Allows an artist to claim the works it took part in, or drop a claim.
+//! - `set_payout_route`: Allows an artist to route its payouts to an account or a linked contract, resolved by the `PayoutRouter`.
+//! - `update_stats`: Allows anyone to refresh the registry statistics, a bounded amount of artists at a time.
+//! - `update_kyc_evidence`: Allows the `VerifierOrigin` to replace the KYC evidence hash on re-verification.
+//! - `renew_verification`: Allows the `VerifierOrigin` to renew the verification of an artist before it expires.
+//! - `suspend_artist` / `reinstate_artist`: Allows the `AdminOrigin` to suspend, ban or reinstate an artist.
+//! - `claim_profile` / `approve_profile_claim`: Allows an artist to take over its genesis profile, with a voucher or the `AdminOrigin` approval.
+//! - `release_expired_profile`: Allows anyone to release a genesis profile left unclaimed past its claim window.
+//! - `export_artist` / `force_restore_artist`: Allows the root to snapshot an artist and restore it after a faulty migration.
+//!
+//! ### Mock runtime
+//!
+//! With the `mock-runtime` feature, the [`mock`] module exports a ready-made test runtime including
+//! this pallet, so downstream pallets can set up a working test environment with one import.
+//!
+//! ### EVM attestations
+//!
+//! With the `evm-attestation` feature, the [`attestation`] module keeps a compact record of every
+//! verified artist in storage, read by an EVM system contract so Solidity dApps can trust the
+//! verifications without an off-chain oracle.
+//!
+//! ### Runtime API
+//!
+//! With the `runtime-api` feature, the [`runtime_api::ArtistsApi`] allows dApps and indexers to
+//! query artists by account ID, verified name, alias or genre without decoding raw storage.
 //!
 //! ### Wrapping Up
 //!
@@ -76,9 +225,20 @@
 #![allow(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "evm-attestation")]
+pub mod attestation;
 mod benchmarking;
-#[cfg(test)]
-mod mock;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod fixtures;
+pub mod migrations;
+#[cfg(any(test, feature = "mock-runtime"))]
+pub mod mock;
+#[cfg(feature = "precompile")]
+pub mod precompile;
+#[cfg(feature = "remote-origin")]
+pub mod remote_origin;
+#[cfg(feature = "runtime-api")]
+pub mod runtime_api;
 #[cfg(test)]
 mod tests;
 mod types;
@@ -86,29 +246,51 @@ pub mod weights;
 
 use weights::WeightInfo;
 
-use frame_support::pallet_prelude::{DispatchResultWithPostInfo, Get, Weight};
+use codec::{Encode, EncodeLike};
+use frame_support::ensure;
+use frame_support::pallet_prelude::{DispatchResult, DispatchResultWithPostInfo, Get, Weight};
 use frame_support::BoundedVec;
 use genres_registry::MusicGenre;
-pub use types::Artist;
+pub use types::normalize_name;
+pub use types::{
+    AbuseVerdict, AliasCapacity, Artist, ArtistAsset, ArtistCallGroup, ArtistField, ArtistKind,
+    ArtistRecord, ArtistRecordV1, ArtistRecordV2, ArtistRecordV3, ArtistRegistrationData,
+    ArtistStatus, ArtistView, ArtistsInspect, AssetType, Availability, AvailabilityFlags,
+    ContractNotice, DepositBreakdown, DepositPolicy, Fingerprint, GenreCapacity, HookFailurePolicy,
+    InvalidName, LinkType, NameLike, NameValidator, NotifyContract, OnArtistRegistered,
+    OnArtistUnregistered, OnArtistVerified, OriginChain, PayoutRoute, PayoutRouter, PayoutTarget,
+    ProxyCheck, QueueOverflowPolicy, RegistryStats, ScoreRegistration, StaleEntry, StoredGenre,
+    UnregisterBlocker, UpdatableAliases, UpdatableAssets, UpdatableData, UpdatableGenres,
+    UpdatableLinks, Utf8NameValidator, VerificationRequest, VersionedArtistRecord,
+    CLAIM_VOUCHER_CONTEXT, LATEST_RECORD_VERSION,
+};
+
+#[cfg(feature = "runtime-benchmarks")]
+pub use types::BenchmarkHelper;
 
-use crate::types::{AccountIdOf, BalanceOf};
-use crate::types::{ArtistAliasOf, UpdatableAssets, UpdatableData, UpdatableGenres};
+use crate::types::VersionedArtistRecordOf;
+use crate::types::{AccountIdOf, BalanceOf, ClaimSignerOf};
+use crate::types::{ArtistAliasOf, FingerprintOf, RoyaltySplitsOf, UpdatableDataOf};
 use crate::Event::ArtistForceUnregistered;
 use crate::Event::ArtistRegistered;
 use crate::Event::{ArtistUnregistered, ArtistUpdated};
+use crate::Event::{ArtistVerified, KycEvidenceUpdated};
+use frame_support::storage::{with_transaction, TransactionOutcome};
 use frame_support::traits::fungible::Credit;
 use frame_support::traits::fungible::{BalancedHold, Inspect, MutateHold};
 use frame_support::traits::tokens::fungible::hold::Inspect as InspectHold;
-use frame_support::traits::tokens::Precision;
+use frame_support::traits::tokens::{Fortitude, Precision, Restriction};
 use frame_support::traits::Imbalance;
 use frame_support::traits::OnUnbalanced;
+use frame_support::weights::WeightMeter;
 use frame_support::PalletId;
-use sp_runtime::traits::Zero;
-use sp_runtime::SaturatedConversion;
+use sp_runtime::traits::{Hash, IdentifyAccount, Verify, Zero};
+use sp_runtime::{DispatchError, Perbill, SaturatedConversion, Saturating};
 
 use frame_system::EnsureSignedBy;
 use sp_runtime::traits::AccountIdConversion;
 
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 use sp_std::prelude::*;
 
 pub use pallet::*;
@@ -122,25 +304,32 @@ pub mod pallet {
     use frame_system::pallet_prelude::*;
 
     #[pallet::pallet]
-    pub struct Pallet<T>(_);
+    #[pallet::storage_version(migrations::STORAGE_VERSION)]
+    pub struct Pallet<T, I = ()>(_);
 
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config<I: 'static = ()>: frame_system::Config {
         /// The Artists pallet id, used for deriving its sovereign account ID.
         #[pallet::constant]
         type PalletId: Get<PalletId>;
 
         /// The overarching event type.
-        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type RuntimeEvent: From<Event<Self, I>>
+            + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         #[cfg(not(feature = "runtime-benchmarks"))]
         /// The way to handle the storage deposit cost of Artist creation
+        ///
+        /// The deposits can be held in a non-native asset with
+        /// `fungible::ItemOf<Assets, DepositAssetId, AccountId>`, adapting a `fungibles`
+        /// implementation supporting holds to the asset picked by `DepositAssetId`.
         type Currency: Inspect<Self::AccountId>
             + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
             + BalancedHold<Self::AccountId>;
 
         #[cfg(feature = "runtime-benchmarks")]
-        /// The way to handle the storage deposit cost of Artist creation
+        /// The way to handle the storage deposit cost of Artist creation, possibly in a
+        /// non-native asset through `fungible::ItemOf`.
         /// Include Currency trait to have access to NegativeImbalance
         type Currency: Mutate<Self::AccountId>
             + Inspect<Self::AccountId>
@@ -148,16 +337,28 @@ pub mod pallet {
             + BalancedHold<Self::AccountId>;
 
         /// The base deposit for registering as an artist on chain.
-        type BaseDeposit: Get<BalanceOf<Self>>;
+        type BaseDeposit: Get<BalanceOf<Self, I>>;
 
         /// The per-byte deposit for placing data hashes on chain.
-        type ByteDeposit: Get<BalanceOf<Self>>;
+        type ByteDeposit: Get<BalanceOf<Self, I>>;
+
+        /// The deposit escrowed by an artist requesting its verification, refunded once the
+        /// request is approved or rejected unless the verifier slashes it.
+        type VerificationDeposit: Get<BalanceOf<Self, I>>;
 
         /// The overarching hold reason.
-        type RuntimeHoldReason: From<HoldReason>;
+        type RuntimeHoldReason: From<HoldReason<I>>;
+
+        /// The origin allowed to verify artists and attach their KYC evidence fingerprint.
+        ///
+        /// Its calls are fee-free, it must not be reachable by any signed account.
+        type VerifierOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
-        /// The Root Origin that allow force unregistering artists.
-        type RootOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// The origin allowed to administrate the registry, such as exempting accounts from deposits
+        /// or moderating artists profiles violating terms.
+        ///
+        /// Its calls are fee-free, it must not be reachable by any signed account.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
         /// Handler for the unbalanced reduction when slashing an artists deposit.
         type Slash: OnUnbalanced<Credit<Self::AccountId, Self::Currency>>;
@@ -166,320 +367,4912 @@ pub mod pallet {
         #[pallet::constant]
         type UnregisterPeriod: Get<u32>;
 
-        /// The maximum length of the artist name.
+        /// How long the main name of an unregistered artist stays retired from the registrations,
+        /// only its previous owner being able to register it back until then.
+        #[pallet::constant]
+        type NameRetentionPeriod: Get<BlockNumberFor<Self>>;
+
+        /// The maximum length of the artist name, bounding the weight of the names related calls.
         #[pallet::constant]
         type MaxNameLen: Get<u32>;
 
+        /// The type of the artists main names and aliases, usually `BoundedVec<u8, MaxNameLen>`
+        /// unless the runtime needs normalized strings or numeric handles.
+        ///
+        /// Changing it on a live chain needs a migration of the stored names. The benchmarks build
+        /// names from up to `MaxNameLen` bytes.
+        type Name: Parameter + MaxEncodedLen + NameLike;
+
+        /// Checks and canonicalizes the main names the artists register or rename to, and tells
+        /// apart the names confusable with each other, such as `Utf8NameValidator` rejecting the
+        /// invisible characters and comparing the names regardless of their case.
+        type NameValidator: NameValidator;
+
+        /// The minimum length of the artists main names, once canonicalized by the
+        /// `NameValidator`.
+        #[pallet::constant]
+        type MinNameLen: Get<u32>;
+
+        /// The length, in bytes, of the name prefixes the artists are indexed under for the
+        /// searches by name prefix, `0` disabling the index.
+        ///
+        /// The searches take prefixes at least as long, so the names shorter than it, allowed by
+        /// a lower `MinNameLen`, can't be found by prefix.
+        #[pallet::constant]
+        type NamePrefixLen: Get<u32>;
+
         /// The maximum amount of genres that an artist can have.
         #[pallet::constant]
         type MaxGenres: Get<u32>;
 
+        /// The maximum amount of genres that an artist can have under each top-level genre, such
+        /// as curating a few electronic subgenres but a single classical one.
+        ///
+        /// The benchmarks assume a single top-level genre can fill `MaxGenres`.
+        type GenreCapacity: GenreCapacity;
+
+        /// The maximum amount of aliases that an artist can have.
+        #[pallet::constant]
+        type MaxAliases: Get<u32>;
+
+        /// The maximum amount of aliases that an artist can hold depending on its verification
+        /// level, such as no alias until being verified, within `MaxAliases`.
+        ///
+        /// The benchmarks assume an unverified artist can hold `MaxAliases` aliases.
+        type AliasCapacity: AliasCapacity;
+
         /// The maximum amount of assets that an artist can have.
         #[pallet::constant]
         type MaxAssets: Get<u32>;
 
+        /// The bytes of new asset fingerprints an artist can add within each `AssetBudgetPeriod`,
+        /// zero disabling the budget. The `AdminOrigin` isn't bound by it.
+        #[pallet::constant]
+        type AssetBudget: Get<u32>;
+
+        /// The length of the period the `AssetBudget` of an artist applies to, starting from its
+        /// first asset addition once the previous one passed.
+        #[pallet::constant]
+        type AssetBudgetPeriod: Get<BlockNumberFor<Self>>;
+
+        /// The new registrations the whole registry accepts within each `RegistrationPeriod`,
+        /// zero disabling the cap. Registrations dispatched by the `AdminOrigin` aren't bound by
+        /// it.
+        #[pallet::constant]
+        type MaxRegistrationsPerPeriod: Get<u32>;
+
+        /// The length of the period the `MaxRegistrationsPerPeriod` applies to, starting from the
+        /// first registration once the previous one passed. A period of one block caps the
+        /// registrations per block.
+        #[pallet::constant]
+        type RegistrationPeriod: Get<BlockNumberFor<Self>>;
+
         /// The maximum amount of contracts that an artist can have.
         #[pallet::constant]
         type MaxContracts: Get<u32>;
 
+        /// The maximum amount of works (songs, albums...) that an artist can claim.
+        #[pallet::constant]
+        type MaxWorks: Get<u32>;
+
+        /// The maximum amount of external links (Spotify ID, ISNI...) that an artist can have.
+        #[pallet::constant]
+        type MaxLinks: Get<u32>;
+
+        /// The maximum length of an artist external link.
+        #[pallet::constant]
+        type MaxLinkLen: Get<u32>;
+
+        /// The maximum length of an artist description stored on-chain, longer ones being only
+        /// stored as a hash.
+        #[pallet::constant]
+        type MaxDescriptionLen: Get<u32>;
+
+        /// The maximum length of an artist description passed to be stored as a hash, the weight
+        /// of hashing it scaling with its length.
+        #[pallet::constant]
+        type MaxHashedDescriptionLen: Get<u32>;
+
+        /// The proof size budget, in bytes, of reading a single artist. The integrity test checks
+        /// it against the worst-case encoded artist, bounded by `MaxContracts` above all.
+        #[pallet::constant]
+        type MaxArtistProofSize: Get<u32>;
+
+        /// The maximum amount of delegates allowed to manage an artist profile.
+        #[pallet::constant]
+        type MaxDelegates: Get<u32>;
+
+        /// The maximum amount of updates an artist can apply at once with `update_many`.
+        #[pallet::constant]
+        type MaxUpdatesPerCall: Get<u32>;
+
+        /// The maximum amount of members of a group artist, pending invitations included.
+        #[pallet::constant]
+        type MaxMembers: Get<u32>;
+
+        /// The minimum share a group can offer one of its members to hold as a co-owner.
+        #[pallet::constant]
+        type MinCoOwnerShare: Get<BalanceOf<Self, I>>;
+
+        /// The maximum amount of beneficiaries an artist can split its royalties between.
+        #[pallet::constant]
+        type MaxRoyaltyBeneficiaries: Get<u32>;
+
+        /// The maximum amount of evidence fingerprints attached to a verification request.
+        #[pallet::constant]
+        type MaxEvidence: Get<u32>;
+
+        /// The maximum amount of verification requests waiting in the pending queue.
+        #[pallet::constant]
+        type MaxPendingVerifications: Get<u32>;
+
+        /// What to do with a new verification request when the pending queue is full.
+        #[pallet::constant]
+        type VerificationQueueOverflow: Get<QueueOverflowPolicy>;
+
+        /// How long a verification request can stay pending since its last evidence submission,
+        /// before the idle cleanup drops it and refunds its deposits. Zero keeps the requests
+        /// pending until decided.
+        #[pallet::constant]
+        type VerificationRequestLifetime: Get<BlockNumberFor<Self>>;
+
+        /// How long a verification lasts since the artist got verified or renewed, before it lapses
+        /// and the artist gets queued back in the pending verifications to be verified again. Zero
+        /// never expires the verifications.
+        #[pallet::constant]
+        type VerificationValidity: Get<BlockNumberFor<Self>>;
+
+        /// How long an artist can stay registered without getting verified, before anyone can
+        /// reap it with `reap_expired`. `None` never expires the unverified artists.
+        #[pallet::constant]
+        type UnverifiedLifetime: Get<Option<BlockNumberFor<Self>>>;
+
+        /// The share of the deposit of a reaped artist given to the account reaping it, the rest
+        /// being refunded to its depositor.
+        #[pallet::constant]
+        type ReapReward: Get<Perbill>;
+
+        /// The maximum amount of stale entries the idle cleanup prunes in a block, and of retired
+        /// names and verified artists it examines, within the weight left in the block.
+        #[pallet::constant]
+        type MaxCleanupPerBlock: Get<u32>;
+
+        /// Handlers called on the artists lifecycle (registration, unregistration and
+        /// verification), so other pallets can react to it (e.g: creating a royalties account).
+        /// `()` does nothing.
+        ///
+        /// They are not called for the genesis artists. They are called once the registry storage
+        /// is up to date, so they can call back into the pallet, and metered up to
+        /// `T::MaxLifecycleHookWeight`.
+        type OnArtistLifecycle: OnArtistRegistered<Self::AccountId>
+            + OnArtistUnregistered<Self::AccountId>
+            + OnArtistVerified<Self::AccountId>;
+
+        /// The maximum weight an `OnArtistLifecycle` handler can consume, charged up front by the
+        /// pallet calls for every handler they trigger.
+        #[pallet::constant]
+        type MaxLifecycleHookWeight: Get<Weight>;
+
+        /// What to do when an `OnArtistLifecycle` handler fails, or goes over
+        /// `T::MaxLifecycleHookWeight`.
+        #[pallet::constant]
+        type LifecycleHookFailure: Get<HookFailurePolicy>;
+
+        /// The origin of the music labels allowed to register artist profiles for other accounts
+        /// with `register_for`, paying their deposit.
+        type LabelOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+        /// The origin of the accounts registering from another chain with `register_remote`,
+        /// such as an XCM `Transact` converted by `pallet_xcm::EnsureXcm`, e.g. through the
+        /// `remote_origin::EnsureRemoteAccount` adapter of the `remote-origin` feature.
+        ///
+        /// It returns the account derived from the sending location, e.g. through
+        /// `HashedDescription`, owning the profile and paying its deposit, along with the chain
+        /// the location belongs to. It must never return an account signing locally.
+        type RemoteRegistrationOrigin: EnsureOrigin<
+            Self::RuntimeOrigin,
+            Success = (Self::AccountId, OriginChain),
+        >;
+
+        /// The proxies allowed to register an artist profile for the account they act for with
+        /// `register_for`, such as the ones of `pallet-proxy`. `()` allows none.
+        type Proxies: ProxyCheck<Self::AccountId>;
+
+        /// The anti-abuse filter scoring the registrations and updates, which can reject them or
+        /// surcharge their deposit. `()` accepts everything.
+        type AbuseFilter: ScoreRegistration<Self, I>;
+
+        /// Notifies the contracts linked to an artist when it gets suspended, banned, reinstated
+        /// or unregistered, so royalties contracts can pause their payouts. `()` notifies
+        /// nothing.
+        type ContractNotifier: NotifyContract<Self::AccountId>;
+
+        /// The maximum weight the notifications of the contracts linked to an artist can consume
+        /// on a status change, charged up front by the calls changing it.
+        #[pallet::constant]
+        type MaxContractNotificationWeight: Get<Weight>;
+
+        /// How long the profiles seeded at genesis on behalf of real-world artists can be claimed,
+        /// unclaimed profiles being released once it passed.
+        #[pallet::constant]
+        type ClaimWindow: Get<BlockNumberFor<Self>>;
+
+        /// The signature of the vouchers letting an artist claim its genesis profile.
+        type ClaimSignature: Parameter + Verify;
+
+        /// The key of the entity signing the profile claim vouchers, once it checked the
+        /// real-world identity of the claimant.
+        type ClaimAuthority: Get<ClaimSignerOf<Self, I>>;
+
+        /// Signing of the profile claim vouchers by the `ClaimAuthority` for the benchmarks.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::ClaimSignature>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
 
     /// A reason for the pallet contracts placing a hold on funds.
+    ///
+    /// Only `ArtistRegistration`, `ArtistData` and `VerificationRequest` are used to hold new
+    /// deposits. The per-field reasons are kept for the funds held before, which are lazily moved
+    /// to `ArtistData` on the next deposit change of their artist.
     #[pallet::composite_enum]
-    pub enum HoldReason {
+    pub enum HoldReason<I: 'static = ()> {
         /// The Pallet has reserved it for registering the base Artist data.
         ArtistRegistration,
-        /// The Pallet has reserved it for storage assets deposit.
+        /// Legacy reason of the storage assets deposit.
         ArtistAssets,
-        /// The Pallet has reserved it for storage description  deposit.
+        /// Legacy reason of the storage description deposit.
         ArtistDescription,
-        /// The Pallet has reserved it for storage main name deposit.
+        /// Legacy reason of the storage main name deposit.
         ArtistName,
-        /// The Pallet has reserved it for storage alias deposit.
+        /// Legacy reason of the storage alias deposit.
         ArtistAlias,
+        /// Legacy reason of the storage linked contracts deposit.
+        ArtistContracts,
+        /// Legacy reason of the storage verification evidence deposit.
+        ArtistVerification,
+        /// The Pallet has reserved it for the storage deposit of the artist data: names,
+        /// description, assets, contracts and verification evidence.
+        ArtistData,
+        /// The Pallet has reserved it as the escrow of a pending verification request.
+        VerificationRequest,
+        /// The Pallet has reserved it as the share of a co-owner member into its group artist.
+        GroupShare,
+    }
+
+    impl<I: 'static> HoldReason<I> {
+        /// The per-field reasons used before the artist data deposits were held under
+        /// `ArtistData`.
+        pub(crate) fn legacy_data_reasons() -> [HoldReason<I>; 6] {
+            [
+                HoldReason::ArtistAssets,
+                HoldReason::ArtistAlias,
+                HoldReason::ArtistDescription,
+                HoldReason::ArtistName,
+                HoldReason::ArtistContracts,
+                HoldReason::ArtistVerification,
+            ]
+        }
     }
 
     #[pallet::type_value]
-    pub fn DefaultAddress<T: Config>() -> T::AccountId {
+    pub fn DefaultAddress<T: Config<I>, I: 'static>() -> T::AccountId {
         let id: T::AccountId = T::PalletId::get().into_account_truncating();
-        Address::<T>::set(id.clone());
+        Address::<T, I>::set(id.clone());
         id
     }
 
+    /// Registered artists, counted so the registry can be paged through with its size known.
+    #[pallet::storage]
+    pub(super) type ArtistOf<T: Config<I>, I: 'static = ()> =
+        CountedStorageMap<_, Twox64Concat, T::AccountId, Artist<T, I>>;
+
+    /// Verified artists indexed by their main name, a verified name can't be claimed by anyone else.
+    #[pallet::storage]
+    pub(super) type ArtistNameOf<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::Name, T::AccountId>;
+
+    /// Registered artists indexed by each of their genres, so they can be enumerated per genre
+    /// without iterating every artist.
+    #[pallet::storage]
+    pub(super) type ArtistsOfGenre<T: Config<I>, I: 'static = ()> =
+        StorageDoubleMap<_, Blake2_128Concat, StoredGenre, Twox64Concat, T::AccountId, ()>;
+
+    /// Artists indexed by their alias, an alias can't be used by two artists nor be the verified
+    /// main name of another artist.
+    #[pallet::storage]
+    pub(super) type ArtistAliasIndex<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::Name, T::AccountId>;
+
+    /// Artists indexed by the first `T::NamePrefixLen` bytes of the confusable key of their main
+    /// name, so the searches by name prefix only iterate a single bucket.
+    #[pallet::storage]
+    pub(super) type ArtistsOfNamePrefix<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, T::NamePrefixLen>,
+        Twox64Concat,
+        T::AccountId,
+        (),
+    >;
+
+    /// Verification requests of artists waiting for the `T::VerifierOrigin`, with the evidence
+    /// fingerprints they submitted.
+    #[pallet::storage]
+    #[pallet::getter(fn get_pending_verification)]
+    pub(super) type PendingVerifications<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, T::AccountId, VerificationRequest<T, I>>;
+
+    /// The artists having a pending verification, oldest request first.
+    #[pallet::storage]
+    pub(super) type VerificationQueue<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BoundedVec<T::AccountId, T::MaxPendingVerifications>, ValueQuery>;
+
+    /// Tombstones of the duplicate artists merged into another one, pointing to the artist they
+    /// have been merged into.
+    #[pallet::storage]
+    #[pallet::getter(fn get_merged_into)]
+    pub type MergedInto<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, T::AccountId, T::AccountId>;
+
+    /// Main names requested by verified artists, waiting for the `T::AdminOrigin` approval.
+    #[pallet::storage]
+    #[pallet::getter(fn get_pending_rename)]
+    pub type PendingRenames<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, T::AccountId, T::Name>;
+
+    /// When artists requested to unregister, starting their `T::UnregisterPeriod`.
+    #[pallet::storage]
+    #[pallet::getter(fn get_unregister_request)]
+    pub type UnregisterRequests<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, T::AccountId, BlockNumberFor<T>>;
+
+    /// Verified artists indexed by the fingerprint of the confusable key of their main name, so no
+    /// name confusable with a verified one, such as one of its case variants, can be claimed.
+    #[pallet::storage]
+    pub(super) type ConfusableNameOf<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Identity, T::Hash, T::AccountId>;
+
+    /// Fingerprints of the names that can't be registered by anyone.
+    #[pallet::storage]
+    pub type BannedNames<T: Config<I>, I: 'static = ()> = StorageMap<_, Identity, T::Hash, ()>;
+
+    /// Fingerprints of the names reserved for a later verified claim, with the account allowed to
+    /// register it once known.
+    #[pallet::storage]
+    pub type ReservedNames<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Identity, T::Hash, Option<T::AccountId>>;
+
+    /// Fingerprints of the names released by unregistered artists, with their previous owner and
+    /// the last block of their `T::NameRetentionPeriod`.
+    #[pallet::storage]
+    pub type RetiredNames<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Identity, T::Hash, (T::AccountId, BlockNumberFor<T>)>;
+
+    /// The last retired name examined by the idle cleanup, the next cleanup resuming after it.
     #[pallet::storage]
-    #[pallet::getter(fn get_artist_by_id)]
-    pub(super) type ArtistOf<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Artist<T>>;
+    pub(super) type RetiredNamesCursor<T: Config<I>, I: 'static = ()> = StorageValue<_, T::Hash>;
+
+    /// The last artist examined by the idle cleanup for an expired verification, the next cleanup
+    /// resuming after it.
+    #[pallet::storage]
+    pub(super) type VerificationExpiryCursor<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, T::AccountId>;
+
+    /// Accounts exempted from holding any deposit when registering or updating their artist data,
+    /// the storage cost being sponsored outside of the artist account.
+    #[pallet::storage]
+    pub type DepositExemptions<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, T::AccountId, ()>;
+
+    /// The aggregated statistics of the registry, as of the last completed `update_stats` pass.
+    #[pallet::storage]
+    #[pallet::getter(fn get_registry_stats)]
+    pub type Stats<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, RegistryStats<BlockNumberFor<T>>, ValueQuery>;
+
+    /// The statistics of an `update_stats` pass in progress, with the last counted artist to resume
+    /// the pass from.
+    #[pallet::storage]
+    pub(super) type PendingStats<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, (RegistryStats<BlockNumberFor<T>>, Option<T::AccountId>)>;
 
     /// Used to cache the account id of this pallet
     #[pallet::storage]
-    pub type Address<T: Config> = StorageValue<_, T::AccountId, ValueQuery, DefaultAddress<T>>;
+    pub type Address<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, T::AccountId, ValueQuery, DefaultAddress<T, I>>;
+
+    /// The genesis profiles open to a claim by their real-world artist, with the last block of
+    /// their claim window.
+    #[pallet::storage]
+    pub type ClaimableProfiles<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, T::AccountId, BlockNumberFor<T>>;
+
+    /// The current `AssetBudgetPeriod` of the artists which added assets, with its first block
+    /// and the bytes of asset fingerprints added within it.
+    #[pallet::storage]
+    pub type AssetBudgetUsage<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, T::AccountId, (BlockNumberFor<T>, u32)>;
+
+    /// The account each artist lets submit its verification evidence, such as its agency,
+    /// without any other right on its profile.
+    #[pallet::storage]
+    #[pallet::getter(fn get_evidence_delegate)]
+    pub type EvidenceDelegates<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, T::AccountId, T::AccountId>;
+
+    /// The payout destination each artist chose, overriding its royalty splits.
+    #[pallet::storage]
+    #[pallet::getter(fn get_payout_route)]
+    pub type PayoutRoutes<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, T::AccountId, PayoutRoute<T::AccountId>>;
+
+    /// The chain each artist registered with `register_remote` comes from, for display purposes.
+    #[pallet::storage]
+    #[pallet::getter(fn get_origin_chain)]
+    pub type RemoteOriginOf<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, T::AccountId, OriginChain>;
+
+    /// The block the last verification of each artist lapsed at, once expired, so the artists
+    /// that got verified don't expire past the `T::UnverifiedLifetime` as never verified ones.
+    #[pallet::storage]
+    pub type LapsedVerifications<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, T::AccountId, BlockNumberFor<T>>;
+
+    /// The current `RegistrationPeriod`, with its first block and the registrations accepted
+    /// within it.
+    #[pallet::storage]
+    pub type RegistrationsUsage<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, (BlockNumberFor<T>, u32)>;
+
+    /// The accounts invited to join a group artist, waiting for their acceptance.
+    #[pallet::storage]
+    pub type MemberInvitations<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        BoundedVec<T::AccountId, T::MaxMembers>,
+        ValueQuery,
+    >;
+
+    /// The co-owner members of the group artists, with the share they hold into the group.
+    #[pallet::storage]
+    pub type CoOwners<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        T::AccountId,
+        BalanceOf<T, I>,
+    >;
+
+    /// The co-ownership shares group artists offered their members, waiting for their
+    /// acceptance.
+    #[pallet::storage]
+    pub type CoOwnershipOffers<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        T::AccountId,
+        BalanceOf<T, I>,
+    >;
+
+    /// The accounts artists offered their profile to, waiting for their acceptance.
+    #[pallet::storage]
+    #[pallet::getter(fn get_ownership_offer)]
+    pub type OwnershipOffers<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, T::AccountId, T::AccountId>;
+
+    /// An artist registered at genesis:
+    /// `(id, main name, alias, genres, description, assets, verified)`.
+    pub type GenesisArtistOf<T> = (
+        <T as frame_system::Config>::AccountId,
+        Vec<u8>,
+        Option<Vec<u8>>,
+        Vec<MusicGenre>,
+        Option<Vec<u8>>,
+        Vec<(AssetType, Vec<u8>)>,
+        bool,
+    );
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
+        /// The founding artists to register at genesis.
+        pub artists: Vec<GenesisArtistOf<T>>,
+        /// Whether the genesis artists are registered without holding any deposit.
+        pub waive_deposits: bool,
+        /// The genesis artists seeded on behalf of real-world artists, to be claimed by them
+        /// within the `ClaimWindow`.
+        pub claimable: Vec<T::AccountId>,
+        #[serde(skip)]
+        pub phantom: PhantomData<I>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config<I>, I: 'static> BuildGenesisConfig for GenesisConfig<T, I> {
+        fn build(&self) {
+            for artist in &self.artists {
+                Pallet::<T, I>::seed_artist(artist, self.waive_deposits);
+            }
+
+            let claim_expiry =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::ClaimWindow::get());
+            for id in &self.claimable {
+                assert!(
+                    ArtistOf::<T, I>::contains_key(id),
+                    "claimable genesis profile is not a genesis artist"
+                );
+                ClaimableProfiles::<T, I>::insert(id, claim_expiry);
+            }
+        }
+    }
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
+    pub enum Event<T: Config<I>, I: 'static = ()> {
         /// A new artist got registered.
         ArtistRegistered {
             /// The address of the new artist.
             id: T::AccountId,
             /// main name of the new artist.
-            name: BoundedVec<u8, T::MaxNameLen>,
+            name: T::Name,
+        },
+
+        /// An Artist requested to unregister, effective once `T::UnregisterPeriod` passed.
+        UnregisterRequested {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// From when the artist can confirm its unregistration.
+            confirmable_at: BlockNumberFor<T>,
         },
 
+        /// An Artist cancelled its unregister request.
+        UnregisterCancelled { id: T::AccountId },
+
         /// An Artist as been unregistered
-        ArtistUnregistered { id: T::AccountId },
+        ArtistUnregistered {
+            /// The address of the removed artist.
+            id: T::AccountId,
+            /// The amount of the artist deposit refunded.
+            deposit: BalanceOf<T, I>,
+            /// The account the deposit has been refunded to.
+            depositor: T::AccountId,
+        },
 
-        /// An Artist as been unregistered from the `T::RootOrigin`
-        ArtistForceUnregistered { id: T::AccountId },
+        /// An Artist as been unregistered from the `T::AdminOrigin`
+        ArtistForceUnregistered {
+            /// The address of the removed artist.
+            id: T::AccountId,
+            /// What has been done with the artist deposit.
+            policy: DepositPolicy,
+            /// The amount of the artist deposit slashed, or refunded with a refund `policy`.
+            deposit: BalanceOf<T, I>,
+            /// The account the deposit was held from, and refunded to with a refund `policy`.
+            depositor: T::AccountId,
+        },
 
         ArtistUpdated {
             /// The address of the updated artist.
             id: T::AccountId,
             /// The new data.
-            new_data: UpdatableData<ArtistAliasOf<T>>,
+            new_data: UpdatableDataOf<T, I>,
         },
-    }
-
-    #[pallet::error]
-    pub enum Error<T> {
-        /// A genre appear multiple time in the artist data.
-        NotUniqueGenre,
-        /// An asset appear multiple time in the artist data.
-        NotUniqueAsset,
-        /// The artist name is already attributed to a verified artist.
-        NameUnavailable,
-        /// Account isn't registered as an Artist.
-        NotRegistered,
-        /// This account ID is already registered as an artist.
-        AlreadyRegistered,
-        /// Artist is verified and can't unregister.
-        IsVerified,
-        /// Unregister period isn't fully passed.
-        PeriodNotPassed,
-        /// The maximum value possible for this field for an artist has been violated.
-        Full,
-        /// Element wasn't found.
-        NotFound,
-    }
 
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// Register the caller as an Artist.
-        #[pallet::weight(T::WeightInfo::register(
-            T::MaxNameLen::get(),
-            T::MaxGenres::get(),
-            T::MaxAssets::get()
-        ))]
-        #[pallet::call_index(0)]
-        pub fn register(
-            origin: OriginFor<T>,
-            main_name: BoundedVec<u8, T::MaxNameLen>,
-            alias: Option<BoundedVec<u8, T::MaxNameLen>>,
-            genres: BoundedVec<MusicGenre, T::MaxGenres>,
-            description: Option<Vec<u8>>,
-            assets: BoundedVec<Vec<u8>, T::MaxAssets>,
-        ) -> DispatchResultWithPostInfo {
-            let origin = ensure_signed(origin)?;
+        /// An Artist data has been updated by the `T::AdminOrigin`.
+        ArtistForceUpdated {
+            /// The address of the updated artist.
+            id: T::AccountId,
+            /// The new data.
+            new_data: UpdatableDataOf<T, I>,
+        },
 
-            ensure!(
-                !ArtistOf::<T>::contains_key(origin.clone()),
-                Error::<T>::AlreadyRegistered
-            );
+        /// The `T::AbuseFilter` surcharged a registration or an update of an artist.
+        SubmissionSurcharged {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The extra deposit held from the artist depositor.
+            amount: BalanceOf<T, I>,
+        },
 
-            let new_artist = Artist::<T>::new(
-                origin.clone(),
-                main_name.clone(),
-                alias,
-                genres,
-                description,
-                assets,
-            )?;
+        /// An Artist field changed, deposited along with the event of the update.
+        ArtistDataChanged {
+            /// The address of the updated artist.
+            id: T::AccountId,
+            /// The value of the field before the update.
+            old: ArtistField<T, I>,
+            /// The value of the field after the update.
+            new: ArtistField<T, I>,
+        },
 
-            // held amount for base artist data registration
-            T::Currency::hold(
-                &HoldReason::ArtistRegistration.into(),
-                &origin,
-                T::BaseDeposit::get(),
-            )?;
+        /// An Artist has been verified by the `T::VerifierOrigin`.
+        ArtistVerified {
+            /// The address of the verified artist.
+            id: T::AccountId,
+            /// The fingerprint of the KYC evidence held by the verifying entity.
+            kyc_evidence_hash: Option<T::Hash>,
+        },
 
-            ArtistOf::insert(origin.clone(), new_artist);
+        /// An Artist submitted evidence fingerprints for its pending verification.
+        VerificationEvidenceSubmitted {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The fingerprints of the submitted evidence (social posts, signed statements...).
+            evidence: BoundedVec<T::Hash, T::MaxEvidence>,
+        },
 
-            Self::deposit_event(ArtistRegistered {
-                id: origin,
-                name: main_name,
-            });
-            Ok(().into())
-        }
+        /// An Artist has been exported by the root, for a later restore.
+        ArtistExported {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The snapshot of the artist, to be passed back to `force_restore_artist`.
+            record: ArtistRecord<T, I>,
+        },
 
-        /// Unregister the caller from being an artist,
-        /// clearing associated artist data mapped to this account.
-        ///
-        /// Enforced by `T::RootOrigin`, ignoring `T::UnregisterPeriod` and slash held balance of the artist.
-        #[pallet::weight(T::WeightInfo::force_unregister(
-            T::MaxNameLen::get(),
-            T::MaxGenres::get(),
-            T::MaxAssets::get()
-        ))]
-        #[pallet::call_index(1)]
-        pub fn force_unregister(
-            origin: OriginFor<T>,
+        /// An Artist has been restored by the root from an exported record.
+        ArtistRestored {
+            /// The address of the artist.
             id: T::AccountId,
-        ) -> DispatchResultWithPostInfo {
-            T::RootOrigin::ensure_origin(origin)?;
+        },
 
-            Self::slash_held_all(&id)?;
+        /// A genesis profile has been claimed by its real-world artist.
+        ProfileClaimed {
+            /// The address the profile was seeded under.
+            profile: T::AccountId,
+            /// The address of the artist now owning the profile.
+            claimant: T::AccountId,
+        },
 
-            ArtistOf::<T>::remove(id.clone());
+        /// A genesis profile left unclaimed past its claim window has been released.
+        ProfileReleased {
+            /// The address of the removed profile.
+            profile: T::AccountId,
+            /// The amount of the profile deposit refunded to its seeding account.
+            deposit: BalanceOf<T, I>,
+        },
 
-            Self::deposit_event(ArtistForceUnregistered { id });
-            Ok(().into())
-        }
+        /// The moderation status of an Artist changed.
+        ArtistStatusChanged {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The new status of the artist.
+            status: ArtistStatus,
+        },
 
-        /// Unregister the caller from being an artist,
-        /// clearing associated artist data mapped to this account
-        #[pallet::weight(T::WeightInfo::unregister(
-            T::MaxNameLen::get(),
-            T::MaxGenres::get(),
-            T::MaxAssets::get()
-        ))]
+        /// An Artist requested its verification, escrowing the verification deposit.
+        VerificationRequested {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The escrowed deposit.
+            deposit: BalanceOf<T, I>,
+        },
+
+        /// The verification request of an Artist has been rejected by the `T::VerifierOrigin`.
+        VerificationRejected {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// Whether the escrowed deposit got refunded or slashed.
+            policy: DepositPolicy,
+            /// The slashed deposit, or the refunded one with a refund `policy`.
+            deposit: BalanceOf<T, I>,
+        },
+
+        /// The pending verification of an Artist got evicted from a full queue, its evidence
+        /// deposit and escrow have been refunded.
+        VerificationEvicted {
+            /// The address of the artist.
+            id: T::AccountId,
+        },
+
+        /// The KYC evidence of a verified Artist has been updated on re-verification.
+        KycEvidenceUpdated {
+            /// The address of the re-verified artist.
+            id: T::AccountId,
+            /// The new fingerprint of the KYC evidence.
+            kyc_evidence_hash: Option<T::Hash>,
+        },
+
+        /// The verification of an Artist has been renewed by the `T::VerifierOrigin`.
+        VerificationRenewed {
+            /// The address of the artist.
+            id: T::AccountId,
+        },
+
+        /// The verification of an Artist expired past `T::VerificationValidity`, the artist is
+        /// unverified until verified again.
+        VerificationExpired {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The block the expired verification was granted or last renewed at.
+            verified_at: BlockNumberFor<T>,
+            /// Whether the artist is pending its verification again.
+            requeued: bool,
+        },
+
+        /// A contract has been linked to an Artist.
+        ContractLinked {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The address of the linked contract.
+            contract: T::AccountId,
+        },
+
+        /// A contract has been unlinked from an Artist.
+        ContractUnlinked {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The address of the unlinked contract.
+            contract: T::AccountId,
+        },
+
+        /// The contracts list of an Artist has been frozen by the `T::AdminOrigin`.
+        ContractsFrozen { id: T::AccountId },
+
+        /// The contracts list of an Artist has been unfrozen by the `T::AdminOrigin`.
+        ContractsUnfrozen { id: T::AccountId },
+
+        /// A name has been reserved by the `T::AdminOrigin`.
+        NameReserved {
+            /// The reserved name.
+            name: T::Name,
+            /// The account allowed to register the name, if known.
+            claimant: Option<T::AccountId>,
+        },
+
+        /// A name reservation has been removed by the `T::AdminOrigin`.
+        NameUnreserved { name: T::Name },
+
+        /// A name has been banned by the `T::AdminOrigin`.
+        NameBanned { name: T::Name },
+
+        /// A name has been unbanned by the `T::AdminOrigin`.
+        NameUnbanned { name: T::Name },
+
+        /// A duplicate Artist has been merged into its primary one by the `T::AdminOrigin`.
+        ArtistsMerged {
+            /// The address of the artist receiving the duplicate data.
+            primary: T::AccountId,
+            /// The address of the removed duplicate artist.
+            duplicate: T::AccountId,
+        },
+
+        /// The main name of an Artist has been changed.
+        ArtistRenamed {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The previous main name of the artist.
+            old_name: T::Name,
+            /// The new main name of the artist.
+            new_name: T::Name,
+        },
+
+        /// A verified Artist requested a main name change, waiting for the `T::AdminOrigin`.
+        RenameRequested {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The requested main name.
+            new_name: T::Name,
+        },
+
+        /// The main name change requested by an Artist has been rejected by the `T::AdminOrigin`.
+        RenameRejected { id: T::AccountId },
+
+        /// An account has been allowed to manage an Artist profile.
+        DelegateAdded {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The address of the delegate.
+            delegate: T::AccountId,
+        },
+
+        /// An account is no longer allowed to manage an Artist profile.
+        DelegateRemoved {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The address of the delegate.
+            delegate: T::AccountId,
+        },
+
+        /// An Artist set or revoked the account handling its verification evidence.
+        EvidenceDelegateSet {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The address of the evidence delegate, if any.
+            delegate: Option<T::AccountId>,
+        },
+
+        /// An Artist set or removed its payout route.
+        PayoutRouteSet {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The new payout route, if any.
+            route: Option<PayoutRoute<T::AccountId>>,
+        },
+
+        /// An Artist data has been updated by one of its delegates.
+        ArtistUpdatedByDelegate {
+            /// The address of the updated artist.
+            id: T::AccountId,
+            /// The address of the delegate.
+            delegate: T::AccountId,
+            /// The new data.
+            new_data: UpdatableDataOf<T, I>,
+        },
+
+        /// The deposit exemption of an account has been set by the `T::AdminOrigin`.
+        DepositExemptionSet {
+            /// The address of the account.
+            id: T::AccountId,
+            /// Whether the account is now exempted from deposits.
+            exempt: bool,
+        },
+
+        /// The registry statistics have been fully refreshed.
+        RegistryStatsUpdated {
+            /// The amount of registered artists.
+            total: u32,
+            /// The amount of verified artists.
+            verified: u32,
+        },
+
+        /// An Artist set the beneficiaries of its royalties.
+        RoyaltySplitsSet {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The beneficiaries with their share.
+            splits: RoyaltySplitsOf<T, I>,
+        },
+
+        /// An Artist cleared the beneficiaries of its royalties.
+        RoyaltySplitsCleared {
+            /// The address of the artist.
+            id: T::AccountId,
+        },
+
+        /// An Artist turned into a group or back into a solo artist.
+        ArtistKindChanged {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The new kind of the artist.
+            kind: ArtistKind,
+        },
+
+        /// A group Artist invited an account to join it.
+        MemberInvited {
+            /// The address of the group.
+            id: T::AccountId,
+            /// The invited account.
+            member: T::AccountId,
+        },
+
+        /// An account accepted to join a group Artist.
+        MemberAdded {
+            /// The address of the group.
+            id: T::AccountId,
+            /// The new member.
+            member: T::AccountId,
+        },
+
+        /// A member left a group Artist, or its invitation got cancelled or declined.
+        MemberRemoved {
+            /// The address of the group.
+            id: T::AccountId,
+            /// The removed member.
+            member: T::AccountId,
+        },
+
+        /// An `OnArtistLifecycle` handler failed, its changes being dropped while the artist call
+        /// went through, as `T::LifecycleHookFailure` is `HookFailurePolicy::Ignore`.
+        LifecycleHookFailed {
+            /// The address of the artist the handler got called for.
+            id: T::AccountId,
+            /// The error of the handler.
+            error: DispatchError,
+        },
+
+        /// The notification of a contract linked to an Artist failed, its changes being dropped.
+        ContractNotificationFailed {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The contract which failed to get notified.
+            contract: T::AccountId,
+            /// The error of the notification.
+            error: DispatchError,
+        },
+
+        /// A genre got added to an Artist.
+        GenreAdded {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The added genre.
+            genre: StoredGenre,
+        },
+
+        /// A genre got removed from an Artist.
+        GenreRemoved {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The removed genre.
+            genre: StoredGenre,
+        },
+
+        /// An asset got added to an Artist.
+        AssetAdded {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The fingerprint of the added asset.
+            hash: FingerprintOf<T>,
+        },
+
+        /// An asset got removed from an Artist.
+        AssetRemoved {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The fingerprint of the removed asset.
+            hash: FingerprintOf<T>,
+        },
+
+        /// An alias got added to an Artist.
+        AliasAdded {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The added alias.
+            alias: T::Name,
+        },
+
+        /// A member of a group Artist became one of its co-owners.
+        CoOwnerJoined {
+            /// The address of the group.
+            id: T::AccountId,
+            /// The co-owner member.
+            member: T::AccountId,
+            /// The share held from the member.
+            share: BalanceOf<T, I>,
+        },
+
+        /// A co-owner of a group Artist left its ownership, its share being settled.
+        CoOwnerExited {
+            /// The address of the group.
+            id: T::AccountId,
+            /// The former co-owner.
+            member: T::AccountId,
+            /// What has been done with the share.
+            policy: DepositPolicy,
+            /// The amount of the share slashed, or refunded with a refund `policy`.
+            share: BalanceOf<T, I>,
+        },
+
+        /// A group Artist offered one of its members to become a co-owner.
+        CoOwnershipOffered {
+            /// The address of the group.
+            id: T::AccountId,
+            /// The member the co-ownership is offered to.
+            member: T::AccountId,
+            /// The share the member would hold.
+            share: BalanceOf<T, I>,
+        },
+
+        /// An Artist offered its profile to another account.
+        OwnershipOffered {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The account the profile is offered to.
+            new_owner: T::AccountId,
+        },
+
+        /// An Artist profile moved to its new owner, along with its deposits and indexes.
+        OwnershipTransferred {
+            /// The previous address of the artist.
+            from: T::AccountId,
+            /// The new address of the artist.
+            to: T::AccountId,
+        },
+
+        /// An expired entry got pruned by the idle cleanup. The deposits of a pruned pending
+        /// verification have been refunded.
+        StalePruned {
+            /// The pruned entry.
+            entry: StaleEntry<T::AccountId, T::Hash>,
+        },
+
+        /// An alias got removed from an Artist.
+        AliasRemoved {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The removed alias.
+            alias: T::Name,
+        },
+
+        /// An Artist claimed a work.
+        WorkClaimed {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The fingerprint of the claimed work.
+            work: T::Hash,
+        },
+
+        /// An Artist disclaimed one of its works.
+        WorkDisclaimed {
+            /// The address of the artist.
+            id: T::AccountId,
+            /// The fingerprint of the disclaimed work.
+            work: T::Hash,
+        },
+
+        /// An Artist applied a batch of updates to its data at once.
+        ArtistBatchUpdated {
+            /// The address of the updated artist.
+            id: T::AccountId,
+            /// The applied updates, in order.
+            updates: BoundedVec<UpdatableDataOf<T, I>, T::MaxUpdatesPerCall>,
+        },
+
+        /// The deposit of an artist got repriced at the current deposit constants.
+        DepositUpdated {
+            /// The address of the repriced artist.
+            id: T::AccountId,
+            /// The deposit tracked by the artist before the repricing.
+            old_deposit: BalanceOf<T, I>,
+            /// The deposit tracked by the artist after the repricing.
+            new_deposit: BalanceOf<T, I>,
+        },
+
+        /// An Artist left unverified past the `T::UnverifiedLifetime` got reaped.
+        ArtistReaped {
+            /// The address of the reaped artist.
+            id: T::AccountId,
+            /// The account which reaped it.
+            reaper: T::AccountId,
+            /// The part of the deposit given to the reaper.
+            reward: BalanceOf<T, I>,
+            /// The rest of the deposit, refunded to the depositor.
+            deposit: BalanceOf<T, I>,
+            /// The account the deposit was held from.
+            depositor: T::AccountId,
+        },
+
+        /// An Artist got registered from another chain with `register_remote`.
+        RemoteArtistRegistered {
+            /// The account derived from the remote location.
+            id: T::AccountId,
+            /// The chain the registration comes from.
+            origin_chain: OriginChain,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T, I = ()> {
+        /// A genre appear multiple time in the artist data.
+        NotUniqueGenre,
+        /// An asset appear multiple time in the artist data.
+        NotUniqueAsset,
+        /// The artist name is already attributed to a verified artist or is the alias of another
+        /// artist.
+        NameUnavailable,
+        /// Account isn't registered as an Artist.
+        NotRegistered,
+        /// This account ID is already registered as an artist.
+        AlreadyRegistered,
+        /// Artist is verified and can't unregister.
+        IsVerified,
+        /// Unregister period isn't fully passed.
+        PeriodNotPassed,
+        /// The artist didn't request to unregister.
+        UnregisterNotRequested,
+        /// The artist already requested to unregister.
+        UnregisterAlreadyRequested,
+        /// The artist already has `T::MaxGenres` genres.
+        GenresFull,
+        /// The genre to remove isn't one of the artist genres.
+        GenreNotFound,
+        /// The artist is already verified.
+        AlreadyVerified,
+        /// The artist isn't verified.
+        NotVerified,
+        /// A contract appear multiple time in the artist data.
+        NotUniqueContract,
+        /// The contracts list of the artist is frozen.
+        ContractsFrozen,
+        /// The contracts list of the artist isn't frozen.
+        ContractsNotFrozen,
+        /// The name is banned from the registry.
+        BannedName,
+        /// The name is reserved for another account.
+        ReservedName,
+        /// The pending verification queue is full.
+        VerificationQueueFull,
+        /// An artist can't be merged into itself.
+        MergeIntoSelf,
+        /// The account has been merged into another artist.
+        ArtistMerged,
+        /// Too many genres are tagged under the same top-level genre.
+        GenreCapacityExceeded,
+        /// The account is already a delegate of the artist.
+        AlreadyDelegate,
+        /// The account isn't a delegate of the artist.
+        NotDelegate,
+        /// A link appear multiple time in the artist data.
+        NotUniqueLink,
+        /// The link is longer than `T::MaxLinkLen`.
+        LinkTooLong,
+        /// The artist already has a pending verification request.
+        VerificationAlreadyRequested,
+        /// The artist has no pending verification request.
+        VerificationNotRequested,
+        /// The alias is already used by another artist, as its alias or verified main name.
+        AliasUnavailable,
+        /// The artist is suspended or banned by a moderation.
+        ArtistSuspended,
+        /// The artist is banned and can't be reinstated.
+        ArtistBanned,
+        /// The restored record was exported under another storage version.
+        RecordVersionMismatch,
+        /// The profile isn't open to a claim.
+        NotClaimable,
+        /// The claim window of the profile has passed.
+        ClaimExpired,
+        /// The claim window of the profile is still open.
+        ClaimWindowOpen,
+        /// The claim voucher isn't signed by the `ClaimAuthority` for this profile and claimant.
+        InvalidVoucher,
+        /// The caller is neither a label nor a proxy of the account to register.
+        NotProxy,
+        /// The description to store on-chain is longer than `T::MaxDescriptionLen`, or the one to
+        /// hash is longer than `T::MaxHashedDescriptionLen`.
+        DescriptionTooLong,
+        /// The artist already added its `T::AssetBudget` of assets within the current period.
+        AssetBudgetExceeded,
+        /// The `T::AbuseFilter` rejected the registration or the update.
+        SubmissionRejected,
+        /// The passed position is past the end of the list.
+        IndexOutOfBounds,
+        /// The royalty shares are not all positive or don't sum to 100%.
+        InvalidRoyaltySplits,
+        /// The same beneficiary appears several times in the royalty splits.
+        DuplicateBeneficiary,
+        /// The passed bytes don't form a valid `T::Name`, a main name or an alias rejected by the
+        /// `T::NameValidator`, or an updated alias not in its canonical form.
+        InvalidName,
+        /// The artist is not a group.
+        NotGroup,
+        /// The group still has members.
+        GroupHasMembers,
+        /// The account is already a member of the group.
+        AlreadyMember,
+        /// The account is not a member of the group, nor invited to join it.
+        NotMember,
+        /// The account is already invited to join the group.
+        AlreadyInvited,
+        /// The account is not invited to join the group.
+        NotInvited,
+        /// A lifecycle handler consumed more than `T::MaxLifecycleHookWeight`.
+        LifecycleHookOverweight,
+        /// The artist can't hold more aliases, at its verification level or at all.
+        TooManyAliases,
+        /// The name got released by an unregistered artist, only it can register it back until
+        /// its `T::NameRetentionPeriod` passed.
+        NameRetired,
+        /// The member is already a co-owner of the group.
+        AlreadyCoOwner,
+        /// A co-ownership share can't be zero.
+        ZeroShare,
+        /// The group didn't offer the member the co-ownership with this share.
+        NoCoOwnershipOffer,
+        /// The co-ownership share is lower than `T::MinCoOwnerShare`.
+        ShareTooLow,
+        /// The artist didn't offer its profile to the caller.
+        NoOwnershipOffer,
+        /// The registry already accepted its `T::MaxRegistrationsPerPeriod` new registrations
+        /// within the current period.
+        TooManyRegistrations,
+        /// The artist got updated since the update nonce the caller expected.
+        StaleUpdate,
+        /// The genre can't be stored by its tag and subtag, the genres registry having changed
+        /// its encoding.
+        UnsupportedGenre,
+        /// The payout contract isn't linked to the artist.
+        ContractNotLinked,
+        /// The artist already has `T::MaxAssets` assets.
+        AssetsFull,
+        /// The asset to remove isn't one of the artist assets.
+        AssetNotFound,
+        /// The artist already has `T::MaxContracts` linked contracts.
+        ContractsFull,
+        /// The contract to unlink isn't linked to the artist.
+        ContractNotFound,
+        /// The artist already has `T::MaxLinks` external links.
+        LinksFull,
+        /// The external link to remove isn't one of the artist links.
+        LinkNotFound,
+        /// The artist already has `T::MaxDelegates` delegates.
+        DelegatesFull,
+        /// The group already has `T::MaxMembers` members and invitations.
+        MembersFull,
+        /// The artist has no royalty splits to clear.
+        RoyaltySplitsNotFound,
+        /// The name isn't reserved.
+        NameNotReserved,
+        /// The name isn't banned.
+        NameNotBanned,
+        /// The artist has no pending rename.
+        RenameNotRequested,
+        /// The main genre is one of the secondary genres of the artist.
+        MainGenreDuplicated,
+        /// The alias appears multiple times in the artist data.
+        NotUniqueAlias,
+        /// The artist doesn't have the alias to remove.
+        AliasNotFound,
+        /// The artist already claims the work.
+        WorkAlreadyClaimed,
+        /// The artist doesn't claim the work.
+        WorkNotClaimed,
+        /// The artist already claims `T::MaxWorks` works.
+        WorksFull,
+        /// The name is confusable with the main name of a verified artist, such as one of its
+        /// case variants.
+        NameConfusable,
+        /// The name is shorter than `T::MinNameLen` once canonicalized.
+        NameTooShort,
+        /// The batch of updates is empty.
+        NoUpdates,
+        /// The searched name prefix is shorter than `T::NamePrefixLen` once made confusable.
+        PrefixTooShort,
+        /// The artist doesn't expire, or its unverified lifetime hasn't elapsed yet.
+        NotExpired,
+        /// The artist already has a pending rename.
+        RenameAlreadyRequested,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+        fn on_idle(_: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let mut meter = WeightMeter::with_limit(remaining_weight);
+            Self::prune_stale(&mut meter);
+            Self::expire_verifications(&mut meter);
+            meter.consumed()
+        }
+
+        fn integrity_test() {
+            let max_artist_len = Artist::<T, I>::max_encoded_len();
+            assert!(
+                max_artist_len <= T::MaxArtistProofSize::get() as usize,
+                "a worst-case artist of {} bytes exceeds the `MaxArtistProofSize` budget",
+                max_artist_len,
+            );
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
+        /// Register the caller as an Artist.
+        ///
+        /// Deprecated in favor of `register_v2`, taking the registration data as a single
+        /// struct. This call is kept for the existing clients but won't get the new registration
+        /// fields.
+        #[pallet::weight(T::WeightInfo::register(
+            T::MaxNameLen::get(),
+            T::MaxGenres::get(),
+            T::MaxAssets::get(),
+            T::MaxHashedDescriptionLen::get()
+        )
+        .saturating_add(T::MaxLifecycleHookWeight::get())
+        .saturating_add(T::MaxContractNotificationWeight::get().saturating_mul(2)))]
+        #[pallet::call_index(0)]
+        pub fn register(
+            origin: OriginFor<T>,
+            main_name: T::Name,
+            alias: Option<T::Name>,
+            genres: BoundedVec<MusicGenre, T::MaxGenres>,
+            description: Option<BoundedVec<u8, T::MaxHashedDescriptionLen>>,
+            assets: BoundedVec<(AssetType, Vec<u8>), T::MaxAssets>,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            Self::note_registration()?;
+            Self::do_register(
+                origin.clone(),
+                origin,
+                ArtistRegistrationData::new(main_name, alias, genres, description, assets),
+            )
+        }
+
+        /// Unregister the passed artist,
+        /// clearing associated artist data mapped to this account.
+        ///
+        /// Enforced by `T::AdminOrigin`, ignoring `T::UnregisterPeriod` and the verification lock.
+        /// The held balance of the artist is slashed or refunded depending on the passed `policy`.
+        #[pallet::weight((
+            T::WeightInfo::force_unregister(
+                T::MaxNameLen::get(),
+                T::MaxGenres::get(),
+                T::MaxAssets::get()
+            )
+            .saturating_add(T::MaxLifecycleHookWeight::get())
+            .saturating_add(T::MaxContractNotificationWeight::get()),
+            Pays::No
+        ))]
+        #[pallet::call_index(1)]
+        pub fn force_unregister(
+            origin: OriginFor<T>,
+            id: T::AccountId,
+            policy: DepositPolicy,
+        ) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let artist = ArtistOf::<T, I>::take(id.clone()).ok_or(Error::<T, I>::NotRegistered)?;
+
+            let deposit = Self::settle_held_all(&artist, policy)?;
+            Self::purge_artist(&id, &artist, policy)?;
+
+            Self::deposit_event(ArtistForceUnregistered {
+                id,
+                policy,
+                deposit,
+                depositor: artist.depositor,
+            });
+            Ok(().into())
+        }
+
+        /// Unregister the caller from being an artist,
+        /// clearing associated artist data mapped to this account
+        ///
+        /// The caller must have requested it with `request_unregister` at least
+        /// `T::UnregisterPeriod` blocks ago.
+        #[pallet::weight(T::WeightInfo::confirm_unregister(
+            T::MaxNameLen::get(),
+            T::MaxGenres::get(),
+            T::MaxAssets::get()
+        )
+        .saturating_add(T::MaxLifecycleHookWeight::get())
+        .saturating_add(T::MaxContractNotificationWeight::get()))]
         #[pallet::call_index(2)]
-        pub fn unregister(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+        pub fn confirm_unregister(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            Self::can_unregister(&origin).map_err(Error::<T, I>::from)?;
+
+            let artist = ArtistOf::<T, I>::take(&origin).ok_or(Error::<T, I>::NotRegistered)?;
+            let deposit = Self::release_held_all(&artist)?;
+            Self::retire_name(&origin, &artist.main_name);
+            Self::purge_artist(&origin, &artist, DepositPolicy::Refund)?;
+
+            Self::deposit_event(ArtistUnregistered {
+                id: origin,
+                deposit,
+                depositor: artist.depositor,
+            });
+            Ok(().into())
+        }
+
+        /// Update the passed caller artist data field with the passed data.
+        ///
+        /// The weight is charged for the bounds of the artist data, the unused part being refunded
+        /// from its actual size.
+        #[pallet::weight({
+            let weight_fn = Pallet::<T, I>::get_weight_update_fn(&data);
+            weight_fn()
+        })]
+        #[pallet::call_index(3)]
+        pub fn update(
+            origin: OriginFor<T>,
+            data: UpdatableDataOf<T, I>,
+        ) -> DispatchResultWithPostInfo {
             let origin = ensure_signed(origin)?;
 
-            Self::can_unregister(&origin)?;
+            Self::do_update(origin, data, None)
+        }
+
+        /// Verify the passed artist, optionally attaching the fingerprint of the KYC evidence
+        /// held off-chain by the verifying entity.
+        #[pallet::weight((
+            T::WeightInfo::verify_artist()
+                .saturating_add(T::MaxLifecycleHookWeight::get())
+                .saturating_add(T::MaxContractNotificationWeight::get()),
+            Pays::No
+        ))]
+        #[pallet::call_index(4)]
+        pub fn verify_artist(
+            origin: OriginFor<T>,
+            id: T::AccountId,
+            kyc_evidence_hash: Option<T::Hash>,
+        ) -> DispatchResultWithPostInfo {
+            T::VerifierOrigin::ensure_origin(origin)?;
+
+            Self::do_verify(id, kyc_evidence_hash)
+        }
+
+        /// Re-verify an already verified artist, replacing the fingerprint of its KYC evidence.
+        #[pallet::weight((T::WeightInfo::update_kyc_evidence(), Pays::No))]
+        #[pallet::call_index(5)]
+        pub fn update_kyc_evidence(
+            origin: OriginFor<T>,
+            id: T::AccountId,
+            kyc_evidence_hash: Option<T::Hash>,
+        ) -> DispatchResultWithPostInfo {
+            T::VerifierOrigin::ensure_origin(origin)?;
+
+            ArtistOf::<T, I>::try_mutate(id.clone(), |maybe_artist| {
+                let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+                ensure!(artist.is_verified(), Error::<T, I>::NotVerified);
+
+                artist.verify(kyc_evidence_hash);
+                #[cfg(feature = "evm-attestation")]
+                attestation::attest::<T, I>(artist);
+
+                Self::deposit_event(KycEvidenceUpdated {
+                    id: id.clone(),
+                    kyc_evidence_hash,
+                });
+                Ok(().into())
+            })
+        }
+
+        /// Exempt or not the passed account from holding deposits for its artist data.
+        ///
+        /// Deposits already held stay held and are still released as usual.
+        #[pallet::weight((T::WeightInfo::set_deposit_exemption(), Pays::No))]
+        #[pallet::call_index(6)]
+        pub fn set_deposit_exemption(
+            origin: OriginFor<T>,
+            id: T::AccountId,
+            exempt: bool,
+        ) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            if exempt {
+                DepositExemptions::<T, I>::insert(id.clone(), ());
+            } else {
+                DepositExemptions::<T, I>::remove(id.clone());
+            }
+
+            Self::deposit_event(Event::DepositExemptionSet { id, exempt });
+            Ok(().into())
+        }
+
+        /// Update the passed artist data field with the passed data, on behalf of the artist.
+        ///
+        /// Enforced by `T::AdminOrigin` to correct profiles violating terms, deposits are still
+        /// held from or refunded to the artist.
+        #[pallet::weight({
+            let weight_fn = Pallet::<T, I>::get_weight_update_fn(&data);
+            (weight_fn(), Pays::No)
+        })]
+        #[pallet::call_index(7)]
+        pub fn force_update(
+            origin: OriginFor<T>,
+            id: T::AccountId,
+            data: UpdatableDataOf<T, I>,
+        ) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let weight = Self::apply_update(&id, &data, None, true)?;
+
+            Self::deposit_event(Event::ArtistForceUpdated { id, new_data: data });
+            Ok(Some(weight).into())
+        }
+
+        /// Freeze the contracts list of the passed artist, blocking any contract linking or
+        /// unlinking until it gets unfrozen.
+        #[pallet::weight((T::WeightInfo::freeze_contracts(), Pays::No))]
+        #[pallet::call_index(8)]
+        pub fn freeze_contracts(
+            origin: OriginFor<T>,
+            id: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            Self::set_contracts_frozen(id.clone(), true)?;
+
+            Self::deposit_event(Event::ContractsFrozen { id });
+            Ok(().into())
+        }
+
+        /// Unfreeze the contracts list of the passed artist.
+        #[pallet::weight((T::WeightInfo::unfreeze_contracts(), Pays::No))]
+        #[pallet::call_index(9)]
+        pub fn unfreeze_contracts(
+            origin: OriginFor<T>,
+            id: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            Self::set_contracts_frozen(id.clone(), false)?;
+
+            Self::deposit_event(Event::ContractsUnfrozen { id });
+            Ok(().into())
+        }
+
+        /// Reserve the passed name for a later verified claim, so it can't be squatted.
+        ///
+        /// Only the `claimant`, once known, is allowed to register it.
+        #[pallet::weight((T::WeightInfo::reserve_name(), Pays::No))]
+        #[pallet::call_index(10)]
+        pub fn reserve_name(
+            origin: OriginFor<T>,
+            name: T::Name,
+            claimant: Option<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ReservedNames::<T, I>::insert(Self::name_hash(&name.to_bytes()), claimant.clone());
+
+            Self::deposit_event(Event::NameReserved { name, claimant });
+            Ok(().into())
+        }
+
+        /// Remove the reservation of the passed name.
+        #[pallet::weight((T::WeightInfo::unreserve_name(), Pays::No))]
+        #[pallet::call_index(11)]
+        pub fn unreserve_name(origin: OriginFor<T>, name: T::Name) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ReservedNames::<T, I>::take(Self::name_hash(&name.to_bytes()))
+                .ok_or(Error::<T, I>::NameNotReserved)?;
+
+            Self::deposit_event(Event::NameUnreserved { name });
+            Ok(().into())
+        }
+
+        /// Ban the passed name from being registered.
+        #[pallet::weight((T::WeightInfo::ban_name(), Pays::No))]
+        #[pallet::call_index(12)]
+        pub fn ban_name(origin: OriginFor<T>, name: T::Name) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            BannedNames::<T, I>::insert(Self::name_hash(&name.to_bytes()), ());
+
+            Self::deposit_event(Event::NameBanned { name });
+            Ok(().into())
+        }
+
+        /// Lift the ban of the passed name.
+        #[pallet::weight((T::WeightInfo::unban_name(), Pays::No))]
+        #[pallet::call_index(13)]
+        pub fn unban_name(origin: OriginFor<T>, name: T::Name) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            BannedNames::<T, I>::take(Self::name_hash(&name.to_bytes()))
+                .ok_or(Error::<T, I>::NameNotBanned)?;
+
+            Self::deposit_event(Event::NameUnbanned { name });
+            Ok(().into())
+        }
+
+        /// Attach the passed evidence fingerprints to the pending verification of the caller,
+        /// replacing any previously submitted ones.
+        ///
+        /// A storage deposit is held for every evidence fingerprint. A new request goes at the end
+        /// of the pending queue, following `T::VerificationQueueOverflow` when it's full.
+        ///
+        /// The evidence is visible to the `T::VerifierOrigin`, making the verification workflow
+        /// traceable on-chain.
+        #[pallet::weight(T::WeightInfo::submit_verification_evidence(T::MaxEvidence::get()))]
+        #[pallet::call_index(14)]
+        pub fn submit_verification_evidence(
+            origin: OriginFor<T>,
+            evidence: BoundedVec<T::Hash, T::MaxEvidence>,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            Self::do_submit_verification_evidence(origin, evidence)
+        }
+
+        /// Merge the passed duplicate artist into the primary one, for an artist accidentally
+        /// registered from two accounts.
+        ///
+        /// The assets, links and contracts of the duplicate are moved into the primary artist,
+        /// within its bounds and holding their deposit from the primary owner. The duplicate
+        /// deposit is refunded and its account tombstoned with a pointer to the primary artist.
+        #[pallet::weight((
+            T::WeightInfo::merge_artists(
+                T::MaxAssets::get(),
+                T::MaxLinks::get(),
+                T::MaxContracts::get()
+            )
+                .saturating_add(T::MaxLifecycleHookWeight::get()),
+            Pays::No
+        ))]
+        #[pallet::call_index(15)]
+        pub fn merge_artists(
+            origin: OriginFor<T>,
+            primary: T::AccountId,
+            duplicate: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(primary != duplicate, Error::<T, I>::MergeIntoSelf);
+
+            let duplicate_artist =
+                ArtistOf::<T, I>::get(&duplicate).ok_or(Error::<T, I>::NotRegistered)?;
+            ensure!(!duplicate_artist.is_verified(), Error::<T, I>::IsVerified);
+            ensure!(
+                !duplicate_artist.contracts_frozen,
+                Error::<T, I>::ContractsFrozen
+            );
+
+            ArtistOf::<T, I>::try_mutate(&primary, |maybe_artist| {
+                let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+                artist.absorb(&duplicate_artist)
+            })?;
+
+            Self::release_held_all(&duplicate_artist)?;
+            ArtistOf::<T, I>::remove(&duplicate);
+            MergedInto::<T, I>::insert(&duplicate, primary.clone());
+            // The contracts of the duplicate now belong to the primary artist, they aren't
+            // notified of its removal
+            let mut duplicate_artist = duplicate_artist;
+            duplicate_artist.contracts = Default::default();
+            Self::purge_artist(&duplicate, &duplicate_artist, DepositPolicy::Refund)?;
+
+            Self::deposit_event(Event::ArtistsMerged { primary, duplicate });
+            Ok(().into())
+        }
+
+        /// Change the main name of the caller artist.
+        ///
+        /// An unverified artist is renamed right away, while a verified artist only requests it,
+        /// the rename being applied once approved by the `T::AdminOrigin`. The requested name is
+        /// checked up front as it would be on approval, and one rename is pending at most.
+        #[pallet::weight(T::WeightInfo::rename(T::MaxNameLen::get())
+            .saturating_add(T::MaxContractNotificationWeight::get().saturating_mul(3)))]
+        #[pallet::call_index(16)]
+        pub fn rename(origin: OriginFor<T>, new_name: T::Name) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+            let new_name = Self::checked_name(new_name)?;
+
+            let artist = ArtistOf::<T, I>::get(&origin).ok_or(Error::<T, I>::NotRegistered)?;
+
+            if artist.is_verified() {
+                ensure!(
+                    !PendingRenames::<T, I>::contains_key(&origin),
+                    Error::<T, I>::RenameAlreadyRequested
+                );
+                // The name is only claimed on approval, a reservation being kept until then
+                with_transaction(|| {
+                    TransactionOutcome::Rollback(Self::claim_name(&new_name, &origin))
+                })?;
+                PendingRenames::<T, I>::insert(&origin, new_name.clone());
+
+                Self::deposit_event(Event::RenameRequested {
+                    id: origin,
+                    new_name,
+                });
+                return Ok(().into());
+            }
+
+            Self::do_rename(origin, new_name)?;
+            Ok(().into())
+        }
+
+        /// Apply the main name change requested by the passed verified artist.
+        #[pallet::weight((
+            T::WeightInfo::approve_rename(T::MaxNameLen::get())
+                .saturating_add(T::MaxContractNotificationWeight::get().saturating_mul(3)),
+            Pays::No
+        ))]
+        #[pallet::call_index(17)]
+        pub fn approve_rename(
+            origin: OriginFor<T>,
+            id: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let new_name =
+                PendingRenames::<T, I>::take(&id).ok_or(Error::<T, I>::RenameNotRequested)?;
+
+            Self::do_rename(id, new_name)?;
+            Ok(().into())
+        }
+
+        /// Reject the main name change requested by the passed verified artist.
+        #[pallet::weight((T::WeightInfo::reject_rename(), Pays::No))]
+        #[pallet::call_index(18)]
+        pub fn reject_rename(origin: OriginFor<T>, id: T::AccountId) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            PendingRenames::<T, I>::take(&id).ok_or(Error::<T, I>::RenameNotRequested)?;
+
+            Self::deposit_event(Event::RenameRejected { id });
+            Ok(().into())
+        }
+
+        /// Request the unregistration of the caller artist, starting the `T::UnregisterPeriod`
+        /// after which it can be confirmed with `confirm_unregister`.
+        #[pallet::weight(T::WeightInfo::request_unregister())]
+        #[pallet::call_index(19)]
+        pub fn request_unregister(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            let artist = ArtistOf::<T, I>::get(&origin).ok_or(Error::<T, I>::NotRegistered)?;
+            ensure!(!artist.is_verified(), Error::<T, I>::IsVerified);
+            ensure!(
+                !UnregisterRequests::<T, I>::contains_key(&origin),
+                Error::<T, I>::UnregisterAlreadyRequested
+            );
+
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            UnregisterRequests::<T, I>::insert(&origin, current_block);
+
+            let period: u32 = T::UnregisterPeriod::get();
+            Self::deposit_event(Event::UnregisterRequested {
+                id: origin,
+                confirmable_at: current_block.saturating_add(period.into()),
+            });
+            Ok(().into())
+        }
+
+        /// Cancel the pending unregister request of the caller artist.
+        #[pallet::weight(T::WeightInfo::cancel_unregister())]
+        #[pallet::call_index(20)]
+        pub fn cancel_unregister(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            UnregisterRequests::<T, I>::take(&origin)
+                .ok_or(Error::<T, I>::UnregisterNotRequested)?;
+
+            Self::deposit_event(Event::UnregisterCancelled { id: origin });
+            Ok(().into())
+        }
+
+        /// Allow the passed account to update the profile of the caller artist, such as its label
+        /// or manager.
+        ///
+        /// A storage deposit is held from the artist for every delegate.
+        #[pallet::weight(T::WeightInfo::add_delegate(T::MaxDelegates::get()))]
+        #[pallet::call_index(21)]
+        pub fn add_delegate(
+            origin: OriginFor<T>,
+            delegate: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            ArtistOf::<T, I>::try_mutate(origin.clone(), |maybe_artist| {
+                let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+                artist.add_delegate(delegate.clone())
+            })?;
+
+            Self::deposit_event(Event::DelegateAdded {
+                id: origin,
+                delegate,
+            });
+            Ok(().into())
+        }
+
+        /// Revoke the passed delegate of the caller artist, releasing its storage deposit.
+        #[pallet::weight(T::WeightInfo::remove_delegate(T::MaxDelegates::get()))]
+        #[pallet::call_index(22)]
+        pub fn remove_delegate(
+            origin: OriginFor<T>,
+            delegate: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            ArtistOf::<T, I>::try_mutate(origin.clone(), |maybe_artist| {
+                let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+                artist.remove_delegate(&delegate)
+            })?;
+
+            Self::deposit_event(Event::DelegateRemoved {
+                id: origin,
+                delegate,
+            });
+            Ok(().into())
+        }
+
+        /// Update the passed artist data field with the passed data, from one of its delegates or,
+        /// for a group, one of its co-owners.
+        ///
+        /// Deposits are held from the artist as for its own updates. Delegates can't unregister
+        /// the artist.
+        #[pallet::weight({
+            let weight_fn = Pallet::<T, I>::get_weight_update_fn(&data);
+            weight_fn()
+        })]
+        #[pallet::call_index(23)]
+        pub fn update_as_delegate(
+            origin: OriginFor<T>,
+            id: T::AccountId,
+            data: UpdatableDataOf<T, I>,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            let artist = ArtistOf::<T, I>::get(&id).ok_or(Error::<T, I>::NotRegistered)?;
+            ensure!(
+                artist.is_delegate(&origin) || CoOwners::<T, I>::contains_key(&id, &origin),
+                Error::<T, I>::NotDelegate
+            );
+
+            let weight = Self::apply_update(&id, &data, None, false)?;
+
+            Self::deposit_event(Event::ArtistUpdatedByDelegate {
+                id,
+                delegate: origin,
+                new_data: data,
+            });
+            Ok(Some(weight).into())
+        }
+
+        /// Count up to `max_artists` artists into the registry statistics, resuming the pass
+        /// left by the previous call.
+        ///
+        /// Anyone can call it, the statistics are stored into `Stats` once every artist got
+        /// counted. Artists changing between the calls of a pass may be counted as they were.
+        #[pallet::weight(T::WeightInfo::update_stats(*max_artists))]
+        #[pallet::call_index(24)]
+        pub fn update_stats(origin: OriginFor<T>, max_artists: u32) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let (mut stats, mut last) = PendingStats::<T, I>::take().unwrap_or_default();
+            let artists = match &last {
+                Some(last) => ArtistOf::<T, I>::iter_from(ArtistOf::<T, I>::hashed_key_for(last)),
+                None => ArtistOf::<T, I>::iter(),
+            };
+
+            let mut counted = 0u32;
+            for (id, artist) in artists {
+                if counted == max_artists {
+                    PendingStats::<T, I>::put((stats, last));
+                    return Ok(Some(T::WeightInfo::update_stats(counted)).into());
+                }
+
+                stats.count(&artist);
+                last = Some(id);
+                counted.saturating_inc();
+            }
+
+            stats.updated_at = <frame_system::Pallet<T>>::block_number();
+            Self::deposit_event(Event::RegistryStatsUpdated {
+                total: stats.total,
+                verified: stats.verified,
+            });
+            Stats::<T, I>::put(stats);
+
+            Ok(Some(T::WeightInfo::update_stats(counted)).into())
+        }
+
+        /// Request the verification of the caller artist, escrowing `T::VerificationDeposit`
+        /// along with the deposit of the passed evidence.
+        ///
+        /// The request is queued for the `T::VerifierOrigin`, that either approves or rejects it.
+        #[pallet::weight(T::WeightInfo::request_verification(T::MaxEvidence::get()))]
+        #[pallet::call_index(25)]
+        pub fn request_verification(
+            origin: OriginFor<T>,
+            evidence: BoundedVec<T::Hash, T::MaxEvidence>,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            ensure!(
+                !PendingVerifications::<T, I>::contains_key(&origin),
+                Error::<T, I>::VerificationAlreadyRequested
+            );
+
+            let deposit = ArtistOf::<T, I>::try_mutate(origin.clone(), |maybe_artist| {
+                let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+                ensure!(!artist.is_verified(), Error::<T, I>::AlreadyVerified);
+
+                let before = artist.deposit;
+                artist.hold_verification_deposit().map_err(|e| e.error)?;
+                let deposit = artist.deposit.saturating_sub(before);
+                artist
+                    .set_evidence_deposit(0, evidence.len() as u32)
+                    .map_err(|e| e.error)?;
+
+                Ok::<_, DispatchError>(deposit)
+            })?;
+
+            Self::enqueue_verification(&origin)?;
+            PendingVerifications::<T, I>::insert(
+                origin.clone(),
+                VerificationRequest::<T, I>::new(evidence.clone()),
+            );
+
+            Self::deposit_event(Event::VerificationRequested {
+                id: origin.clone(),
+                deposit,
+            });
+            Self::deposit_event(Event::VerificationEvidenceSubmitted {
+                id: origin,
+                evidence,
+            });
+            Ok(().into())
+        }
+
+        /// Approve the pending verification request of the passed artist, refunding its escrow
+        /// and verifying it.
+        #[pallet::weight((
+            T::WeightInfo::approve_verification()
+                .saturating_add(T::MaxLifecycleHookWeight::get())
+                .saturating_add(T::MaxContractNotificationWeight::get()),
+            Pays::No
+        ))]
+        #[pallet::call_index(26)]
+        pub fn approve_verification(
+            origin: OriginFor<T>,
+            id: T::AccountId,
+            kyc_evidence_hash: Option<T::Hash>,
+        ) -> DispatchResultWithPostInfo {
+            T::VerifierOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                PendingVerifications::<T, I>::contains_key(&id),
+                Error::<T, I>::VerificationNotRequested
+            );
+
+            Self::do_verify(id, kyc_evidence_hash)
+        }
+
+        /// Reject the pending verification request of the passed artist, refunding its evidence
+        /// deposit.
+        ///
+        /// The escrow is refunded or slashed to `T::Slash` for abusive requests, following the
+        /// passed policy.
+        #[pallet::weight((T::WeightInfo::reject_verification(), Pays::No))]
+        #[pallet::call_index(27)]
+        pub fn reject_verification(
+            origin: OriginFor<T>,
+            id: T::AccountId,
+            policy: DepositPolicy,
+        ) -> DispatchResultWithPostInfo {
+            T::VerifierOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                PendingVerifications::<T, I>::contains_key(&id),
+                Error::<T, I>::VerificationNotRequested
+            );
+
+            let deposit = ArtistOf::<T, I>::try_mutate(id.clone(), |maybe_artist| {
+                let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+
+                artist
+                    .set_evidence_deposit(Self::pending_evidence_count(&id), 0)
+                    .map_err(|e| e.error)?;
+                artist
+                    .settle_verification_deposit(policy)
+                    .map_err(|e| e.error)
+            })?;
+            Self::remove_pending_verification(&id);
+
+            Self::deposit_event(Event::VerificationRejected {
+                id,
+                policy,
+                deposit,
+            });
+            Ok(().into())
+        }
+
+        /// Suspend the passed artist, or ban it for good, hiding its profile and blocking its
+        /// updates and new contracts.
+        #[pallet::weight((
+            T::WeightInfo::suspend_artist().saturating_add(T::MaxContractNotificationWeight::get()),
+            Pays::No
+        ))]
+        #[pallet::call_index(28)]
+        pub fn suspend_artist(
+            origin: OriginFor<T>,
+            id: T::AccountId,
+            ban: bool,
+        ) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let status = if ban {
+                ArtistStatus::Banned
+            } else {
+                ArtistStatus::Suspended
+            };
+            Self::set_status(id, status)?;
+            Ok(().into())
+        }
+
+        /// Reinstate the passed suspended artist.
+        #[pallet::weight((
+            T::WeightInfo::reinstate_artist().saturating_add(T::MaxContractNotificationWeight::get()),
+            Pays::No
+        ))]
+        #[pallet::call_index(29)]
+        pub fn reinstate_artist(
+            origin: OriginFor<T>,
+            id: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            Self::set_status(id, ArtistStatus::Active)?;
+            Ok(().into())
+        }
+
+        /// Export the passed artist as a portable record carried by the `ArtistExported` event.
+        #[pallet::weight(T::WeightInfo::export_artist())]
+        #[pallet::call_index(30)]
+        pub fn export_artist(origin: OriginFor<T>, id: T::AccountId) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            let artist = ArtistOf::<T, I>::get(&id).ok_or(Error::<T, I>::NotRegistered)?;
+
+            Self::deposit_event(Event::ArtistExported {
+                id,
+                record: ArtistRecord::new(artist),
+            });
+            Ok(().into())
+        }
+
+        /// Overwrite the artist of the passed record with it, fixing the names, aliases and
+        /// genres indexes.
+        ///
+        /// Meant for state surgery after a faulty migration, the held deposits and the lifecycle
+        /// handlers are left untouched.
+        #[pallet::weight(T::WeightInfo::force_restore_artist(T::MaxGenres::get()))]
+        #[pallet::call_index(31)]
+        pub fn force_restore_artist(
+            origin: OriginFor<T>,
+            record: ArtistRecord<T, I>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            ensure!(
+                record.storage_version == migrations::STORAGE_VERSION_NUMBER,
+                Error::<T, I>::RecordVersionMismatch
+            );
+
+            let artist = record.artist;
+            let id = artist.owner.clone();
+
+            if artist.holds_verification() {
+                ensure!(
+                    ArtistNameOf::<T, I>::get(&artist.main_name).map_or(true, |owner| owner == id),
+                    Error::<T, I>::NameUnavailable
+                );
+                Self::ensure_name_not_confusable(&artist.main_name, &id)?;
+            }
+            for alias in artist.aliases.iter() {
+                Self::ensure_alias_available(&id, alias)?;
+            }
+
+            let old = ArtistOf::<T, I>::get(&id);
+            if let Some(old) = &old {
+                if old.holds_verification() {
+                    Self::unindex_verified_name(&old.main_name, &id);
+                }
+            }
+            Self::reindex_genres(
+                &id,
+                &old.as_ref().map_or(Vec::new(), |old| old.indexed_genres()),
+                &artist.indexed_genres(),
+            );
+            Self::reindex_aliases(
+                &id,
+                old.as_ref().map_or(&[][..], |old| &old.aliases[..]),
+                &artist.aliases,
+            );
+            Self::reindex_name_prefix(
+                &id,
+                old.as_ref().map(|old| &old.main_name),
+                Some(&artist.main_name),
+            );
+            if artist.holds_verification() {
+                Self::index_verified_name(&artist.main_name, &id);
+            }
+            #[cfg(feature = "evm-attestation")]
+            if artist.holds_verification() {
+                attestation::attest::<T, I>(&artist);
+            } else {
+                attestation::revoke::<T, I>(&id);
+            }
+
+            ArtistOf::<T, I>::insert(&id, &artist);
+            Self::check_artist(&id, &artist)?;
+
+            Self::deposit_event(Event::ArtistRestored { id });
+            Ok(().into())
+        }
+
+        /// Claim the passed genesis profile within its claim window, with a voucher of the
+        /// `ClaimAuthority` over `Pallet::claim_voucher_payload(profile, caller)`.
+        ///
+        /// The caller takes over the profile along with its held deposits.
+        #[pallet::weight(T::WeightInfo::claim_profile()
+            .saturating_add(T::MaxLifecycleHookWeight::get().saturating_mul(2))
+            .saturating_add(T::MaxContractNotificationWeight::get()))]
+        #[pallet::call_index(32)]
+        pub fn claim_profile(
+            origin: OriginFor<T>,
+            profile: T::AccountId,
+            voucher: T::ClaimSignature,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            ensure!(
+                voucher.verify(
+                    &Self::claim_voucher_payload(&profile, &origin)[..],
+                    &T::ClaimAuthority::get()
+                ),
+                Error::<T, I>::InvalidVoucher
+            );
+
+            Self::do_claim_profile(profile, origin)?;
+            Ok(().into())
+        }
+
+        /// Hand the passed genesis profile over to the passed claimant within its claim window,
+        /// once the governance checked the claimant identity.
+        #[pallet::weight((
+            T::WeightInfo::approve_profile_claim()
+                .saturating_add(T::MaxLifecycleHookWeight::get().saturating_mul(2))
+                .saturating_add(T::MaxContractNotificationWeight::get()),
+            Pays::No
+        ))]
+        #[pallet::call_index(33)]
+        pub fn approve_profile_claim(
+            origin: OriginFor<T>,
+            profile: T::AccountId,
+            claimant: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            Self::do_claim_profile(profile, claimant)?;
+            Ok(().into())
+        }
+
+        /// Release the passed genesis profile left unclaimed past its claim window, refunding its
+        /// deposit to the account it was seeded under.
+        #[pallet::weight(T::WeightInfo::release_expired_profile()
+            .saturating_add(T::MaxLifecycleHookWeight::get())
+            .saturating_add(T::MaxContractNotificationWeight::get()))]
+        #[pallet::call_index(34)]
+        pub fn release_expired_profile(
+            origin: OriginFor<T>,
+            profile: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let expiry =
+                ClaimableProfiles::<T, I>::get(&profile).ok_or(Error::<T, I>::NotClaimable)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() > expiry,
+                Error::<T, I>::ClaimWindowOpen
+            );
+
+            let artist = ArtistOf::<T, I>::take(&profile).ok_or(Error::<T, I>::NotRegistered)?;
+            let deposit = Self::release_held_all(&artist)?;
+            Self::purge_artist(&profile, &artist, DepositPolicy::Refund)?;
+
+            Self::deposit_event(Event::ProfileReleased { profile, deposit });
+            Ok(().into())
+        }
+
+        /// Register the passed target account as an Artist, the caller paying its deposit.
+        ///
+        /// Only callable by the `T::LabelOrigin`, or by a proxy of the target account. The target
+        /// owns the profile, while its deposits stay held from the caller until it unregisters.
+        ///
+        /// The `T::AdminOrigin` can also register the target, without being bound by the
+        /// `T::MaxRegistrationsPerPeriod`. Nothing is held from the target which didn't consent
+        /// to it, the registry sponsoring the deposits of the registration as for the exempted
+        /// accounts, while its later updates hold their deposits as usual.
+        #[pallet::weight(T::WeightInfo::register_for(
+            T::MaxNameLen::get(),
+            T::MaxGenres::get(),
+            T::MaxAssets::get(),
+            T::MaxHashedDescriptionLen::get()
+        )
+        .saturating_add(T::MaxLifecycleHookWeight::get())
+        .saturating_add(T::MaxContractNotificationWeight::get().saturating_mul(2)))]
+        #[pallet::call_index(35)]
+        pub fn register_for(
+            origin: OriginFor<T>,
+            target: T::AccountId,
+            main_name: T::Name,
+            alias: Option<T::Name>,
+            genres: BoundedVec<MusicGenre, T::MaxGenres>,
+            description: Option<BoundedVec<u8, T::MaxHashedDescriptionLen>>,
+            assets: BoundedVec<(AssetType, Vec<u8>), T::MaxAssets>,
+        ) -> DispatchResultWithPostInfo {
+            let data = ArtistRegistrationData::new(main_name, alias, genres, description, assets);
+            let depositor = match T::LabelOrigin::try_origin(origin) {
+                Ok(label) => {
+                    Self::note_registration()?;
+                    label
+                }
+                Err(origin) => match T::AdminOrigin::try_origin(origin) {
+                    Ok(_) => {
+                        return Self::with_deposits_waived(&target, || {
+                            Self::do_register(target.clone(), target.clone(), data)
+                        });
+                    }
+                    Err(origin) => {
+                        let who = ensure_signed(origin)?;
+                        ensure!(T::Proxies::is_proxy(&target, &who), Error::<T, I>::NotProxy);
+                        Self::note_registration()?;
+                        who
+                    }
+                },
+            };
+
+            Self::do_register(target, depositor, data)
+        }
+
+        /// Set the beneficiaries of the caller artist royalties, replacing the previous ones.
+        ///
+        /// The shares must sum to 100%, each beneficiary appearing once. A storage deposit is held
+        /// from the artist for every beneficiary.
+        #[pallet::weight(T::WeightInfo::set_royalty_splits(T::MaxRoyaltyBeneficiaries::get()))]
+        #[pallet::call_index(36)]
+        pub fn set_royalty_splits(
+            origin: OriginFor<T>,
+            splits: RoyaltySplitsOf<T, I>,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            ArtistOf::<T, I>::try_mutate(origin.clone(), |maybe_artist| {
+                let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+                ensure!(artist.is_active(), Error::<T, I>::ArtistSuspended);
+                artist.set_royalty_splits(splits.clone())
+            })?;
+
+            Self::deposit_event(Event::RoyaltySplitsSet { id: origin, splits });
+            Ok(().into())
+        }
+
+        /// Clear the beneficiaries of the caller artist royalties, releasing their storage
+        /// deposit.
+        #[pallet::weight(T::WeightInfo::clear_royalty_splits(T::MaxRoyaltyBeneficiaries::get()))]
+        #[pallet::call_index(37)]
+        pub fn clear_royalty_splits(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            ArtistOf::<T, I>::try_mutate(origin.clone(), |maybe_artist| {
+                let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+                ensure!(artist.is_active(), Error::<T, I>::ArtistSuspended);
+                artist.clear_royalty_splits()
+            })?;
+
+            Self::deposit_event(Event::RoyaltySplitsCleared { id: origin });
+            Ok(().into())
+        }
+
+        /// Turn the caller artist into a group or back into a solo artist.
+        ///
+        /// A group must remove its members before turning solo, its pending invitations being
+        /// dropped.
+        #[pallet::weight(T::WeightInfo::set_artist_kind())]
+        #[pallet::call_index(38)]
+        pub fn set_artist_kind(
+            origin: OriginFor<T>,
+            kind: ArtistKind,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            ArtistOf::<T, I>::try_mutate(origin.clone(), |maybe_artist| {
+                let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+                ensure!(artist.is_active(), Error::<T, I>::ArtistSuspended);
+                artist.set_kind(kind)
+            })?;
+            if kind == ArtistKind::Solo {
+                MemberInvitations::<T, I>::remove(&origin);
+            }
+
+            Self::deposit_event(Event::ArtistKindChanged { id: origin, kind });
+            Ok(().into())
+        }
+
+        /// Invite the passed account to join the caller group artist, the account becoming a
+        /// member once it accepts with `accept_membership`.
+        #[pallet::weight(T::WeightInfo::add_member(T::MaxMembers::get()))]
+        #[pallet::call_index(39)]
+        pub fn add_member(
+            origin: OriginFor<T>,
+            member: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            let artist = ArtistOf::<T, I>::get(&origin).ok_or(Error::<T, I>::NotRegistered)?;
+            ensure!(artist.is_active(), Error::<T, I>::ArtistSuspended);
+            ensure!(artist.kind == ArtistKind::Group, Error::<T, I>::NotGroup);
+            ensure!(!artist.is_member(&member), Error::<T, I>::AlreadyMember);
+
+            MemberInvitations::<T, I>::try_mutate(&origin, |invitations| {
+                ensure!(
+                    !invitations.contains(&member),
+                    Error::<T, I>::AlreadyInvited
+                );
+                ensure!(
+                    invitations.len().saturating_add(artist.members.len())
+                        < T::MaxMembers::get() as usize,
+                    Error::<T, I>::MembersFull
+                );
+                invitations
+                    .try_push(member.clone())
+                    .map_err(|_| Error::<T, I>::MembersFull)
+            })?;
+
+            Self::deposit_event(Event::MemberInvited { id: origin, member });
+            Ok(().into())
+        }
+
+        /// Accept the invitation of the passed group artist, joining its members.
+        ///
+        /// A storage deposit is held from the group for every member.
+        #[pallet::weight(T::WeightInfo::accept_membership(T::MaxMembers::get()))]
+        #[pallet::call_index(40)]
+        pub fn accept_membership(
+            origin: OriginFor<T>,
+            group: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            let mut invitations = MemberInvitations::<T, I>::get(&group);
+            let pos = invitations
+                .iter()
+                .position(|x| x == &origin)
+                .ok_or(Error::<T, I>::NotInvited)?;
+
+            ArtistOf::<T, I>::try_mutate(&group, |maybe_artist| {
+                let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+                artist.add_member(origin.clone())
+            })?;
+            invitations.remove(pos);
+            MemberInvitations::<T, I>::insert(&group, invitations);
+
+            Self::deposit_event(Event::MemberAdded {
+                id: group,
+                member: origin,
+            });
+            Ok(().into())
+        }
+
+        /// Remove the passed member from the caller group artist, or cancel its invitation,
+        /// releasing its storage deposit.
+        #[pallet::weight(T::WeightInfo::remove_member(T::MaxMembers::get()))]
+        #[pallet::call_index(41)]
+        pub fn remove_member(
+            origin: OriginFor<T>,
+            member: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            Self::do_remove_member(&origin, &member)?;
+
+            Self::deposit_event(Event::MemberRemoved { id: origin, member });
+            Ok(().into())
+        }
+
+        /// Leave the passed group artist, or decline its invitation, releasing the member storage
+        /// deposit of the group.
+        #[pallet::weight(T::WeightInfo::leave_group(T::MaxMembers::get()))]
+        #[pallet::call_index(42)]
+        pub fn leave_group(
+            origin: OriginFor<T>,
+            group: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            Self::do_remove_member(&group, &origin)?;
+
+            Self::deposit_event(Event::MemberRemoved {
+                id: group,
+                member: origin,
+            });
+            Ok(().into())
+        }
+
+        /// Register the caller as an Artist with the passed registration data.
+        ///
+        /// Supersedes `register`, the data struct getting the new registration fields.
+        ///
+        /// The alias, unique across artists, is claimed along with the main name in this same
+        /// call, so no other artist can take it between the registration and a later update.
+        #[pallet::weight(T::WeightInfo::register_v2(
+            T::MaxNameLen::get(),
+            T::MaxGenres::get(),
+            T::MaxAssets::get(),
+            T::MaxLinks::get(),
+            T::MaxHashedDescriptionLen::get()
+        )
+        .saturating_add(T::MaxLifecycleHookWeight::get()))]
+        #[pallet::call_index(43)]
+        pub fn register_v2(
+            origin: OriginFor<T>,
+            data: ArtistRegistrationData<T, I>,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            Self::note_registration()?;
+            Self::do_register(origin.clone(), origin, data)
+        }
+
+        /// Become a co-owner of the passed group artist the caller is a member of, holding the
+        /// passed share from it, as offered by the group with `offer_co_ownership`.
+        ///
+        /// Co-owners can update the group as its delegates. Leaving the group, or getting removed
+        /// from it, settles their share back along with their rights.
+        #[pallet::weight(T::WeightInfo::join_ownership())]
+        #[pallet::call_index(44)]
+        pub fn join_ownership(
+            origin: OriginFor<T>,
+            group: T::AccountId,
+            share: BalanceOf<T, I>,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            let artist = ArtistOf::<T, I>::get(&group).ok_or(Error::<T, I>::NotRegistered)?;
+            ensure!(artist.is_member(&origin), Error::<T, I>::NotMember);
+            ensure!(
+                !CoOwners::<T, I>::contains_key(&group, &origin),
+                Error::<T, I>::AlreadyCoOwner
+            );
+            ensure!(
+                CoOwnershipOffers::<T, I>::get(&group, &origin) == Some(share),
+                Error::<T, I>::NoCoOwnershipOffer
+            );
+
+            T::Currency::hold(&HoldReason::<I>::GroupShare.into(), &origin, share)?;
+            CoOwnershipOffers::<T, I>::remove(&group, &origin);
+            CoOwners::<T, I>::insert(&group, &origin, share);
+
+            Self::deposit_event(Event::CoOwnerJoined {
+                id: group,
+                member: origin,
+                share,
+            });
+            Ok(().into())
+        }
+
+        /// Offer the passed member of the caller group artist to become a co-owner, holding the
+        /// passed share, which it accepts with `join_ownership`.
+        ///
+        /// Offering the co-ownership again replaces the previous offer. The share can't be lower
+        /// than `T::MinCoOwnerShare`.
+        #[pallet::weight(T::WeightInfo::offer_co_ownership())]
+        #[pallet::call_index(45)]
+        pub fn offer_co_ownership(
+            origin: OriginFor<T>,
+            member: T::AccountId,
+            share: BalanceOf<T, I>,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            let artist = ArtistOf::<T, I>::get(&origin).ok_or(Error::<T, I>::NotRegistered)?;
+            ensure!(artist.is_member(&member), Error::<T, I>::NotMember);
+            ensure!(
+                !CoOwners::<T, I>::contains_key(&origin, &member),
+                Error::<T, I>::AlreadyCoOwner
+            );
+            ensure!(!share.is_zero(), Error::<T, I>::ZeroShare);
+            ensure!(
+                share >= T::MinCoOwnerShare::get(),
+                Error::<T, I>::ShareTooLow
+            );
+
+            CoOwnershipOffers::<T, I>::insert(&origin, &member, share);
+
+            Self::deposit_event(Event::CoOwnershipOffered {
+                id: origin,
+                member,
+                share,
+            });
+            Ok(().into())
+        }
+
+        /// Let the passed account, such as an agency handling the verification paperwork, submit
+        /// the verification evidence of the caller artist, replacing the previous one, or revoke
+        /// it with `None`.
+        ///
+        /// The evidence delegate has no other right on the profile.
+        #[pallet::weight(T::WeightInfo::set_evidence_delegate())]
+        #[pallet::call_index(48)]
+        pub fn set_evidence_delegate(
+            origin: OriginFor<T>,
+            delegate: Option<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            ensure!(
+                ArtistOf::<T, I>::contains_key(&origin),
+                Error::<T, I>::NotRegistered
+            );
+            EvidenceDelegates::<T, I>::set(&origin, delegate.clone());
+
+            Self::deposit_event(Event::EvidenceDelegateSet {
+                id: origin,
+                delegate,
+            });
+            Ok(().into())
+        }
+
+        /// Attach the passed evidence fingerprints to the pending verification of the passed
+        /// artist, from its evidence delegate, as `submit_verification_evidence` does for the
+        /// artist itself.
+        ///
+        /// The evidence deposit is held from the artist.
+        #[pallet::weight(T::WeightInfo::submit_verification_evidence_as_delegate(
+            T::MaxEvidence::get()
+        ))]
+        #[pallet::call_index(49)]
+        pub fn submit_verification_evidence_as_delegate(
+            origin: OriginFor<T>,
+            id: T::AccountId,
+            evidence: BoundedVec<T::Hash, T::MaxEvidence>,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            ensure!(
+                EvidenceDelegates::<T, I>::get(&id).as_ref() == Some(&origin),
+                Error::<T, I>::NotDelegate
+            );
+
+            Self::do_submit_verification_evidence(id, evidence)
+        }
+
+        /// Offer the caller artist profile to the passed account, such as a new key or a
+        /// multisig, which takes it over once it calls `accept_ownership`.
+        ///
+        /// Offering the profile again replaces the previous offer. The new owner can't be an
+        /// artist already.
+        #[pallet::weight(T::WeightInfo::transfer_ownership())]
+        #[pallet::call_index(46)]
+        pub fn transfer_ownership(
+            origin: OriginFor<T>,
+            new_owner: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            let artist = ArtistOf::<T, I>::get(&origin).ok_or(Error::<T, I>::NotRegistered)?;
+            ensure!(artist.is_active(), Error::<T, I>::ArtistSuspended);
+            Self::ensure_can_take_over(&new_owner)?;
+
+            OwnershipOffers::<T, I>::insert(&origin, &new_owner);
+
+            Self::deposit_event(Event::OwnershipOffered {
+                id: origin,
+                new_owner,
+            });
+            Ok(().into())
+        }
+
+        /// Take over the passed artist profile offered to the caller, moving the artist record,
+        /// its held deposits and its names, aliases and genres indexes to the caller.
+        ///
+        /// A pending verification request is dropped, its escrow being refunded. The group
+        /// memberships of the artist stay with its previous account.
+        #[pallet::weight(T::WeightInfo::accept_ownership()
+            .saturating_add(T::MaxLifecycleHookWeight::get().saturating_mul(2))
+            .saturating_add(T::MaxContractNotificationWeight::get()))]
+        #[pallet::call_index(47)]
+        pub fn accept_ownership(
+            origin: OriginFor<T>,
+            artist: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            ensure!(
+                OwnershipOffers::<T, I>::get(&artist).as_ref() == Some(&origin),
+                Error::<T, I>::NoOwnershipOffer
+            );
+            Self::ensure_can_take_over(&origin)?;
+
+            Self::transfer_artist(&artist, &origin)?;
+
+            Self::deposit_event(Event::OwnershipTransferred {
+                from: artist,
+                to: origin,
+            });
+            Ok(().into())
+        }
+
+        /// Update the caller artist data field with the passed data, only if the artist update
+        /// nonce is the passed expected one.
+        ///
+        /// Supersedes `update`, letting clients make sure the profile they edit wasn't updated
+        /// meanwhile, the update failing with `StaleUpdate` otherwise.
+        #[pallet::weight({
+            let weight_fn = Pallet::<T, I>::get_weight_update_fn(&data);
+            weight_fn()
+        })]
+        #[pallet::call_index(50)]
+        pub fn update_v2(
+            origin: OriginFor<T>,
+            data: UpdatableDataOf<T, I>,
+            expected_nonce: Option<u32>,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            Self::do_update(origin, data, expected_nonce)
+        }
+
+        /// Route the payouts of the caller artist to the passed account or linked contract,
+        /// rather than to its royalty beneficiaries, or remove its route with `None`.
+        ///
+        /// A contract route is removed along with the contract when it gets unlinked.
+        #[pallet::weight(T::WeightInfo::set_payout_route())]
+        #[pallet::call_index(51)]
+        pub fn set_payout_route(
+            origin: OriginFor<T>,
+            route: Option<PayoutRoute<T::AccountId>>,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            let artist = ArtistOf::<T, I>::get(&origin).ok_or(Error::<T, I>::NotRegistered)?;
+            if let Some(PayoutRoute::Contract(contract)) = &route {
+                ensure!(
+                    artist.contracts.contains(contract),
+                    Error::<T, I>::ContractNotLinked
+                );
+            }
+            PayoutRoutes::<T, I>::set(&origin, route.clone());
+
+            Self::deposit_event(Event::PayoutRouteSet { id: origin, route });
+            Ok(().into())
+        }
+
+        /// Renew the verification of the passed verified artist from the current block, keeping
+        /// its KYC evidence, so it doesn't expire past `T::VerificationValidity`.
+        #[pallet::weight((T::WeightInfo::renew_verification(), Pays::No))]
+        #[pallet::call_index(52)]
+        pub fn renew_verification(
+            origin: OriginFor<T>,
+            id: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            T::VerifierOrigin::ensure_origin(origin)?;
+
+            ArtistOf::<T, I>::try_mutate(id.clone(), |maybe_artist| {
+                let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+                ensure!(artist.is_verified(), Error::<T, I>::NotVerified);
+
+                artist.verify(artist.kyc_evidence_hash);
+                #[cfg(feature = "evm-attestation")]
+                attestation::attest::<T, I>(artist);
+
+                Self::deposit_event(Event::VerificationRenewed { id: id.clone() });
+                Ok(().into())
+            })
+        }
+
+        /// Claim the passed work (song, album...) for the caller artist, from the fingerprint
+        /// (hash) of the work, for the releases to be cross-checked against its claimed works.
+        ///
+        /// A storage deposit is held from the artist for every work.
+        #[pallet::weight(T::WeightInfo::claim_work(T::MaxWorks::get()))]
+        #[pallet::call_index(53)]
+        pub fn claim_work(origin: OriginFor<T>, work: T::Hash) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            ArtistOf::<T, I>::try_mutate(origin.clone(), |maybe_artist| {
+                let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+                ensure!(artist.is_active(), Error::<T, I>::ArtistSuspended);
+                artist.claim_work(work)
+            })?;
+
+            Self::deposit_event(Event::WorkClaimed { id: origin, work });
+            Ok(().into())
+        }
+
+        /// Disclaim the passed work of the caller artist, releasing its storage deposit.
+        #[pallet::weight(T::WeightInfo::disclaim_work(T::MaxWorks::get()))]
+        #[pallet::call_index(54)]
+        pub fn disclaim_work(origin: OriginFor<T>, work: T::Hash) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            ArtistOf::<T, I>::try_mutate(origin.clone(), |maybe_artist| {
+                let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+                artist.disclaim_work(&work)
+            })?;
+
+            Self::deposit_event(Event::WorkDisclaimed { id: origin, work });
+            Ok(().into())
+        }
+
+        /// Apply all the passed updates to the data of the caller artist at once, in order, such
+        /// as setting its alias, genres and description after its onboarding.
+        ///
+        /// The updates are all-or-nothing, none of them being kept if any fails. The weight is
+        /// charged as the sum of the weights of the updates, the unused part being refunded from
+        /// their actual weights.
+        #[pallet::weight(Pallet::<T, I>::update_many_weight(updates))]
+        #[pallet::call_index(55)]
+        pub fn update_many(
+            origin: OriginFor<T>,
+            updates: BoundedVec<UpdatableDataOf<T, I>, T::MaxUpdatesPerCall>,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+
+            Self::do_update_many(origin, updates)
+        }
+
+        /// Reprice the deposit of the passed artist at the current `T::BaseDeposit` and
+        /// `T::ByteDeposit`, holding or releasing the difference from its depositor, for the
+        /// existing artists to follow the changes of the deposit constants.
+        ///
+        /// Anyone can poke any artist. The `T::AbuseFilter` surcharges are kept, and the artists
+        /// having a pending verification request are repriced once it gets ruled on.
+        #[pallet::weight(T::WeightInfo::poke_deposit())]
+        #[pallet::call_index(56)]
+        pub fn poke_deposit(
+            origin: OriginFor<T>,
+            target: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            ensure!(
+                !PendingVerifications::<T, I>::contains_key(&target),
+                Error::<T, I>::VerificationAlreadyRequested
+            );
+
+            let (old_deposit, new_deposit) =
+                ArtistOf::<T, I>::try_mutate(&target, |maybe_artist| {
+                    let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+                    let old_deposit = artist.deposit;
+                    artist.reprice_deposit().map_err(|e| e.error)?;
+
+                    Ok::<_, DispatchError>((old_deposit, artist.deposit))
+                })?;
+
+            Self::deposit_event(Event::DepositUpdated {
+                id: target,
+                old_deposit,
+                new_deposit,
+            });
+            Ok(().into())
+        }
+
+        /// Unregister the passed artist, left unverified for more than the
+        /// `T::UnverifiedLifetime` since its registration, so dormant profiles don't pile up.
+        ///
+        /// Anyone can reap an expired artist, getting the `T::ReapReward` share of its deposit as
+        /// an incentive, the rest being refunded to its depositor. Artists having a pending
+        /// verification request, or genesis profiles waiting for their claim, don't expire.
+        #[pallet::weight(T::WeightInfo::reap_expired(
+            T::MaxNameLen::get(),
+            T::MaxGenres::get(),
+            T::MaxAssets::get()
+        )
+        .saturating_add(T::MaxLifecycleHookWeight::get())
+        .saturating_add(T::MaxContractNotificationWeight::get()))]
+        #[pallet::call_index(57)]
+        pub fn reap_expired(
+            origin: OriginFor<T>,
+            target: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let reaper = ensure_signed(origin)?;
+
+            ensure!(
+                ArtistOf::<T, I>::contains_key(&target),
+                Error::<T, I>::NotRegistered
+            );
+            let expiry = Self::get_unverified_expiry(&target).ok_or(Error::<T, I>::NotExpired)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= expiry,
+                Error::<T, I>::NotExpired
+            );
+
+            let artist = ArtistOf::<T, I>::take(&target).ok_or(Error::<T, I>::NotRegistered)?;
+            let reward = Self::transfer_held_up_to(
+                &artist,
+                &reaper,
+                T::ReapReward::get().mul_floor(artist.deposit),
+            )?;
+            let deposit =
+                Self::release_held_up_to(&artist, Self::held_cap(&artist).saturating_sub(reward))?;
+            Self::retire_name(&target, &artist.main_name);
+            Self::purge_artist(&target, &artist, DepositPolicy::Refund)?;
+
+            Self::deposit_event(Event::ArtistReaped {
+                id: target,
+                reaper,
+                reward,
+                deposit,
+                depositor: artist.depositor,
+            });
+            Ok(().into())
+        }
+
+        /// Register an account of another chain as an Artist with the passed registration data,
+        /// typically through an XCM `Transact`.
+        ///
+        /// Only callable by the `T::RemoteRegistrationOrigin`, the account derived from the remote
+        /// location owning the profile and paying its deposit. The profile is tagged with the
+        /// chain it comes from, for display purposes.
+        #[pallet::weight(T::WeightInfo::register_remote(
+            T::MaxNameLen::get(),
+            T::MaxGenres::get(),
+            T::MaxAssets::get(),
+            T::MaxLinks::get(),
+            T::MaxHashedDescriptionLen::get()
+        )
+        .saturating_add(T::MaxLifecycleHookWeight::get())
+        .saturating_add(T::MaxContractNotificationWeight::get().saturating_mul(2)))]
+        #[pallet::call_index(58)]
+        pub fn register_remote(
+            origin: OriginFor<T>,
+            data: ArtistRegistrationData<T, I>,
+        ) -> DispatchResultWithPostInfo {
+            let (who, origin_chain) = T::RemoteRegistrationOrigin::ensure_origin(origin)?;
+
+            Self::note_registration()?;
+            Self::do_register(who.clone(), who.clone(), data)?;
+            RemoteOriginOf::<T, I>::insert(&who, origin_chain);
+
+            Self::deposit_event(Event::RemoteArtistRegistered {
+                id: who,
+                origin_chain,
+            });
+            Ok(().into())
+        }
+    }
+}
+
+impl<T, I: 'static> Pallet<T, I>
+where
+    T: frame_system::Config + Config<I>,
+{
+    /// Update the data field of the passed artist with the passed data, failing if its update
+    /// nonce isn't the expected one.
+    fn do_update(
+        origin: T::AccountId,
+        data: UpdatableDataOf<T, I>,
+        expected_nonce: Option<u32>,
+    ) -> DispatchResultWithPostInfo {
+        let weight = Self::apply_update(&origin, &data, expected_nonce, false)?;
+
+        Self::deposit_event(ArtistUpdated {
+            id: origin,
+            new_data: data,
+        });
+        Ok(Some(weight).into())
+    }
+
+    /// Apply all the passed updates to the passed artist, in order, returning their actual
+    /// weight.
+    ///
+    /// Nothing is kept if any update fails, the storage transaction of the dispatch reverting the
+    /// updates applied before the failing one.
+    fn do_update_many(
+        origin: T::AccountId,
+        updates: BoundedVec<UpdatableDataOf<T, I>, T::MaxUpdatesPerCall>,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(!updates.is_empty(), Error::<T, I>::NoUpdates);
+
+        let weight = updates.iter().try_fold(Weight::zero(), |acc, data| {
+            Self::apply_update(&origin, data, None, false).map(|weight| acc.saturating_add(weight))
+        })?;
+
+        Self::deposit_event(Event::ArtistBatchUpdated {
+            id: origin,
+            updates,
+        });
+        Ok(Some(weight).into())
+    }
+
+    /// Update the data field of the passed artist with the passed data, depositing the events of
+    /// the changed field, and return the actual weight of the update.
+    ///
+    /// A `forced` update, made by the `T::AdminOrigin`, also applies to a suspended artist and
+    /// goes through neither the `T::AssetBudget` nor the `T::AbuseFilter`.
+    fn apply_update(
+        origin: &T::AccountId,
+        data: &UpdatableDataOf<T, I>,
+        expected_nonce: Option<u32>,
+        forced: bool,
+    ) -> Result<Weight, DispatchErrorWithPostInfo> {
+        ArtistOf::<T, I>::try_mutate(origin, |maybe_artist| {
+            if let Some(artist) = maybe_artist {
+                ensure!(forced || artist.is_active(), Error::<T, I>::ArtistSuspended);
+                if let Some(expected_nonce) = expected_nonce {
+                    ensure!(
+                        artist.update_nonce == expected_nonce,
+                        Error::<T, I>::StaleUpdate
+                    );
+                }
+                if let Some(alias) = data.added_alias() {
+                    Self::ensure_alias_available(origin, alias)?;
+                }
+                let (budget_usage, verdict) = if forced {
+                    (None, AbuseVerdict::Accept)
+                } else {
+                    let budget_usage = Self::asset_budget_usage(origin, data)?;
+                    let verdict = T::AbuseFilter::score_update(origin, data);
+                    ensure!(
+                        verdict != AbuseVerdict::Reject,
+                        Error::<T, I>::SubmissionRejected
+                    );
+                    (budget_usage, verdict)
+                };
+
+                let old = artist.field(data);
+                let weight = Self::actual_update_weight(data, artist);
+                let old_genres = artist.indexed_genres();
+                let old_aliases = artist.aliases.clone();
+                let old_assets = artist.asset_hashes();
+                artist.update(data.clone())?;
+                artist.note_update();
+                Self::reindex_genres(origin, &old_genres, &artist.indexed_genres());
+                Self::reindex_aliases(origin, &old_aliases, &artist.aliases);
+                if let Some(usage) = budget_usage {
+                    AssetBudgetUsage::<T, I>::insert(origin, usage);
+                }
+                Self::hold_surcharge(origin, artist, verdict)?;
+
+                Self::deposit_event(Event::ArtistDataChanged {
+                    id: origin.clone(),
+                    old,
+                    new: artist.field(data),
+                });
+                Self::deposit_update_events(origin, &old_genres, &old_aliases, &old_assets, artist);
+
+                Ok(weight)
+            } else {
+                return Err(Error::<T, I>::NotRegistered.into());
+            }
+        })
+    }
+
+    /// Return the artist registered with the passed account, if any.
+    ///
+    /// The artist invariants are checked in debug builds.
+    pub fn get_artist_by_id<KArg: EncodeLike<T::AccountId>>(id: KArg) -> Option<Artist<T, I>> {
+        let artist = ArtistOf::<T, I>::get(id);
+
+        if let Some(artist) = &artist {
+            debug_assert!(
+                Self::check_artist(artist.owner(), artist).is_ok(),
+                "artist invariants violated"
+            );
+        }
+
+        artist
+    }
+
+    /// Return the verified artist registered with the passed main name, if any.
+    ///
+    /// The name index consistency is checked in debug builds. The artists whose verification
+    /// lapsed aren't returned, even before the cleanup drops them from the index.
+    pub fn get_artist_by_name(name: Vec<u8>) -> Option<Artist<T, I>> {
+        let name = normalize_name::<T::Name>(name).ok()?;
+        let artist = ArtistNameOf::<T, I>::get(&name).and_then(Self::get_artist_by_id);
+
+        if let Some(artist) = &artist {
+            debug_assert!(
+                artist.holds_verification() && artist.main_name == name,
+                "name index points to an artist not verified under this name"
+            );
+        }
+
+        artist.filter(|artist| artist.is_verified())
+    }
+
+    /// Return the sum of the deposits tracked by the registered artists, along with the sum
+    /// actually held from their depositors, both only differing on an accounting drift.
+    ///
+    /// Iterates the whole registry, meant for off-chain audits.
+    pub fn total_deposits_held() -> (BalanceOf<T, I>, BalanceOf<T, I>) {
+        Self::tracked_deposits().into_iter().fold(
+            (Zero::zero(), Zero::zero()),
+            |(tracked, held), (depositor, deposit)| {
+                (
+                    tracked.saturating_add(deposit),
+                    held.saturating_add(Self::held_deposits(&depositor)),
+                )
+            },
+        )
+    }
+
+    /// Return the depositors holding less than the deposits tracked by their artists, such as
+    /// after a slash bypassing the registry, along with the tracked and the held amounts.
+    ///
+    /// A label is listed once for all the artists it registered. Iterates the whole registry,
+    /// meant for off-chain audits.
+    pub fn artists_with_shortfall() -> Vec<(T::AccountId, BalanceOf<T, I>, BalanceOf<T, I>)> {
+        Self::tracked_deposits()
+            .into_iter()
+            .filter_map(|(depositor, deposit)| {
+                let held = Self::held_deposits(&depositor);
+                (held < deposit).then_some((depositor, deposit, held))
+            })
+            .collect()
+    }
+
+    /// The deposits tracked by the registered artists, summed per depositor.
+    fn tracked_deposits() -> BTreeMap<T::AccountId, BalanceOf<T, I>> {
+        let mut deposits = BTreeMap::<T::AccountId, BalanceOf<T, I>>::new();
+        for artist in ArtistOf::<T, I>::iter_values() {
+            let deposit = deposits.entry(artist.depositor).or_insert_with(Zero::zero);
+            *deposit = deposit.saturating_add(artist.deposit);
+        }
+
+        deposits
+    }
+
+    /// The deposits actually held from the passed depositor, under every artist deposit reason.
+    fn held_deposits(depositor: &T::AccountId) -> BalanceOf<T, I> {
+        Self::deposit_reasons()
+            .into_iter()
+            .fold(BalanceOf::<T, I>::zero(), |acc, reason| {
+                acc.saturating_add(T::Currency::balance_on_hold(&reason.into(), depositor))
+            })
+    }
+
+    /// Return the view of the artist registered with the passed account, with the breakdown of
+    /// its deposit, if any.
+    pub fn get_artist_view(id: &T::AccountId) -> Option<ArtistView<T, I>> {
+        Self::get_artist_by_id(id).map(ArtistView::new)
+    }
+
+    /// Return the headline genre of the artist registered with the passed account ID along with
+    /// its secondary genres, if any.
+    pub fn get_artist_genres(id: &T::AccountId) -> Option<(Option<MusicGenre>, Vec<MusicGenre>)> {
+        Self::get_artist_by_id(id).map(|artist| {
+            (
+                artist.main_genre.and_then(|genre| genre.genre()),
+                artist
+                    .genres
+                    .iter()
+                    .filter_map(StoredGenre::genre)
+                    .collect(),
+            )
+        })
+    }
+
+    /// Return the latest version of the client-facing artist records.
+    pub fn latest_record_version() -> u32 {
+        LATEST_RECORD_VERSION
+    }
+
+    /// Return the artist registered with the passed account ID as a client-facing record of the
+    /// passed version, if any and if this version is released.
+    pub fn get_artist_record(
+        id: &T::AccountId,
+        version: u32,
+    ) -> Option<VersionedArtistRecordOf<T, I>> {
+        Self::get_artist_by_id(id).and_then(|artist| artist.to_record(id.clone(), version))
+    }
+
+    /// Return whether the passed call only manages the profile of an artist (its data, name,
+    /// verification, group members and claimed works), never registering or unregistering it,
+    /// transferring its ownership, granting its rights to other accounts nor routing its
+    /// royalties.
+    ///
+    /// Runtimes filter their artist manager proxies with it, through
+    /// [`ArtistCallGroup::ManagementCall`].
+    pub fn is_management_call(call: &Call<T, I>) -> bool {
+        matches!(
+            call,
+            Call::update { .. }
+                | Call::update_v2 { .. }
+                | Call::update_many { .. }
+                | Call::update_as_delegate { .. }
+                | Call::rename { .. }
+                | Call::submit_verification_evidence { .. }
+                | Call::submit_verification_evidence_as_delegate { .. }
+                | Call::request_verification { .. }
+                | Call::set_artist_kind { .. }
+                | Call::add_member { .. }
+                | Call::remove_member { .. }
+                | Call::claim_work { .. }
+                | Call::disclaim_work { .. }
+        )
+    }
+
+    /// Return the artist registered with the passed alias, if any.
+    pub fn get_artist_by_alias(alias: Vec<u8>) -> Option<Artist<T, I>> {
+        let alias = normalize_name::<T::Name>(alias).ok()?;
+        let artist = ArtistAliasIndex::<T, I>::get(&alias).and_then(Self::get_artist_by_id);
+
+        if let Some(artist) = &artist {
+            debug_assert!(
+                artist.aliases.contains(&alias),
+                "alias index points to an artist without this alias"
+            );
+        }
+
+        artist
+    }
+
+    /// Check the invariants of the artist registered with the passed account, meant to be called
+    /// from `try-state` or tests to catch state corruption early.
+    pub fn validate_artist(id: &T::AccountId) -> Result<(), DispatchError> {
+        let artist = ArtistOf::<T, I>::get(id).ok_or(Error::<T, I>::NotRegistered)?;
+        Self::check_artist(id, &artist)
+    }
+
+    fn check_artist(id: &T::AccountId, artist: &Artist<T, I>) -> Result<(), DispatchError> {
+        ensure!(
+            artist.owner() == id,
+            DispatchError::Other("artist stored under another account")
+        );
+
+        if artist.holds_verification() {
+            ensure!(
+                ArtistNameOf::<T, I>::get(&artist.main_name).as_ref() == Some(id),
+                DispatchError::Other("verified artist missing from the name index")
+            );
+        }
+
+        ensure!(
+            artist
+                .indexed_genres()
+                .iter()
+                .all(|genre| ArtistsOfGenre::<T, I>::contains_key(genre, id)),
+            DispatchError::Other("artist genre missing from the genres index")
+        );
+
+        if let Some(bucket) = Self::name_prefix_bucket(&artist.main_name.to_bytes()) {
+            ensure!(
+                ArtistsOfNamePrefix::<T, I>::contains_key(bucket, id),
+                DispatchError::Other("artist missing from the name prefix index")
+            );
+        }
+
+        // The index only keeps one artist per alias, any other one being a duplicate
+        ensure!(
+            artist
+                .aliases()
+                .iter()
+                .all(|alias| ArtistAliasIndex::<T, I>::get(alias).as_ref() == Some(id)),
+            DispatchError::Other("artist alias missing from the alias index")
+        );
+
+        let mut hashes = BTreeSet::new();
+        ensure!(
+            artist
+                .assets()
+                .iter()
+                .all(|asset| hashes.insert(asset.hash.digest())),
+            DispatchError::Other("duplicate asset fingerprint")
+        );
+
+        let mut genres = BTreeSet::new();
+        ensure!(
+            artist
+                .indexed_genres()
+                .iter()
+                .all(|genre| genres.insert(*genre)),
+            DispatchError::Other("duplicate genre")
+        );
+
+        let mut contracts = BTreeSet::new();
+        ensure!(
+            artist
+                .contracts()
+                .iter()
+                .all(|contract| contracts.insert(contract)),
+            DispatchError::Other("duplicate contract")
+        );
+
+        let mut delegates = BTreeSet::new();
+        ensure!(
+            artist
+                .delegates()
+                .iter()
+                .all(|delegate| delegates.insert(delegate)),
+            DispatchError::Other("duplicate delegate")
+        );
+
+        let links = artist.links();
+        ensure!(
+            links
+                .iter()
+                .enumerate()
+                .all(|(i, link)| !links[..i].contains(link)),
+            DispatchError::Other("duplicate link")
+        );
+
+        let mut members = BTreeSet::new();
+        ensure!(
+            artist.members().iter().all(|member| members.insert(member)),
+            DispatchError::Other("duplicate member")
+        );
+        ensure!(
+            artist.kind == ArtistKind::Group || artist.members.is_empty(),
+            DispatchError::Other("solo artist with members")
+        );
+
+        let splits = artist.royalty_splits();
+        if !splits.is_empty() {
+            let total = splits.iter().fold(0u64, |total, (_, share)| {
+                total.saturating_add(share.deconstruct().into())
+            });
+            ensure!(
+                total == Perbill::one().deconstruct().into(),
+                DispatchError::Other("royalty shares not summing to 100%")
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Check the invariants of every artist, of the verified names, aliases and genres indexes, and
+    /// that every depositor holds exactly the deposits tracked by its artists.
+    #[cfg(any(feature = "try-runtime", test))]
+    fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+        ensure!(
+            ArtistOf::<T, I>::count() as usize == ArtistOf::<T, I>::iter_keys().count(),
+            "artists counter doesn't match the registered artists"
+        );
+
+        for (id, artist) in ArtistOf::<T, I>::iter() {
+            Self::check_artist(&id, &artist)?;
+        }
+
+        for (depositor, deposit) in Self::tracked_deposits() {
+            ensure!(
+                Self::held_deposits(&depositor) == deposit,
+                "the held deposits don't match the tracked ones"
+            );
+        }
+
+        for (name, id) in ArtistNameOf::<T, I>::iter() {
+            let artist = ArtistOf::<T, I>::get(&id).ok_or("name index points to no artist")?;
+            ensure!(
+                artist.holds_verification() && artist.main_name == name,
+                "name index points to an artist not verified under this name"
+            );
+        }
+
+        for (name_hash, id) in ConfusableNameOf::<T, I>::iter() {
+            let artist =
+                ArtistOf::<T, I>::get(&id).ok_or("confusable name index points to no artist")?;
+            ensure!(
+                artist.holds_verification()
+                    && Self::confusable_name_hash(&artist.main_name) == name_hash,
+                "confusable name index points to an artist not verified under this name"
+            );
+        }
+
+        for id in ClaimableProfiles::<T, I>::iter_keys() {
+            ensure!(
+                ArtistOf::<T, I>::contains_key(&id),
+                "claimable profile points to no artist"
+            );
+        }
+
+        for id in AssetBudgetUsage::<T, I>::iter_keys() {
+            ensure!(
+                ArtistOf::<T, I>::contains_key(&id),
+                "asset budget usage of no artist"
+            );
+        }
+
+        for (id, member, _) in CoOwners::<T, I>::iter() {
+            let artist = ArtistOf::<T, I>::get(&id).ok_or("co-owners of no artist")?;
+            ensure!(artist.is_member(&member), "co-owner not a member");
+        }
+
+        for (id, member, _) in CoOwnershipOffers::<T, I>::iter() {
+            let artist = ArtistOf::<T, I>::get(&id).ok_or("co-ownership offer of no artist")?;
+            ensure!(
+                artist.is_member(&member),
+                "co-ownership offered to a non-member"
+            );
+            ensure!(
+                !CoOwners::<T, I>::contains_key(&id, &member),
+                "co-ownership offered to a co-owner"
+            );
+        }
+
+        for (id, invitations) in MemberInvitations::<T, I>::iter() {
+            let artist = ArtistOf::<T, I>::get(&id).ok_or("member invitations of no artist")?;
+            ensure!(
+                artist.kind == ArtistKind::Group,
+                "member invitations of a solo artist"
+            );
+            ensure!(
+                invitations.iter().all(|member| !artist.is_member(member)),
+                "invited account already a member"
+            );
+            ensure!(
+                invitations.len().saturating_add(artist.members.len())
+                    <= T::MaxMembers::get() as usize,
+                "more members and invitations than `MaxMembers`"
+            );
+        }
+
+        #[cfg(feature = "evm-attestation")]
+        for (id, record) in attestation::VerificationAttestations::<T, I>::iter() {
+            let artist = ArtistOf::<T, I>::get(&id).ok_or("attestation of no artist")?;
+            ensure!(
+                artist.verified_at == Some(record.verified_at)
+                    && artist.kyc_evidence_hash == record.kyc_evidence_hash,
+                "attestation doesn't match the artist verification"
+            );
+        }
+
+        for (alias, id) in ArtistAliasIndex::<T, I>::iter() {
+            let artist = ArtistOf::<T, I>::get(&id).ok_or("alias index points to no artist")?;
+            ensure!(
+                artist.aliases.contains(&alias),
+                "alias index points to an artist without this alias"
+            );
+        }
+
+        for (genre, id) in ArtistsOfGenre::<T, I>::iter_keys() {
+            let artist = ArtistOf::<T, I>::get(&id).ok_or("genres index points to no artist")?;
+            ensure!(
+                artist.indexed_genres().contains(&genre),
+                "genres index points to an artist without this genre"
+            );
+        }
+
+        for (bucket, id) in ArtistsOfNamePrefix::<T, I>::iter_keys() {
+            let artist =
+                ArtistOf::<T, I>::get(&id).ok_or("name prefix index points to no artist")?;
+            ensure!(
+                Self::name_prefix_bucket(&artist.main_name.to_bytes()) == Some(bucket),
+                "name prefix index points to an artist under another prefix"
+            );
+        }
+
+        for id in RemoteOriginOf::<T, I>::iter_keys() {
+            ensure!(
+                ArtistOf::<T, I>::contains_key(&id),
+                "remote origin tags an unregistered account"
+            );
+        }
+
+        for id in LapsedVerifications::<T, I>::iter_keys() {
+            ensure!(
+                ArtistOf::<T, I>::contains_key(&id),
+                "lapsed verification of an unregistered account"
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Return every registered artist having the passed genre.
+    pub fn get_artists_by_genre(genre: MusicGenre) -> Vec<Artist<T, I>> {
+        ArtistsOfGenre::<T, I>::iter_key_prefix(StoredGenre::from(genre))
+            .filter_map(|id| ArtistOf::<T, I>::get(id))
+            .collect()
+    }
+
+    /// Return the registered artists whose main name starts with the passed prefix, compared by
+    /// their confusable key (e.g: case insensitive), among the `count` next artists of the index
+    /// after the `start_after` one or from the first one, along with the last examined artist
+    /// to resume the search from, `None` once the search is over.
+    ///
+    /// Only the bucket of the `T::NamePrefixLen` first bytes of the prefix is iterated, shorter
+    /// prefixes being rejected with `PrefixTooShort`. Nothing matches while the index is
+    /// disabled.
+    pub fn get_artists_by_name_prefix(
+        prefix: Vec<u8>,
+        start_after: Option<&T::AccountId>,
+        count: u32,
+    ) -> Result<(Vec<Artist<T, I>>, Option<T::AccountId>), DispatchError> {
+        let key = T::NameValidator::confusable_key(&prefix);
+        let Some(bucket) = Self::name_prefix_bucket(&prefix) else {
+            return Ok((Vec::new(), None));
+        };
+        ensure!(
+            key.len() >= T::NamePrefixLen::get() as usize,
+            Error::<T, I>::PrefixTooShort
+        );
+
+        let ids: Vec<T::AccountId> = match start_after {
+            Some(id) => ArtistsOfNamePrefix::<T, I>::iter_key_prefix_from(
+                &bucket,
+                ArtistsOfNamePrefix::<T, I>::hashed_key_for(&bucket, id),
+            )
+            .take(count as usize)
+            .collect(),
+            None => ArtistsOfNamePrefix::<T, I>::iter_key_prefix(&bucket)
+                .take(count as usize)
+                .collect(),
+        };
+        let next = ids.last().filter(|_| ids.len() == count as usize).cloned();
+
+        let artists = ids
+            .into_iter()
+            .filter_map(|id| ArtistOf::<T, I>::get(id))
+            .filter(|artist| {
+                T::NameValidator::confusable_key(&artist.main_name.to_bytes()).starts_with(&key)
+            })
+            .collect();
+        Ok((artists, next))
+    }
+
+    /// Return the block from which the passed artist can be reaped with `reap_expired`, if it
+    /// is registered and expires: it never got verified, even if its verification lapsed since,
+    /// has no pending verification request and isn't a genesis profile waiting for its claim.
+    pub fn get_unverified_expiry(id: &T::AccountId) -> Option<BlockNumberFor<T>> {
+        let lifetime = T::UnverifiedLifetime::get()?;
+        let artist = ArtistOf::<T, I>::get(id)?;
+
+        if artist.holds_verification()
+            || LapsedVerifications::<T, I>::contains_key(id)
+            || PendingVerifications::<T, I>::contains_key(id)
+            || ClaimableProfiles::<T, I>::contains_key(id)
+        {
+            return None;
+        }
+
+        Some(artist.registered_at.saturating_add(lifetime))
+    }
+
+    /// Return the amount of registered artists.
+    pub fn artists_count() -> u32 {
+        ArtistOf::<T, I>::count()
+    }
+
+    /// Return up to `count` registered artists, starting after the `start_after` one or from the
+    /// first one.
+    ///
+    /// The artists are always enumerated in the same order, so explorers can page through the
+    /// registry by passing the last artist of a page to get the next one.
+    pub fn get_artists_page(start_after: Option<&T::AccountId>, count: u32) -> Vec<Artist<T, I>> {
+        let artists = match start_after {
+            Some(id) => ArtistOf::<T, I>::iter_from(ArtistOf::<T, I>::hashed_key_for(id)),
+            None => ArtistOf::<T, I>::iter(),
+        };
+
+        artists
+            .take(count as usize)
+            .map(|(_, artist)| artist)
+            .collect()
+    }
+
+    /// Run the whole registration of the passed artist without keeping any change, returning the
+    /// artist as it would be stored (fingerprints, deposit...) or the registration error.
+    ///
+    /// Frontends display it as a faithful preview before the artist signs its registration.
+    pub fn simulate_register(
+        id: T::AccountId,
+        main_name: Vec<u8>,
+        alias: Option<Vec<u8>>,
+        genres: Vec<MusicGenre>,
+        description: Option<Vec<u8>>,
+        assets: Vec<(AssetType, Vec<u8>)>,
+    ) -> Result<Artist<T, I>, DispatchError> {
+        let main_name = normalize_name::<T::Name>(main_name).map_err(Error::<T, I>::from)?;
+        let alias = alias
+            .map(|x| normalize_name::<T::Name>(x).map_err(Error::<T, I>::from))
+            .transpose()?;
+        let genres: BoundedVec<MusicGenre, T::MaxGenres> =
+            genres.try_into().map_err(|_| Error::<T, I>::GenresFull)?;
+        let assets: BoundedVec<(AssetType, Vec<u8>), T::MaxAssets> =
+            assets.try_into().map_err(|_| Error::<T, I>::AssetsFull)?;
+        let description = description
+            .map(|x| x.try_into().map_err(|_| Error::<T, I>::DescriptionTooLong))
+            .transpose()?;
+
+        with_transaction(|| {
+            let artist = Self::note_registration()
+                .and_then(|_| {
+                    Self::do_register(
+                        id.clone(),
+                        id.clone(),
+                        ArtistRegistrationData::new(main_name, alias, genres, description, assets),
+                    )
+                    .map_err(|e| e.error)
+                })
+                .and_then(|_| {
+                    ArtistOf::<T, I>::get(&id).ok_or(Error::<T, I>::NotRegistered.into())
+                });
+
+            TransactionOutcome::Rollback(artist)
+        })
+    }
+
+    /// Return the deposit held for registering an artist with a main name and an optional alias
+    /// of the passed lengths, the passed amount of assets and a description of the passed length,
+    /// zero for none, so wallets display it before the registration.
+    ///
+    /// Deposit exemptions and `T::AbuseFilter` surcharges aren't accounted for.
+    pub fn registration_deposit(
+        name_len: u32,
+        alias_len: Option<u32>,
+        assets: u32,
+        description_len: u32,
+    ) -> Result<BalanceOf<T, I>, DispatchError> {
+        let name = |len: u32| {
+            normalize_name::<T::Name>(sp_std::vec![0; len as usize]).map_err(Error::<T, I>::from)
+        };
+        let main_name = name(name_len)?;
+        let alias = alias_len.map(name).transpose()?;
+        ensure!(assets <= T::MaxAssets::get(), Error::<T, I>::AssetsFull);
+
+        Ok(Artist::<T, I>::registration_deposit(
+            &main_name,
+            &alias,
+            assets,
+            description_len > 0,
+        ))
+    }
+
+    /// Run the passed update of the passed artist without keeping any change, returning the
+    /// amounts it would hold and release from the artist depositor, one of them being zero, or
+    /// the update error.
+    ///
+    /// Wallets display it before the artist signs its update.
+    pub fn update_deposit_delta(
+        id: T::AccountId,
+        data: UpdatableDataOf<T, I>,
+    ) -> Result<(BalanceOf<T, I>, BalanceOf<T, I>), DispatchError> {
+        with_transaction(|| {
+            let delta = ArtistOf::<T, I>::get(&id)
+                .ok_or(Error::<T, I>::NotRegistered.into())
+                .and_then(|before| {
+                    Self::update(frame_system::RawOrigin::Signed(id.clone()).into(), data)
+                        .map_err(|e| e.error)?;
+                    let after = ArtistOf::<T, I>::get(&id).ok_or(Error::<T, I>::NotRegistered)?;
+
+                    Ok((
+                        after.deposit.saturating_sub(before.deposit),
+                        before.deposit.saturating_sub(after.deposit),
+                    ))
+                });
+
+            TransactionOutcome::Rollback(delta)
+        })
+    }
+
+    /// Return up to `count` of the contracts linked to the passed artist, starting from the
+    /// `start` one, so consumers can page through long contracts lists.
+    ///
+    /// The whole artist is still read from storage.
+    pub fn get_artist_contracts(id: &T::AccountId, start: u32, count: u32) -> Vec<T::AccountId> {
+        ArtistOf::<T, I>::get(id)
+            .map(|artist| {
+                artist
+                    .contracts
+                    .into_iter()
+                    .skip(start as usize)
+                    .take(count as usize)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Move the passed artist in the genres index from its `old` genres to its `new` ones.
+    fn reindex_genres(id: &T::AccountId, old: &[StoredGenre], new: &[StoredGenre]) {
+        for genre in old.iter().filter(|genre| !new.contains(genre)) {
+            ArtistsOfGenre::<T, I>::remove(genre, id);
+        }
+        for genre in new.iter().filter(|genre| !old.contains(genre)) {
+            ArtistsOfGenre::<T, I>::insert(genre, id, ());
+        }
+    }
+
+    /// Ensure the passed alias can be used by `who`, being a canonical name accepted by the
+    /// `T::NameValidator`, neither the alias nor the verified main name of another artist, nor
+    /// confusable with the latter.
+    fn ensure_alias_available(who: &T::AccountId, alias: &T::Name) -> DispatchResult {
+        ensure!(
+            &Self::checked_name(alias.clone())? == alias,
+            Error::<T, I>::InvalidName
+        );
+        ensure!(
+            ArtistAliasIndex::<T, I>::get(alias).map_or(true, |owner| &owner == who),
+            Error::<T, I>::AliasUnavailable
+        );
+        ensure!(
+            ArtistNameOf::<T, I>::get(alias).map_or(true, |owner| &owner == who),
+            Error::<T, I>::AliasUnavailable
+        );
+
+        Self::ensure_name_not_confusable(alias, who)
+    }
+
+    /// Deposit the granular events of an update of the passed artist, from its genres, aliases
+    /// and asset fingerprints before the update.
+    fn deposit_update_events(
+        id: &T::AccountId,
+        old_genres: &[StoredGenre],
+        old_aliases: &[T::Name],
+        old_assets: &[FingerprintOf<T>],
+        artist: &Artist<T, I>,
+    ) {
+        let new_genres = artist.indexed_genres();
+        for genre in old_genres.iter().filter(|x| !new_genres.contains(x)) {
+            Self::deposit_event(Event::GenreRemoved {
+                id: id.clone(),
+                genre: *genre,
+            });
+        }
+        for genre in new_genres.iter().filter(|x| !old_genres.contains(x)) {
+            Self::deposit_event(Event::GenreAdded {
+                id: id.clone(),
+                genre: *genre,
+            });
+        }
+
+        let new_assets = artist.asset_hashes();
+        for hash in old_assets.iter().filter(|x| !new_assets.contains(x)) {
+            Self::deposit_event(Event::AssetRemoved {
+                id: id.clone(),
+                hash: hash.clone(),
+            });
+        }
+        for hash in new_assets.iter().filter(|x| !old_assets.contains(x)) {
+            Self::deposit_event(Event::AssetAdded {
+                id: id.clone(),
+                hash: hash.clone(),
+            });
+        }
+
+        for alias in old_aliases.iter().filter(|x| !artist.aliases.contains(x)) {
+            Self::deposit_event(Event::AliasRemoved {
+                id: id.clone(),
+                alias: alias.clone(),
+            });
+        }
+        for alias in artist.aliases.iter().filter(|x| !old_aliases.contains(x)) {
+            Self::deposit_event(Event::AliasAdded {
+                id: id.clone(),
+                alias: alias.clone(),
+            });
+        }
+    }
+
+    /// Move the passed artist in the aliases index from its `old` aliases to its `new` ones,
+    /// which must have been checked with `ensure_alias_available`.
+    fn reindex_aliases(id: &T::AccountId, old: &[T::Name], new: &[T::Name]) {
+        for alias in old.iter().filter(|alias| !new.contains(alias)) {
+            if ArtistAliasIndex::<T, I>::get(alias).as_ref() == Some(id) {
+                ArtistAliasIndex::<T, I>::remove(alias);
+            }
+        }
+        for alias in new.iter().filter(|alias| !old.contains(alias)) {
+            ArtistAliasIndex::<T, I>::insert(alias, id);
+        }
+    }
+
+    /// Link the passed contract to the passed artist, holding the contract storage deposit from
+    /// the artist.
+    ///
+    /// Meant to be called by the pallets deploying contracts on behalf of artists
+    /// (e.g: royalties contracts).
+    pub fn link_contract(id: &T::AccountId, contract: T::AccountId) -> DispatchResult {
+        ArtistOf::<T, I>::try_mutate(id, |maybe_artist| {
+            let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+            ensure!(artist.is_active(), Error::<T, I>::ArtistSuspended);
+            artist.add_contract(contract.clone()).map_err(|e| e.error)?;
+
+            Self::deposit_event(Event::ContractLinked {
+                id: id.clone(),
+                contract,
+            });
+            Ok(())
+        })
+    }
+
+    /// Unlink the passed contract from the passed artist, releasing the contract storage deposit.
+    pub fn unlink_contract(id: &T::AccountId, contract: &T::AccountId) -> DispatchResult {
+        ArtistOf::<T, I>::try_mutate(id, |maybe_artist| {
+            let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+            artist.remove_contract(contract).map_err(|e| e.error)?;
+            if PayoutRoutes::<T, I>::get(id) == Some(PayoutRoute::Contract(contract.clone())) {
+                PayoutRoutes::<T, I>::remove(id);
+            }
+
+            Self::deposit_event(Event::ContractUnlinked {
+                id: id.clone(),
+                contract: contract.clone(),
+            });
+            Ok(())
+        })
+    }
+
+    fn set_contracts_frozen(id: T::AccountId, frozen: bool) -> DispatchResultWithPostInfo {
+        ArtistOf::<T, I>::try_mutate(id, |maybe_artist| {
+            let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+            artist.set_contracts_frozen(frozen)
+        })
+    }
+
+    /// Return the canonical bytes of the passed raw name, under which it would be registered and
+    /// indexed once checked by the `T::NameValidator`, or the error of the invalid names.
+    pub fn normalized_name(raw: Vec<u8>) -> Result<Vec<u8>, DispatchError> {
+        let name = normalize_name::<T::Name>(raw).map_err(Error::<T, I>::from)?;
+        Ok(Self::checked_name(name)?.to_bytes())
+    }
+
+    /// Return the fingerprint under which the passed name is banned or reserved.
+    pub fn name_hash(name: &[u8]) -> T::Hash {
+        T::Hashing::hash(name)
+    }
+
+    /// Ensure the passed name can be registered by `who`, consuming the name reservation if `who`
+    /// is its claimant.
+    fn claim_name(name: &T::Name, who: &T::AccountId) -> DispatchResult {
+        let name_hash = Self::name_hash(&name.to_bytes());
+
+        // The names held by lapsed verifications are freed without waiting for the cleanup
+        let holders = [
+            ArtistNameOf::<T, I>::get(name),
+            ConfusableNameOf::<T, I>::get(Self::confusable_name_hash(name)),
+        ];
+        for holder in holders.into_iter().flatten() {
+            ArtistOf::<T, I>::mutate(&holder, |maybe_artist| {
+                if let Some(artist) = maybe_artist {
+                    Self::expire_lapsed_verification(&holder, artist);
+                }
+            });
+        }
+
+        ensure!(
+            !BannedNames::<T, I>::contains_key(name_hash),
+            Error::<T, I>::BannedName
+        );
+
+        if let Some(claimant) = ReservedNames::<T, I>::get(name_hash) {
+            ensure!(claimant.as_ref() == Some(who), Error::<T, I>::ReservedName);
+            ReservedNames::<T, I>::remove(name_hash);
+        }
+
+        if let Some((owner, retired_until)) = RetiredNames::<T, I>::get(name_hash) {
+            ensure!(
+                &owner == who || frame_system::Pallet::<T>::block_number() > retired_until,
+                Error::<T, I>::NameRetired
+            );
+            RetiredNames::<T, I>::remove(name_hash);
+        }
+
+        ensure!(
+            !ArtistNameOf::<T, I>::contains_key(name),
+            Error::<T, I>::NameUnavailable
+        );
+        ensure!(
+            ArtistAliasIndex::<T, I>::get(name).map_or(true, |owner| &owner == who),
+            Error::<T, I>::NameUnavailable
+        );
+        Self::ensure_name_not_confusable(name, who)?;
+
+        Ok(())
+    }
+
+    /// Check the passed main name with the `T::NameValidator` and against `T::MinNameLen`,
+    /// returning its canonical form.
+    fn checked_name(name: T::Name) -> Result<T::Name, DispatchError> {
+        let raw = name.to_bytes();
+        let canonical = T::NameValidator::validate(&raw).map_err(Error::<T, I>::from)?;
+        ensure!(
+            canonical.len() as u32 >= T::MinNameLen::get(),
+            Error::<T, I>::NameTooShort
+        );
+
+        if canonical == raw {
+            return Ok(name);
+        }
+        T::Name::try_from_bytes(canonical).ok_or_else(|| Error::<T, I>::InvalidName.into())
+    }
+
+    /// Return the fingerprint of the confusable key of the passed name, under which the verified
+    /// names are indexed.
+    fn confusable_name_hash(name: &T::Name) -> T::Hash {
+        T::Hashing::hash(&T::NameValidator::confusable_key(&name.to_bytes()))
+    }
+
+    /// Ensure the passed name isn't confusable with the main name of a verified artist other than
+    /// `who`.
+    fn ensure_name_not_confusable(name: &T::Name, who: &T::AccountId) -> DispatchResult {
+        ensure!(
+            ConfusableNameOf::<T, I>::get(Self::confusable_name_hash(name))
+                .map_or(true, |owner| &owner == who),
+            Error::<T, I>::NameConfusable
+        );
+
+        Ok(())
+    }
+
+    /// The bucket of the name prefix index the passed name falls in, the first
+    /// `T::NamePrefixLen` bytes of its confusable key, `None` while the index is disabled.
+    fn name_prefix_bucket(name: &[u8]) -> Option<BoundedVec<u8, T::NamePrefixLen>> {
+        let len = T::NamePrefixLen::get() as usize;
+        if len == 0 {
+            return None;
+        }
+
+        let mut key = T::NameValidator::confusable_key(name);
+        key.truncate(len);
+        key.try_into().ok()
+    }
+
+    /// Move the passed artist in the name prefix index from its `old` main name to its `new`
+    /// one, if any.
+    fn reindex_name_prefix(id: &T::AccountId, old: Option<&T::Name>, new: Option<&T::Name>) {
+        if let Some(bucket) = old.and_then(|name| Self::name_prefix_bucket(&name.to_bytes())) {
+            ArtistsOfNamePrefix::<T, I>::remove(bucket, id);
+        }
+        if let Some(bucket) = new.and_then(|name| Self::name_prefix_bucket(&name.to_bytes())) {
+            ArtistsOfNamePrefix::<T, I>::insert(bucket, id, ());
+        }
+    }
+
+    /// Index the main name of the passed verified artist, as is and under its confusable key.
+    fn index_verified_name(name: &T::Name, id: &T::AccountId) {
+        ArtistNameOf::<T, I>::insert(name, id.clone());
+        ConfusableNameOf::<T, I>::insert(Self::confusable_name_hash(name), id.clone());
+    }
+
+    /// Remove the main name of the passed artist from the verified names indexes, as long as they
+    /// point to it.
+    fn unindex_verified_name(name: &T::Name, id: &T::AccountId) {
+        if ArtistNameOf::<T, I>::get(name).as_ref() == Some(id) {
+            ArtistNameOf::<T, I>::remove(name);
+        }
+        ConfusableNameOf::<T, I>::mutate_exists(Self::confusable_name_hash(name), |owner| {
+            if owner.as_ref() == Some(id) {
+                *owner = None;
+            }
+        });
+    }
+
+    /// Clear everything kept about the passed artist, already taken from `ArtistOf` with its
+    /// deposit settled: its indexes and pending requests, the shares of its co-owners settled
+    /// following the passed policy. Its contracts and the lifecycle hook are then notified of its
+    /// removal.
+    ///
+    /// Every path removing an artist goes through it, the storage kept per artist only having
+    /// to be cleared here.
+    fn purge_artist(
+        id: &T::AccountId,
+        artist: &Artist<T, I>,
+        policy: DepositPolicy,
+    ) -> DispatchResult {
+        if artist.holds_verification() {
+            Self::unindex_verified_name(&artist.main_name, id);
+            PendingRenames::<T, I>::remove(id);
+            #[cfg(feature = "evm-attestation")]
+            attestation::revoke::<T, I>(id);
+        }
+        Self::reindex_genres(id, &artist.indexed_genres(), &[]);
+        Self::reindex_aliases(id, &artist.aliases, &[]);
+        Self::reindex_name_prefix(id, Some(&artist.main_name), None);
+        ClaimableProfiles::<T, I>::remove(id);
+        AssetBudgetUsage::<T, I>::remove(id);
+        MemberInvitations::<T, I>::remove(id);
+        Self::settle_co_owners(id, policy)?;
+        UnregisterRequests::<T, I>::remove(id);
+        OwnershipOffers::<T, I>::remove(id);
+        EvidenceDelegates::<T, I>::remove(id);
+        PayoutRoutes::<T, I>::remove(id);
+        RemoteOriginOf::<T, I>::remove(id);
+        LapsedVerifications::<T, I>::remove(id);
+        Self::remove_pending_verification(id);
+        Self::notify_contracts(id, &artist.contracts, ContractNotice::Unregistered);
+        Self::run_lifecycle_hook(id, T::OnArtistLifecycle::on_artist_unregistered)
+    }
+
+    /// Retire the main name of the passed unregistered artist for `T::NameRetentionPeriod`, if
+    /// any.
+    fn retire_name(id: &T::AccountId, name: &T::Name) {
+        let period = T::NameRetentionPeriod::get();
+        if period.is_zero() {
+            return;
+        }
+
+        let retired_until = frame_system::Pallet::<T>::block_number().saturating_add(period);
+        RetiredNames::<T, I>::insert(
+            Self::name_hash(&name.to_bytes()),
+            (id.clone(), retired_until),
+        );
+    }
+
+    /// Register `id` as an artist, holding its deposit from the passed depositor.
+    fn do_register(
+        id: T::AccountId,
+        depositor: T::AccountId,
+        data: ArtistRegistrationData<T, I>,
+    ) -> DispatchResultWithPostInfo {
+        let ArtistRegistrationData {
+            main_name,
+            alias,
+            genres,
+            description,
+            assets,
+            open_to,
+            contact_hash,
+            links,
+        } = data;
+
+        ensure!(
+            !ArtistOf::<T, I>::contains_key(id.clone()),
+            Error::<T, I>::AlreadyRegistered
+        );
+        ensure!(
+            !MergedInto::<T, I>::contains_key(&id),
+            Error::<T, I>::ArtistMerged
+        );
+        let main_name = Self::checked_name(main_name)?;
+        Self::claim_name(&main_name, &id)?;
+        let alias = alias.map(Self::checked_name).transpose()?;
+        if let Some(alias) = &alias {
+            Self::ensure_alias_available(&id, alias)?;
+        }
+        let verdict = T::AbuseFilter::score_registration(
+            &id,
+            &main_name,
+            alias.as_ref(),
+            &genres,
+            description.as_ref().map(|x| x.as_slice()),
+            &assets,
+        );
+        ensure!(
+            verdict != AbuseVerdict::Reject,
+            Error::<T, I>::SubmissionRejected
+        );
+
+        let mut new_artist = Artist::<T, I>::new(
+            id.clone(),
+            depositor,
+            main_name.clone(),
+            alias,
+            genres,
+            description.map(BoundedVec::into_inner),
+            assets,
+        )?;
+        new_artist.update(UpdatableData::OpenTo(open_to))?;
+        if contact_hash.is_some() {
+            new_artist.update(UpdatableData::ContactHash(contact_hash))?;
+        }
+        for (kind, link) in links {
+            new_artist.update(UpdatableData::Links(UpdatableLinks::Add(kind, link)))?;
+        }
+        Self::hold_surcharge(&id, &mut new_artist, verdict)?;
+
+        Self::reindex_genres(&id, &[], &new_artist.indexed_genres());
+        Self::reindex_aliases(&id, &[], &new_artist.aliases);
+        Self::reindex_name_prefix(&id, None, Some(&new_artist.main_name));
+        ArtistOf::<T, I>::insert(id.clone(), new_artist);
+        Self::run_lifecycle_hook(&id, T::OnArtistLifecycle::on_artist_registered)?;
+
+        Self::deposit_event(ArtistRegistered {
+            id,
+            name: main_name,
+        });
+        Ok(().into())
+    }
+
+    /// Register the passed genesis artist straight into the storage, panicking if it is invalid.
+    ///
+    /// Nothing is held from the artist if `waive_deposits` is set.
+    pub(crate) fn seed_artist(artist: &GenesisArtistOf<T>, waive_deposits: bool) {
+        let (id, main_name, alias, genres, description, assets, verified) = artist;
+        assert!(
+            !ArtistOf::<T, I>::contains_key(id),
+            "genesis artist registered twice"
+        );
+
+        let main_name = normalize_name::<T::Name>(main_name.clone())
+            .ok()
+            .and_then(|name| Self::checked_name(name).ok())
+            .expect("genesis artist name invalid");
+        let alias: Option<ArtistAliasOf<T, I>> = alias
+            .clone()
+            .map(|x| normalize_name::<T::Name>(x).expect("genesis artist alias invalid"));
+        let genres: BoundedVec<MusicGenre, T::MaxGenres> = genres
+            .clone()
+            .try_into()
+            .expect("genesis artist has too many genres");
+        let assets: BoundedVec<(AssetType, Vec<u8>), T::MaxAssets> = assets
+            .clone()
+            .try_into()
+            .expect("genesis artist has too many assets");
+
+        let new_artist = || {
+            Artist::<T, I>::new(
+                id.clone(),
+                id.clone(),
+                main_name,
+                alias,
+                genres,
+                description.clone(),
+                assets,
+            )
+            .expect("genesis artist should be valid")
+        };
+        let mut artist = if waive_deposits {
+            Self::with_deposits_waived(id, new_artist)
+        } else {
+            new_artist()
+        };
+
+        if *verified {
+            assert!(
+                !ArtistNameOf::<T, I>::contains_key(&artist.main_name)
+                    && Self::ensure_name_not_confusable(&artist.main_name, id).is_ok(),
+                "genesis verified artist name is not unique"
+            );
+            artist.verify(None);
+            Self::index_verified_name(&artist.main_name, id);
+        }
+
+        Self::reindex_genres(id, &[], &artist.indexed_genres());
+        for alias in artist.aliases.iter() {
+            Self::ensure_alias_available(id, alias).expect("genesis artist alias is not unique");
+        }
+        Self::reindex_aliases(id, &[], &artist.aliases);
+        Self::reindex_name_prefix(id, None, Some(&artist.main_name));
+        ArtistOf::<T, I>::insert(id, artist);
+    }
+
+    /// Run the passed closure with the passed account exempted from deposits, only for its time
+    /// if it wasn't already, so nothing gets held from it.
+    fn with_deposits_waived<R>(id: &T::AccountId, f: impl FnOnce() -> R) -> R {
+        let exempt = !DepositExemptions::<T, I>::contains_key(id);
+        if exempt {
+            DepositExemptions::<T, I>::insert(id, ());
+        }
+
+        let result = f();
+
+        if exempt {
+            DepositExemptions::<T, I>::remove(id);
+        }
+        result
+    }
+
+    /// Hold the surcharge of the passed `T::AbuseFilter` verdict from the depositor of the passed
+    /// artist, if any.
+    fn hold_surcharge(
+        id: &T::AccountId,
+        artist: &mut Artist<T, I>,
+        verdict: AbuseVerdict<BalanceOf<T, I>>,
+    ) -> DispatchResultWithPostInfo {
+        if let AbuseVerdict::Surcharge(amount) = verdict {
+            artist.hold_surcharge(amount)?;
+
+            Self::deposit_event(Event::SubmissionSurcharged {
+                id: id.clone(),
+                amount,
+            });
+        }
+
+        Ok(().into())
+    }
+
+    /// The `AssetBudgetUsage` of the passed artist once the passed data got applied, if it adds
+    /// an asset while the `AssetBudget` is enabled.
+    ///
+    /// Errors if the asset doesn't fit in what is left of the budget of the current period.
+    fn asset_budget_usage(
+        id: &T::AccountId,
+        data: &UpdatableDataOf<T, I>,
+    ) -> Result<Option<(BlockNumberFor<T>, u32)>, DispatchError> {
+        let budget = T::AssetBudget::get();
+        if budget == 0
+            || !matches!(
+                data,
+                UpdatableData::Assets(
+                    UpdatableAssets::Add(..) | UpdatableAssets::AddFingerprint(..)
+                )
+            )
+        {
+            return Ok(None);
+        }
+
+        let now = frame_system::Pallet::<T>::block_number();
+        let (period_start, used) = match AssetBudgetUsage::<T, I>::get(id) {
+            Some((start, used)) if now.saturating_sub(start) < T::AssetBudgetPeriod::get() => {
+                (start, used)
+            }
+            _ => (now, 0),
+        };
+
+        let used = used.saturating_add(ArtistAsset::<T>::max_encoded_len() as u32);
+        ensure!(used <= budget, Error::<T, I>::AssetBudgetExceeded);
+
+        Ok(Some((period_start, used)))
+    }
+
+    /// Attach the passed evidence fingerprints to the pending verification of the passed artist,
+    /// queuing a new request.
+    fn do_submit_verification_evidence(
+        id: T::AccountId,
+        evidence: BoundedVec<T::Hash, T::MaxEvidence>,
+    ) -> DispatchResultWithPostInfo {
+        ArtistOf::<T, I>::try_mutate(id.clone(), |maybe_artist| {
+            let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+            ensure!(!artist.is_verified(), Error::<T, I>::AlreadyVerified);
+
+            artist.set_evidence_deposit(Self::pending_evidence_count(&id), evidence.len() as u32)
+        })?;
+
+        if !PendingVerifications::<T, I>::contains_key(&id) {
+            Self::enqueue_verification(&id)?;
+        }
+
+        PendingVerifications::<T, I>::insert(
+            id.clone(),
+            VerificationRequest::<T, I>::new(evidence.clone()),
+        );
+
+        Self::deposit_event(Event::VerificationEvidenceSubmitted { id, evidence });
+        Ok(().into())
+    }
+
+    /// Count a new registration against the `T::MaxRegistrationsPerPeriod`, if enabled.
+    ///
+    /// Errors if the registry already accepted its registrations of the current period.
+    fn note_registration() -> DispatchResult {
+        let cap = T::MaxRegistrationsPerPeriod::get();
+        if cap == 0 {
+            return Ok(());
+        }
+
+        let now = frame_system::Pallet::<T>::block_number();
+        let (period_start, count) = match RegistrationsUsage::<T, I>::get() {
+            Some((start, count)) if now.saturating_sub(start) < T::RegistrationPeriod::get() => {
+                (start, count)
+            }
+            _ => (now, 0),
+        };
+
+        ensure!(count < cap, Error::<T, I>::TooManyRegistrations);
+        RegistrationsUsage::<T, I>::put((period_start, count.saturating_add(1)));
+
+        Ok(())
+    }
+
+    /// The payload the `ClaimAuthority` signs to let `claimant` claim the genesis `profile`.
+    pub fn claim_voucher_payload(profile: &T::AccountId, claimant: &T::AccountId) -> Vec<u8> {
+        (CLAIM_VOUCHER_CONTEXT, profile, claimant).encode()
+    }
+
+    /// Hand the passed genesis profile over to the passed claimant, within its claim window.
+    fn do_claim_profile(profile: T::AccountId, claimant: T::AccountId) -> DispatchResult {
+        let expiry = ClaimableProfiles::<T, I>::get(&profile).ok_or(Error::<T, I>::NotClaimable)?;
+        ensure!(
+            frame_system::Pallet::<T>::block_number() <= expiry,
+            Error::<T, I>::ClaimExpired
+        );
+        Self::ensure_can_take_over(&claimant)?;
+
+        Self::transfer_artist(&profile, &claimant)?;
+        ClaimableProfiles::<T, I>::remove(&profile);
+
+        Self::deposit_event(Event::ProfileClaimed { profile, claimant });
+        Ok(())
+    }
+
+    /// Ensure the passed account can take an artist profile over, not being an artist already.
+    fn ensure_can_take_over(who: &T::AccountId) -> DispatchResult {
+        ensure!(
+            !ArtistOf::<T, I>::contains_key(who),
+            Error::<T, I>::AlreadyRegistered
+        );
+        ensure!(
+            !MergedInto::<T, I>::contains_key(who),
+            Error::<T, I>::ArtistMerged
+        );
+
+        Ok(())
+    }
+
+    /// Move the artist registered with `from` under `to`, along with the deposits held from
+    /// `from` and its entries in the names, aliases and genres indexes.
+    ///
+    /// A pending verification request is dropped, its escrow being refunded to its depositor.
+    fn transfer_artist(from: &T::AccountId, to: &T::AccountId) -> DispatchResult {
+        let mut artist = ArtistOf::<T, I>::take(from).ok_or(Error::<T, I>::NotRegistered)?;
+        Self::expire_lapsed_verification(from, &mut artist);
+
+        if PendingVerifications::<T, I>::contains_key(from) {
+            artist
+                .settle_verification_deposit(DepositPolicy::Refund)
+                .map_err(|e| e.error)?;
+            Self::remove_pending_verification(from);
+        }
+
+        // The deposit of an artist registered by a label stays held from the label, while the
+        // artist only takes its own deposit along from the deposits it holds for other artists
+        if artist.depositor == *from {
+            let mut remaining = Self::held_cap(&artist);
+            for reason in Self::deposit_reasons() {
+                let held = T::Currency::balance_on_hold(&reason.into(), from).min(remaining);
+                if held.is_zero() {
+                    continue;
+                }
+                remaining = remaining.saturating_sub(held);
+
+                T::Currency::transfer_on_hold(
+                    &reason.into(),
+                    from,
+                    to,
+                    held,
+                    Precision::Exact,
+                    Restriction::OnHold,
+                    Fortitude::Polite,
+                )?;
+            }
+            artist.depositor = to.clone();
+        }
+
+        artist.owner = to.clone();
+        if artist.is_verified() {
+            Self::index_verified_name(&artist.main_name, to);
+            if let Some(new_name) = PendingRenames::<T, I>::take(from) {
+                PendingRenames::<T, I>::insert(to, new_name);
+            }
+            #[cfg(feature = "evm-attestation")]
+            {
+                attestation::revoke::<T, I>(from);
+                attestation::attest::<T, I>(&artist);
+            }
+        }
+        Self::reindex_genres(from, &artist.indexed_genres(), &[]);
+        Self::reindex_genres(to, &[], &artist.indexed_genres());
+        Self::reindex_aliases(from, &artist.aliases, &[]);
+        Self::reindex_aliases(to, &[], &artist.aliases);
+        Self::reindex_name_prefix(from, Some(&artist.main_name), None);
+        Self::reindex_name_prefix(to, None, Some(&artist.main_name));
+        if let Some(usage) = AssetBudgetUsage::<T, I>::take(from) {
+            AssetBudgetUsage::<T, I>::insert(to, usage);
+        }
+        let invitations = MemberInvitations::<T, I>::take(from);
+        if !invitations.is_empty() {
+            MemberInvitations::<T, I>::insert(to, invitations);
+        }
+        for (member, share) in CoOwners::<T, I>::drain_prefix(from) {
+            CoOwners::<T, I>::insert(to, member, share);
+        }
+        for (member, share) in CoOwnershipOffers::<T, I>::drain_prefix(from) {
+            CoOwnershipOffers::<T, I>::insert(to, member, share);
+        }
+        UnregisterRequests::<T, I>::remove(from);
+        OwnershipOffers::<T, I>::remove(from);
+        EvidenceDelegates::<T, I>::remove(from);
+        if let Some(route) = PayoutRoutes::<T, I>::take(from) {
+            PayoutRoutes::<T, I>::insert(to, route);
+        }
+        if let Some(origin_chain) = RemoteOriginOf::<T, I>::take(from) {
+            RemoteOriginOf::<T, I>::insert(to, origin_chain);
+        }
+        if let Some(lapsed_at) = LapsedVerifications::<T, I>::take(from) {
+            LapsedVerifications::<T, I>::insert(to, lapsed_at);
+        }
+        ArtistOf::<T, I>::insert(to, artist);
+
+        Self::run_lifecycle_hook(from, T::OnArtistLifecycle::on_artist_unregistered)?;
+        Self::run_lifecycle_hook(to, T::OnArtistLifecycle::on_artist_registered)
+    }
 
-            Self::release_held_all(&origin)?;
+    /// Notify the passed contracts linked to `id` of its status change through
+    /// `T::ContractNotifier`, within `T::MaxContractNotificationWeight`.
+    ///
+    /// A failing notification has its changes dropped and is reported by a
+    /// `ContractNotificationFailed` event, without failing the status change. The contracts left
+    /// once the weight limit is exhausted aren't notified.
+    fn notify_contracts(id: &T::AccountId, contracts: &[T::AccountId], notice: ContractNotice) {
+        let mut meter = WeightMeter::with_limit(T::MaxContractNotificationWeight::get());
 
-            ArtistOf::<T>::remove(origin.clone());
+        for contract in contracts {
+            let result = with_transaction(|| {
+                match T::ContractNotifier::notify(id, contract, notice, &mut meter) {
+                    Ok(()) => TransactionOutcome::Commit(Ok(())),
+                    Err(error) => TransactionOutcome::Rollback(Err(error)),
+                }
+            });
 
-            Self::deposit_event(ArtistUnregistered { id: origin });
-            Ok(().into())
+            if let Err(error) = result {
+                Self::deposit_event(Event::ContractNotificationFailed {
+                    id: id.clone(),
+                    contract: contract.clone(),
+                    error,
+                });
+                if error == DispatchError::Exhausted {
+                    break;
+                }
+            }
         }
+    }
 
-        /// Update the passed caller artist data field with the passed data.
-        #[pallet::weight({
-            let weight_fn = Pallet::<T>::get_weight_update_fn(&data);
-            weight_fn()
-        })]
-        #[pallet::call_index(3)]
-        pub fn update(
-            origin: OriginFor<T>,
-            data: UpdatableData<ArtistAliasOf<T>>,
-        ) -> DispatchResultWithPostInfo {
-            let origin = ensure_signed(origin)?;
+    /// Run the passed `T::OnArtistLifecycle` handler for `id`, metered up to
+    /// `T::MaxLifecycleHookWeight`.
+    ///
+    /// A failing handler has its changes dropped, and fails the caller or only gets reported
+    /// depending on `T::LifecycleHookFailure`.
+    fn run_lifecycle_hook(
+        id: &T::AccountId,
+        hook: impl FnOnce(&T::AccountId, &mut WeightMeter) -> DispatchResult,
+    ) -> DispatchResult {
+        let limit = T::MaxLifecycleHookWeight::get();
+        let mut meter = WeightMeter::with_limit(limit);
 
-            ArtistOf::<T>::try_mutate(origin.clone(), |maybe_artist| {
-                if let Some(artist) = maybe_artist {
-                    artist.update(data.clone())?;
-                    Self::deposit_event(ArtistUpdated {
-                        id: origin,
-                        new_data: data,
+        let result = with_transaction(|| {
+            let result = hook(id, &mut meter).and_then(|_| {
+                ensure!(
+                    meter.consumed().all_lte(limit),
+                    Error::<T, I>::LifecycleHookOverweight
+                );
+                Ok(())
+            });
+            match result {
+                Ok(()) => TransactionOutcome::Commit(Ok(())),
+                Err(error) => TransactionOutcome::Rollback(Err(error)),
+            }
+        });
+
+        match (result, T::LifecycleHookFailure::get()) {
+            (Err(error), HookFailurePolicy::Ignore) => {
+                Self::deposit_event(Event::LifecycleHookFailed {
+                    id: id.clone(),
+                    error,
+                });
+                Ok(())
+            }
+            (result, _) => result,
+        }
+    }
+
+    /// Remove the passed member from the group, or drop its pending invitation.
+    fn do_remove_member(group: &T::AccountId, member: &T::AccountId) -> DispatchResult {
+        let removed = MemberInvitations::<T, I>::mutate_exists(group, |maybe_invitations| {
+            let invitations = maybe_invitations.as_mut()?;
+            let pos = invitations.iter().position(|x| x == member)?;
+            invitations.remove(pos);
+            if invitations.is_empty() {
+                *maybe_invitations = None;
+            }
+            Some(())
+        });
+        if removed.is_some() {
+            return Ok(());
+        }
+
+        ArtistOf::<T, I>::try_mutate(group, |maybe_artist| {
+            let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+            artist.remove_member(member)
+        })
+        .map_err(|e| e.error)?;
+
+        CoOwnershipOffers::<T, I>::remove(group, member);
+        Self::settle_co_owner(group, member, DepositPolicy::Refund)
+    }
+
+    /// Remove the passed member from the co-owners of the group, if it is one, settling its share
+    /// following the passed policy.
+    fn settle_co_owner(
+        group: &T::AccountId,
+        member: &T::AccountId,
+        policy: DepositPolicy,
+    ) -> DispatchResult {
+        let Some(share) = CoOwners::<T, I>::take(group, member) else {
+            return Ok(());
+        };
+
+        let reason = HoldReason::<I>::GroupShare.into();
+        let share = match policy {
+            DepositPolicy::Refund => {
+                T::Currency::release(&reason, member, share, Precision::BestEffort)?
+            }
+            DepositPolicy::Slash | DepositPolicy::SlashPart(_) => {
+                let (credit, _) = <<T as pallet::Config<I>>::Currency as BalancedHold<
+                    AccountIdOf<T>,
+                >>::slash(
+                    &reason, member, policy.slashed_part().mul_floor(share)
+                );
+                let slashed = credit.peek();
+                T::Slash::on_unbalanced(credit);
+
+                let rest = share.saturating_sub(slashed);
+                if !rest.is_zero() {
+                    T::Currency::release(&reason, member, rest, Precision::BestEffort)?;
+                }
+                slashed
+            }
+        };
+
+        Self::deposit_event(Event::CoOwnerExited {
+            id: group.clone(),
+            member: member.clone(),
+            policy,
+            share,
+        });
+        Ok(())
+    }
+
+    /// Settle the shares of all the co-owners of the passed group following the passed policy,
+    /// dropping the co-ownerships it offered.
+    fn settle_co_owners(group: &T::AccountId, policy: DepositPolicy) -> DispatchResult {
+        let _ = CoOwnershipOffers::<T, I>::clear_prefix(group, T::MaxMembers::get(), None);
+        let members: Vec<T::AccountId> = CoOwners::<T, I>::iter_key_prefix(group).collect();
+        members
+            .iter()
+            .try_for_each(|member| Self::settle_co_owner(group, member, policy))
+    }
+
+    /// Set the moderation status of the passed artist, a banned artist keeping its status.
+    fn set_status(id: T::AccountId, status: ArtistStatus) -> DispatchResult {
+        let contracts = ArtistOf::<T, I>::try_mutate(&id, |maybe_artist| {
+            let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+            ensure!(
+                artist.status != ArtistStatus::Banned,
+                Error::<T, I>::ArtistBanned
+            );
+
+            artist.status = status;
+            Ok::<_, DispatchError>(artist.contracts.clone())
+        })?;
+        Self::notify_contracts(&id, &contracts, ContractNotice::StatusChanged(status));
+
+        Self::deposit_event(Event::ArtistStatusChanged { id, status });
+        Ok(())
+    }
+
+    /// Rename the passed artist, keeping the verified names index consistent.
+    fn do_rename(id: T::AccountId, new_name: T::Name) -> DispatchResult {
+        Self::claim_name(&new_name, &id)?;
+
+        let old_name = ArtistOf::<T, I>::try_mutate(&id, |maybe_artist| {
+            let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+            Self::expire_lapsed_verification(&id, artist);
+            let old_name = artist.main_name.clone();
+
+            artist
+                .set_main_name(new_name.clone())
+                .map_err(|e| e.error)?;
+            Self::reindex_name_prefix(&id, Some(&old_name), Some(&new_name));
+
+            if artist.is_verified() {
+                Self::unindex_verified_name(&old_name, &id);
+                Self::index_verified_name(&new_name, &id);
+            }
+
+            Ok::<_, DispatchError>(old_name)
+        })?;
+
+        Self::deposit_event(Event::ArtistRenamed {
+            id,
+            old_name,
+            new_name,
+        });
+        Ok(())
+    }
+
+    /// Push the passed artist at the end of the pending verification queue, rejecting it or
+    /// evicting the oldest request if the queue is full.
+    fn enqueue_verification(id: &T::AccountId) -> DispatchResult {
+        VerificationQueue::<T, I>::try_mutate(|queue| {
+            if queue.len() as u32 >= T::MaxPendingVerifications::get() {
+                match (T::VerificationQueueOverflow::get(), queue.first().cloned()) {
+                    (QueueOverflowPolicy::EvictOldest, Some(oldest)) => {
+                        queue.remove(0);
+                        Self::evict_verification(oldest)?;
+                    }
+                    _ => return Err(Error::<T, I>::VerificationQueueFull.into()),
+                }
+            }
+
+            queue
+                .try_push(id.clone())
+                .map_err(|_| Error::<T, I>::VerificationQueueFull.into())
+        })
+    }
+
+    /// Drop the pending verification of the passed artist, refunding its evidence deposit.
+    fn evict_verification(id: T::AccountId) -> DispatchResult {
+        Self::drop_verification(&id)?;
+        Self::deposit_event(Event::VerificationEvicted { id });
+        Ok(())
+    }
+
+    /// Remove the pending verification of the passed artist, refunding its deposits, leaving the
+    /// queue to the caller.
+    fn drop_verification(id: &T::AccountId) -> DispatchResult {
+        let evidence_count = Self::pending_evidence_count(id);
+        PendingVerifications::<T, I>::remove(id);
+        ArtistOf::<T, I>::try_mutate(id, |maybe_artist| {
+            if let Some(artist) = maybe_artist {
+                artist
+                    .set_evidence_deposit(evidence_count, 0)
+                    .map_err(|e| e.error)?;
+                artist
+                    .settle_verification_deposit(DepositPolicy::Refund)
+                    .map_err(|e| e.error)?;
+            }
+            Ok::<(), DispatchError>(())
+        })
+    }
+
+    /// Prune the expired pending verifications, then the expired retired names, within
+    /// `T::MaxCleanupPerBlock` and the passed weight meter.
+    ///
+    /// The retired names are examined by batches, resuming from the `RetiredNamesCursor` so the
+    /// map gets walked across blocks.
+    fn prune_stale(meter: &mut WeightMeter) {
+        let mut budget = T::MaxCleanupPerBlock::get();
+        let now = frame_system::Pallet::<T>::block_number();
+        let read = T::DbWeight::get().reads(1);
+
+        let lifetime = T::VerificationRequestLifetime::get();
+        if !lifetime.is_zero() && meter.try_consume(read).is_ok() {
+            let mut pruned: Vec<T::AccountId> = Vec::new();
+            for id in VerificationQueue::<T, I>::get() {
+                if budget.is_zero() || meter.try_consume(read).is_err() {
+                    break;
+                }
+                let expired = PendingVerifications::<T, I>::get(&id).map_or(false, |request| {
+                    request.submitted_at().saturating_add(lifetime) < now
+                });
+                if !expired
+                    || meter
+                        .try_consume(T::WeightInfo::prune_pending_verification())
+                        .is_err()
+                {
+                    continue;
+                }
+
+                let result = with_transaction(|| match Self::drop_verification(&id) {
+                    Ok(()) => TransactionOutcome::Commit(Ok(())),
+                    Err(e) => TransactionOutcome::Rollback(Err(e)),
+                });
+                if result.is_ok() {
+                    budget.saturating_dec();
+                    pruned.push(id.clone());
+                    Self::deposit_event(Event::StalePruned {
+                        entry: StaleEntry::Verification(id),
                     });
-                    Ok(().into())
-                } else {
-                    return Err(Error::<T>::NotRegistered.into());
                 }
+            }
+
+            if !pruned.is_empty() {
+                VerificationQueue::<T, I>::mutate(|queue| queue.retain(|id| !pruned.contains(id)));
+            }
+        }
+
+        let batch = budget.min(
+            meter
+                .remaining()
+                .checked_div_per_component(&T::WeightInfo::prune_retired_name())
+                .unwrap_or(u64::MAX)
+                .saturated_into(),
+        );
+        if batch.is_zero() {
+            return;
+        }
+
+        let names: Vec<(T::Hash, (T::AccountId, BlockNumberFor<T>))> =
+            match RetiredNamesCursor::<T, I>::get() {
+                Some(last) => {
+                    RetiredNames::<T, I>::iter_from(RetiredNames::<T, I>::hashed_key_for(last))
+                        .take(batch as usize)
+                        .collect()
+                }
+                None => RetiredNames::<T, I>::iter().take(batch as usize).collect(),
+            };
+        meter.consume(T::WeightInfo::prune_retired_name().saturating_mul(names.len() as u64));
+
+        match names.last() {
+            Some((last, _)) if names.len() as u32 == batch => RetiredNamesCursor::<T, I>::put(last),
+            _ => RetiredNamesCursor::<T, I>::kill(),
+        }
+
+        for (name_hash, (_, retired_until)) in names {
+            if now > retired_until {
+                RetiredNames::<T, I>::remove(name_hash);
+                Self::deposit_event(Event::StalePruned {
+                    entry: StaleEntry::RetiredName(name_hash),
+                });
+            }
+        }
+    }
+
+    /// Expire the verifications lapsed past `T::VerificationValidity`, within
+    /// `T::MaxCleanupPerBlock` and the passed weight meter, dropping their names from the
+    /// verified names index and queuing them back in the pending verifications. The lapsed
+    /// artists already read as unverified in the meantime.
+    ///
+    /// The artists are examined by batches, resuming from the `VerificationExpiryCursor` so the
+    /// registry gets walked across blocks.
+    fn expire_verifications(meter: &mut WeightMeter) {
+        let validity = T::VerificationValidity::get();
+        if validity.is_zero() {
+            return;
+        }
+
+        // Each expiry may notify the contracts of the artist
+        let per_artist = T::WeightInfo::expire_verification()
+            .saturating_add(T::MaxContractNotificationWeight::get());
+        let batch = T::MaxCleanupPerBlock::get().min(
+            meter
+                .remaining()
+                .checked_div_per_component(&per_artist)
+                .unwrap_or(u64::MAX)
+                .saturated_into(),
+        );
+        if batch.is_zero() {
+            return;
+        }
+
+        // One more artist is read to know whether the walk is over
+        let mut ids: Vec<T::AccountId> = match VerificationExpiryCursor::<T, I>::get() {
+            Some(last) => ArtistOf::<T, I>::iter_keys_from(ArtistOf::<T, I>::hashed_key_for(last))
+                .take(batch.saturating_add(1) as usize)
+                .collect(),
+            None => ArtistOf::<T, I>::iter_keys()
+                .take(batch.saturating_add(1) as usize)
+                .collect(),
+        };
+        let walk_over = ids.len() as u32 <= batch;
+        ids.truncate(batch as usize);
+        meter.consume(per_artist.saturating_mul(ids.len() as u64));
+
+        match ids.last() {
+            Some(last) if !walk_over => VerificationExpiryCursor::<T, I>::put(last),
+            _ => VerificationExpiryCursor::<T, I>::kill(),
+        }
+
+        for id in ids {
+            ArtistOf::<T, I>::mutate(&id, |maybe_artist| {
+                if let Some(artist) = maybe_artist {
+                    Self::expire_lapsed_verification(&id, artist);
+                }
+            });
+        }
+    }
+
+    /// Expire the lapsed verification of the passed artist, if any, dropping its main name from
+    /// the verified names index, notifying its contracts and queuing it back in the pending
+    /// verifications.
+    ///
+    /// The artist already reads as unverified once its verification lapsed, this cleans the
+    /// index up. The artist only gets queued back when its depositor can escrow the
+    /// `T::VerificationDeposit` and the queue has room left, the paying requests never being
+    /// evicted for it: otherwise it stays unverified until it requests its verification again.
+    fn expire_lapsed_verification(id: &T::AccountId, artist: &mut Artist<T, I>) {
+        let verified_at = match artist.verified_at {
+            Some(verified_at) if !artist.is_verified() => verified_at,
+            _ => return,
+        };
+
+        artist.verified_at = None;
+        #[cfg(feature = "evm-attestation")]
+        attestation::attest::<T, I>(artist);
+        Self::unindex_verified_name(&artist.main_name, id);
+        LapsedVerifications::<T, I>::insert(
+            id,
+            verified_at.saturating_add(T::VerificationValidity::get()),
+        );
+
+        let requeued = PendingVerifications::<T, I>::contains_key(id)
+            || with_transaction(|| match Self::requeue_verification(id, artist) {
+                Ok(()) => TransactionOutcome::Commit(Ok(())),
+                Err(e) => TransactionOutcome::Rollback(Err(e)),
             })
+            .is_ok();
+        Self::notify_contracts(id, &artist.contracts, ContractNotice::Unverified);
+
+        Self::deposit_event(Event::VerificationExpired {
+            id: id.clone(),
+            verified_at,
+            requeued,
+        });
+    }
+
+    /// Queue the passed artist back in the pending verifications without any evidence,
+    /// escrowing the `T::VerificationDeposit` from its depositor, unless the queue is full.
+    fn requeue_verification(id: &T::AccountId, artist: &mut Artist<T, I>) -> DispatchResult {
+        ensure!(
+            (VerificationQueue::<T, I>::decode_len().unwrap_or_default() as u32)
+                < T::MaxPendingVerifications::get(),
+            Error::<T, I>::VerificationQueueFull
+        );
+
+        artist.hold_verification_deposit().map_err(|e| e.error)?;
+        Self::enqueue_verification(id)?;
+        PendingVerifications::<T, I>::insert(
+            id,
+            VerificationRequest::<T, I>::new(Default::default()),
+        );
+
+        Ok(())
+    }
+
+    /// The number of evidence fingerprints of the pending verification of the passed artist.
+    fn pending_evidence_count(id: &T::AccountId) -> u32 {
+        PendingVerifications::<T, I>::get(id)
+            .map(|request| request.evidence().len() as u32)
+            .unwrap_or_default()
+    }
+
+    /// Remove the pending verification of the passed artist, if any, from the queue.
+    fn remove_pending_verification(id: &T::AccountId) {
+        if PendingVerifications::<T, I>::take(id).is_some() {
+            VerificationQueue::<T, I>::mutate(|queue| queue.retain(|x| x != id));
         }
     }
-}
 
-impl<T> Pallet<T>
-where
-    T: frame_system::Config + Config,
-{
-    /// Release the held deposit for all reasons handled by this pallet.
-    fn release_held_all(account_id: &T::AccountId) -> DispatchResultWithPostInfo {
-        // return all held deposits
-        T::Currency::release(
-            &HoldReason::ArtistRegistration.into(),
-            &account_id,
-            T::BaseDeposit::get(),
-            Precision::BestEffort,
-        )?;
-        T::Currency::release(
-            &HoldReason::ArtistAssets.into(),
-            &account_id,
-            T::Currency::balance_on_hold(&HoldReason::ArtistAssets.into(), &account_id),
-            Precision::BestEffort,
-        )?;
-        T::Currency::release(
-            &HoldReason::ArtistAlias.into(),
-            &account_id,
-            T::Currency::balance_on_hold(&HoldReason::ArtistAlias.into(), &account_id),
-            Precision::BestEffort,
-        )?;
-        T::Currency::release(
-            &HoldReason::ArtistDescription.into(),
-            &account_id,
-            T::Currency::balance_on_hold(&HoldReason::ArtistDescription.into(), &account_id),
-            Precision::BestEffort,
-        )?;
-        T::Currency::release(
-            &HoldReason::ArtistName.into(),
-            &account_id,
-            T::Currency::balance_on_hold(&HoldReason::ArtistName.into(), &account_id),
-            Precision::BestEffort,
-        )?;
+    /// Verify the passed artist, refunding the deposits of its pending verification, if any.
+    fn do_verify(
+        id: T::AccountId,
+        kyc_evidence_hash: Option<T::Hash>,
+    ) -> DispatchResultWithPostInfo {
+        ArtistOf::<T, I>::try_mutate(id.clone(), |maybe_artist| -> DispatchResultWithPostInfo {
+            let artist = maybe_artist.as_mut().ok_or(Error::<T, I>::NotRegistered)?;
+            ensure!(!artist.is_verified(), Error::<T, I>::AlreadyVerified);
+            Self::expire_lapsed_verification(&id, artist);
+            ensure!(
+                !ArtistNameOf::<T, I>::contains_key(&artist.main_name),
+                Error::<T, I>::NameUnavailable
+            );
+            ensure!(
+                ArtistAliasIndex::<T, I>::get(&artist.main_name).map_or(true, |owner| owner == id),
+                Error::<T, I>::NameUnavailable
+            );
+            Self::ensure_name_not_confusable(&artist.main_name, &id)?;
+
+            artist.verify(kyc_evidence_hash);
+            #[cfg(feature = "evm-attestation")]
+            attestation::attest::<T, I>(artist);
+            Self::index_verified_name(&artist.main_name, &id);
+            artist.set_evidence_deposit(Self::pending_evidence_count(&id), 0)?;
+            artist.settle_verification_deposit(DepositPolicy::Refund)?;
+            Self::remove_pending_verification(&id);
+
+            Ok(().into())
+        })?;
+        // Out of the mutation, for the handler to see the verified artist
+        Self::run_lifecycle_hook(&id, T::OnArtistLifecycle::on_artist_verified)?;
+
+        Self::deposit_event(ArtistVerified {
+            id,
+            kyc_evidence_hash,
+        });
         Ok(().into())
     }
 
-    /// Slash the held deposit for all reasons handled by this pallet.
-    fn slash_held_all(account_id: &T::AccountId) -> DispatchResultWithPostInfo {
+    /// Every reason this pallet may hold an artist deposit for.
+    fn deposit_reasons() -> [HoldReason<I>; 9] {
+        let [assets, alias, description, name, contracts, verification] =
+            HoldReason::<I>::legacy_data_reasons();
+
+        [
+            HoldReason::ArtistRegistration,
+            HoldReason::ArtistData,
+            HoldReason::VerificationRequest,
+            assets,
+            alias,
+            description,
+            name,
+            contracts,
+            verification,
+        ]
+    }
+
+    /// The most that can be released or slashed from the depositor of the passed artist.
+    ///
+    /// A depositor holds the deposits of all the artists it registered under the same reasons,
+    /// its own profile included, only the tracked deposit of the artist can be taken from it.
+    fn held_cap(artist: &Artist<T, I>) -> BalanceOf<T, I> {
+        artist.deposit
+    }
+
+    /// The deposit held from the depositor of the passed artist for all reasons handled by this
+    /// pallet.
+    fn held_all(artist: &Artist<T, I>) -> BalanceOf<T, I> {
+        Self::deposit_reasons()
+            .into_iter()
+            .fold(Zero::zero(), |acc: BalanceOf<T, I>, reason| {
+                acc.saturating_add(T::Currency::balance_on_hold(
+                    &reason.into(),
+                    &artist.depositor,
+                ))
+            })
+            .min(Self::held_cap(artist))
+    }
+
+    /// Settle the deposit held from the depositor of the passed artist following the passed
+    /// policy, returning the slashed amount, or the released one with a refund `policy`.
+    fn settle_held_all(
+        artist: &Artist<T, I>,
+        policy: DepositPolicy,
+    ) -> Result<BalanceOf<T, I>, DispatchError> {
+        match policy {
+            DepositPolicy::Refund => Self::release_held_all(artist),
+            DepositPolicy::Slash => Self::slash_held_up_to(artist, Self::held_cap(artist)),
+            DepositPolicy::SlashPart(part) => {
+                let slashed =
+                    Self::slash_held_up_to(artist, part.mul_floor(Self::held_all(artist)))?;
+                Self::release_held_up_to(artist, Self::held_cap(artist).saturating_sub(slashed))?;
+                Ok(slashed)
+            }
+        }
+    }
+
+    /// Release the deposit held from the depositor of the passed artist for all reasons handled
+    /// by this pallet, returning the released amount.
+    fn release_held_all(artist: &Artist<T, I>) -> Result<BalanceOf<T, I>, DispatchError> {
+        Self::release_held_up_to(artist, Self::held_cap(artist))
+    }
+
+    /// Release up to `cap` of the deposit held from the depositor of the passed artist for all
+    /// reasons handled by this pallet, returning the released amount.
+    fn release_held_up_to(
+        artist: &Artist<T, I>,
+        cap: BalanceOf<T, I>,
+    ) -> Result<BalanceOf<T, I>, DispatchError> {
+        let mut remaining = cap;
+        let mut released: BalanceOf<T, I> = Zero::zero();
+
+        for reason in Self::deposit_reasons() {
+            let held = T::Currency::balance_on_hold(&reason.into(), &artist.depositor);
+            let amount = T::Currency::release(
+                &reason.into(),
+                &artist.depositor,
+                held.min(remaining),
+                Precision::BestEffort,
+            )?;
+            released = released.saturating_add(amount);
+            remaining = remaining.saturating_sub(amount);
+        }
+
+        Ok(released)
+    }
+
+    /// Transfer up to `cap` of the deposit held from the depositor of the passed artist for all
+    /// reasons handled by this pallet to the free balance of `dest`, returning the transferred
+    /// amount.
+    fn transfer_held_up_to(
+        artist: &Artist<T, I>,
+        dest: &T::AccountId,
+        cap: BalanceOf<T, I>,
+    ) -> Result<BalanceOf<T, I>, DispatchError> {
+        let mut remaining = cap;
+        let mut transferred: BalanceOf<T, I> = Zero::zero();
+
+        for reason in Self::deposit_reasons() {
+            let held = T::Currency::balance_on_hold(&reason.into(), &artist.depositor);
+            if held.min(remaining).is_zero() {
+                continue;
+            }
+
+            let amount = T::Currency::transfer_on_hold(
+                &reason.into(),
+                &artist.depositor,
+                dest,
+                held.min(remaining),
+                Precision::BestEffort,
+                Restriction::Free,
+                Fortitude::Polite,
+            )?;
+            transferred = transferred.saturating_add(amount);
+            remaining = remaining.saturating_sub(amount);
+        }
+
+        Ok(transferred)
+    }
+
+    /// Slash up to `cap` of the deposit held from the depositor of the passed artist for all
+    /// reasons handled by this pallet, returning the slashed amount.
+    fn slash_held_up_to(
+        artist: &Artist<T, I>,
+        cap: BalanceOf<T, I>,
+    ) -> Result<BalanceOf<T, I>, DispatchError> {
+        let mut remaining = cap;
+        let mut imbalance = Credit::<T::AccountId, T::Currency>::zero();
+
         // slash and handle slash for all held deposits
-        let imbalance = <<T as pallet::Config>::Currency as BalancedHold<AccountIdOf<T>>>::slash(
-            &HoldReason::ArtistRegistration.into(),
-            &account_id,
-            T::BaseDeposit::get(),
-        )
-        .0
-        .merge(
-            <<T as pallet::Config>::Currency as BalancedHold<AccountIdOf<T>>>::slash(
-                &HoldReason::ArtistAssets.into(),
-                &account_id,
-                T::Currency::balance_on_hold(&HoldReason::ArtistAssets.into(), &account_id),
-            )
-            .0,
-        )
-        .merge(
-            <<T as pallet::Config>::Currency as BalancedHold<AccountIdOf<T>>>::slash(
-                &HoldReason::ArtistAssets.into(),
-                &account_id,
-                T::Currency::balance_on_hold(&HoldReason::ArtistAssets.into(), &account_id),
-            )
-            .0,
-        )
-        .merge(
-            <<T as pallet::Config>::Currency as BalancedHold<AccountIdOf<T>>>::slash(
-                &HoldReason::ArtistAlias.into(),
-                &account_id,
-                T::Currency::balance_on_hold(&HoldReason::ArtistAlias.into(), &account_id),
-            )
-            .0,
-        )
-        .merge(
-            <<T as pallet::Config>::Currency as BalancedHold<AccountIdOf<T>>>::slash(
-                &HoldReason::ArtistDescription.into(),
-                &account_id,
-                T::Currency::balance_on_hold(&HoldReason::ArtistDescription.into(), &account_id),
-            )
-            .0,
-        )
-        .merge(
-            <<T as pallet::Config>::Currency as BalancedHold<AccountIdOf<T>>>::slash(
-                &HoldReason::ArtistName.into(),
-                &account_id,
-                T::Currency::balance_on_hold(&HoldReason::ArtistName.into(), &account_id),
-            )
-            .0,
-        );
+        for reason in Self::deposit_reasons() {
+            let held = T::Currency::balance_on_hold(&reason.into(), &artist.depositor);
+            let (credit, _) = <<T as pallet::Config<I>>::Currency as BalancedHold<
+                AccountIdOf<T>,
+            >>::slash(
+                &reason.into(), &artist.depositor, held.min(remaining)
+            );
+            remaining = remaining.saturating_sub(credit.peek());
+            imbalance = imbalance.merge(credit);
+        }
 
-        if !imbalance.peek().is_zero() {
+        let slashed = imbalance.peek();
+        if !slashed.is_zero() {
             T::Slash::on_unbalanced(imbalance);
         }
 
-        Ok(().into())
+        Ok(slashed)
+    }
+
+    /// The weight of applying all the passed updates with `update_many`, summing their weights.
+    fn update_many_weight(updates: &[UpdatableDataOf<T, I>]) -> Weight {
+        updates.iter().fold(Weight::zero(), |acc, data| {
+            let weight_fn = Self::get_weight_update_fn(data);
+            acc.saturating_add(weight_fn())
+        })
     }
 
     /// Returns a closure that computes the weight of an update operation based on the provided data.
@@ -489,7 +5282,7 @@ where
     ///
     /// # Arguments
     ///
-    /// * `data` - A reference to `UpdatableData<ArtistAliasOf<T>>`, an enum representing the type of
+    /// * `data` - A reference to `UpdatableDataOf<T, I>`, an enum representing the type of
     ///   data to be updated. The generic `T` is typically a type associated with a specific blockchain
     ///   implementation.
     ///
@@ -513,7 +5306,7 @@ where
     ///
     /// This approach allows dynamic determination of operation costs on the blockchain, adapting to
     /// the current context and specific parameters of each update operation.
-    fn get_weight_update_fn(data: &UpdatableData<ArtistAliasOf<T>>) -> Box<dyn FnOnce() -> Weight> {
+    fn get_weight_update_fn(data: &UpdatableDataOf<T, I>) -> Box<dyn FnOnce() -> Weight> {
         match data {
             UpdatableData::Genres(x) => match x {
                 UpdatableGenres::Add(_) => {
@@ -522,53 +5315,263 @@ where
                 UpdatableGenres::Remove(_) => {
                     Box::new(move || T::WeightInfo::update_remove_genres(T::MaxGenres::get()))
                 }
-                UpdatableGenres::Clear => {
-                    Box::new(move || T::WeightInfo::update_clear_genres(T::MaxGenres::get()))
+                UpdatableGenres::RemoveAt(_) => {
+                    Box::new(move || T::WeightInfo::update_remove_genres_at(T::MaxGenres::get()))
                 }
+                UpdatableGenres::Clear => Box::new(move || {
+                    T::WeightInfo::update_clear_genres(0)
+                        .saturating_add(T::WeightInfo::clear_genre_index(T::MaxGenres::get()))
+                }),
             },
             UpdatableData::Assets(x) => match x {
-                UpdatableAssets::Add(_) => {
+                UpdatableAssets::Add(..) | UpdatableAssets::AddFingerprint(..) => {
                     Box::new(move || T::WeightInfo::update_add_assets(T::MaxAssets::get()))
                 }
                 UpdatableAssets::Remove(_) => {
                     Box::new(move || T::WeightInfo::update_remove_assets(T::MaxAssets::get()))
                 }
+                UpdatableAssets::RemoveFingerprint(_) => Box::new(move || {
+                    T::WeightInfo::update_remove_assets_fingerprint(T::MaxAssets::get())
+                }),
+                UpdatableAssets::RemoveAt(_) => {
+                    Box::new(move || T::WeightInfo::update_remove_assets_at(T::MaxAssets::get()))
+                }
                 UpdatableAssets::Clear => {
                     Box::new(move || T::WeightInfo::update_clear_assets(T::MaxAssets::get()))
                 }
             },
-            UpdatableData::Description(_) => Box::new(move || T::WeightInfo::update_description()),
+            UpdatableData::Links(x) => match x {
+                UpdatableLinks::Add(..) => {
+                    Box::new(move || T::WeightInfo::update_add_links(T::MaxLinks::get()))
+                }
+                UpdatableLinks::Remove(..) => {
+                    Box::new(move || T::WeightInfo::update_remove_links(T::MaxLinks::get()))
+                }
+                UpdatableLinks::Clear => {
+                    Box::new(move || T::WeightInfo::update_clear_links(T::MaxLinks::get()))
+                }
+            },
+            UpdatableData::Description(_) => Box::new(move || {
+                T::WeightInfo::update_description(T::MaxHashedDescriptionLen::get())
+            }),
+            UpdatableData::DescriptionFingerprint(_) => {
+                Box::new(move || T::WeightInfo::update_description(0))
+            }
+            UpdatableData::RawDescription(_) => {
+                Box::new(move || T::WeightInfo::update_raw_description(T::MaxDescriptionLen::get()))
+            }
+            UpdatableData::OpenTo(_) => Box::new(move || T::WeightInfo::update_open_to()),
+            UpdatableData::MainGenre(_) => Box::new(move || T::WeightInfo::update_main_genre()),
+            UpdatableData::ContactHash(_) => Box::new(move || T::WeightInfo::update_contact_hash()),
             UpdatableData::Alias(_) => Box::new(move || {
                 T::WeightInfo::update_alias(T::MaxNameLen::get(), T::MaxNameLen::get())
+                    .saturating_add(T::WeightInfo::update_clear_aliases(T::MaxAliases::get()))
             }),
+            UpdatableData::Aliases(x) => match x {
+                UpdatableAliases::Add(_) => Box::new(move || {
+                    T::WeightInfo::update_add_aliases(T::MaxAliases::get().saturating_sub(1))
+                }),
+                UpdatableAliases::Remove(_) => {
+                    Box::new(move || T::WeightInfo::update_remove_aliases(T::MaxAliases::get()))
+                }
+                UpdatableAliases::Clear => {
+                    Box::new(move || T::WeightInfo::update_clear_aliases(T::MaxAliases::get()))
+                }
+            },
+        }
+    }
+
+    /// Return the weight of updating the passed artist with the passed data, measured from its
+    /// actual data sizes rather than their bounds.
+    fn actual_update_weight(data: &UpdatableDataOf<T, I>, artist: &Artist<T, I>) -> Weight {
+        let genres = artist.genres.len() as u32;
+        let assets = artist.assets.len() as u32;
+        let links = artist.links.len() as u32;
+        let aliases = artist.aliases.len() as u32;
+
+        match data {
+            UpdatableData::Genres(x) => match x {
+                UpdatableGenres::Add(_) => T::WeightInfo::update_add_genres(genres),
+                UpdatableGenres::Remove(_) => T::WeightInfo::update_remove_genres(genres),
+                UpdatableGenres::RemoveAt(_) => T::WeightInfo::update_remove_genres_at(genres),
+                // The artist record is cleared as an empty one, the genres index cleanup being
+                // charged on its own
+                UpdatableGenres::Clear => T::WeightInfo::update_clear_genres(0)
+                    .saturating_add(T::WeightInfo::clear_genre_index(genres)),
+            },
+            UpdatableData::Assets(x) => match x {
+                UpdatableAssets::Add(..) | UpdatableAssets::AddFingerprint(..) => {
+                    T::WeightInfo::update_add_assets(assets)
+                }
+                UpdatableAssets::Remove(_) => T::WeightInfo::update_remove_assets(assets),
+                UpdatableAssets::RemoveFingerprint(_) => {
+                    T::WeightInfo::update_remove_assets_fingerprint(assets)
+                }
+                UpdatableAssets::RemoveAt(_) => T::WeightInfo::update_remove_assets_at(assets),
+                UpdatableAssets::Clear => T::WeightInfo::update_clear_assets(assets),
+            },
+            UpdatableData::Links(x) => match x {
+                UpdatableLinks::Add(..) => T::WeightInfo::update_add_links(links),
+                UpdatableLinks::Remove(..) => T::WeightInfo::update_remove_links(links),
+                UpdatableLinks::Clear => T::WeightInfo::update_clear_links(links),
+            },
+            UpdatableData::Description(x) => {
+                T::WeightInfo::update_description(x.as_ref().map_or(0, |x| x.len() as u32))
+            }
+            UpdatableData::DescriptionFingerprint(_) => T::WeightInfo::update_description(0),
+            UpdatableData::RawDescription(x) => {
+                T::WeightInfo::update_raw_description(x.as_ref().map_or(0, |x| x.len() as u32))
+            }
+            UpdatableData::OpenTo(_) => T::WeightInfo::update_open_to(),
+            UpdatableData::MainGenre(_) => T::WeightInfo::update_main_genre(),
+            UpdatableData::ContactHash(_) => T::WeightInfo::update_contact_hash(),
+            // The aliases beyond the first one replaced are charged as cleared
+            UpdatableData::Alias(x) => {
+                let old_len = artist
+                    .aliases
+                    .first()
+                    .map_or(0, |alias| alias.to_bytes().len() as u32);
+                let new_len = x.as_ref().map_or(0, |alias| alias.to_bytes().len() as u32);
+                T::WeightInfo::update_alias(old_len, new_len)
+                    .saturating_add(T::WeightInfo::update_clear_aliases(aliases))
+            }
+            UpdatableData::Aliases(x) => match x {
+                UpdatableAliases::Add(_) => T::WeightInfo::update_add_aliases(aliases),
+                UpdatableAliases::Remove(_) => T::WeightInfo::update_remove_aliases(aliases),
+                UpdatableAliases::Clear => T::WeightInfo::update_clear_aliases(aliases),
+            },
         }
     }
 
-    /// Return if the actual account ID can unregister from being an Artist.
-    fn can_unregister(who: &T::AccountId) -> DispatchResultWithPostInfo {
-        let artist_data = Pallet::<T>::get_artist_by_id(&who);
+    /// Return if the passed account can confirm its unregistration from being an Artist, or what
+    /// blocks it, such as the blocks left before its `T::UnregisterPeriod` elapses.
+    ///
+    /// Wallets display it instead of letting the artist submit a failing extrinsic.
+    pub fn can_unregister(who: &T::AccountId) -> Result<(), UnregisterBlocker> {
+        let artist_data = Pallet::<T, I>::get_artist_by_id(&who);
 
         match artist_data {
             Some(data) => {
                 // verified artists can't unregister
                 if data.is_verified() {
-                    return Err(Error::<T>::IsVerified.into());
+                    return Err(UnregisterBlocker::Verified);
                 }
 
+                let requested_at =
+                    UnregisterRequests::<T, I>::get(who).ok_or(UnregisterBlocker::NotRequested)?;
                 let current_block = <frame_system::Pallet<T>>::block_number();
-                let expected_passed_time: u32 = T::UnregisterPeriod::get();
+                let elapsed: u32 = current_block.saturating_sub(requested_at).saturated_into();
 
                 // Verify that we passed the Unregister Period
-                if current_block - data.registered_at < expected_passed_time.saturated_into() {
-                    return Err(Error::<T>::PeriodNotPassed.into());
+                let remaining = T::UnregisterPeriod::get().saturating_sub(elapsed);
+                if !remaining.is_zero() {
+                    return Err(UnregisterBlocker::PeriodRemaining(remaining));
                 }
 
-                Ok(().into())
+                Ok(())
             }
-            None => Err(Error::<T>::NotRegistered.into()),
+            None => Err(UnregisterBlocker::NotRegistered),
+        }
+    }
+}
+
+impl<T: Config<I>, I: 'static> From<UnregisterBlocker> for Error<T, I> {
+    fn from(blocker: UnregisterBlocker) -> Self {
+        match blocker {
+            UnregisterBlocker::NotRegistered => Error::<T, I>::NotRegistered,
+            UnregisterBlocker::Verified => Error::<T, I>::IsVerified,
+            UnregisterBlocker::NotRequested => Error::<T, I>::UnregisterNotRequested,
+            UnregisterBlocker::PeriodRemaining(_) => Error::<T, I>::PeriodNotPassed,
         }
     }
 }
 
-pub type EnsureArtistsPallet<T> =
-    EnsureSignedBy<Address<T>, <T as frame_system::Config>::AccountId>;
+impl<T: Config<I>, I: 'static> From<InvalidName> for Error<T, I> {
+    fn from(_: InvalidName) -> Self {
+        Error::<T, I>::InvalidName
+    }
+}
+
+impl<T: Config<I>, I: 'static> ArtistsInspect<T::AccountId> for Pallet<T, I> {
+    type Work = T::Hash;
+
+    fn is_artist(who: &T::AccountId) -> bool {
+        ArtistOf::<T, I>::contains_key(who)
+    }
+
+    fn is_verified(who: &T::AccountId) -> bool {
+        ArtistOf::<T, I>::get(who).map_or(false, |artist| artist.is_verified())
+    }
+
+    fn is_suspended(who: &T::AccountId) -> bool {
+        ArtistOf::<T, I>::get(who).map_or(false, |artist| !artist.is_active())
+    }
+
+    fn artist_name(who: &T::AccountId) -> Option<Vec<u8>> {
+        ArtistOf::<T, I>::get(who).map(|artist| artist.main_name.to_bytes())
+    }
+
+    fn contracts_of(who: &T::AccountId) -> Vec<T::AccountId> {
+        ArtistOf::<T, I>::get(who)
+            .map(|artist| artist.contracts.into_inner())
+            .unwrap_or_default()
+    }
+
+    fn royalty_splits(who: &T::AccountId) -> Vec<(T::AccountId, Perbill)> {
+        ArtistOf::<T, I>::get(who)
+            .map(|artist| artist.royalty_splits.into_inner())
+            .unwrap_or_default()
+    }
+
+    fn members_of(who: &T::AccountId) -> Vec<T::AccountId> {
+        ArtistOf::<T, I>::get(who)
+            .map(|artist| artist.members.into_inner())
+            .unwrap_or_default()
+    }
+
+    fn main_genre(who: &T::AccountId) -> Option<MusicGenre> {
+        ArtistOf::<T, I>::get(who)
+            .and_then(|artist| artist.main_genre)
+            .and_then(|genre| genre.genre())
+    }
+
+    fn secondary_genres(who: &T::AccountId) -> Vec<MusicGenre> {
+        ArtistOf::<T, I>::get(who)
+            .map(|artist| {
+                artist
+                    .genres
+                    .iter()
+                    .filter_map(StoredGenre::genre)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn works_of(who: &T::AccountId) -> Vec<T::Hash> {
+        ArtistOf::<T, I>::get(who)
+            .map(|artist| artist.works.into_inner())
+            .unwrap_or_default()
+    }
+
+    fn claims_work(who: &T::AccountId, work: &T::Hash) -> bool {
+        ArtistOf::<T, I>::get(who).map_or(false, |artist| artist.works.contains(work))
+    }
+}
+
+impl<T: Config<I>, I: 'static> PayoutRouter<T::AccountId> for Pallet<T, I> {
+    fn payout_target(artist: &T::AccountId) -> Option<PayoutTarget<T::AccountId>> {
+        let stored = ArtistOf::<T, I>::get(artist)?;
+
+        Some(match PayoutRoutes::<T, I>::get(artist) {
+            Some(PayoutRoute::Account(account)) => PayoutTarget::Account(account),
+            Some(PayoutRoute::Contract(contract)) => PayoutTarget::Contract(contract),
+            None if !stored.royalty_splits.is_empty() => {
+                PayoutTarget::SplitSet(stored.royalty_splits.into_inner())
+            }
+            None => PayoutTarget::Account(artist.clone()),
+        })
+    }
+}
+
+pub type EnsureArtistsPallet<T, I = ()> =
+    EnsureSignedBy<Address<T, I>, <T as frame_system::Config>::AccountId>;
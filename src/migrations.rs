@@ -0,0 +1,2551 @@
+// This file is part of Allfeat.
+
+// Copyright (C) Allfeat (FR) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations of the Artists pallet.
+//!
+//! They run on the default instance of the pallet, the only one of the registries predating its
+//! instances.
+
+use super::*;
+use frame_support::traits::StorageVersion;
+
+/// The in-code storage version.
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(STORAGE_VERSION_NUMBER);
+
+/// The number of the in-code storage version, tagging the exported [`ArtistRecord`]s.
+pub const STORAGE_VERSION_NUMBER: u16 = 21;
+
+/// Migrate the storage from the version 0 to the version 1, typing the artists assets.
+///
+/// Bare asset fingerprints become [`ArtistAsset`]s typed as [`AssetType::Other`], considered added
+/// at the registration of their artist.
+pub mod v1 {
+    use super::*;
+    use crate::types::ArtistAliasOf;
+    use codec::{Decode, Encode};
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use frame_support::Twox64Concat;
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_std::marker::PhantomData;
+
+    /// The artists storage on the version 1 layout, the current one being the later versions.
+    #[frame_support::storage_alias]
+    pub(crate) type ArtistOf<T: Config> =
+        StorageMap<Pallet<T>, Twox64Concat, AccountIdOf<T>, v2::OldArtist<T>>;
+
+    /// The artist layout before the typed assets.
+    #[derive(Encode, Decode)]
+    pub(crate) struct OldArtist<T: Config> {
+        pub(crate) owner: AccountIdOf<T>,
+        pub(crate) registered_at: BlockNumberFor<T>,
+        pub(crate) verified_at: Option<BlockNumberFor<T>>,
+        pub(crate) kyc_evidence_hash: Option<T::Hash>,
+        pub(crate) main_name: T::Name,
+        pub(crate) alias: Option<ArtistAliasOf<T>>,
+        pub(crate) genres: BoundedVec<MusicGenre, T::MaxGenres>,
+        pub(crate) description: Option<T::Hash>,
+        pub(crate) assets: BoundedVec<T::Hash, T::MaxAssets>,
+        pub(crate) contracts: BoundedVec<AccountIdOf<T>, T::MaxContracts>,
+        pub(crate) contracts_frozen: bool,
+        pub(crate) deposit: BalanceOf<T>,
+    }
+
+    impl<T: Config> OldArtist<T> {
+        fn migrate(self) -> v2::OldArtist<T> {
+            let registered_at = self.registered_at;
+            let assets: BoundedVec<v12::OldArtistAsset<T>, T::MaxAssets> = self
+                .assets
+                .into_iter()
+                .map(|hash| v12::OldArtistAsset {
+                    kind: AssetType::Other,
+                    hash,
+                    added_at: registered_at,
+                })
+                .collect::<Vec<_>>()
+                .try_into()
+                .expect("the assets bound is unchanged; qed");
+
+            v2::OldArtist {
+                owner: self.owner,
+                registered_at,
+                verified_at: self.verified_at,
+                kyc_evidence_hash: self.kyc_evidence_hash,
+                main_name: self.main_name,
+                alias: self.alias,
+                genres: self.genres,
+                description: self.description,
+                assets,
+                contracts: self.contracts,
+                contracts_frozen: self.contracts_frozen,
+                deposit: self.deposit,
+            }
+        }
+    }
+
+    /// The typed assets migration, without any storage version check.
+    ///
+    /// Use [`MigrateToV1`] instead of this one from a runtime.
+    pub struct UncheckedMigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+
+            ArtistOf::<T>::translate::<OldArtist<T>, _>(|_, old| {
+                translated.saturating_inc();
+                Some(old.migrate())
+            });
+
+            log::info!(
+                target: "runtime::artists",
+                "migrated {} artists to typed assets",
+                translated
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let count = ArtistOf::<T>::iter_keys().count() as u32;
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let old_count = u32::decode(&mut &state[..])
+                .map_err(|_| "the pre-upgrade state can't be decoded")?;
+
+            // Every artist must decode on the new layout.
+            let new_count = ArtistOf::<T>::iter().count() as u32;
+            ensure!(
+                old_count == new_count,
+                "some artists were lost during the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Type the artists assets, only if the on-chain storage version is 0, and bump it to 1.
+    pub type MigrateToV1<T> = VersionedMigration<
+        0,
+        1,
+        UncheckedMigrateToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrate the storage from the version 1 to the version 2, adding the artists availability and
+/// delegates.
+///
+/// Existing artists are considered open to nothing until they update it, and have no delegate.
+pub mod v2 {
+    use super::*;
+    use crate::types::ArtistAliasOf;
+    use codec::{Decode, Encode};
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use frame_support::Twox64Concat;
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_std::marker::PhantomData;
+
+    /// The artists storage on the version 2 and 3 layout, the current one being the later versions.
+    #[frame_support::storage_alias]
+    pub(crate) type ArtistOf<T: Config> =
+        StorageMap<Pallet<T>, Twox64Concat, AccountIdOf<T>, v4::OldArtist<T>>;
+
+    /// The artist layout before the availability and the delegates.
+    #[derive(Encode, Decode)]
+    pub(crate) struct OldArtist<T: Config> {
+        pub(crate) owner: AccountIdOf<T>,
+        pub(crate) registered_at: BlockNumberFor<T>,
+        pub(crate) verified_at: Option<BlockNumberFor<T>>,
+        pub(crate) kyc_evidence_hash: Option<T::Hash>,
+        pub(crate) main_name: T::Name,
+        pub(crate) alias: Option<ArtistAliasOf<T>>,
+        pub(crate) genres: BoundedVec<MusicGenre, T::MaxGenres>,
+        pub(crate) description: Option<T::Hash>,
+        pub(crate) assets: BoundedVec<v12::OldArtistAsset<T>, T::MaxAssets>,
+        pub(crate) contracts: BoundedVec<AccountIdOf<T>, T::MaxContracts>,
+        pub(crate) contracts_frozen: bool,
+        pub(crate) deposit: BalanceOf<T>,
+    }
+
+    impl<T: Config> OldArtist<T> {
+        fn migrate(self) -> v4::OldArtist<T> {
+            v4::OldArtist {
+                owner: self.owner,
+                registered_at: self.registered_at,
+                verified_at: self.verified_at,
+                kyc_evidence_hash: self.kyc_evidence_hash,
+                main_name: self.main_name,
+                alias: self.alias,
+                genres: self.genres,
+                open_to: Default::default(),
+                description: self.description,
+                assets: self.assets,
+                contracts: self.contracts,
+                contracts_frozen: self.contracts_frozen,
+                delegates: Default::default(),
+                deposit: self.deposit,
+            }
+        }
+    }
+
+    /// The availability and delegates migration, without any storage version check.
+    ///
+    /// Use [`MigrateToV2`] instead of this one from a runtime.
+    pub struct UncheckedMigrateToV2<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+
+            ArtistOf::<T>::translate::<OldArtist<T>, _>(|_, old| {
+                translated.saturating_inc();
+                Some(old.migrate())
+            });
+
+            log::info!(
+                target: "runtime::artists",
+                "migrated {} artists to the availability flags and delegates",
+                translated
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let count = ArtistOf::<T>::iter_keys().count() as u32;
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let old_count = u32::decode(&mut &state[..])
+                .map_err(|_| "the pre-upgrade state can't be decoded")?;
+
+            // Every artist must decode on the new layout.
+            let new_count = ArtistOf::<T>::iter().count() as u32;
+            ensure!(
+                old_count == new_count,
+                "some artists were lost during the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Add the artists availability and delegates, only if the on-chain storage version is 1, and bump it to 2.
+    pub type MigrateToV2<T> = VersionedMigration<
+        1,
+        2,
+        UncheckedMigrateToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrate the storage from the version 2 to the version 3, backfilling the genres index of the
+/// existing artists.
+pub mod v3 {
+    use super::*;
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use sp_std::marker::PhantomData;
+
+    /// The genres index backfill, without any storage version check.
+    ///
+    /// Use [`MigrateToV3`] instead of this one from a runtime.
+    pub struct UncheckedMigrateToV3<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut reads = 0u64;
+            let mut writes = 0u64;
+
+            for (id, artist) in v2::ArtistOf::<T>::iter() {
+                reads.saturating_inc();
+
+                for genre in artist.genres.iter() {
+                    v14::ArtistsOfGenre::<T>::insert(genre, &id, ());
+                    writes.saturating_inc();
+                }
+            }
+
+            log::info!(
+                target: "runtime::artists",
+                "indexed {} genres of {} artists",
+                writes,
+                reads
+            );
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            for (id, artist) in v2::ArtistOf::<T>::iter() {
+                ensure!(
+                    artist
+                        .genres
+                        .iter()
+                        .all(|genre| v14::ArtistsOfGenre::<T>::contains_key(genre, &id)),
+                    "artist genre missing from the genres index"
+                );
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Backfill the genres index, only if the on-chain storage version is 2, and bump it to 3.
+    pub type MigrateToV3<T> = VersionedMigration<
+        2,
+        3,
+        UncheckedMigrateToV3<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrate the storage from the version 3 to the version 4, adding the artists contact
+/// commitment and external links.
+///
+/// Existing artists have no contact commitment nor links until they update them.
+pub mod v4 {
+    use super::*;
+    use crate::types::ArtistAliasOf;
+    use codec::{Decode, Encode};
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_std::marker::PhantomData;
+
+    /// The artist layout before the contact commitment and the external links.
+    #[derive(Encode, Decode)]
+    pub(crate) struct OldArtist<T: Config> {
+        pub(crate) owner: AccountIdOf<T>,
+        pub(crate) registered_at: BlockNumberFor<T>,
+        pub(crate) verified_at: Option<BlockNumberFor<T>>,
+        pub(crate) kyc_evidence_hash: Option<T::Hash>,
+        pub(crate) main_name: T::Name,
+        pub(crate) alias: Option<ArtistAliasOf<T>>,
+        pub(crate) genres: BoundedVec<MusicGenre, T::MaxGenres>,
+        pub(crate) open_to: AvailabilityFlags,
+        pub(crate) description: Option<T::Hash>,
+        pub(crate) assets: BoundedVec<v12::OldArtistAsset<T>, T::MaxAssets>,
+        pub(crate) contracts: BoundedVec<AccountIdOf<T>, T::MaxContracts>,
+        pub(crate) contracts_frozen: bool,
+        pub(crate) delegates: BoundedVec<AccountIdOf<T>, T::MaxDelegates>,
+        pub(crate) deposit: BalanceOf<T>,
+    }
+
+    impl<T: Config> OldArtist<T> {
+        fn migrate(self) -> v7::OldArtist<T> {
+            v7::OldArtist {
+                owner: self.owner,
+                registered_at: self.registered_at,
+                verified_at: self.verified_at,
+                kyc_evidence_hash: self.kyc_evidence_hash,
+                main_name: self.main_name,
+                alias: self.alias,
+                genres: self.genres,
+                open_to: self.open_to,
+                description: self.description,
+                contact_hash: None,
+                links: Default::default(),
+                assets: self.assets,
+                contracts: self.contracts,
+                contracts_frozen: self.contracts_frozen,
+                delegates: self.delegates,
+                deposit: self.deposit,
+            }
+        }
+    }
+
+    /// The contact commitment and external links migration, without any storage version check.
+    ///
+    /// Use [`MigrateToV4`] instead of this one from a runtime.
+    pub struct UncheckedMigrateToV4<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV4<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+
+            v7::ArtistOf::<T>::translate::<OldArtist<T>, _>(|_, old| {
+                translated.saturating_inc();
+                Some(old.migrate())
+            });
+
+            log::info!(
+                target: "runtime::artists",
+                "migrated {} artists to the contact commitment and external links",
+                translated
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let count = v7::ArtistOf::<T>::iter_keys().count() as u32;
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let old_count = u32::decode(&mut &state[..])
+                .map_err(|_| "the pre-upgrade state can't be decoded")?;
+
+            // Every artist must decode on the new layout.
+            let new_count = v7::ArtistOf::<T>::iter().count() as u32;
+            ensure!(
+                old_count == new_count,
+                "some artists were lost during the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Add the artists contact commitment and links, only if the on-chain storage version is 3,
+    /// and bump it to 4.
+    pub type MigrateToV4<T> = VersionedMigration<
+        3,
+        4,
+        UncheckedMigrateToV4<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrate the storage from the version 4 to the version 5, initializing the counter of the
+/// artists now stored in a counted map.
+pub mod v5 {
+    use super::*;
+    use codec::{Decode, Encode};
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use sp_std::marker::PhantomData;
+
+    /// The artists counter initialization, without any storage version check.
+    ///
+    /// Use [`MigrateToV5`] instead of this one from a runtime.
+    pub struct UncheckedMigrateToV5<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV5<T> {
+        fn on_runtime_upgrade() -> Weight {
+            // Count the keys, the artists being on the layout of the version 6 until the next
+            // migration.
+            let count = v7::ArtistOf::<T>::iter_keys().count() as u32;
+            crate::CounterForArtistOf::<T>::set(count);
+
+            log::info!(
+                target: "runtime::artists",
+                "counted {} artists",
+                count
+            );
+
+            T::DbWeight::get().reads_writes(count.into(), 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let count = ArtistOf::<T>::iter_keys().count() as u32;
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let old_count = u32::decode(&mut &state[..])
+                .map_err(|_| "the pre-upgrade state can't be decoded")?;
+
+            ensure!(
+                ArtistOf::<T>::count() == old_count,
+                "the artists counter doesn't match the registered artists"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Initialize the artists counter, only if the on-chain storage version is 4, and bump it
+    /// to 5.
+    pub type MigrateToV5<T> = VersionedMigration<
+        4,
+        5,
+        UncheckedMigrateToV5<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrate the storage from the version 5 to the version 6, backfilling the aliases index.
+///
+/// Aliases were not unique before, only the first artist found with an alias, not being the
+/// verified main name of another artist, gets indexed under it. The others keep their alias
+/// until they update it.
+pub mod v6 {
+    use super::*;
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use sp_std::marker::PhantomData;
+
+    /// The aliases index backfill, without any storage version check.
+    ///
+    /// Use [`MigrateToV6`] instead of this one from a runtime.
+    pub struct UncheckedMigrateToV6<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV6<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut reads = 0u64;
+            let mut writes = 0u64;
+            let mut duplicates = 0u64;
+
+            for (id, artist) in v7::ArtistOf::<T>::iter() {
+                reads.saturating_inc();
+
+                if let Some(alias) = &artist.alias {
+                    reads.saturating_accrue(2);
+
+                    if Pallet::<T>::ensure_alias_available(&id, alias).is_ok() {
+                        Pallet::<T>::reindex_aliases(&id, &[], &[alias.clone()]);
+                        writes.saturating_inc();
+                    } else {
+                        duplicates.saturating_inc();
+                    }
+                }
+            }
+
+            log::info!(
+                target: "runtime::artists",
+                "indexed {} aliases of {} artists, {} duplicates skipped",
+                writes,
+                reads,
+                duplicates
+            );
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            for (alias, id) in ArtistAliasIndex::<T>::iter() {
+                let artist =
+                    v7::ArtistOf::<T>::get(&id).ok_or("alias index points to no artist")?;
+                ensure!(
+                    artist.alias.as_ref() == Some(&alias),
+                    "alias index points to an artist without this alias"
+                );
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Backfill the aliases index, only if the on-chain storage version is 5, and bump it to 6.
+    pub type MigrateToV6<T> = VersionedMigration<
+        5,
+        6,
+        UncheckedMigrateToV6<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrate the storage from the version 6 to the version 7, adding the artists moderation
+/// status.
+///
+/// Existing artists are active.
+pub mod v7 {
+    use super::*;
+    use crate::types::ArtistAliasOf;
+    use codec::{Decode, Encode};
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use frame_support::Twox64Concat;
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_std::marker::PhantomData;
+
+    /// The artists storage on the versions 4 to 6 layout.
+    #[frame_support::storage_alias]
+    pub(crate) type ArtistOf<T: Config> =
+        StorageMap<Pallet<T>, Twox64Concat, AccountIdOf<T>, OldArtist<T>>;
+
+    /// The artist layout before the moderation status.
+    #[derive(Encode, Decode)]
+    pub(crate) struct OldArtist<T: Config> {
+        pub(crate) owner: AccountIdOf<T>,
+        pub(crate) registered_at: BlockNumberFor<T>,
+        pub(crate) verified_at: Option<BlockNumberFor<T>>,
+        pub(crate) kyc_evidence_hash: Option<T::Hash>,
+        pub(crate) main_name: T::Name,
+        pub(crate) alias: Option<ArtistAliasOf<T>>,
+        pub(crate) genres: BoundedVec<MusicGenre, T::MaxGenres>,
+        pub(crate) open_to: AvailabilityFlags,
+        pub(crate) description: Option<T::Hash>,
+        pub(crate) contact_hash: Option<T::Hash>,
+        pub(crate) links: BoundedVec<(LinkType, BoundedVec<u8, T::MaxLinkLen>), T::MaxLinks>,
+        pub(crate) assets: BoundedVec<v12::OldArtistAsset<T>, T::MaxAssets>,
+        pub(crate) contracts: BoundedVec<AccountIdOf<T>, T::MaxContracts>,
+        pub(crate) contracts_frozen: bool,
+        pub(crate) delegates: BoundedVec<AccountIdOf<T>, T::MaxDelegates>,
+        pub(crate) deposit: BalanceOf<T>,
+    }
+
+    impl<T: Config> OldArtist<T> {
+        fn migrate(self) -> v8::OldArtist<T> {
+            v8::OldArtist {
+                owner: self.owner,
+                registered_at: self.registered_at,
+                verified_at: self.verified_at,
+                kyc_evidence_hash: self.kyc_evidence_hash,
+                status: ArtistStatus::Active,
+                main_name: self.main_name,
+                alias: self.alias,
+                genres: self.genres,
+                open_to: self.open_to,
+                description: self.description,
+                contact_hash: self.contact_hash,
+                links: self.links,
+                assets: self.assets,
+                contracts: self.contracts,
+                contracts_frozen: self.contracts_frozen,
+                delegates: self.delegates,
+                deposit: self.deposit,
+            }
+        }
+    }
+
+    /// The moderation status migration, without any storage version check.
+    ///
+    /// Use [`MigrateToV7`] instead of this one from a runtime.
+    pub struct UncheckedMigrateToV7<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV7<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+
+            v8::ArtistOf::<T>::translate::<OldArtist<T>, _>(|_, old| {
+                translated.saturating_inc();
+                Some(old.migrate())
+            });
+
+            log::info!(
+                target: "runtime::artists",
+                "migrated {} artists to the moderation status",
+                translated
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let count = ArtistOf::<T>::iter_keys().count() as u32;
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let old_count = u32::decode(&mut &state[..])
+                .map_err(|_| "the pre-upgrade state can't be decoded")?;
+
+            // Every artist must decode on the new layout.
+            let new_count = v8::ArtistOf::<T>::iter().count() as u32;
+            ensure!(
+                old_count == new_count,
+                "some artists were lost during the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Add the artists moderation status, only if the on-chain storage version is 6, and bump it
+    /// to 7.
+    pub type MigrateToV7<T> = VersionedMigration<
+        6,
+        7,
+        UncheckedMigrateToV7<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrate the storage from the version 7 to the version 8, tracking the account the deposit of
+/// each artist is held from.
+///
+/// Existing artists paid their own deposit, their owner becomes their depositor.
+pub mod v8 {
+    use super::*;
+    use crate::types::ArtistAliasOf;
+    use codec::{Decode, Encode};
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use frame_support::Twox64Concat;
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_std::marker::PhantomData;
+
+    /// The artists storage on the version 7 layout.
+    #[frame_support::storage_alias]
+    pub(crate) type ArtistOf<T: Config> =
+        StorageMap<Pallet<T>, Twox64Concat, AccountIdOf<T>, OldArtist<T>>;
+
+    /// The artist layout before the depositor.
+    #[derive(Encode, Decode)]
+    pub(crate) struct OldArtist<T: Config> {
+        pub(crate) owner: AccountIdOf<T>,
+        pub(crate) registered_at: BlockNumberFor<T>,
+        pub(crate) verified_at: Option<BlockNumberFor<T>>,
+        pub(crate) kyc_evidence_hash: Option<T::Hash>,
+        pub(crate) status: ArtistStatus,
+        pub(crate) main_name: T::Name,
+        pub(crate) alias: Option<ArtistAliasOf<T>>,
+        pub(crate) genres: BoundedVec<MusicGenre, T::MaxGenres>,
+        pub(crate) open_to: AvailabilityFlags,
+        pub(crate) description: Option<T::Hash>,
+        pub(crate) contact_hash: Option<T::Hash>,
+        pub(crate) links: BoundedVec<(LinkType, BoundedVec<u8, T::MaxLinkLen>), T::MaxLinks>,
+        pub(crate) assets: BoundedVec<v12::OldArtistAsset<T>, T::MaxAssets>,
+        pub(crate) contracts: BoundedVec<AccountIdOf<T>, T::MaxContracts>,
+        pub(crate) contracts_frozen: bool,
+        pub(crate) delegates: BoundedVec<AccountIdOf<T>, T::MaxDelegates>,
+        pub(crate) deposit: BalanceOf<T>,
+    }
+
+    impl<T: Config> OldArtist<T> {
+        fn migrate(self) -> v9::OldArtist<T> {
+            v9::OldArtist {
+                depositor: self.owner.clone(),
+                owner: self.owner,
+                registered_at: self.registered_at,
+                verified_at: self.verified_at,
+                kyc_evidence_hash: self.kyc_evidence_hash,
+                status: self.status,
+                main_name: self.main_name,
+                alias: self.alias,
+                genres: self.genres,
+                open_to: self.open_to,
+                description: self.description,
+                contact_hash: self.contact_hash,
+                links: self.links,
+                assets: self.assets,
+                contracts: self.contracts,
+                contracts_frozen: self.contracts_frozen,
+                delegates: self.delegates,
+                deposit: self.deposit,
+            }
+        }
+    }
+
+    /// The depositor migration, without any storage version check.
+    ///
+    /// Use [`MigrateToV8`] instead of this one from a runtime.
+    pub struct UncheckedMigrateToV8<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV8<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+
+            v9::ArtistOf::<T>::translate::<OldArtist<T>, _>(|_, old| {
+                translated.saturating_inc();
+                Some(old.migrate())
+            });
+
+            log::info!(
+                target: "runtime::artists",
+                "migrated {} artists to the depositor",
+                translated
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let count = ArtistOf::<T>::iter_keys().count() as u32;
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let old_count = u32::decode(&mut &state[..])
+                .map_err(|_| "the pre-upgrade state can't be decoded")?;
+
+            // Every artist must decode on the new layout.
+            let new_count = v9::ArtistOf::<T>::iter().count() as u32;
+            ensure!(
+                old_count == new_count,
+                "some artists were lost during the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Add the artists depositor, only if the on-chain storage version is 7, and bump it to 8.
+    pub type MigrateToV8<T> = VersionedMigration<
+        7,
+        8,
+        UncheckedMigrateToV8<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrate the storage from the version 8 to the version 9, making room for the descriptions
+/// stored on-chain.
+///
+/// Existing artists only have a hashed description, their raw description is empty.
+pub mod v9 {
+    use super::*;
+    use crate::types::ArtistAliasOf;
+    use codec::{Decode, Encode};
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use frame_support::Twox64Concat;
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_std::marker::PhantomData;
+
+    /// The artists storage on the version 8 layout.
+    #[frame_support::storage_alias]
+    pub(crate) type ArtistOf<T: Config> =
+        StorageMap<Pallet<T>, Twox64Concat, AccountIdOf<T>, OldArtist<T>>;
+
+    /// The artist layout before the raw description.
+    #[derive(Encode, Decode)]
+    pub(crate) struct OldArtist<T: Config> {
+        pub(crate) owner: AccountIdOf<T>,
+        pub(crate) registered_at: BlockNumberFor<T>,
+        pub(crate) verified_at: Option<BlockNumberFor<T>>,
+        pub(crate) kyc_evidence_hash: Option<T::Hash>,
+        pub(crate) status: ArtistStatus,
+        pub(crate) main_name: T::Name,
+        pub(crate) alias: Option<ArtistAliasOf<T>>,
+        pub(crate) genres: BoundedVec<MusicGenre, T::MaxGenres>,
+        pub(crate) open_to: AvailabilityFlags,
+        pub(crate) description: Option<T::Hash>,
+        pub(crate) contact_hash: Option<T::Hash>,
+        pub(crate) links: BoundedVec<(LinkType, BoundedVec<u8, T::MaxLinkLen>), T::MaxLinks>,
+        pub(crate) assets: BoundedVec<v12::OldArtistAsset<T>, T::MaxAssets>,
+        pub(crate) contracts: BoundedVec<AccountIdOf<T>, T::MaxContracts>,
+        pub(crate) contracts_frozen: bool,
+        pub(crate) delegates: BoundedVec<AccountIdOf<T>, T::MaxDelegates>,
+        pub(crate) deposit: BalanceOf<T>,
+        pub(crate) depositor: AccountIdOf<T>,
+    }
+
+    impl<T: Config> OldArtist<T> {
+        fn migrate(self) -> v10::OldArtist<T> {
+            v10::OldArtist {
+                owner: self.owner,
+                registered_at: self.registered_at,
+                verified_at: self.verified_at,
+                kyc_evidence_hash: self.kyc_evidence_hash,
+                status: self.status,
+                main_name: self.main_name,
+                alias: self.alias,
+                genres: self.genres,
+                open_to: self.open_to,
+                description: self.description,
+                raw_description: None,
+                contact_hash: self.contact_hash,
+                links: self.links,
+                assets: self.assets,
+                contracts: self.contracts,
+                contracts_frozen: self.contracts_frozen,
+                delegates: self.delegates,
+                deposit: self.deposit,
+                depositor: self.depositor,
+            }
+        }
+    }
+
+    /// The raw description migration, without any storage version check.
+    ///
+    /// Use [`MigrateToV9`] instead of this one from a runtime.
+    pub struct UncheckedMigrateToV9<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV9<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+
+            v10::ArtistOf::<T>::translate::<OldArtist<T>, _>(|_, old| {
+                translated.saturating_inc();
+                Some(old.migrate())
+            });
+
+            log::info!(
+                target: "runtime::artists",
+                "migrated {} artists to the raw description",
+                translated
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let count = ArtistOf::<T>::iter_keys().count() as u32;
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let old_count = u32::decode(&mut &state[..])
+                .map_err(|_| "the pre-upgrade state can't be decoded")?;
+
+            // Every artist must decode on the new layout.
+            let new_count = v10::ArtistOf::<T>::iter().count() as u32;
+            ensure!(
+                old_count == new_count,
+                "some artists were lost during the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Add the artists raw description, only if the on-chain storage version is 8, and bump it to
+    /// 9.
+    pub type MigrateToV9<T> = VersionedMigration<
+        8,
+        9,
+        UncheckedMigrateToV9<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrate the storage from the version 9 to the version 10, making room for the artists royalty
+/// splits.
+///
+/// Existing artists don't have any royalty beneficiary.
+pub mod v10 {
+    use super::*;
+    use crate::types::ArtistAliasOf;
+    use codec::{Decode, Encode};
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use frame_support::Twox64Concat;
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_std::marker::PhantomData;
+
+    /// The artists storage on the version 9 layout.
+    #[frame_support::storage_alias]
+    pub(crate) type ArtistOf<T: Config> =
+        StorageMap<Pallet<T>, Twox64Concat, AccountIdOf<T>, OldArtist<T>>;
+
+    /// The artist layout before the royalty splits.
+    #[derive(Encode, Decode)]
+    pub(crate) struct OldArtist<T: Config> {
+        pub(crate) owner: AccountIdOf<T>,
+        pub(crate) registered_at: BlockNumberFor<T>,
+        pub(crate) verified_at: Option<BlockNumberFor<T>>,
+        pub(crate) kyc_evidence_hash: Option<T::Hash>,
+        pub(crate) status: ArtistStatus,
+        pub(crate) main_name: T::Name,
+        pub(crate) alias: Option<ArtistAliasOf<T>>,
+        pub(crate) genres: BoundedVec<MusicGenre, T::MaxGenres>,
+        pub(crate) open_to: AvailabilityFlags,
+        pub(crate) description: Option<T::Hash>,
+        pub(crate) raw_description: Option<BoundedVec<u8, T::MaxDescriptionLen>>,
+        pub(crate) contact_hash: Option<T::Hash>,
+        pub(crate) links: BoundedVec<(LinkType, BoundedVec<u8, T::MaxLinkLen>), T::MaxLinks>,
+        pub(crate) assets: BoundedVec<v12::OldArtistAsset<T>, T::MaxAssets>,
+        pub(crate) contracts: BoundedVec<AccountIdOf<T>, T::MaxContracts>,
+        pub(crate) contracts_frozen: bool,
+        pub(crate) delegates: BoundedVec<AccountIdOf<T>, T::MaxDelegates>,
+        pub(crate) deposit: BalanceOf<T>,
+        pub(crate) depositor: AccountIdOf<T>,
+    }
+
+    impl<T: Config> OldArtist<T> {
+        fn migrate(self) -> v11::OldArtist<T> {
+            v11::OldArtist {
+                owner: self.owner,
+                registered_at: self.registered_at,
+                verified_at: self.verified_at,
+                kyc_evidence_hash: self.kyc_evidence_hash,
+                status: self.status,
+                main_name: self.main_name,
+                alias: self.alias,
+                genres: self.genres,
+                open_to: self.open_to,
+                description: self.description,
+                raw_description: self.raw_description,
+                contact_hash: self.contact_hash,
+                links: self.links,
+                assets: self.assets,
+                contracts: self.contracts,
+                contracts_frozen: self.contracts_frozen,
+                delegates: self.delegates,
+                royalty_splits: Default::default(),
+                deposit: self.deposit,
+                depositor: self.depositor,
+            }
+        }
+    }
+
+    /// The royalty splits migration, without any storage version check.
+    ///
+    /// Use [`MigrateToV10`] instead of this one from a runtime.
+    pub struct UncheckedMigrateToV10<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV10<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+
+            v11::ArtistOf::<T>::translate::<OldArtist<T>, _>(|_, old| {
+                translated.saturating_inc();
+                Some(old.migrate())
+            });
+
+            log::info!(
+                target: "runtime::artists",
+                "migrated {} artists to the royalty splits",
+                translated
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let count = ArtistOf::<T>::iter_keys().count() as u32;
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let old_count = u32::decode(&mut &state[..])
+                .map_err(|_| "the pre-upgrade state can't be decoded")?;
+
+            // Every artist must decode on the new layout.
+            let new_count = v11::ArtistOf::<T>::iter().count() as u32;
+            ensure!(
+                old_count == new_count,
+                "some artists were lost during the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Add the artists royalty splits, only if the on-chain storage version is 9, and bump it to
+    /// 10.
+    pub type MigrateToV10<T> = VersionedMigration<
+        9,
+        10,
+        UncheckedMigrateToV10<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrate the storage from the version 10 to the version 11, making room for the group artists
+/// members.
+///
+/// Existing artists are solo artists without any member.
+pub mod v11 {
+    use super::*;
+    use crate::types::{ArtistAliasOf, RoyaltySplitsOf};
+    use codec::{Decode, Encode};
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use frame_support::Twox64Concat;
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_std::marker::PhantomData;
+
+    /// The artists storage on the version 10 layout.
+    #[frame_support::storage_alias]
+    pub(crate) type ArtistOf<T: Config> =
+        StorageMap<Pallet<T>, Twox64Concat, AccountIdOf<T>, OldArtist<T>>;
+
+    /// The artist layout before the group members.
+    #[derive(Encode, Decode)]
+    pub(crate) struct OldArtist<T: Config> {
+        pub(crate) owner: AccountIdOf<T>,
+        pub(crate) registered_at: BlockNumberFor<T>,
+        pub(crate) verified_at: Option<BlockNumberFor<T>>,
+        pub(crate) kyc_evidence_hash: Option<T::Hash>,
+        pub(crate) status: ArtistStatus,
+        pub(crate) main_name: T::Name,
+        pub(crate) alias: Option<ArtistAliasOf<T>>,
+        pub(crate) genres: BoundedVec<MusicGenre, T::MaxGenres>,
+        pub(crate) open_to: AvailabilityFlags,
+        pub(crate) description: Option<T::Hash>,
+        pub(crate) raw_description: Option<BoundedVec<u8, T::MaxDescriptionLen>>,
+        pub(crate) contact_hash: Option<T::Hash>,
+        pub(crate) links: BoundedVec<(LinkType, BoundedVec<u8, T::MaxLinkLen>), T::MaxLinks>,
+        pub(crate) assets: BoundedVec<v12::OldArtistAsset<T>, T::MaxAssets>,
+        pub(crate) contracts: BoundedVec<AccountIdOf<T>, T::MaxContracts>,
+        pub(crate) contracts_frozen: bool,
+        pub(crate) delegates: BoundedVec<AccountIdOf<T>, T::MaxDelegates>,
+        pub(crate) royalty_splits: RoyaltySplitsOf<T>,
+        pub(crate) deposit: BalanceOf<T>,
+        pub(crate) depositor: AccountIdOf<T>,
+    }
+
+    impl<T: Config> OldArtist<T> {
+        fn migrate(self) -> v12::OldArtist<T> {
+            v12::OldArtist {
+                owner: self.owner,
+                registered_at: self.registered_at,
+                verified_at: self.verified_at,
+                kyc_evidence_hash: self.kyc_evidence_hash,
+                status: self.status,
+                main_name: self.main_name,
+                alias: self.alias,
+                genres: self.genres,
+                open_to: self.open_to,
+                description: self.description,
+                raw_description: self.raw_description,
+                contact_hash: self.contact_hash,
+                links: self.links,
+                assets: self.assets,
+                contracts: self.contracts,
+                contracts_frozen: self.contracts_frozen,
+                delegates: self.delegates,
+                kind: ArtistKind::Solo,
+                members: Default::default(),
+                royalty_splits: self.royalty_splits,
+                deposit: self.deposit,
+                depositor: self.depositor,
+            }
+        }
+    }
+
+    /// The group members migration, without any storage version check.
+    ///
+    /// Use [`MigrateToV11`] instead of this one from a runtime.
+    pub struct UncheckedMigrateToV11<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV11<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+
+            v12::ArtistOf::<T>::translate::<OldArtist<T>, _>(|_, old| {
+                translated.saturating_inc();
+                Some(old.migrate())
+            });
+
+            log::info!(
+                target: "runtime::artists",
+                "migrated {} artists to the group members",
+                translated
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let count = ArtistOf::<T>::iter_keys().count() as u32;
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let old_count = u32::decode(&mut &state[..])
+                .map_err(|_| "the pre-upgrade state can't be decoded")?;
+
+            // Every artist must decode on the new layout.
+            let new_count = v12::ArtistOf::<T>::iter().count() as u32;
+            ensure!(
+                old_count == new_count,
+                "some artists were lost during the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Add the artists group members, only if the on-chain storage version is 10, and bump it to
+    /// 11.
+    pub type MigrateToV11<T> = VersionedMigration<
+        10,
+        11,
+        UncheckedMigrateToV11<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrate the storage from the version 11 to the version 12, declaring the hash algorithm of the
+/// description and assets fingerprints.
+///
+/// Existing fingerprints have been hashed on-chain, they become [`Fingerprint::Native`] ones.
+pub mod v12 {
+    use super::*;
+    use crate::types::{ArtistAliasOf, RoyaltySplitsOf};
+    use codec::{Decode, Encode};
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use frame_support::Twox64Concat;
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_std::marker::PhantomData;
+
+    /// The artists storage on the version 11 layout, the current one being the later versions.
+    #[frame_support::storage_alias]
+    pub(crate) type ArtistOf<T: Config> =
+        StorageMap<Pallet<T>, Twox64Concat, AccountIdOf<T>, OldArtist<T>>;
+
+    /// The asset layout before the fingerprints algorithm.
+    #[derive(Encode, Decode)]
+    pub(crate) struct OldArtistAsset<T: Config> {
+        pub(crate) kind: AssetType,
+        pub(crate) hash: T::Hash,
+        pub(crate) added_at: BlockNumberFor<T>,
+    }
+
+    /// The artist layout before the fingerprints algorithm.
+    #[derive(Encode, Decode)]
+    pub(crate) struct OldArtist<T: Config> {
+        pub(crate) owner: AccountIdOf<T>,
+        pub(crate) registered_at: BlockNumberFor<T>,
+        pub(crate) verified_at: Option<BlockNumberFor<T>>,
+        pub(crate) kyc_evidence_hash: Option<T::Hash>,
+        pub(crate) status: ArtistStatus,
+        pub(crate) main_name: T::Name,
+        pub(crate) alias: Option<ArtistAliasOf<T>>,
+        pub(crate) genres: BoundedVec<MusicGenre, T::MaxGenres>,
+        pub(crate) open_to: AvailabilityFlags,
+        pub(crate) description: Option<T::Hash>,
+        pub(crate) raw_description: Option<BoundedVec<u8, T::MaxDescriptionLen>>,
+        pub(crate) contact_hash: Option<T::Hash>,
+        pub(crate) links: BoundedVec<(LinkType, BoundedVec<u8, T::MaxLinkLen>), T::MaxLinks>,
+        pub(crate) assets: BoundedVec<OldArtistAsset<T>, T::MaxAssets>,
+        pub(crate) contracts: BoundedVec<AccountIdOf<T>, T::MaxContracts>,
+        pub(crate) contracts_frozen: bool,
+        pub(crate) delegates: BoundedVec<AccountIdOf<T>, T::MaxDelegates>,
+        pub(crate) kind: ArtistKind,
+        pub(crate) members: BoundedVec<AccountIdOf<T>, T::MaxMembers>,
+        pub(crate) royalty_splits: RoyaltySplitsOf<T>,
+        pub(crate) deposit: BalanceOf<T>,
+        pub(crate) depositor: AccountIdOf<T>,
+    }
+
+    impl<T: Config> OldArtist<T> {
+        fn migrate(self) -> v13::OldArtist<T> {
+            let assets: BoundedVec<ArtistAsset<T>, T::MaxAssets> = self
+                .assets
+                .into_iter()
+                .map(|asset| ArtistAsset {
+                    kind: asset.kind,
+                    hash: Fingerprint::Native(asset.hash),
+                    added_at: asset.added_at,
+                })
+                .collect::<Vec<_>>()
+                .try_into()
+                .expect("the assets bound is unchanged; qed");
+
+            v13::OldArtist {
+                owner: self.owner,
+                registered_at: self.registered_at,
+                verified_at: self.verified_at,
+                kyc_evidence_hash: self.kyc_evidence_hash,
+                status: self.status,
+                main_name: self.main_name,
+                alias: self.alias,
+                genres: self.genres,
+                open_to: self.open_to,
+                description: self.description.map(Fingerprint::Native),
+                raw_description: self.raw_description,
+                contact_hash: self.contact_hash,
+                links: self.links,
+                assets,
+                contracts: self.contracts,
+                contracts_frozen: self.contracts_frozen,
+                delegates: self.delegates,
+                kind: self.kind,
+                members: self.members,
+                royalty_splits: self.royalty_splits,
+                deposit: self.deposit,
+                depositor: self.depositor,
+            }
+        }
+    }
+
+    /// The fingerprints algorithm migration, without any storage version check.
+    ///
+    /// Use [`MigrateToV12`] instead of this one from a runtime.
+    pub struct UncheckedMigrateToV12<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV12<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+
+            v13::ArtistOf::<T>::translate::<OldArtist<T>, _>(|_, old| {
+                translated.saturating_inc();
+                Some(old.migrate())
+            });
+
+            log::info!(
+                target: "runtime::artists",
+                "migrated {} artists to the native fingerprints",
+                translated
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let count = ArtistOf::<T>::iter_keys().count() as u32;
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let old_count = u32::decode(&mut &state[..])
+                .map_err(|_| "the pre-upgrade state can't be decoded")?;
+
+            // Every artist must decode on the new layout.
+            let new_count = v13::ArtistOf::<T>::iter().count() as u32;
+            ensure!(
+                old_count == new_count,
+                "some artists were lost during the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Declare the existing fingerprints as native ones, only if the on-chain storage version is
+    /// 11, and bump it to 12.
+    pub type MigrateToV12<T> = VersionedMigration<
+        11,
+        12,
+        UncheckedMigrateToV12<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrate the storage from the version 12 to the version 13, tracking the artists updates.
+///
+/// Existing artists are considered last updated at their registration, with a zero update nonce.
+pub mod v13 {
+    use super::*;
+    use crate::types::{ArtistAliasOf, FingerprintOf, RoyaltySplitsOf};
+    use codec::{Decode, Encode};
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use frame_support::Twox64Concat;
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_std::marker::PhantomData;
+
+    /// The artists storage on the version 12 layout, the current one being the later versions.
+    #[frame_support::storage_alias]
+    pub(crate) type ArtistOf<T: Config> =
+        StorageMap<Pallet<T>, Twox64Concat, AccountIdOf<T>, OldArtist<T>>;
+
+    /// The artist layout before the updates tracking.
+    #[derive(Encode, Decode)]
+    pub(crate) struct OldArtist<T: Config> {
+        pub(crate) owner: AccountIdOf<T>,
+        pub(crate) registered_at: BlockNumberFor<T>,
+        pub(crate) verified_at: Option<BlockNumberFor<T>>,
+        pub(crate) kyc_evidence_hash: Option<T::Hash>,
+        pub(crate) status: ArtistStatus,
+        pub(crate) main_name: T::Name,
+        pub(crate) alias: Option<ArtistAliasOf<T>>,
+        pub(crate) genres: BoundedVec<MusicGenre, T::MaxGenres>,
+        pub(crate) open_to: AvailabilityFlags,
+        pub(crate) description: Option<FingerprintOf<T>>,
+        pub(crate) raw_description: Option<BoundedVec<u8, T::MaxDescriptionLen>>,
+        pub(crate) contact_hash: Option<T::Hash>,
+        pub(crate) links: BoundedVec<(LinkType, BoundedVec<u8, T::MaxLinkLen>), T::MaxLinks>,
+        pub(crate) assets: BoundedVec<ArtistAsset<T>, T::MaxAssets>,
+        pub(crate) contracts: BoundedVec<AccountIdOf<T>, T::MaxContracts>,
+        pub(crate) contracts_frozen: bool,
+        pub(crate) delegates: BoundedVec<AccountIdOf<T>, T::MaxDelegates>,
+        pub(crate) kind: ArtistKind,
+        pub(crate) members: BoundedVec<AccountIdOf<T>, T::MaxMembers>,
+        pub(crate) royalty_splits: RoyaltySplitsOf<T>,
+        pub(crate) deposit: BalanceOf<T>,
+        pub(crate) depositor: AccountIdOf<T>,
+    }
+
+    impl<T: Config> OldArtist<T> {
+        fn migrate(self) -> v14::OldArtist<T> {
+            v14::OldArtist {
+                owner: self.owner,
+                registered_at: self.registered_at,
+                last_updated_at: self.registered_at,
+                update_nonce: 0,
+                verified_at: self.verified_at,
+                kyc_evidence_hash: self.kyc_evidence_hash,
+                status: self.status,
+                main_name: self.main_name,
+                alias: self.alias,
+                genres: self.genres,
+                open_to: self.open_to,
+                description: self.description,
+                raw_description: self.raw_description,
+                contact_hash: self.contact_hash,
+                links: self.links,
+                assets: self.assets,
+                contracts: self.contracts,
+                contracts_frozen: self.contracts_frozen,
+                delegates: self.delegates,
+                kind: self.kind,
+                members: self.members,
+                royalty_splits: self.royalty_splits,
+                deposit: self.deposit,
+                depositor: self.depositor,
+            }
+        }
+    }
+
+    /// The updates tracking migration, without any storage version check.
+    ///
+    /// Use [`MigrateToV13`] instead of this one from a runtime.
+    pub struct UncheckedMigrateToV13<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV13<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+
+            v14::ArtistOf::<T>::translate::<OldArtist<T>, _>(|_, old| {
+                translated.saturating_inc();
+                Some(old.migrate())
+            });
+
+            log::info!(
+                target: "runtime::artists",
+                "migrated {} artists to the updates tracking",
+                translated
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let count = ArtistOf::<T>::iter_keys().count() as u32;
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let old_count = u32::decode(&mut &state[..])
+                .map_err(|_| "the pre-upgrade state can't be decoded")?;
+
+            // Every artist must decode on the new layout.
+            let new_count = v14::ArtistOf::<T>::iter().count() as u32;
+            ensure!(
+                old_count == new_count,
+                "some artists were lost during the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Start tracking the artists updates, only if the on-chain storage version is 12, and bump
+    /// it to 13.
+    pub type MigrateToV13<T> = VersionedMigration<
+        12,
+        13,
+        UncheckedMigrateToV13<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrate the storage from the version 13 to the version 14, storing the artists genres by their
+/// registry tag and subtag.
+///
+/// The genres index is rebuilt under the stored genres keys.
+pub mod v14 {
+    use super::*;
+    use crate::types::{ArtistAliasOf, FingerprintOf, RoyaltySplitsOf};
+    use codec::{Decode, Encode};
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use frame_support::{Blake2_128Concat, Twox64Concat};
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_std::marker::PhantomData;
+
+    /// The artists storage on the version 13 layout, the current one being the later versions.
+    #[frame_support::storage_alias]
+    pub(crate) type ArtistOf<T: Config> =
+        StorageMap<Pallet<T>, Twox64Concat, AccountIdOf<T>, OldArtist<T>>;
+
+    /// The genres index on the version 13 layout, keyed by the registry genres.
+    #[frame_support::storage_alias]
+    pub(crate) type ArtistsOfGenre<T: Config> =
+        StorageDoubleMap<Pallet<T>, Blake2_128Concat, MusicGenre, Twox64Concat, AccountIdOf<T>, ()>;
+
+    /// The artist layout before the stored genres.
+    #[derive(Encode, Decode)]
+    pub(crate) struct OldArtist<T: Config> {
+        pub(crate) owner: AccountIdOf<T>,
+        pub(crate) registered_at: BlockNumberFor<T>,
+        pub(crate) last_updated_at: BlockNumberFor<T>,
+        pub(crate) update_nonce: u32,
+        pub(crate) verified_at: Option<BlockNumberFor<T>>,
+        pub(crate) kyc_evidence_hash: Option<T::Hash>,
+        pub(crate) status: ArtistStatus,
+        pub(crate) main_name: T::Name,
+        pub(crate) alias: Option<ArtistAliasOf<T>>,
+        pub(crate) genres: BoundedVec<MusicGenre, T::MaxGenres>,
+        pub(crate) open_to: AvailabilityFlags,
+        pub(crate) description: Option<FingerprintOf<T>>,
+        pub(crate) raw_description: Option<BoundedVec<u8, T::MaxDescriptionLen>>,
+        pub(crate) contact_hash: Option<T::Hash>,
+        pub(crate) links: BoundedVec<(LinkType, BoundedVec<u8, T::MaxLinkLen>), T::MaxLinks>,
+        pub(crate) assets: BoundedVec<ArtistAsset<T>, T::MaxAssets>,
+        pub(crate) contracts: BoundedVec<AccountIdOf<T>, T::MaxContracts>,
+        pub(crate) contracts_frozen: bool,
+        pub(crate) delegates: BoundedVec<AccountIdOf<T>, T::MaxDelegates>,
+        pub(crate) kind: ArtistKind,
+        pub(crate) members: BoundedVec<AccountIdOf<T>, T::MaxMembers>,
+        pub(crate) royalty_splits: RoyaltySplitsOf<T>,
+        pub(crate) deposit: BalanceOf<T>,
+        pub(crate) depositor: AccountIdOf<T>,
+    }
+
+    impl<T: Config> OldArtist<T> {
+        fn migrate(self) -> v16::OldArtist<T> {
+            let genres: BoundedVec<StoredGenre, T::MaxGenres> = self
+                .genres
+                .into_iter()
+                .map(StoredGenre::from)
+                .collect::<Vec<_>>()
+                .try_into()
+                .expect("the genres bound is unchanged; qed");
+
+            v16::OldArtist {
+                owner: self.owner,
+                registered_at: self.registered_at,
+                last_updated_at: self.last_updated_at,
+                update_nonce: self.update_nonce,
+                verified_at: self.verified_at,
+                kyc_evidence_hash: self.kyc_evidence_hash,
+                status: self.status,
+                main_name: self.main_name,
+                alias: self.alias,
+                genres,
+                open_to: self.open_to,
+                description: self.description,
+                raw_description: self.raw_description,
+                contact_hash: self.contact_hash,
+                links: self.links,
+                assets: self.assets,
+                contracts: self.contracts,
+                contracts_frozen: self.contracts_frozen,
+                delegates: self.delegates,
+                kind: self.kind,
+                members: self.members,
+                royalty_splits: self.royalty_splits,
+                deposit: self.deposit,
+                depositor: self.depositor,
+            }
+        }
+    }
+
+    /// The stored genres migration, without any storage version check.
+    ///
+    /// Use [`MigrateToV14`] instead of this one from a runtime.
+    pub struct UncheckedMigrateToV14<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV14<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            let mut indexed = 0u64;
+
+            // The old keys are dropped without being decoded, whatever registry genre they hold.
+            let cleared = ArtistsOfGenre::<T>::clear(u32::MAX, None).unique as u64;
+
+            v16::ArtistOf::<T>::translate::<OldArtist<T>, _>(|id, old| {
+                translated.saturating_inc();
+                let artist = old.migrate();
+                for genre in artist.genres.iter() {
+                    crate::ArtistsOfGenre::<T>::insert(genre, &id, ());
+                    indexed.saturating_inc();
+                }
+                Some(artist)
+            });
+
+            log::info!(
+                target: "runtime::artists",
+                "migrated {} artists to the stored genres, reindexing {} genres",
+                translated,
+                indexed
+            );
+
+            T::DbWeight::get().reads_writes(
+                translated,
+                translated.saturating_add(indexed).saturating_add(cleared),
+            )
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let count = ArtistOf::<T>::iter_keys().count() as u32;
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let old_count = u32::decode(&mut &state[..])
+                .map_err(|_| "the pre-upgrade state can't be decoded")?;
+
+            // Every artist must decode on the new layout.
+            let new_count = v16::ArtistOf::<T>::iter().count() as u32;
+            ensure!(
+                old_count == new_count,
+                "some artists were lost during the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Store the artists genres by their registry tag and subtag, only if the on-chain storage
+    /// version is 13, and bump it to 14.
+    pub type MigrateToV14<T> = VersionedMigration<
+        13,
+        14,
+        UncheckedMigrateToV14<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrate the storage from the version 14 to the version 15, converting the held deposits.
+///
+/// The deposits held in the `Native` currency are released and their `Conversion` is held in the
+/// configured `Currency` instead, for the chains moving their deposits to a non-native asset. The
+/// legacy per-field reasons are collapsed into `ArtistData` on the way.
+pub mod v15 {
+    use super::*;
+    #[cfg(feature = "try-runtime")]
+    use codec::Decode;
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use sp_runtime::traits::Convert;
+    use sp_std::marker::PhantomData;
+
+    /// The deposits conversion migration, without any storage version check.
+    ///
+    /// Use [`MigrateToV15`] instead of this one from a runtime.
+    pub struct UncheckedMigrateToV15<T, Native, Conversion>(PhantomData<(T, Native, Conversion)>);
+
+    impl<T, Native, Conversion> UncheckedMigrateToV15<T, Native, Conversion>
+    where
+        T: Config,
+        Native: MutateHold<AccountIdOf<T>, Reason = T::RuntimeHoldReason, Balance = BalanceOf<T>>,
+        Conversion: Convert<BalanceOf<T>, BalanceOf<T>>,
+    {
+        /// Release up to `cap` held from `who` in the native currency for the `from` reason and
+        /// hold its conversion in the configured currency for the `into` one.
+        ///
+        /// Returns the released native amount and the converted amount held, which is zero if
+        /// `who` lacks the converted amount.
+        fn convert_hold(
+            who: &AccountIdOf<T>,
+            from: HoldReason,
+            into: HoldReason,
+            cap: BalanceOf<T>,
+        ) -> (BalanceOf<T>, BalanceOf<T>) {
+            let held = <Native as InspectHold<_>>::balance_on_hold(&from.into(), who).min(cap);
+            if held.is_zero() {
+                return (Zero::zero(), Zero::zero());
+            }
+
+            let released =
+                <Native as MutateHold<_>>::release(&from.into(), who, held, Precision::BestEffort)
+                    .unwrap_or_default();
+            let converted = Conversion::convert(released);
+            if converted.is_zero() || T::Currency::hold(&into.into(), who, converted).is_err() {
+                return (released, Zero::zero());
+            }
+
+            (released, converted)
+        }
+    }
+
+    impl<T, Native, Conversion> OnRuntimeUpgrade for UncheckedMigrateToV15<T, Native, Conversion>
+    where
+        T: Config,
+        Native: MutateHold<AccountIdOf<T>, Reason = T::RuntimeHoldReason, Balance = BalanceOf<T>>,
+        Conversion: Convert<BalanceOf<T>, BalanceOf<T>>,
+    {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            let mut shares = 0u64;
+            let mut converted = 0u64;
+            let mut unfunded = 0u64;
+
+            v16::ArtistOf::<T>::translate::<v16::OldArtist<T>, _>(|_, mut artist| {
+                translated.saturating_inc();
+                let mut remaining = artist.deposit;
+                let mut deposit: BalanceOf<T> = Zero::zero();
+
+                for reason in Pallet::<T>::deposit_reasons() {
+                    let into = match reason {
+                        HoldReason::ArtistRegistration | HoldReason::VerificationRequest => reason,
+                        _ => HoldReason::ArtistData,
+                    };
+                    let (released, held) =
+                        Self::convert_hold(&artist.depositor, reason, into, remaining);
+                    if released.is_zero() {
+                        continue;
+                    }
+
+                    converted.saturating_inc();
+                    if held.is_zero() {
+                        unfunded.saturating_inc();
+                    }
+                    remaining = remaining.saturating_sub(released);
+                    deposit = deposit.saturating_add(held);
+                }
+
+                artist.deposit = deposit;
+                Some(artist)
+            });
+
+            crate::CoOwners::<T>::translate::<BalanceOf<T>, _>(|_, member, share| {
+                shares.saturating_inc();
+                let (released, held) = Self::convert_hold(
+                    &member,
+                    HoldReason::GroupShare,
+                    HoldReason::GroupShare,
+                    share,
+                );
+                if !released.is_zero() {
+                    converted.saturating_inc();
+                    if held.is_zero() {
+                        unfunded.saturating_inc();
+                    }
+                }
+                Some(held)
+            });
+
+            log::info!(
+                target: "runtime::artists",
+                "converted {} deposits of {} artists and {} group shares, {} of them left unfunded",
+                converted,
+                translated,
+                shares,
+                unfunded
+            );
+
+            // Each artist reads the holds of all the deposit reasons, each conversion writing the
+            // native and converted holds and balances.
+            let reasons = Pallet::<T>::deposit_reasons().len() as u64;
+            T::DbWeight::get().reads_writes(
+                translated
+                    .saturating_mul(reasons.saturating_add(1))
+                    .saturating_add(shares.saturating_mul(2)),
+                translated
+                    .saturating_add(shares)
+                    .saturating_add(converted.saturating_mul(4)),
+            )
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let count = v16::ArtistOf::<T>::iter_keys().count() as u32;
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let old_count = u32::decode(&mut &state[..])
+                .map_err(|_| "the pre-upgrade state can't be decoded")?;
+
+            let new_count = v16::ArtistOf::<T>::iter().count() as u32;
+            ensure!(
+                old_count == new_count,
+                "some artists were lost during the migration"
+            );
+
+            // The tracked deposits must be held in the configured currency.
+            for (_, artist) in v16::ArtistOf::<T>::iter() {
+                ensure!(
+                    Pallet::<T>::deposit_reasons().iter().fold(
+                        BalanceOf::<T>::zero(),
+                        |acc, reason| acc.saturating_add(T::Currency::balance_on_hold(
+                            &(*reason).into(),
+                            &artist.depositor
+                        ))
+                    ) >= artist.deposit,
+                    "an artist deposit isn't held in the configured currency"
+                );
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Convert the deposits held in the `Native` currency to the configured `Currency`, only if
+    /// the on-chain storage version is 14, and bump it to 15.
+    ///
+    /// The chains keeping their deposits in the native currency run it with the `Currency` as
+    /// `Native` and `sp_runtime::traits::Identity` as `Conversion`, which only collapses the
+    /// legacy hold reasons.
+    pub type MigrateToV15<T, Native, Conversion> = VersionedMigration<
+        14,
+        15,
+        UncheckedMigrateToV15<T, Native, Conversion>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrate the storage from the version 15 to the version 16, splitting the headline genre of the
+/// artists from their secondary genres.
+///
+/// Existing artists have no main genre, their genres all becoming secondary ones.
+pub mod v16 {
+    use super::*;
+    use crate::types::{ArtistAliasOf, FingerprintOf, RoyaltySplitsOf};
+    use codec::{Decode, Encode};
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use frame_support::Twox64Concat;
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_std::marker::PhantomData;
+
+    /// The artists storage on the version 15 layout, the current one being the later versions.
+    #[frame_support::storage_alias]
+    pub(crate) type ArtistOf<T: Config> =
+        StorageMap<Pallet<T>, Twox64Concat, AccountIdOf<T>, OldArtist<T>>;
+
+    /// The artist layout before the main genre.
+    #[derive(Encode, Decode)]
+    pub(crate) struct OldArtist<T: Config> {
+        pub(crate) owner: AccountIdOf<T>,
+        pub(crate) registered_at: BlockNumberFor<T>,
+        pub(crate) last_updated_at: BlockNumberFor<T>,
+        pub(crate) update_nonce: u32,
+        pub(crate) verified_at: Option<BlockNumberFor<T>>,
+        pub(crate) kyc_evidence_hash: Option<T::Hash>,
+        pub(crate) status: ArtistStatus,
+        pub(crate) main_name: T::Name,
+        pub(crate) alias: Option<ArtistAliasOf<T>>,
+        pub(crate) genres: BoundedVec<StoredGenre, T::MaxGenres>,
+        pub(crate) open_to: AvailabilityFlags,
+        pub(crate) description: Option<FingerprintOf<T>>,
+        pub(crate) raw_description: Option<BoundedVec<u8, T::MaxDescriptionLen>>,
+        pub(crate) contact_hash: Option<T::Hash>,
+        pub(crate) links: BoundedVec<(LinkType, BoundedVec<u8, T::MaxLinkLen>), T::MaxLinks>,
+        pub(crate) assets: BoundedVec<ArtistAsset<T>, T::MaxAssets>,
+        pub(crate) contracts: BoundedVec<AccountIdOf<T>, T::MaxContracts>,
+        pub(crate) contracts_frozen: bool,
+        pub(crate) delegates: BoundedVec<AccountIdOf<T>, T::MaxDelegates>,
+        pub(crate) kind: ArtistKind,
+        pub(crate) members: BoundedVec<AccountIdOf<T>, T::MaxMembers>,
+        pub(crate) royalty_splits: RoyaltySplitsOf<T>,
+        pub(crate) deposit: BalanceOf<T>,
+        pub(crate) depositor: AccountIdOf<T>,
+    }
+
+    impl<T: Config> OldArtist<T> {
+        fn migrate(self) -> v17::OldArtist<T> {
+            v17::OldArtist {
+                owner: self.owner,
+                registered_at: self.registered_at,
+                last_updated_at: self.last_updated_at,
+                update_nonce: self.update_nonce,
+                verified_at: self.verified_at,
+                kyc_evidence_hash: self.kyc_evidence_hash,
+                status: self.status,
+                main_name: self.main_name,
+                alias: self.alias,
+                main_genre: None,
+                genres: self.genres,
+                open_to: self.open_to,
+                description: self.description,
+                raw_description: self.raw_description,
+                contact_hash: self.contact_hash,
+                links: self.links,
+                assets: self.assets,
+                contracts: self.contracts,
+                contracts_frozen: self.contracts_frozen,
+                delegates: self.delegates,
+                kind: self.kind,
+                members: self.members,
+                royalty_splits: self.royalty_splits,
+                deposit: self.deposit,
+                depositor: self.depositor,
+            }
+        }
+    }
+
+    /// The main genre migration, without any storage version check.
+    ///
+    /// Use [`MigrateToV16`] instead of this one from a runtime.
+    pub struct UncheckedMigrateToV16<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV16<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+
+            v17::ArtistOf::<T>::translate::<OldArtist<T>, _>(|_, old| {
+                translated.saturating_inc();
+                Some(old.migrate())
+            });
+
+            log::info!(
+                target: "runtime::artists",
+                "migrated {} artists to the main genre",
+                translated
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let count = ArtistOf::<T>::iter_keys().count() as u32;
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let old_count = u32::decode(&mut &state[..])
+                .map_err(|_| "the pre-upgrade state can't be decoded")?;
+
+            // Every artist must decode on the new layout.
+            let new_count = v17::ArtistOf::<T>::iter().count() as u32;
+            ensure!(
+                old_count == new_count,
+                "some artists were lost during the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Add the empty main genre to existing artists, only if the on-chain storage version is 15,
+    /// and bump it to 16.
+    pub type MigrateToV16<T> = VersionedMigration<
+        15,
+        16,
+        UncheckedMigrateToV16<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrate the storage from the version 16 to the version 17, letting the artists hold multiple
+/// aliases.
+///
+/// The alias of existing artists becomes their single alias, the aliases index being unchanged.
+pub mod v17 {
+    use super::*;
+    use crate::types::{ArtistAliasOf, FingerprintOf, RoyaltySplitsOf};
+    use codec::{Decode, Encode};
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use frame_support::Twox64Concat;
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_std::marker::PhantomData;
+
+    /// The artists storage on the version 16 layout, the current one being the later versions.
+    #[frame_support::storage_alias]
+    pub(crate) type ArtistOf<T: Config> =
+        StorageMap<Pallet<T>, Twox64Concat, AccountIdOf<T>, OldArtist<T>>;
+
+    /// The artist layout before the multiple aliases.
+    #[derive(Encode, Decode)]
+    pub(crate) struct OldArtist<T: Config> {
+        pub(crate) owner: AccountIdOf<T>,
+        pub(crate) registered_at: BlockNumberFor<T>,
+        pub(crate) last_updated_at: BlockNumberFor<T>,
+        pub(crate) update_nonce: u32,
+        pub(crate) verified_at: Option<BlockNumberFor<T>>,
+        pub(crate) kyc_evidence_hash: Option<T::Hash>,
+        pub(crate) status: ArtistStatus,
+        pub(crate) main_name: T::Name,
+        pub(crate) alias: Option<ArtistAliasOf<T>>,
+        pub(crate) main_genre: Option<StoredGenre>,
+        pub(crate) genres: BoundedVec<StoredGenre, T::MaxGenres>,
+        pub(crate) open_to: AvailabilityFlags,
+        pub(crate) description: Option<FingerprintOf<T>>,
+        pub(crate) raw_description: Option<BoundedVec<u8, T::MaxDescriptionLen>>,
+        pub(crate) contact_hash: Option<T::Hash>,
+        pub(crate) links: BoundedVec<(LinkType, BoundedVec<u8, T::MaxLinkLen>), T::MaxLinks>,
+        pub(crate) assets: BoundedVec<ArtistAsset<T>, T::MaxAssets>,
+        pub(crate) contracts: BoundedVec<AccountIdOf<T>, T::MaxContracts>,
+        pub(crate) contracts_frozen: bool,
+        pub(crate) delegates: BoundedVec<AccountIdOf<T>, T::MaxDelegates>,
+        pub(crate) kind: ArtistKind,
+        pub(crate) members: BoundedVec<AccountIdOf<T>, T::MaxMembers>,
+        pub(crate) royalty_splits: RoyaltySplitsOf<T>,
+        pub(crate) deposit: BalanceOf<T>,
+        pub(crate) depositor: AccountIdOf<T>,
+    }
+
+    impl<T: Config> OldArtist<T> {
+        fn migrate(self) -> v18::OldArtist<T> {
+            v18::OldArtist {
+                owner: self.owner,
+                registered_at: self.registered_at,
+                last_updated_at: self.last_updated_at,
+                update_nonce: self.update_nonce,
+                verified_at: self.verified_at,
+                kyc_evidence_hash: self.kyc_evidence_hash,
+                status: self.status,
+                main_name: self.main_name,
+                // An optional alias encodes to as many bytes as the aliases holding it, the held
+                // name deposit staying the same
+                aliases: BoundedVec::truncate_from(self.alias.into_iter().collect()),
+                main_genre: self.main_genre,
+                genres: self.genres,
+                open_to: self.open_to,
+                description: self.description,
+                raw_description: self.raw_description,
+                contact_hash: self.contact_hash,
+                links: self.links,
+                assets: self.assets,
+                contracts: self.contracts,
+                contracts_frozen: self.contracts_frozen,
+                delegates: self.delegates,
+                kind: self.kind,
+                members: self.members,
+                royalty_splits: self.royalty_splits,
+                deposit: self.deposit,
+                depositor: self.depositor,
+            }
+        }
+    }
+
+    /// The multiple aliases migration, without any storage version check.
+    ///
+    /// Use [`MigrateToV17`] instead of this one from a runtime.
+    pub struct UncheckedMigrateToV17<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV17<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+
+            v18::ArtistOf::<T>::translate::<OldArtist<T>, _>(|_, old| {
+                translated.saturating_inc();
+                Some(old.migrate())
+            });
+
+            log::info!(
+                target: "runtime::artists",
+                "migrated {} artists to the multiple aliases",
+                translated
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let count = ArtistOf::<T>::iter_keys().count() as u32;
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let old_count = u32::decode(&mut &state[..])
+                .map_err(|_| "the pre-upgrade state can't be decoded")?;
+
+            // Every artist must decode on the new layout.
+            let new_count = v18::ArtistOf::<T>::iter().count() as u32;
+            ensure!(
+                old_count == new_count,
+                "some artists were lost during the migration"
+            );
+
+            // The aliases index must still point to artists holding the alias.
+            for (alias, id) in crate::ArtistAliasIndex::<T>::iter() {
+                let artist =
+                    v18::ArtistOf::<T>::get(&id).ok_or("alias index points to no artist")?;
+                ensure!(
+                    artist.aliases.contains(&alias),
+                    "alias index points to an artist without this alias"
+                );
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Turn the alias of existing artists into their single alias, only if the on-chain storage
+    /// version is 16, and bump it to 17.
+    ///
+    /// `MaxAliases` must be at least 1 for the existing aliases to be kept.
+    pub type MigrateToV17<T> = VersionedMigration<
+        16,
+        17,
+        UncheckedMigrateToV17<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrate the storage from the version 17 to the version 18, adding the works claimed by the
+/// artists.
+///
+/// Existing artists claim no work, their deposit being unchanged.
+pub mod v18 {
+    use super::*;
+    use crate::types::{ArtistAliasOf, FingerprintOf, RoyaltySplitsOf};
+    use codec::{Decode, Encode};
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use frame_support::Twox64Concat;
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_std::marker::PhantomData;
+
+    /// The artists storage on the version 17 layout, the current one being the later versions.
+    #[frame_support::storage_alias]
+    pub(crate) type ArtistOf<T: Config> =
+        StorageMap<Pallet<T>, Twox64Concat, AccountIdOf<T>, OldArtist<T>>;
+
+    /// The artist layout before the claimed works.
+    #[derive(Encode, Decode)]
+    pub(crate) struct OldArtist<T: Config> {
+        pub(crate) owner: AccountIdOf<T>,
+        pub(crate) registered_at: BlockNumberFor<T>,
+        pub(crate) last_updated_at: BlockNumberFor<T>,
+        pub(crate) update_nonce: u32,
+        pub(crate) verified_at: Option<BlockNumberFor<T>>,
+        pub(crate) kyc_evidence_hash: Option<T::Hash>,
+        pub(crate) status: ArtistStatus,
+        pub(crate) main_name: T::Name,
+        pub(crate) aliases: BoundedVec<ArtistAliasOf<T>, T::MaxAliases>,
+        pub(crate) main_genre: Option<StoredGenre>,
+        pub(crate) genres: BoundedVec<StoredGenre, T::MaxGenres>,
+        pub(crate) open_to: AvailabilityFlags,
+        pub(crate) description: Option<FingerprintOf<T>>,
+        pub(crate) raw_description: Option<BoundedVec<u8, T::MaxDescriptionLen>>,
+        pub(crate) contact_hash: Option<T::Hash>,
+        pub(crate) links: BoundedVec<(LinkType, BoundedVec<u8, T::MaxLinkLen>), T::MaxLinks>,
+        pub(crate) assets: BoundedVec<ArtistAsset<T>, T::MaxAssets>,
+        pub(crate) contracts: BoundedVec<AccountIdOf<T>, T::MaxContracts>,
+        pub(crate) contracts_frozen: bool,
+        pub(crate) delegates: BoundedVec<AccountIdOf<T>, T::MaxDelegates>,
+        pub(crate) kind: ArtistKind,
+        pub(crate) members: BoundedVec<AccountIdOf<T>, T::MaxMembers>,
+        pub(crate) royalty_splits: RoyaltySplitsOf<T>,
+        pub(crate) deposit: BalanceOf<T>,
+        pub(crate) depositor: AccountIdOf<T>,
+    }
+
+    impl<T: Config> OldArtist<T> {
+        fn migrate(self) -> v20::OldArtist<T> {
+            v20::OldArtist {
+                owner: self.owner,
+                registered_at: self.registered_at,
+                last_updated_at: self.last_updated_at,
+                update_nonce: self.update_nonce,
+                verified_at: self.verified_at,
+                kyc_evidence_hash: self.kyc_evidence_hash,
+                status: self.status,
+                main_name: self.main_name,
+                aliases: self.aliases,
+                main_genre: self.main_genre,
+                genres: self.genres,
+                open_to: self.open_to,
+                description: self.description,
+                raw_description: self.raw_description,
+                contact_hash: self.contact_hash,
+                links: self.links,
+                assets: self.assets,
+                works: Default::default(),
+                contracts: self.contracts,
+                contracts_frozen: self.contracts_frozen,
+                delegates: self.delegates,
+                kind: self.kind,
+                members: self.members,
+                royalty_splits: self.royalty_splits,
+                deposit: self.deposit,
+                depositor: self.depositor,
+            }
+        }
+    }
+
+    /// The claimed works migration, without any storage version check.
+    ///
+    /// Use [`MigrateToV18`] instead of this one from a runtime.
+    pub struct UncheckedMigrateToV18<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV18<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+
+            v20::ArtistOf::<T>::translate::<OldArtist<T>, _>(|_, old| {
+                translated.saturating_inc();
+                Some(old.migrate())
+            });
+
+            log::info!(
+                target: "runtime::artists",
+                "migrated {} artists to the claimed works",
+                translated
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let count = ArtistOf::<T>::iter_keys().count() as u32;
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let old_count = u32::decode(&mut &state[..])
+                .map_err(|_| "the pre-upgrade state can't be decoded")?;
+
+            // Every artist must decode on the new layout, without any claimed work.
+            let mut new_count = 0u32;
+            for artist in v20::ArtistOf::<T>::iter_values() {
+                ensure!(
+                    artist.works.is_empty(),
+                    "an artist claims works after the migration"
+                );
+                new_count.saturating_inc();
+            }
+            ensure!(
+                old_count == new_count,
+                "some artists were lost during the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Add the empty claimed works to existing artists, only if the on-chain storage version is
+    /// 17, and bump it to 18.
+    pub type MigrateToV18<T> = VersionedMigration<
+        17,
+        18,
+        UncheckedMigrateToV18<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrate the storage from the version 18 to the version 19, indexing the names of the verified
+/// artists under their confusable key.
+///
+/// The first artist found keeps a name confusable with the one of another verified artist, the
+/// governance being left to rename the other one.
+pub mod v19 {
+    use super::*;
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use sp_std::marker::PhantomData;
+
+    /// The confusable names index backfill, without any storage version check.
+    ///
+    /// Use [`MigrateToV19`] instead of this one from a runtime.
+    pub struct UncheckedMigrateToV19<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV19<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut reads = 0u64;
+            let mut writes = 0u64;
+            let mut duplicates = 0u64;
+
+            for (name, id) in ArtistNameOf::<T>::iter() {
+                reads.saturating_accrue(2);
+
+                if Pallet::<T>::ensure_name_not_confusable(&name, &id).is_ok() {
+                    ConfusableNameOf::<T>::insert(Pallet::<T>::confusable_name_hash(&name), id);
+                    writes.saturating_inc();
+                } else {
+                    duplicates.saturating_inc();
+                }
+            }
+
+            log::info!(
+                target: "runtime::artists",
+                "indexed {} confusable names, {} duplicates skipped",
+                writes,
+                duplicates
+            );
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            for (name_hash, id) in ConfusableNameOf::<T>::iter() {
+                let artist = v20::ArtistOf::<T>::get(&id)
+                    .ok_or("confusable name index points to no artist")?;
+                ensure!(
+                    Pallet::<T>::confusable_name_hash(&artist.main_name) == name_hash,
+                    "confusable name index points to an artist without this name"
+                );
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Backfill the confusable names index, only if the on-chain storage version is 18, and bump
+    /// it to 19.
+    pub type MigrateToV19<T> = VersionedMigration<
+        18,
+        19,
+        UncheckedMigrateToV19<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrate the storage from the version 19 to the version 20, tracking the surcharges held in
+/// the deposit of the artists apart, for the deposits to be repriced without them.
+///
+/// The deposits are unchanged, the part of them not required by the artist data nor by a pending
+/// verification request being considered a surcharge.
+pub mod v20 {
+    use super::*;
+    use crate::types::{ArtistAliasOf, FingerprintOf, RoyaltySplitsOf};
+    use codec::{Decode, Encode, MaxEncodedLen};
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use frame_support::Twox64Concat;
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_std::marker::PhantomData;
+
+    /// The artists storage on the version 19 layout, the current one being the later versions.
+    #[frame_support::storage_alias]
+    pub(crate) type ArtistOf<T: Config> =
+        StorageMap<Pallet<T>, Twox64Concat, AccountIdOf<T>, OldArtist<T>>;
+
+    /// The artist layout before the tracked surcharges.
+    #[derive(Encode, Decode)]
+    pub(crate) struct OldArtist<T: Config> {
+        pub(crate) owner: AccountIdOf<T>,
+        pub(crate) registered_at: BlockNumberFor<T>,
+        pub(crate) last_updated_at: BlockNumberFor<T>,
+        pub(crate) update_nonce: u32,
+        pub(crate) verified_at: Option<BlockNumberFor<T>>,
+        pub(crate) kyc_evidence_hash: Option<T::Hash>,
+        pub(crate) status: ArtistStatus,
+        pub(crate) main_name: T::Name,
+        pub(crate) aliases: BoundedVec<ArtistAliasOf<T>, T::MaxAliases>,
+        pub(crate) main_genre: Option<StoredGenre>,
+        pub(crate) genres: BoundedVec<StoredGenre, T::MaxGenres>,
+        pub(crate) open_to: AvailabilityFlags,
+        pub(crate) description: Option<FingerprintOf<T>>,
+        pub(crate) raw_description: Option<BoundedVec<u8, T::MaxDescriptionLen>>,
+        pub(crate) contact_hash: Option<T::Hash>,
+        pub(crate) links: BoundedVec<(LinkType, BoundedVec<u8, T::MaxLinkLen>), T::MaxLinks>,
+        pub(crate) assets: BoundedVec<ArtistAsset<T>, T::MaxAssets>,
+        pub(crate) works: BoundedVec<T::Hash, T::MaxWorks>,
+        pub(crate) contracts: BoundedVec<AccountIdOf<T>, T::MaxContracts>,
+        pub(crate) contracts_frozen: bool,
+        pub(crate) delegates: BoundedVec<AccountIdOf<T>, T::MaxDelegates>,
+        pub(crate) kind: ArtistKind,
+        pub(crate) members: BoundedVec<AccountIdOf<T>, T::MaxMembers>,
+        pub(crate) royalty_splits: RoyaltySplitsOf<T>,
+        pub(crate) deposit: BalanceOf<T>,
+        pub(crate) depositor: AccountIdOf<T>,
+    }
+
+    impl<T: Config> OldArtist<T> {
+        fn migrate(self) -> Artist<T> {
+            let mut artist = Artist {
+                owner: self.owner,
+                registered_at: self.registered_at,
+                last_updated_at: self.last_updated_at,
+                update_nonce: self.update_nonce,
+                verified_at: self.verified_at,
+                kyc_evidence_hash: self.kyc_evidence_hash,
+                status: self.status,
+                main_name: self.main_name,
+                aliases: self.aliases,
+                main_genre: self.main_genre,
+                genres: self.genres,
+                open_to: self.open_to,
+                description: self.description,
+                raw_description: self.raw_description,
+                contact_hash: self.contact_hash,
+                links: self.links,
+                assets: self.assets,
+                works: self.works,
+                contracts: self.contracts,
+                contracts_frozen: self.contracts_frozen,
+                delegates: self.delegates,
+                kind: self.kind,
+                members: self.members,
+                royalty_splits: self.royalty_splits,
+                deposit: self.deposit,
+                depositor: self.depositor,
+                surcharge: Zero::zero(),
+            };
+
+            // The escrow and evidence deposits of a pending verification aren't surcharges
+            let pending = match crate::PendingVerifications::<T>::get(&artist.owner) {
+                Some(request) => T::ByteDeposit::get()
+                    .saturating_mul(T::Hash::max_encoded_len().saturated_into())
+                    .saturating_mul((request.evidence().len() as u32).into())
+                    .saturating_add(T::VerificationDeposit::get()),
+                None => Zero::zero(),
+            };
+            artist.surcharge = artist.deposit_breakdown().other.saturating_sub(pending);
+
+            artist
+        }
+    }
+
+    /// The tracked surcharges migration, without any storage version check.
+    ///
+    /// Use [`MigrateToV20`] instead of this one from a runtime.
+    pub struct UncheckedMigrateToV20<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV20<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+
+            crate::ArtistOf::<T>::translate::<OldArtist<T>, _>(|_, old| {
+                translated.saturating_inc();
+                Some(old.migrate())
+            });
+
+            log::info!(
+                target: "runtime::artists",
+                "migrated {} artists to the tracked surcharges",
+                translated
+            );
+
+            // The pending verification of every artist is read along with it
+            T::DbWeight::get().reads_writes(translated.saturating_mul(2), translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let deposits = ArtistOf::<T>::iter()
+                .map(|(id, artist)| (id, artist.deposit))
+                .collect::<Vec<_>>();
+            Ok(deposits.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let deposits = Vec::<(AccountIdOf<T>, BalanceOf<T>)>::decode(&mut &state[..])
+                .map_err(|_| "the pre-upgrade state can't be decoded")?;
+
+            // Every artist must decode on the new layout, with the same deposit.
+            for (id, deposit) in deposits.iter() {
+                let artist = crate::ArtistOf::<T>::get(id)
+                    .ok_or("some artists were lost during the migration")?;
+                ensure!(
+                    artist.deposit == *deposit,
+                    "an artist deposit changed during the migration"
+                );
+                ensure!(
+                    artist.surcharge <= artist.deposit,
+                    "an artist surcharge exceeds its deposit"
+                );
+            }
+            ensure!(
+                crate::ArtistOf::<T>::iter_keys().count() == deposits.len(),
+                "some artists were lost during the migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Track the surcharges held by existing artists, only if the on-chain storage version is 19,
+    /// and bump it to 20.
+    pub type MigrateToV20<T> = VersionedMigration<
+        19,
+        20,
+        UncheckedMigrateToV20<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrate the storage from the version 20 to the version 21, indexing the artists under the
+/// prefix of their main name.
+pub mod v21 {
+    use super::*;
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::traits::OnRuntimeUpgrade;
+    use sp_std::marker::PhantomData;
+
+    /// The name prefix index backfill, without any storage version check.
+    ///
+    /// Use [`MigrateToV21`] instead of this one from a runtime.
+    pub struct UncheckedMigrateToV21<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateToV21<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut reads = 0u64;
+            let mut writes = 0u64;
+
+            for (id, artist) in crate::ArtistOf::<T>::iter() {
+                reads.saturating_inc();
+
+                if let Some(bucket) = Pallet::<T>::name_prefix_bucket(&artist.main_name.to_bytes())
+                {
+                    ArtistsOfNamePrefix::<T>::insert(bucket, id, ());
+                    writes.saturating_inc();
+                }
+            }
+
+            log::info!(
+                target: "runtime::artists",
+                "indexed {} artists under their name prefix",
+                writes
+            );
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            for (id, artist) in crate::ArtistOf::<T>::iter() {
+                if let Some(bucket) = Pallet::<T>::name_prefix_bucket(&artist.main_name.to_bytes())
+                {
+                    ensure!(
+                        ArtistsOfNamePrefix::<T>::contains_key(bucket, id),
+                        "artist missing from the name prefix index"
+                    );
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Backfill the name prefix index, only if the on-chain storage version is 20, and bump it to
+    /// 21.
+    pub type MigrateToV21<T> = VersionedMigration<
+        20,
+        21,
+        UncheckedMigrateToV21<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
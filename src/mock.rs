@@ -16,14 +16,21 @@
 // limitations under the License.
 
 //! # Artists test environment.
+//!
+//! Also exported with the `mock-runtime` feature for the pallets integrating with the artists one:
+//!
+//! ```ignore
+//! use pallet_artists::mock::{new_test_ext, Artists, Producers, RuntimeOrigin, Test};
+//! ```
 
 use super::*;
 use crate as pallet_artists;
 use frame_support::derive_impl;
+use frame_support::instances::Instance1;
 use frame_support::traits::{ConstU128, ConstU16, ConstU32, ConstU64};
-use frame_support::{parameter_types, PalletId};
-use frame_system::EnsureRoot;
-use sp_runtime::testing::H256;
+use frame_support::{ord_parameter_types, parameter_types, PalletId};
+use frame_system::{EnsureRoot, EnsureSignedBy};
+use sp_runtime::testing::{TestSignature, H256};
 use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
 use sp_runtime::BuildStorage;
 
@@ -35,6 +42,7 @@ frame_support::construct_runtime!(
         System: frame_system,
         Balances: pallet_balances,
         Artists: pallet_artists,
+        Producers: pallet_artists::<Instance1>,
     }
 );
 
@@ -83,22 +91,350 @@ impl pallet_balances::Config for Test {
 
 parameter_types! {
     pub const ArtistsPalletId: PalletId = PalletId(*b"py/artst");
+    pub const ProducersPalletId: PalletId = PalletId(*b"py/prdcr");
+    pub static VerificationQueueOverflow: QueueOverflowPolicy = QueueOverflowPolicy::Reject;
+    pub static MaxElectronicGenres: u32 = 5;
+    pub static UnverifiedAliases: u32 = 5;
+    pub static MaxArtistProofSize: u32 = 32 * 1024;
+    pub static MinNameLen: u32 = 1;
+    pub static AssetBudget: u32 = 32 * 1024;
+    pub static MaxRegistrationsPerPeriod: u32 = 0;
+    pub static LifecycleCalls: Vec<LifecycleCall> = Vec::new();
+    pub static LifecycleHookWeight: Weight = Weight::zero();
+    pub static LifecycleHookFailure: HookFailurePolicy = HookFailurePolicy::Ignore;
+    pub static ContractNotices: Vec<(u64, u64, ContractNotice)> = Vec::new();
+    pub static FailingContracts: Vec<u64> = Vec::new();
+    pub static ContractNotificationCost: Weight = Weight::zero();
+    pub const ClaimAuthority: u64 = 99;
+    pub const MaxLifecycleHookWeight: Weight = Weight::from_parts(1_000_000, 1024);
+    pub const MaxContractNotificationWeight: Weight = Weight::from_parts(1_000_000, 1024);
+    pub static ProxyPairs: Vec<(u64, u64)> = Vec::new();
+    pub static DeniedNames: Vec<Vec<u8>> = Vec::new();
+    pub static AbuseSurcharge: u128 = 0;
+    pub static BaseDeposit: u128 = 5;
+    pub static ByteDeposit: u128 = 1;
+    pub static UnverifiedLifetime: Option<u64> = Some(500);
+    pub const ReapReward: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(10);
+    pub const NeverExpires: Option<u64> = None;
+    pub static RemoteAccounts: Vec<(u64, OriginChain)> = Vec::new();
+}
+
+ord_parameter_types! {
+    pub const Label: u64 = 5;
+}
+
+/// A call of the `OnArtistLifecycle` handlers, recorded in `LifecycleCalls`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum LifecycleCall {
+    Registered(u64),
+    Unregistered(u64),
+    Verified(u64),
+}
+
+/// Records the artists lifecycle into `LifecycleCalls`, consuming `LifecycleHookWeight` for
+/// every call.
+pub struct MockLifecycle;
+
+impl MockLifecycle {
+    fn record(call: LifecycleCall, meter: &mut WeightMeter) -> DispatchResult {
+        meter
+            .try_consume(LifecycleHookWeight::get())
+            .map_err(|_| DispatchError::Exhausted)?;
+
+        let mut calls = LifecycleCalls::get();
+        calls.push(call);
+        LifecycleCalls::set(calls);
+        Ok(())
+    }
+}
+
+impl OnArtistRegistered<u64> for MockLifecycle {
+    fn on_artist_registered(who: &u64, meter: &mut WeightMeter) -> DispatchResult {
+        Self::record(LifecycleCall::Registered(*who), meter)
+    }
+}
+
+impl OnArtistUnregistered<u64> for MockLifecycle {
+    fn on_artist_unregistered(who: &u64, meter: &mut WeightMeter) -> DispatchResult {
+        Self::record(LifecycleCall::Unregistered(*who), meter)
+    }
+}
+
+impl OnArtistVerified<u64> for MockLifecycle {
+    fn on_artist_verified(who: &u64, meter: &mut WeightMeter) -> DispatchResult {
+        Self::record(LifecycleCall::Verified(*who), meter)
+    }
+}
+
+/// Records the contract notifications into `ContractNotices`, consuming `ContractNotificationCost`
+/// for every notification and failing for the `FailingContracts`.
+pub struct MockContractNotifier;
+
+impl NotifyContract<u64> for MockContractNotifier {
+    fn notify(
+        artist: &u64,
+        contract: &u64,
+        notice: ContractNotice,
+        meter: &mut WeightMeter,
+    ) -> DispatchResult {
+        meter
+            .try_consume(ContractNotificationCost::get())
+            .map_err(|_| DispatchError::Exhausted)?;
+        if FailingContracts::get().contains(contract) {
+            return Err(DispatchError::Other("failing contract"));
+        }
+
+        let mut notices = ContractNotices::get();
+        notices.push((*artist, *contract, notice));
+        ContractNotices::set(notices);
+        Ok(())
+    }
+}
+
+/// Lets the `(delegator, delegate)` pairs of `ProxyPairs` act for each other.
+pub struct MockProxies;
+
+impl ProxyCheck<u64> for MockProxies {
+    fn is_proxy(delegator: &u64, delegate: &u64) -> bool {
+        ProxyPairs::get().contains(&(*delegator, *delegate))
+    }
+}
+
+/// Accepts the signed origins of the accounts listed in `RemoteAccounts`, as if derived from their
+/// paired chain by an XCM origin conversion.
+pub struct MockRemoteOrigin;
+
+impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for MockRemoteOrigin {
+    type Success = (u64, OriginChain);
+
+    fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+        let who = match o.clone().into() {
+            Ok(frame_system::RawOrigin::Signed(who)) => who,
+            _ => return Err(o),
+        };
+
+        RemoteAccounts::get()
+            .into_iter()
+            .find(|(account, _)| *account == who)
+            .ok_or(o)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        RemoteAccounts::set(vec![(1000, OriginChain::Sibling(2000))]);
+        Ok(RuntimeOrigin::signed(1000))
+    }
+}
+
+/// Rejects the main names and aliases listed in `DeniedNames`, surcharging the other submissions
+/// by `AbuseSurcharge`.
+pub struct MockAbuseFilter;
+
+impl MockAbuseFilter {
+    fn score(names: &[&[u8]]) -> AbuseVerdict<u128> {
+        if names
+            .iter()
+            .any(|name| DeniedNames::get().contains(&name.to_vec()))
+        {
+            AbuseVerdict::Reject
+        } else if AbuseSurcharge::get() > 0 {
+            AbuseVerdict::Surcharge(AbuseSurcharge::get())
+        } else {
+            AbuseVerdict::Accept
+        }
+    }
+}
+
+impl ScoreRegistration<Test> for MockAbuseFilter {
+    fn score_registration(
+        _: &u64,
+        main_name: &BoundedVec<u8, ConstU32<64>>,
+        alias: Option<&BoundedVec<u8, ConstU32<64>>>,
+        _: &[MusicGenre],
+        _: Option<&[u8]>,
+        _: &[(AssetType, Vec<u8>)],
+    ) -> AbuseVerdict<u128> {
+        match alias {
+            Some(alias) => Self::score(&[&main_name[..], &alias[..]]),
+            None => Self::score(&[&main_name[..]]),
+        }
+    }
+
+    fn score_update(
+        _: &u64,
+        data: &UpdatableData<BoundedVec<u8, ConstU32<64>>, H256>,
+    ) -> AbuseVerdict<u128> {
+        match data.added_alias() {
+            Some(alias) => Self::score(&[&alias[..]]),
+            None => Self::score(&[]),
+        }
+    }
+}
+
+/// Signs the profile claim vouchers as the mock `ClaimAuthority`.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockClaimAuthority;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<TestSignature> for MockClaimAuthority {
+    fn sign_claim_voucher(payload: &[u8]) -> TestSignature {
+        TestSignature(ClaimAuthority::get(), payload.to_vec())
+    }
+}
+
+/// Curates the classical genres to a single one, the electronic ones following
+/// `MaxElectronicGenres`.
+pub struct MockGenreCapacity;
+
+impl GenreCapacity for MockGenreCapacity {
+    fn max_per_genre(genre: &MusicGenre) -> u32 {
+        match genre {
+            MusicGenre::Electronic(_) => MaxElectronicGenres::get(),
+            MusicGenre::Classical(_) => 1,
+            _ => u32::MAX,
+        }
+    }
+}
+
+/// Lets the unverified artists hold `UnverifiedAliases` aliases, and the verified ones 5.
+pub struct MockAliasCapacity;
+
+impl AliasCapacity for MockAliasCapacity {
+    fn max_aliases(verified: bool) -> u32 {
+        if verified {
+            5
+        } else {
+            UnverifiedAliases::get()
+        }
+    }
 }
 
 impl Config for Test {
     type PalletId = ArtistsPalletId;
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
+    type BaseDeposit = BaseDeposit;
+    type ByteDeposit = ByteDeposit;
+    type VerificationDeposit = ConstU128<10>;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type VerifierOrigin = EnsureRoot<Self::AccountId>;
+    type AdminOrigin = EnsureRoot<Self::AccountId>;
+    type Slash = ();
+    type UnregisterPeriod = ConstU32<10>;
+    type NameRetentionPeriod = ConstU64<20>;
+    type MaxNameLen = ConstU32<64>;
+    type Name = BoundedVec<u8, ConstU32<64>>;
+    type NameValidator = Utf8NameValidator;
+    type MinNameLen = MinNameLen;
+    type NamePrefixLen = ConstU32<3>;
+    type MaxGenres = ConstU32<5>;
+    type MaxAliases = ConstU32<5>;
+    type GenreCapacity = MockGenreCapacity;
+    type AliasCapacity = MockAliasCapacity;
+    type MaxAssets = ConstU32<32>;
+    type AssetBudget = AssetBudget;
+    type AssetBudgetPeriod = ConstU64<10>;
+    type MaxRegistrationsPerPeriod = MaxRegistrationsPerPeriod;
+    type RegistrationPeriod = ConstU64<10>;
+    type MaxContracts = ConstU32<2048>;
+    type MaxWorks = ConstU32<10>;
+    type MaxArtistProofSize = MaxArtistProofSize;
+    type MaxLinks = ConstU32<4>;
+    type MaxLinkLen = ConstU32<128>;
+    type MaxDescriptionLen = ConstU32<256>;
+    type MaxHashedDescriptionLen = ConstU32<1024>;
+    type MaxDelegates = ConstU32<2>;
+    type MaxUpdatesPerCall = ConstU32<4>;
+    type MaxMembers = ConstU32<6>;
+    type MinCoOwnerShare = ConstU128<10>;
+    type MaxRoyaltyBeneficiaries = ConstU32<8>;
+    type MaxEvidence = ConstU32<8>;
+    type MaxPendingVerifications = ConstU32<2>;
+    type VerificationQueueOverflow = VerificationQueueOverflow;
+    type VerificationRequestLifetime = ConstU64<30>;
+    type VerificationValidity = ConstU64<200>;
+    type UnverifiedLifetime = UnverifiedLifetime;
+    type ReapReward = ReapReward;
+    type MaxCleanupPerBlock = ConstU32<2>;
+    type OnArtistLifecycle = MockLifecycle;
+    type MaxLifecycleHookWeight = MaxLifecycleHookWeight;
+    type LifecycleHookFailure = LifecycleHookFailure;
+    type LabelOrigin = EnsureSignedBy<Label, u64>;
+    type Proxies = MockProxies;
+    type RemoteRegistrationOrigin = MockRemoteOrigin;
+    type AbuseFilter = MockAbuseFilter;
+    type ContractNotifier = MockContractNotifier;
+    type MaxContractNotificationWeight = MaxContractNotificationWeight;
+    type ClaimWindow = ConstU64<100>;
+    type ClaimSignature = TestSignature;
+    type ClaimAuthority = ClaimAuthority;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MockClaimAuthority;
+    type WeightInfo = ();
+}
+
+/// A second registry, of the producers and engineers, with the default handlers.
+impl Config<Instance1> for Test {
+    type PalletId = ProducersPalletId;
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
     type BaseDeposit = ConstU128<5>;
     type ByteDeposit = ConstU128<1>;
+    type VerificationDeposit = ConstU128<10>;
     type RuntimeHoldReason = RuntimeHoldReason;
-    type RootOrigin = EnsureRoot<Self::AccountId>;
+    type VerifierOrigin = EnsureRoot<Self::AccountId>;
+    type AdminOrigin = EnsureRoot<Self::AccountId>;
     type Slash = ();
     type UnregisterPeriod = ConstU32<10>;
+    type NameRetentionPeriod = ConstU64<20>;
     type MaxNameLen = ConstU32<64>;
+    type Name = BoundedVec<u8, ConstU32<64>>;
+    type NameValidator = ();
+    type MinNameLen = ConstU32<1>;
+    type NamePrefixLen = ConstU32<3>;
     type MaxGenres = ConstU32<5>;
+    type MaxAliases = ConstU32<5>;
+    type GenreCapacity = ();
+    type AliasCapacity = ();
     type MaxAssets = ConstU32<32>;
+    type AssetBudget = ConstU32<0>;
+    type AssetBudgetPeriod = ConstU64<10>;
+    type MaxRegistrationsPerPeriod = ConstU32<0>;
+    type RegistrationPeriod = ConstU64<10>;
     type MaxContracts = ConstU32<2048>;
+    type MaxWorks = ConstU32<10>;
+    type MaxArtistProofSize = MaxArtistProofSize;
+    type MaxLinks = ConstU32<4>;
+    type MaxLinkLen = ConstU32<128>;
+    type MaxDescriptionLen = ConstU32<256>;
+    type MaxHashedDescriptionLen = ConstU32<1024>;
+    type MaxDelegates = ConstU32<2>;
+    type MaxUpdatesPerCall = ConstU32<4>;
+    type MaxMembers = ConstU32<6>;
+    type MinCoOwnerShare = ConstU128<10>;
+    type MaxRoyaltyBeneficiaries = ConstU32<8>;
+    type MaxEvidence = ConstU32<8>;
+    type MaxPendingVerifications = ConstU32<2>;
+    type VerificationQueueOverflow = VerificationQueueOverflow;
+    type VerificationRequestLifetime = ConstU64<30>;
+    type VerificationValidity = ConstU64<200>;
+    type UnverifiedLifetime = NeverExpires;
+    type ReapReward = ReapReward;
+    type MaxCleanupPerBlock = ConstU32<2>;
+    type OnArtistLifecycle = ();
+    type MaxLifecycleHookWeight = MaxLifecycleHookWeight;
+    type LifecycleHookFailure = LifecycleHookFailure;
+    type LabelOrigin = EnsureSignedBy<Label, u64>;
+    type Proxies = ();
+    type RemoteRegistrationOrigin = frame_support::traits::NeverEnsureOrigin<(u64, OriginChain)>;
+    type AbuseFilter = ();
+    type ContractNotifier = ();
+    type MaxContractNotificationWeight = MaxContractNotificationWeight;
+    type ClaimWindow = ConstU64<100>;
+    type ClaimSignature = TestSignature;
+    type ClaimAuthority = ClaimAuthority;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MockClaimAuthority;
     type WeightInfo = ();
 }
 
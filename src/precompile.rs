@@ -0,0 +1,181 @@
+// This file is part of Allfeat.
+
+// Copyright (C) Allfeat (FR) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Artists precompile support.
+//!
+//! With the `precompile` feature, the functions of this module return the Solidity ABI encoded
+//! outputs of the artists queries, so a runtime precompile only has to map the caller address to
+//! its account and forward the call, e.g:
+//!
+//! ```ignore
+//! impl<R: pallet_artists::Config> Precompile for ArtistsPrecompile<R> {
+//!     fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+//!         // The 4 bytes selectors of the signatures below and the account of the address
+//!         let (selector, who) = parse_input(handle)?;
+//!         let output = match selector {
+//!             // isArtist(address) returns (bool)
+//!             IS_ARTIST => pallet_artists::precompile::is_artist::<R, ()>(&who),
+//!             // isVerified(address) returns (bool)
+//!             IS_VERIFIED => pallet_artists::precompile::is_verified::<R, ()>(&who),
+//!             // mainName(address) returns (bytes)
+//!             MAIN_NAME => pallet_artists::precompile::main_name::<R, ()>(&who),
+//!             // genreIds(address) returns (uint32, uint32[])
+//!             GENRE_IDS => pallet_artists::precompile::genre_ids::<R, ()>(&who),
+//!             // artistSummary(address) returns (bool, bool, bytes, uint32, uint32[])
+//!             ARTIST_SUMMARY => pallet_artists::precompile::artist_summary::<R, ()>(&who),
+//!             _ => return Err(revert("unknown selector")),
+//!         };
+//!         Ok(PrecompileOutput { exit_status: ExitSucceed::Returned, output })
+//!     }
+//! }
+//! ```
+//!
+//! The encodings only ever get new queries, the outputs of the existing ones never change.
+//! Genres are identified by their [`genre_id`], `0` standing for no genre.
+
+use crate::types::{AccountIdOf, NameLike, StoredGenre};
+use crate::{ArtistOf, Config};
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::Vec;
+
+/// The size of a Solidity ABI word.
+pub const WORD_LEN: usize = 32;
+
+/// A Solidity value returned by the queries.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum AbiValue {
+    Bool(bool),
+    Uint32(u32),
+    Bytes(Vec<u8>),
+    Uint32Array(Vec<u32>),
+}
+
+/// The summary of an artist as returned to the contracts, empty if the account isn't an artist.
+#[derive(Clone, PartialEq, Eq, Default, RuntimeDebug)]
+pub struct ArtistSummary {
+    pub is_artist: bool,
+    pub is_verified: bool,
+    /// The raw bytes of the main name.
+    pub main_name: Vec<u8>,
+    /// The [`genre_id`] of the headline genre, `0` if none.
+    pub main_genre_id: u32,
+    /// The [`genre_id`] of every secondary genre.
+    pub genre_ids: Vec<u32>,
+}
+
+impl ArtistSummary {
+    /// The summary as the `(bool, bool, bytes, uint32, uint32[])` return values.
+    pub fn abi_encode(&self) -> Vec<u8> {
+        encode(&[
+            AbiValue::Bool(self.is_artist),
+            AbiValue::Bool(self.is_verified),
+            AbiValue::Bytes(self.main_name.clone()),
+            AbiValue::Uint32(self.main_genre_id),
+            AbiValue::Uint32Array(self.genre_ids.clone()),
+        ])
+    }
+}
+
+/// The stable identifier of a genre for the contracts: its registry tag plus one in the upper
+/// 16 bits and its subtag plus one, if any, in the lower 16 bits, so no genre gets the id `0`.
+pub fn genre_id(genre: &StoredGenre) -> u32 {
+    let tag = u32::from(genre.tag) + 1;
+    let subtag = genre.subtag.map_or(0, |x| u32::from(x) + 1);
+
+    (tag << 16) | (subtag & 0xffff)
+}
+
+/// The passed values encoded as the return values of a Solidity function, the dynamic ones
+/// (`bytes`, `uint32[]`) being appended after the head words in order.
+pub fn encode(values: &[AbiValue]) -> Vec<u8> {
+    let head_len = values.len() * WORD_LEN;
+    let mut head = Vec::with_capacity(head_len);
+    let mut tail = Vec::new();
+
+    for value in values {
+        match value {
+            AbiValue::Bool(x) => head.extend(word(u128::from(*x))),
+            AbiValue::Uint32(x) => head.extend(word(u128::from(*x))),
+            AbiValue::Bytes(x) => {
+                head.extend(word((head_len + tail.len()) as u128));
+                tail.extend(word(x.len() as u128));
+                tail.extend(x);
+                tail.resize(tail.len() + (WORD_LEN - x.len() % WORD_LEN) % WORD_LEN, 0);
+            }
+            AbiValue::Uint32Array(x) => {
+                head.extend(word((head_len + tail.len()) as u128));
+                tail.extend(word(x.len() as u128));
+                x.iter()
+                    .for_each(|item| tail.extend(word(u128::from(*item))));
+            }
+        }
+    }
+
+    head.extend(tail);
+    head
+}
+
+/// The passed value as a big endian Solidity word.
+fn word(value: u128) -> [u8; WORD_LEN] {
+    let mut word = [0u8; WORD_LEN];
+    word[WORD_LEN - 16..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// The summary of the passed account, empty if it isn't an artist.
+pub fn summary_of<T: Config<I>, I: 'static>(who: &AccountIdOf<T>) -> ArtistSummary {
+    ArtistOf::<T, I>::get(who)
+        .map(|artist| ArtistSummary {
+            is_artist: true,
+            is_verified: artist.is_verified(),
+            main_name: artist.main_name.to_bytes(),
+            main_genre_id: artist.main_genre.as_ref().map_or(0, genre_id),
+            genre_ids: artist.genres.iter().map(genre_id).collect(),
+        })
+        .unwrap_or_default()
+}
+
+/// `isArtist(address) returns (bool)`
+pub fn is_artist<T: Config<I>, I: 'static>(who: &AccountIdOf<T>) -> Vec<u8> {
+    encode(&[AbiValue::Bool(ArtistOf::<T, I>::contains_key(who))])
+}
+
+/// `isVerified(address) returns (bool)`
+pub fn is_verified<T: Config<I>, I: 'static>(who: &AccountIdOf<T>) -> Vec<u8> {
+    let verified = ArtistOf::<T, I>::get(who).map_or(false, |artist| artist.is_verified());
+    encode(&[AbiValue::Bool(verified)])
+}
+
+/// `mainName(address) returns (bytes)`, empty if the account isn't an artist.
+pub fn main_name<T: Config<I>, I: 'static>(who: &AccountIdOf<T>) -> Vec<u8> {
+    encode(&[AbiValue::Bytes(summary_of::<T, I>(who).main_name)])
+}
+
+/// `genreIds(address) returns (uint32, uint32[])`, the headline genre followed by the secondary
+/// ones.
+pub fn genre_ids<T: Config<I>, I: 'static>(who: &AccountIdOf<T>) -> Vec<u8> {
+    let summary = summary_of::<T, I>(who);
+    encode(&[
+        AbiValue::Uint32(summary.main_genre_id),
+        AbiValue::Uint32Array(summary.genre_ids),
+    ])
+}
+
+/// `artistSummary(address) returns (bool, bool, bytes, uint32, uint32[])`
+pub fn artist_summary<T: Config<I>, I: 'static>(who: &AccountIdOf<T>) -> Vec<u8> {
+    summary_of::<T, I>(who).abi_encode()
+}
@@ -0,0 +1,93 @@
+// This file is part of Allfeat.
+
+// Copyright (C) Allfeat (FR) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Remote registration origin.
+//!
+//! With the `remote-origin` feature, [`EnsureRemoteAccount`] builds the
+//! `RemoteRegistrationOrigin` out of the XCM origin check of the runtime and its location to
+//! account conversion, deriving the [`OriginChain`] of the artist from the sending location
+//! rather than trusting anything the sender passes, e.g:
+//!
+//! ```ignore
+//! impl pallet_artists::Config for Runtime {
+//!     type RemoteRegistrationOrigin = pallet_artists::remote_origin::EnsureRemoteAccount<
+//!         pallet_xcm::EnsureXcm<Everything>,
+//!         HashedDescription<AccountId, DescribeFamily<DescribeAllTerminal>>,
+//!         AccountId,
+//!     >;
+//! }
+//! ```
+//!
+//! Only the locations of the relay chain and of its sibling parachains are accepted, the local
+//! ones signing on this very chain and the ones of other consensus systems being rejected.
+
+use crate::OriginChain;
+use frame_support::traits::EnsureOrigin;
+use sp_std::marker::PhantomData;
+use xcm::latest::{Junction, Location};
+use xcm_executor::traits::ConvertLocation;
+
+/// The chain the passed location belongs to, seen from a parachain: the sibling parachain its
+/// first junction names, else the relay chain. `None` for the local locations and the ones of
+/// other consensus systems.
+pub fn origin_chain_of(location: &Location) -> Option<OriginChain> {
+    if location.parent_count() != 1 {
+        return None;
+    }
+
+    match location.first_interior() {
+        Some(Junction::Parachain(id)) => Some(OriginChain::Sibling(*id)),
+        Some(Junction::GlobalConsensus(_)) => None,
+        _ => Some(OriginChain::Relay),
+    }
+}
+
+/// Ensures the `Origin` location check passes, returning the account `LocationToAccountId`
+/// derives from the location along with the chain it belongs to.
+///
+/// The benchmarks take the successful origin of `Origin`, which must be one of a sibling
+/// parachain or of the relay chain.
+pub struct EnsureRemoteAccount<Origin, LocationToAccountId, AccountId>(
+    PhantomData<(Origin, LocationToAccountId, AccountId)>,
+);
+
+impl<RuntimeOrigin, Origin, LocationToAccountId, AccountId> EnsureOrigin<RuntimeOrigin>
+    for EnsureRemoteAccount<Origin, LocationToAccountId, AccountId>
+where
+    RuntimeOrigin: Clone,
+    Origin: EnsureOrigin<RuntimeOrigin, Success = Location>,
+    LocationToAccountId: ConvertLocation<AccountId>,
+{
+    type Success = (AccountId, OriginChain);
+
+    fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+        let location = Origin::try_origin(o.clone())?;
+
+        match (
+            LocationToAccountId::convert_location(&location),
+            origin_chain_of(&location),
+        ) {
+            (Some(who), Some(origin_chain)) => Ok((who, origin_chain)),
+            _ => Err(o),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        Origin::try_successful_origin()
+    }
+}
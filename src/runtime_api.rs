@@ -0,0 +1,304 @@
+// This file is part of Allfeat.
+
+// Copyright (C) Allfeat (FR) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Artists runtime API.
+//!
+//! The runtime implements it by forwarding to the [`Pallet`](crate::Pallet) helpers, e.g:
+//!
+//! ```ignore
+//! impl pallet_artists::runtime_api::ArtistsApi<Block, AccountId, Artist<Runtime>> for Runtime {
+//!     fn artist_by_id(id: AccountId) -> Option<Artist<Runtime>> {
+//!         Artists::get_artist_by_id(id)
+//!     }
+//!     fn artist_by_name(name: Vec<u8>) -> Option<Artist<Runtime>> {
+//!         Artists::get_artist_by_name(name)
+//!     }
+//!     fn artists_by_genre(genre: MusicGenre) -> Vec<Artist<Runtime>> {
+//!         Artists::get_artists_by_genre(genre)
+//!     }
+//!     fn artist_contracts(id: AccountId, start: u32, count: u32) -> Vec<AccountId> {
+//!         Artists::get_artist_contracts(&id, start, count)
+//!     }
+//!     fn artists_count() -> u32 {
+//!         Artists::artists_count()
+//!     }
+//!     fn artists_page(start_after: Option<AccountId>, count: u32) -> Vec<Artist<Runtime>> {
+//!         Artists::get_artists_page(start_after.as_ref(), count)
+//!     }
+//!     fn artist_by_alias(alias: Vec<u8>) -> Option<Artist<Runtime>> {
+//!         Artists::get_artist_by_alias(alias)
+//!     }
+//!     fn simulate_register(
+//!         id: AccountId,
+//!         main_name: Vec<u8>,
+//!         alias: Option<Vec<u8>>,
+//!         genres: Vec<MusicGenre>,
+//!         description: Option<Vec<u8>>,
+//!         assets: Vec<(AssetType, Vec<u8>)>,
+//!     ) -> Result<Artist<Runtime>, DispatchError> {
+//!         Artists::simulate_register(id, main_name, alias, genres, description, assets)
+//!     }
+//!     fn normalize_name(raw: Vec<u8>) -> Result<Vec<u8>, DispatchError> {
+//!         Artists::normalized_name(raw)
+//!     }
+//!     fn can_unregister(id: AccountId) -> Result<(), UnregisterBlocker> {
+//!         Artists::can_unregister(&id)
+//!     }
+//!     fn artist_genres(id: AccountId) -> Option<(Option<MusicGenre>, Vec<MusicGenre>)> {
+//!         Artists::get_artist_genres(&id)
+//!     }
+//!     fn artists_by_name_prefix(
+//!         prefix: Vec<u8>,
+//!         start_after: Option<AccountId>,
+//!         count: u32,
+//!     ) -> Result<(Vec<Artist<Runtime>>, Option<AccountId>), DispatchError> {
+//!         Artists::get_artists_by_name_prefix(prefix, start_after.as_ref(), count)
+//!     }
+//! }
+//! ```
+//!
+//! The artist views, along with the breakdown of their deposit, are served by the separate
+//! [`ArtistViewApi`]:
+//!
+//! ```ignore
+//! impl pallet_artists::runtime_api::ArtistViewApi<Block, AccountId, ArtistView<Runtime>>
+//!     for Runtime
+//! {
+//!     fn artist_view(id: AccountId) -> Option<ArtistView<Runtime>> {
+//!         Artists::get_artist_view(&id)
+//!     }
+//! }
+//! ```
+//!
+//! Auditors compare the deposits tracked by the artists with the actual holds through the
+//! separate [`DepositsAuditApi`]:
+//!
+//! ```ignore
+//! impl pallet_artists::runtime_api::DepositsAuditApi<Block, AccountId, Balance> for Runtime {
+//!     fn total_deposits_held() -> (Balance, Balance) {
+//!         Artists::total_deposits_held()
+//!     }
+//!     fn artists_with_shortfall() -> Vec<(AccountId, Balance, Balance)> {
+//!         Artists::artists_with_shortfall()
+//!     }
+//! }
+//! ```
+//!
+//! SDKs negotiate the format of the artist records through the separate [`ArtistRecordsApi`],
+//! asking for the highest version they support up to the latest one:
+//!
+//! ```ignore
+//! impl pallet_artists::runtime_api::ArtistRecordsApi<
+//!     Block,
+//!     AccountId,
+//!     VersionedArtistRecord<AccountId, BlockNumber, Hash, Balance>,
+//! > for Runtime
+//! {
+//!     fn latest_version() -> u32 {
+//!         Artists::latest_record_version()
+//!     }
+//!     fn artist_record(
+//!         id: AccountId,
+//!         version: u32,
+//!     ) -> Option<VersionedArtistRecord<AccountId, BlockNumber, Hash, Balance>> {
+//!         Artists::get_artist_record(&id, version)
+//!     }
+//! }
+//! ```
+//!
+//! Wallets estimate the deposits of a registration or an update before its submission through
+//! the separate [`DepositEstimationApi`], `UpdatableData` being the pallet one:
+//!
+//! ```ignore
+//! impl pallet_artists::runtime_api::DepositEstimationApi<
+//!     Block,
+//!     AccountId,
+//!     Balance,
+//!     UpdatableData<ArtistName, Hash>,
+//! > for Runtime
+//! {
+//!     fn registration_deposit(
+//!         name_len: u32,
+//!         alias_len: Option<u32>,
+//!         assets: u32,
+//!         description_len: u32,
+//!     ) -> Result<Balance, DispatchError> {
+//!         Artists::registration_deposit(name_len, alias_len, assets, description_len)
+//!     }
+//!     fn update_deposit_delta(
+//!         id: AccountId,
+//!         data: UpdatableData<ArtistName, Hash>,
+//!     ) -> Result<(Balance, Balance), DispatchError> {
+//!         Artists::update_deposit_delta(id, data)
+//!     }
+//! }
+//! ```
+
+use crate::{AssetType, OriginChain, UnregisterBlocker};
+use codec::Codec;
+use genres_registry::MusicGenre;
+use sp_runtime::DispatchError;
+use sp_std::prelude::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// The API to query the artists registry.
+    #[api_version(10)]
+    pub trait ArtistsApi<AccountId, Artist>
+    where
+        AccountId: Codec,
+        Artist: Codec,
+    {
+        /// Return the artist registered with the passed account ID, if any.
+        fn artist_by_id(id: AccountId) -> Option<Artist>;
+
+        /// Return the verified artist registered with the passed main name, if any.
+        fn artist_by_name(name: Vec<u8>) -> Option<Artist>;
+
+        /// Return every registered artist having the passed genre.
+        fn artists_by_genre(genre: MusicGenre) -> Vec<Artist>;
+
+        /// Return up to `count` of the contracts linked to the passed artist, starting from the
+        /// `start` one.
+        #[api_version(2)]
+        fn artist_contracts(id: AccountId, start: u32, count: u32) -> Vec<AccountId>;
+
+        /// Return the amount of registered artists.
+        #[api_version(3)]
+        fn artists_count() -> u32;
+
+        /// Return up to `count` registered artists, starting after the `start_after` one or from
+        /// the first one, always in the same order.
+        #[api_version(3)]
+        fn artists_page(start_after: Option<AccountId>, count: u32) -> Vec<Artist>;
+
+        /// Return the artist registered with the passed alias, if any.
+        #[api_version(4)]
+        fn artist_by_alias(alias: Vec<u8>) -> Option<Artist>;
+
+        /// Return the artist as it would be stored by registering it from the passed account,
+        /// with its fingerprints and deposit, or the error its registration would fail with.
+        /// Nothing is kept in the storage.
+        #[api_version(5)]
+        fn simulate_register(
+            id: AccountId,
+            main_name: Vec<u8>,
+            alias: Option<Vec<u8>>,
+            genres: Vec<MusicGenre>,
+            description: Option<Vec<u8>>,
+            assets: Vec<(AssetType, Vec<u8>)>,
+        ) -> Result<Artist, DispatchError>;
+
+        /// Return the canonical bytes under which the passed raw name would be registered and
+        /// indexed, or the error of the invalid names.
+        #[api_version(6)]
+        fn normalize_name(raw: Vec<u8>) -> Result<Vec<u8>, DispatchError>;
+
+        /// Return if the passed account can confirm its unregistration, or what blocks it, such
+        /// as the blocks left before its unregister period elapses.
+        #[api_version(7)]
+        fn can_unregister(id: AccountId) -> Result<(), UnregisterBlocker>;
+
+        /// Return the headline genre of the passed artist along with its secondary genres, if
+        /// registered.
+        #[api_version(8)]
+        fn artist_genres(id: AccountId) -> Option<(Option<MusicGenre>, Vec<MusicGenre>)>;
+
+        /// Return the registered artists whose main name starts with the passed prefix, compared
+        /// by their confusable keys (e.g: regardless of their case), among the `count` next ones
+        /// after the `start_after` one or from the first one, along with the last one examined to
+        /// resume from, or the error of a prefix shorter than the indexed ones.
+        #[api_version(9)]
+        fn artists_by_name_prefix(
+            prefix: Vec<u8>,
+            start_after: Option<AccountId>,
+            count: u32,
+        ) -> Result<(Vec<Artist>, Option<AccountId>), DispatchError>;
+
+        /// Return the chain the passed artist registered from with `register_remote`, if
+        /// registered remotely.
+        #[api_version(10)]
+        fn artist_origin_chain(id: AccountId) -> Option<OriginChain>;
+    }
+
+    /// The API to query the artists along with the breakdown of their deposit, so artists know
+    /// what trimming their profile would give back.
+    pub trait ArtistViewApi<AccountId, ArtistView>
+    where
+        AccountId: Codec,
+        ArtistView: Codec,
+    {
+        /// Return the view of the artist registered with the passed account ID, if any.
+        fn artist_view(id: AccountId) -> Option<ArtistView>;
+    }
+
+    /// The API to query the artists as client-facing records, whose layout is frozen per
+    /// version so older clients keep working across the pallet schema evolutions.
+    pub trait ArtistRecordsApi<AccountId, Record>
+    where
+        AccountId: Codec,
+        Record: Codec,
+    {
+        /// Return the latest version of the records, every version up to it being served.
+        fn latest_version() -> u32;
+
+        /// Return the artist registered with the passed account ID as a record of the passed
+        /// version, if any and if this version is served.
+        fn artist_record(id: AccountId, version: u32) -> Option<Record>;
+    }
+
+    /// The API to audit the deposits of the registry, detecting the accounting drifts introduced
+    /// by bugs or slashes.
+    pub trait DepositsAuditApi<AccountId, Balance>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        /// Return the sum of the deposits tracked by the registered artists, along with the sum
+        /// actually held from their depositors.
+        fn total_deposits_held() -> (Balance, Balance);
+
+        /// Return the depositors holding less than the deposits tracked by their artists, with
+        /// the tracked and the held amounts.
+        fn artists_with_shortfall() -> Vec<(AccountId, Balance, Balance)>;
+    }
+
+    /// The API to estimate the deposits of the registrations and updates before their
+    /// submission.
+    pub trait DepositEstimationApi<AccountId, Balance, UpdatableData>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        UpdatableData: Codec,
+    {
+        /// Return the deposit held for registering an artist with a main name and an optional
+        /// alias of the passed lengths, the passed amount of assets and a description of the
+        /// passed length, zero for none.
+        fn registration_deposit(
+            name_len: u32,
+            alias_len: Option<u32>,
+            assets: u32,
+            description_len: u32,
+        ) -> Result<Balance, DispatchError>;
+
+        /// Return the amounts the passed update of the passed artist would hold and release, or
+        /// the error it would fail with.
+        fn update_deposit_delta(
+            id: AccountId,
+            data: UpdatableData,
+        ) -> Result<(Balance, Balance), DispatchError>;
+    }
+}
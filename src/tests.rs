@@ -21,12 +21,17 @@
 
 use super::*;
 use crate::mock::*;
-use crate::types::{ArtistAliasOf, UpdatableData};
+use crate::types::{
+    ArtistAliasOf, AssetType, UpdatableAssets, UpdatableDataOf, UpdatableGenres, UpdatableLinks,
+};
 use crate::Error as ArtistsError;
 use codec::{Encode, MaxEncodedLen};
-use frame_support::pallet_prelude::Get;
+use frame_support::pallet_prelude::{ConstU32, Get};
 use frame_support::{assert_noop, assert_ok};
-use genres_registry::ElectronicSubtype;
+use genres_registry::{ClassicalSubtype, ElectronicSubtype};
+use sp_runtime::testing::H256;
+use sp_runtime::traits::Hash;
+use sp_runtime::BuildStorage;
 use sp_runtime::DispatchError::BadOrigin;
 use sp_runtime::Saturating;
 use sp_std::prelude::Vec;
@@ -35,8 +40,8 @@ struct ArtistMock<T: Config> {
     pub main_name: BoundedVec<u8, <T as Config>::MaxNameLen>,
     pub alias: Option<BoundedVec<u8, <T as Config>::MaxNameLen>>,
     pub genres: BoundedVec<MusicGenre, T::MaxGenres>,
-    pub description: Option<Vec<u8>>,
-    pub assets: BoundedVec<Vec<u8>, T::MaxAssets>,
+    pub description: Option<BoundedVec<u8, <T as Config>::MaxHashedDescriptionLen>>,
+    pub assets: BoundedVec<(AssetType, Vec<u8>), T::MaxAssets>,
 }
 
 fn to_bounded_alias(str: String) -> ArtistAliasOf<Test> {
@@ -51,11 +56,25 @@ fn tester_artist<T: Config>() -> ArtistMock<T> {
         main_name: b"Tester".to_vec().try_into().unwrap(),
         alias: Some(b"Dark Singer".to_vec().try_into().unwrap()),
         genres: genres.try_into().unwrap(),
-        description: Some(b"A simple tester artist.".to_vec()),
+        description: Some(b"A simple tester artist.".to_vec().try_into().unwrap()),
         assets: Default::default(),
     }
 }
 
+/// The tester artist with an alias of the same length unique to the passed account, for tests
+/// registering several artists.
+fn tester_artist_of<T: Config>(id: u64) -> ArtistMock<T> {
+    let mut artist = tester_artist::<T>();
+    artist.alias = Some(
+        format!("Dark Singer {}", id)
+            .as_bytes()
+            .to_vec()
+            .try_into()
+            .unwrap(),
+    );
+    artist
+}
+
 fn expected_artist_cost<T: Config>(artist: &ArtistMock<T>) -> BalanceOf<T> {
     let hash_size = T::Hash::max_encoded_len();
 
@@ -137,19 +156,169 @@ fn artist_force_unregister_works() {
             artist.assets.clone(),
         ));
 
-        // Can't force unregister if not Root origin
+        // Can't force unregister if not Admin origin
         assert_noop!(
-            Artists::force_unregister(RuntimeOrigin::signed(artist_id), artist_id),
+            Artists::force_unregister(
+                RuntimeOrigin::signed(artist_id),
+                artist_id,
+                DepositPolicy::Slash
+            ),
             BadOrigin
         );
 
-        assert_ok!(Artists::force_unregister(RuntimeOrigin::root(), artist_id,));
+        assert_ok!(Artists::force_unregister(
+            RuntimeOrigin::root(),
+            artist_id,
+            DepositPolicy::Slash
+        ));
 
-        // Deposit has been returned
+        // Deposit has been slashed
         let new_balance = Balances::free_balance(&artist_id);
         let expected_cost = expected_artist_cost(&artist);
 
         assert_eq!(new_balance, old_balance - expected_cost);
+        assert_eq!(Balances::total_balance_on_hold(&artist_id), 0);
+
+        // Can't force unregister an unregistered artist
+        assert_noop!(
+            Artists::force_unregister(RuntimeOrigin::root(), artist_id, DepositPolicy::Slash),
+            Error::<Test>::NotRegistered
+        );
+    })
+}
+
+#[test]
+fn artist_force_unregister_refund_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        let old_balance = Balances::free_balance(&artist_id);
+
+        System::set_block_number(1);
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert_ok!(Artists::verify_artist(
+            RuntimeOrigin::root(),
+            artist_id,
+            None
+        ));
+
+        // Verification and the unregister period are bypassed
+        assert_ok!(Artists::force_unregister(
+            RuntimeOrigin::root(),
+            artist_id,
+            DepositPolicy::Refund
+        ));
+        System::assert_last_event(
+            Event::<Test>::ArtistForceUnregistered {
+                id: artist_id,
+                policy: DepositPolicy::Refund,
+                deposit: expected_artist_cost(&artist),
+                depositor: artist_id,
+            }
+            .into(),
+        );
+
+        // Deposit has been returned and the name is released
+        assert_eq!(Balances::free_balance(&artist_id), old_balance);
+        assert!(Artists::get_artist_by_id(artist_id).is_none());
+        assert!(Artists::get_artist_by_name(artist.main_name.to_vec()).is_none());
+    })
+}
+
+#[test]
+fn artist_force_unregister_slash_part_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        let old_balance = Balances::free_balance(&artist_id);
+
+        System::set_block_number(1);
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        let policy = DepositPolicy::SlashPart(Perbill::from_percent(50));
+        assert_ok!(Artists::force_unregister(
+            RuntimeOrigin::root(),
+            artist_id,
+            policy
+        ));
+
+        // Half of the deposit has been slashed, the other half returned
+        let slashed = Perbill::from_percent(50).mul_floor(expected_artist_cost(&artist));
+        System::assert_last_event(
+            Event::<Test>::ArtistForceUnregistered {
+                id: artist_id,
+                policy,
+                deposit: slashed,
+                depositor: artist_id,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::free_balance(&artist_id), old_balance - slashed);
+        assert_eq!(Balances::total_balance_on_hold(&artist_id), 0);
+    })
+}
+
+#[test]
+fn artist_force_update_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // Can't force update if not Admin origin
+        assert_noop!(
+            Artists::force_update(
+                RuntimeOrigin::signed(2),
+                artist_id,
+                UpdatableDataOf::<Test>::Alias(None),
+            ),
+            BadOrigin
+        );
+
+        assert_ok!(Artists::force_update(
+            RuntimeOrigin::root(),
+            artist_id,
+            UpdatableDataOf::<Test>::Alias(None),
+        ));
+        assert!(Artists::get_artist_by_id(artist_id)
+            .unwrap()
+            .aliases()
+            .is_empty());
+
+        assert_noop!(
+            Artists::force_update(
+                RuntimeOrigin::root(),
+                2,
+                UpdatableDataOf::<Test>::Alias(None),
+            ),
+            Error::<Test>::NotRegistered
+        );
     })
 }
 
@@ -161,9 +330,13 @@ fn artist_unregister_works() {
 
         // Can't unregister if not registered
         assert_noop!(
-            Artists::unregister(RuntimeOrigin::signed(artist_id)),
+            Artists::request_unregister(RuntimeOrigin::signed(artist_id)),
             Error::<Test>::NotRegistered
         );
+        assert_eq!(
+            Artists::can_unregister(&artist_id),
+            Err(UnregisterBlocker::NotRegistered)
+        );
 
         assert_ok!(Artists::register(
             RuntimeOrigin::signed(artist_id),
@@ -174,24 +347,76 @@ fn artist_unregister_works() {
             artist.assets.clone(),
         ));
 
+        let unregister_cd: u32 = <Test as Config>::UnregisterPeriod::get();
+
+        // Can't unregister without requesting it first, even long after the registration
+        frame_system::Pallet::<Test>::set_block_number(unregister_cd.saturated_into());
+        assert_noop!(
+            Artists::confirm_unregister(RuntimeOrigin::signed(artist_id)),
+            Error::<Test>::UnregisterNotRequested
+        );
+        assert_eq!(
+            Artists::can_unregister(&artist_id),
+            Err(UnregisterBlocker::NotRequested)
+        );
+
+        assert_ok!(Artists::request_unregister(RuntimeOrigin::signed(
+            artist_id
+        )));
+        assert_noop!(
+            Artists::request_unregister(RuntimeOrigin::signed(artist_id)),
+            Error::<Test>::UnregisterAlreadyRequested
+        );
+
         // Can't unregister if not waited the unregister period
         assert_noop!(
-            Artists::unregister(RuntimeOrigin::signed(artist_id)),
+            Artists::confirm_unregister(RuntimeOrigin::signed(artist_id)),
             Error::<Test>::PeriodNotPassed
         );
+        assert_eq!(
+            Artists::can_unregister(&artist_id),
+            Err(UnregisterBlocker::PeriodRemaining(unregister_cd))
+        );
 
-        let unregister_cd: u32 = <Test as Config>::UnregisterPeriod::get();
-        frame_system::Pallet::<Test>::set_block_number(unregister_cd.saturated_into());
+        // A cancelled request has to be made again
+        assert_ok!(Artists::cancel_unregister(RuntimeOrigin::signed(artist_id)));
+        frame_system::Pallet::<Test>::set_block_number((2 * unregister_cd).saturated_into());
+        assert_noop!(
+            Artists::confirm_unregister(RuntimeOrigin::signed(artist_id)),
+            Error::<Test>::UnregisterNotRequested
+        );
+
+        assert_ok!(Artists::request_unregister(RuntimeOrigin::signed(
+            artist_id
+        )));
+        frame_system::Pallet::<Test>::set_block_number((3 * unregister_cd - 3).saturated_into());
+        assert_eq!(
+            Artists::can_unregister(&artist_id),
+            Err(UnregisterBlocker::PeriodRemaining(3))
+        );
+        frame_system::Pallet::<Test>::set_block_number((3 * unregister_cd).saturated_into());
+        assert_eq!(Artists::can_unregister(&artist_id), Ok(()));
 
         let old_balance = Balances::free_balance(&artist_id);
 
-        assert_ok!(Artists::unregister(RuntimeOrigin::signed(artist_id)));
+        assert_ok!(Artists::confirm_unregister(RuntimeOrigin::signed(
+            artist_id
+        )));
+        assert!(Artists::get_unregister_request(artist_id).is_none());
 
         // Deposit has been returned
         let new_balance = Balances::free_balance(&artist_id);
         let expected_cost = expected_artist_cost(&artist);
 
         assert_eq!(new_balance, old_balance + expected_cost);
+        System::assert_last_event(
+            Event::<Test>::ArtistUnregistered {
+                id: artist_id,
+                deposit: expected_cost,
+                depositor: artist_id,
+            }
+            .into(),
+        );
     })
 }
 
@@ -214,21 +439,7211 @@ fn artist_update_alias_works() {
 
         assert_ok!(Artists::update(
             RuntimeOrigin::signed(artist_id),
-            UpdatableData::<ArtistAliasOf<Test>>::Alias(Some(new_alias)),
+            UpdatableDataOf::<Test>::Alias(Some(new_alias)),
         ));
 
         // Can't update if the caller is not a registered artist
         assert_noop!(
             Artists::update(
                 RuntimeOrigin::signed(2),
-                UpdatableData::<ArtistAliasOf<Test>>::Alias(None),
+                UpdatableDataOf::<Test>::Alias(None),
+            ),
+            Error::<Test>::NotRegistered
+        );
+
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Alias(None),
+        ));
+    })
+}
+
+#[test]
+fn update_nonce_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        let registered = Artists::get_artist_by_id(artist_id).unwrap();
+        assert_eq!(registered.update_nonce, 0);
+        assert_eq!(registered.last_updated_at, registered.registered_at);
+
+        System::set_block_number(3);
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Genres(UpdatableGenres::Clear),
+        ));
+        let updated = Artists::get_artist_by_id(artist_id).unwrap();
+        assert_eq!(updated.update_nonce, 1);
+        assert_eq!(updated.last_updated_at, 3);
+
+        // Can't update from a stale nonce
+        assert_noop!(
+            Artists::update_v2(
+                RuntimeOrigin::signed(artist_id),
+                UpdatableDataOf::<Test>::Alias(None),
+                Some(0),
+            ),
+            Error::<Test>::StaleUpdate
+        );
+
+        System::set_block_number(4);
+        assert_ok!(Artists::update_v2(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Alias(None),
+            Some(1),
+        ));
+        assert_ok!(Artists::update_v2(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::OpenTo(Default::default()),
+            None,
+        ));
+
+        // A failed update doesn't bump the nonce
+        assert_noop!(
+            Artists::update(
+                RuntimeOrigin::signed(artist_id),
+                UpdatableDataOf::<Test>::Genres(UpdatableGenres::RemoveAt(5)),
             ),
+            Error::<Test>::IndexOutOfBounds
+        );
+
+        // The delegated and forced updates are tracked as well
+        assert_ok!(Artists::force_update(
+            RuntimeOrigin::root(),
+            artist_id,
+            UpdatableDataOf::<Test>::Alias(None),
+        ));
+        let updated = Artists::get_artist_by_id(artist_id).unwrap();
+        assert_eq!(updated.update_nonce, 4);
+        assert_eq!(updated.last_updated_at, 4);
+    })
+}
+
+#[test]
+fn update_many_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let alias = to_bounded_alias("Dark Singer".into());
+        let house = MusicGenre::Electronic(Some(ElectronicSubtype::House));
+
+        System::set_block_number(1);
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            None,
+            Default::default(),
+            None,
+            Default::default(),
+        ));
+
+        assert_noop!(
+            Artists::update_many(RuntimeOrigin::signed(artist_id), Default::default()),
+            ArtistsError::<Test>::NoUpdates
+        );
+
+        // Nothing is kept if any update fails
+        let failing: BoundedVec<_, _> = vec![
+            UpdatableDataOf::<Test>::Alias(Some(alias.clone())),
+            UpdatableDataOf::<Test>::Genres(UpdatableGenres::RemoveAt(0)),
+        ]
+        .try_into()
+        .unwrap();
+        assert_noop!(
+            Artists::update_many(RuntimeOrigin::signed(artist_id), failing),
+            ArtistsError::<Test>::IndexOutOfBounds
+        );
+
+        let updates: BoundedVec<_, _> = vec![
+            UpdatableDataOf::<Test>::Alias(Some(alias.clone())),
+            UpdatableDataOf::<Test>::Genres(UpdatableGenres::Add(house)),
+            UpdatableDataOf::<Test>::Description(artist.description.clone().map(|d| d.to_vec())),
+        ]
+        .try_into()
+        .unwrap();
+        assert_ok!(Artists::update_many(
+            RuntimeOrigin::signed(artist_id),
+            updates.clone()
+        ));
+        System::assert_last_event(
+            Event::<Test>::ArtistBatchUpdated {
+                id: artist_id,
+                updates,
+            }
+            .into(),
+        );
+
+        // The batch amounts to the single updates
+        let updated = Artists::get_artist_by_id(artist_id).unwrap();
+        assert_eq!(updated.aliases().to_vec(), vec![alias]);
+        assert_eq!(updated.genres.len(), 1);
+        assert!(updated.description.is_some());
+        assert_eq!(updated.update_nonce, 3);
+        assert_eq!(Balances::total_balance_on_hold(&artist_id), updated.deposit);
+        assert_ok!(Artists::do_try_state());
+    })
+}
+
+#[test]
+fn artist_verify_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let kyc_evidence_hash = Some(<Test as frame_system::Config>::Hashing::hash(b"kyc"));
+
+        // Can't verify an unregistered artist
+        assert_noop!(
+            Artists::verify_artist(RuntimeOrigin::root(), artist_id, kyc_evidence_hash),
             Error::<Test>::NotRegistered
         );
 
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // Can't re-verify an artist that isn't verified yet
+        assert_noop!(
+            Artists::update_kyc_evidence(RuntimeOrigin::root(), artist_id, None),
+            Error::<Test>::NotVerified
+        );
+
+        // Can't verify if not Verifier origin
+        assert_noop!(
+            Artists::verify_artist(
+                RuntimeOrigin::signed(artist_id),
+                artist_id,
+                kyc_evidence_hash
+            ),
+            BadOrigin
+        );
+
+        assert_ok!(Artists::verify_artist(
+            RuntimeOrigin::root(),
+            artist_id,
+            kyc_evidence_hash
+        ));
+
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        assert!(stored.is_verified());
+        assert_eq!(stored.kyc_evidence_hash(), &kyc_evidence_hash);
+        assert_eq!(
+            Artists::can_unregister(&artist_id),
+            Err(UnregisterBlocker::Verified)
+        );
+
+        // Can't verify a second time
+        assert_noop!(
+            Artists::verify_artist(RuntimeOrigin::root(), artist_id, None),
+            Error::<Test>::AlreadyVerified
+        );
+
+        let new_hash = Some(<Test as frame_system::Config>::Hashing::hash(b"new kyc"));
+        assert_ok!(Artists::update_kyc_evidence(
+            RuntimeOrigin::root(),
+            artist_id,
+            new_hash
+        ));
+
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        assert_eq!(stored.kyc_evidence_hash(), &new_hash);
+    })
+}
+
+#[test]
+fn artist_deposit_tracking_works() {
+    new_test_ext().execute_with(|| {
+        let mut artist = tester_artist::<Test>();
+        artist.assets = vec![
+            (AssetType::Image, b"asset0".to_vec()),
+            (AssetType::Audio, b"asset1".to_vec()),
+        ]
+        .try_into()
+        .unwrap();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        let stored_deposit = |id| *Artists::get_artist_by_id(id).unwrap().deposit();
+        let hash_cost: BalanceOf<Test> = <Test as Config>::ByteDeposit::get()
+            .saturating_mul(<Test as frame_system::Config>::Hash::max_encoded_len() as u128);
+
+        let expected_cost = expected_artist_cost(&artist);
+        assert_eq!(stored_deposit(artist_id), expected_cost);
+        assert_eq!(Balances::total_balance_on_hold(&artist_id), expected_cost);
+
+        // Assets are stored typed along their fingerprint
+        let stored_assets = Artists::get_artist_by_id(artist_id)
+            .unwrap()
+            .assets()
+            .clone();
+        assert_eq!(stored_assets[1].kind(), &AssetType::Audio);
+        assert_eq!(
+            stored_assets[1].hash(),
+            &Fingerprint::Native(<Test as frame_system::Config>::Hashing::hash(b"asset1"))
+        );
+
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Assets(UpdatableAssets::Add(
+                AssetType::Image,
+                b"asset2".to_vec()
+            )),
+        ));
+        assert_eq!(stored_deposit(artist_id), expected_cost + hash_cost);
+
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Description(None),
+        ));
+        assert_eq!(stored_deposit(artist_id), expected_cost);
+
         assert_ok!(Artists::update(
             RuntimeOrigin::signed(artist_id),
-            UpdatableData::<ArtistAliasOf<Test>>::Alias(None),
+            UpdatableDataOf::<Test>::Assets(UpdatableAssets::Clear),
+        ));
+        assert_eq!(stored_deposit(artist_id), expected_cost - 3 * hash_cost);
+        assert_eq!(
+            Balances::total_balance_on_hold(&artist_id),
+            stored_deposit(artist_id)
+        );
+
+        let unregister_cd: u32 = <Test as Config>::UnregisterPeriod::get();
+        assert_ok!(Artists::request_unregister(RuntimeOrigin::signed(
+            artist_id
+        )));
+        frame_system::Pallet::<Test>::set_block_number(unregister_cd.saturated_into());
+
+        assert_ok!(Artists::confirm_unregister(RuntimeOrigin::signed(
+            artist_id
+        )));
+
+        // Every held deposit has been returned
+        assert_eq!(Balances::total_balance_on_hold(&artist_id), 0);
+        assert_eq!(Balances::free_balance(&artist_id), 500);
+    })
+}
+
+#[test]
+fn fingerprints_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        let stored_deposit = |id| *Artists::get_artist_by_id(id).unwrap().deposit();
+        let initial_deposit = stored_deposit(artist_id);
+        let digest = [7u8; 32];
+        let mut raw_cid = vec![0x01, 0x55, 0x12, 0x20];
+        raw_cid.extend_from_slice(&digest);
+        let raw_cid: BoundedVec<u8, ConstU32<64>> = raw_cid.try_into().unwrap();
+
+        // The fingerprint deposit depends on its length
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Assets(UpdatableAssets::AddFingerprint(
+                AssetType::Audio,
+                Fingerprint::Sha256(digest)
+            )),
+        ));
+        assert_eq!(
+            stored_deposit(artist_id),
+            initial_deposit + <Test as Config>::ByteDeposit::get() * 32
+        );
+
+        // The same digest can't be declared again with another algorithm
+        assert_noop!(
+            Artists::update(
+                RuntimeOrigin::signed(artist_id),
+                UpdatableDataOf::<Test>::Assets(UpdatableAssets::AddFingerprint(
+                    AssetType::Audio,
+                    Fingerprint::IpfsCid(raw_cid.clone())
+                )),
+            ),
+            ArtistsError::<Test>::NotUniqueAsset
+        );
+
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Assets(UpdatableAssets::RemoveFingerprint(
+                Fingerprint::IpfsCid(raw_cid.clone())
+            )),
+        ));
+        assert!(Artists::get_artist_by_id(artist_id)
+            .unwrap()
+            .assets()
+            .is_empty());
+        assert_eq!(stored_deposit(artist_id), initial_deposit);
+
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Assets(UpdatableAssets::AddFingerprint(
+                AssetType::Image,
+                Fingerprint::IpfsCid(raw_cid.clone())
+            )),
+        ));
+        assert_eq!(
+            stored_deposit(artist_id),
+            initial_deposit + <Test as Config>::ByteDeposit::get() * 36
+        );
+
+        // The description can be declared by its fingerprint too
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::DescriptionFingerprint(Some(Fingerprint::Sha256(digest))),
+        ));
+        assert_eq!(
+            Artists::get_artist_by_id(artist_id).unwrap().description(),
+            &Some(Fingerprint::Sha256(digest))
+        );
+        assert_eq!(
+            Balances::total_balance_on_hold(&artist_id),
+            stored_deposit(artist_id)
+        );
+        assert_ok!(Artists::do_try_state());
+    })
+}
+
+#[test]
+fn deposit_estimation_works() {
+    new_test_ext().execute_with(|| {
+        let mut artist = tester_artist::<Test>();
+        artist.assets = vec![(AssetType::Image, b"asset0".to_vec())]
+            .try_into()
+            .unwrap();
+        let artist_id = 1u64;
+        let hash_cost: BalanceOf<Test> = <Test as Config>::ByteDeposit::get()
+            .saturating_mul(<Test as frame_system::Config>::Hash::max_encoded_len() as u128);
+
+        // The registration deposit only depends on the data lengths
+        let estimated = Artists::registration_deposit(
+            artist.main_name.len() as u32,
+            artist.alias.as_ref().map(|alias| alias.len() as u32),
+            artist.assets.len() as u32,
+            artist.description.as_ref().unwrap().len() as u32,
+        );
+        assert_eq!(estimated, Ok(expected_artist_cost(&artist)));
+        assert_eq!(
+            Artists::registration_deposit(<Test as Config>::MaxNameLen::get() + 1, None, 0, 0),
+            Err(ArtistsError::<Test>::InvalidName.into())
+        );
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        let deposit = *Artists::get_artist_by_id(artist_id).unwrap().deposit();
+        assert_eq!(estimated, Ok(deposit));
+
+        // Updates are dry-run without keeping any change
+        assert_eq!(
+            Artists::update_deposit_delta(
+                artist_id,
+                UpdatableDataOf::<Test>::Assets(UpdatableAssets::Add(
+                    AssetType::Audio,
+                    b"asset1".to_vec()
+                )),
+            ),
+            Ok((hash_cost, 0))
+        );
+        assert_eq!(
+            Artists::update_deposit_delta(artist_id, UpdatableDataOf::<Test>::Description(None)),
+            Ok((0, hash_cost))
+        );
+        assert_eq!(
+            Artists::update_deposit_delta(
+                artist_id,
+                UpdatableDataOf::<Test>::Assets(UpdatableAssets::Add(
+                    AssetType::Audio,
+                    b"asset0".to_vec()
+                )),
+            ),
+            Err(ArtistsError::<Test>::NotUniqueAsset.into())
+        );
+        assert_eq!(
+            Artists::update_deposit_delta(2, UpdatableDataOf::<Test>::Description(None)),
+            Err(ArtistsError::<Test>::NotRegistered.into())
+        );
+        assert_eq!(
+            *Artists::get_artist_by_id(artist_id).unwrap().deposit(),
+            deposit
+        );
+        assert_eq!(Balances::total_balance_on_hold(&artist_id), deposit);
+    })
+}
+
+#[test]
+fn artist_deposit_exemption_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        // Can't set an exemption if not Admin origin
+        assert_noop!(
+            Artists::set_deposit_exemption(RuntimeOrigin::signed(artist_id), artist_id, true),
+            BadOrigin
+        );
+
+        assert_ok!(Artists::set_deposit_exemption(
+            RuntimeOrigin::root(),
+            artist_id,
+            true
+        ));
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // Nothing has been held for the exempted artist
+        assert_eq!(Balances::free_balance(&artist_id), 500);
+        assert_eq!(*Artists::get_artist_by_id(artist_id).unwrap().deposit(), 0);
+
+        assert_ok!(Artists::set_deposit_exemption(
+            RuntimeOrigin::root(),
+            artist_id,
+            false
+        ));
+
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Assets(UpdatableAssets::Add(
+                AssetType::Image,
+                b"asset".to_vec()
+            )),
+        ));
+
+        // The exemption is lifted for the new data
+        let hash_cost: BalanceOf<Test> = <Test as Config>::ByteDeposit::get()
+            .saturating_mul(<Test as frame_system::Config>::Hash::max_encoded_len() as u128);
+        assert_eq!(Balances::free_balance(&artist_id), 500 - hash_cost);
+    })
+}
+
+#[test]
+fn artist_lookup_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // Only verified artists can be retrieved by their name
+        assert!(Artists::get_artist_by_name(artist.main_name.to_vec()).is_none());
+
+        assert_ok!(Artists::verify_artist(
+            RuntimeOrigin::root(),
+            artist_id,
+            None
+        ));
+
+        let by_name = Artists::get_artist_by_name(artist.main_name.to_vec()).unwrap();
+        assert_eq!(by_name.owner(), &artist_id);
+
+        let by_genre = Artists::get_artists_by_genre(artist.genres[0]);
+        assert_eq!(by_genre.len(), 1);
+        assert_eq!(by_genre[0].owner(), &artist_id);
+        assert!(Artists::get_artists_by_genre(MusicGenre::Classical(Some(
+            ClassicalSubtype::Symphony
+        )))
+        .is_empty());
+
+        // The verified name can't be registered again
+        assert_noop!(
+            Artists::register(
+                RuntimeOrigin::signed(2),
+                artist.main_name,
+                artist.alias,
+                artist.genres,
+                artist.description,
+                artist.assets,
+            ),
+            ArtistsError::<Test>::NameUnavailable
+        );
+    })
+}
+
+#[test]
+fn genesis_artists_works() {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 500), (2, 500)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    crate::GenesisConfig::<Test> {
+        artists: vec![
+            (
+                1,
+                b"Founder".to_vec(),
+                None,
+                vec![MusicGenre::Electronic(Some(ElectronicSubtype::House))],
+                None,
+                vec![],
+                true,
+            ),
+            (2, b"Label".to_vec(), None, vec![], None, vec![], false),
+        ],
+        waive_deposits: true,
+        claimable: vec![],
+        phantom: Default::default(),
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    sp_io::TestExternalities::from(t).execute_with(|| {
+        let founder = Artists::get_artist_by_id(1).unwrap();
+        assert!(founder.is_verified());
+        assert_eq!(
+            Artists::get_artist_by_name(b"Founder".to_vec())
+                .unwrap()
+                .owner(),
+            &1
+        );
+        assert!(!Artists::get_artist_by_id(2).unwrap().is_verified());
+
+        // Deposits have been waived and the accounts aren't exempted afterward
+        assert_eq!(Balances::free_balance(&1), 500);
+        assert_eq!(*founder.deposit(), 0);
+        assert!(!crate::DepositExemptions::<Test>::contains_key(1));
+    });
+}
+
+#[test]
+fn artist_freeze_contracts_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let contract = 42u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        let old_balance = Balances::free_balance(&artist_id);
+
+        assert_ok!(Artists::link_contract(&artist_id, contract));
+        assert_eq!(
+            Artists::get_artist_by_id(artist_id).unwrap().contracts(),
+            &vec![contract]
+        );
+        assert!(Balances::free_balance(&artist_id) < old_balance);
+        assert_noop!(
+            Artists::link_contract(&artist_id, contract),
+            Error::<Test>::NotUniqueContract
+        );
+
+        // Can't freeze if not Admin origin
+        assert_noop!(
+            Artists::freeze_contracts(RuntimeOrigin::signed(artist_id), artist_id),
+            BadOrigin
+        );
+        assert_noop!(
+            Artists::unfreeze_contracts(RuntimeOrigin::root(), artist_id),
+            Error::<Test>::ContractsNotFrozen
+        );
+
+        assert_ok!(Artists::freeze_contracts(RuntimeOrigin::root(), artist_id));
+        assert!(*Artists::get_artist_by_id(artist_id)
+            .unwrap()
+            .contracts_frozen());
+
+        // Contracts can't be linked nor unlinked while frozen
+        assert_noop!(
+            Artists::link_contract(&artist_id, 43),
+            Error::<Test>::ContractsFrozen
+        );
+        assert_noop!(
+            Artists::unlink_contract(&artist_id, &contract),
+            Error::<Test>::ContractsFrozen
+        );
+
+        assert_ok!(Artists::unfreeze_contracts(
+            RuntimeOrigin::root(),
+            artist_id
+        ));
+        assert_ok!(Artists::unlink_contract(&artist_id, &contract));
+        assert!(Artists::get_artist_by_id(artist_id)
+            .unwrap()
+            .contracts()
+            .is_empty());
+        assert_eq!(Balances::free_balance(&artist_id), old_balance);
+        assert_noop!(
+            Artists::unlink_contract(&artist_id, &contract),
+            Error::<Test>::ContractNotFound
+        );
+    })
+}
+
+#[test]
+fn artist_name_moderation_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let squatter = 1u64;
+        let claimant = 2u64;
+
+        // Can't reserve a name if not Admin origin
+        assert_noop!(
+            Artists::reserve_name(
+                RuntimeOrigin::signed(squatter),
+                artist.main_name.clone(),
+                None
+            ),
+            BadOrigin
+        );
+
+        assert_ok!(Artists::reserve_name(
+            RuntimeOrigin::root(),
+            artist.main_name.clone(),
+            Some(claimant)
+        ));
+
+        assert_noop!(
+            Artists::register(
+                RuntimeOrigin::signed(squatter),
+                artist.main_name.clone(),
+                artist.alias.clone(),
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            ),
+            ArtistsError::<Test>::ReservedName
+        );
+
+        // The claimant can register the reserved name, consuming the reservation
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(claimant),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert_noop!(
+            Artists::unreserve_name(RuntimeOrigin::root(), artist.main_name.clone()),
+            ArtistsError::<Test>::NameNotReserved
+        );
+
+        let banned_name: BoundedVec<u8, <Test as Config>::MaxNameLen> =
+            b"Offensive".to_vec().try_into().unwrap();
+        assert_ok!(Artists::ban_name(
+            RuntimeOrigin::root(),
+            banned_name.clone()
+        ));
+
+        assert_noop!(
+            Artists::register(
+                RuntimeOrigin::signed(squatter),
+                banned_name.clone(),
+                None,
+                Default::default(),
+                None,
+                Default::default(),
+            ),
+            ArtistsError::<Test>::BannedName
+        );
+
+        assert_ok!(Artists::unban_name(
+            RuntimeOrigin::root(),
+            banned_name.clone()
+        ));
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(squatter),
+            banned_name,
+            None,
+            Default::default(),
+            None,
+            Default::default(),
+        ));
+    })
+}
+
+#[test]
+fn artist_submit_verification_evidence_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let evidence: BoundedVec<_, <Test as Config>::MaxEvidence> = vec![
+            <Test as frame_system::Config>::Hashing::hash(b"social post"),
+            <Test as frame_system::Config>::Hashing::hash(b"signed statement"),
+        ]
+        .try_into()
+        .unwrap();
+
+        // Can't submit evidence if not registered
+        assert_noop!(
+            Artists::submit_verification_evidence(
+                RuntimeOrigin::signed(artist_id),
+                evidence.clone()
+            ),
+            Error::<Test>::NotRegistered
+        );
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        assert_ok!(Artists::submit_verification_evidence(
+            RuntimeOrigin::signed(artist_id),
+            evidence.clone()
+        ));
+        assert_eq!(
+            Artists::get_pending_verification(artist_id)
+                .unwrap()
+                .evidence(),
+            &evidence
+        );
+
+        // The verification clears the pending request
+        assert_ok!(Artists::verify_artist(
+            RuntimeOrigin::root(),
+            artist_id,
+            None
+        ));
+        assert!(Artists::get_pending_verification(artist_id).is_none());
+
+        assert_noop!(
+            Artists::submit_verification_evidence(RuntimeOrigin::signed(artist_id), evidence),
+            Error::<Test>::AlreadyVerified
+        );
+    })
+}
+
+#[test]
+fn evidence_delegate_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let agency = 2u64;
+        let evidence: BoundedVec<_, <Test as Config>::MaxEvidence> =
+            vec![<Test as frame_system::Config>::Hashing::hash(
+                b"signed statement",
+            )]
+            .try_into()
+            .unwrap();
+
+        assert_noop!(
+            Artists::set_evidence_delegate(RuntimeOrigin::signed(artist_id), Some(agency)),
+            Error::<Test>::NotRegistered
+        );
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert_ok!(Artists::set_evidence_delegate(
+            RuntimeOrigin::signed(artist_id),
+            Some(agency)
+        ));
+        System::assert_last_event(
+            Event::<Test>::EvidenceDelegateSet {
+                id: artist_id,
+                delegate: Some(agency),
+            }
+            .into(),
+        );
+
+        // Only the evidence delegate submits the evidence of the artist
+        assert_noop!(
+            Artists::submit_verification_evidence_as_delegate(
+                RuntimeOrigin::signed(3),
+                artist_id,
+                evidence.clone()
+            ),
+            Error::<Test>::NotDelegate
+        );
+        assert_ok!(Artists::submit_verification_evidence_as_delegate(
+            RuntimeOrigin::signed(agency),
+            artist_id,
+            evidence.clone()
+        ));
+        assert_eq!(
+            Artists::get_pending_verification(artist_id)
+                .unwrap()
+                .evidence(),
+            &evidence
+        );
+        assert_eq!(Balances::total_balance_on_hold(&agency), 0);
+
+        // It has no right on the profile itself
+        assert_noop!(
+            Artists::update_as_delegate(
+                RuntimeOrigin::signed(agency),
+                artist_id,
+                UpdatableDataOf::<Test>::Description(None),
+            ),
+            Error::<Test>::NotDelegate
+        );
+
+        assert_ok!(Artists::set_evidence_delegate(
+            RuntimeOrigin::signed(artist_id),
+            None
+        ));
+        assert_noop!(
+            Artists::submit_verification_evidence_as_delegate(
+                RuntimeOrigin::signed(agency),
+                artist_id,
+                evidence
+            ),
+            Error::<Test>::NotDelegate
+        );
+    })
+}
+
+#[test]
+fn artist_verification_queue_overflow_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let artist = tester_artist::<Test>();
+        let evidence: BoundedVec<_, <Test as Config>::MaxEvidence> =
+            vec![<Test as frame_system::Config>::Hashing::hash(
+                b"social post",
+            )]
+            .try_into()
+            .unwrap();
+
+        for id in 1..=3u64 {
+            assert_ok!(Artists::register(
+                RuntimeOrigin::signed(id),
+                artist.main_name.clone(),
+                tester_artist_of::<Test>(id).alias,
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            ));
+        }
+        let registered_cost = expected_artist_cost(&tester_artist_of::<Test>(1));
+        let evidence_cost: BalanceOf<Test> = <Test as Config>::ByteDeposit::get()
+            .saturating_mul(<Test as frame_system::Config>::Hash::max_encoded_len() as u128);
+
+        assert_ok!(Artists::submit_verification_evidence(
+            RuntimeOrigin::signed(1),
+            evidence.clone()
+        ));
+        assert_ok!(Artists::submit_verification_evidence(
+            RuntimeOrigin::signed(2),
+            evidence.clone()
+        ));
+        assert_eq!(
+            Balances::total_balance_on_hold(&1),
+            registered_cost + evidence_cost
+        );
+
+        // The queue is full
+        assert_noop!(
+            Artists::submit_verification_evidence(RuntimeOrigin::signed(3), evidence.clone()),
+            ArtistsError::<Test>::VerificationQueueFull
+        );
+
+        // Replacing the evidence of a queued request is still possible
+        assert_ok!(Artists::submit_verification_evidence(
+            RuntimeOrigin::signed(1),
+            evidence.clone()
+        ));
+
+        // Evict the oldest request to make room for the new one
+        VerificationQueueOverflow::set(QueueOverflowPolicy::EvictOldest);
+        assert_ok!(Artists::submit_verification_evidence(
+            RuntimeOrigin::signed(3),
+            evidence.clone()
+        ));
+        System::assert_has_event(Event::<Test>::VerificationEvicted { id: 1 }.into());
+        assert!(Artists::get_pending_verification(1).is_none());
+        assert_eq!(Balances::total_balance_on_hold(&1), registered_cost);
+        assert_eq!(
+            crate::VerificationQueue::<Test>::get().into_inner(),
+            vec![2, 3]
+        );
+
+        // A verification leaves the queue and refunds its evidence deposit
+        assert_ok!(Artists::verify_artist(RuntimeOrigin::root(), 2, None));
+        assert_eq!(Balances::total_balance_on_hold(&2), registered_cost);
+        assert_eq!(
+            crate::VerificationQueue::<Test>::get().into_inner(),
+            vec![3]
+        );
+
+        VerificationQueueOverflow::set(QueueOverflowPolicy::Reject);
+    })
+}
+
+#[test]
+fn migrate_to_v1_works() {
+    use crate::migrations::v1::{ArtistOf as V1ArtistOf, MigrateToV1, OldArtist};
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let mut artist = tester_artist::<Test>();
+        artist.assets = vec![(AssetType::Image, b"asset0".to_vec())]
+            .try_into()
+            .unwrap();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // Store the artist back with its bare assets fingerprints
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        let old_artist = OldArtist::<Test> {
+            owner: stored.owner.clone(),
+            registered_at: stored.registered_at,
+            verified_at: stored.verified_at,
+            kyc_evidence_hash: stored.kyc_evidence_hash,
+            main_name: stored.main_name.clone(),
+            alias: stored.aliases.first().cloned(),
+            genres: old_layout_genres(&stored),
+            description: old_layout_description(&stored),
+            assets: vec![native_hash(&stored.assets[0].hash)]
+                .try_into()
+                .unwrap(),
+            contracts: stored.contracts.clone(),
+            contracts_frozen: stored.contracts_frozen,
+            deposit: stored.deposit,
+        };
+        frame_support::storage::unhashed::put(
+            &crate::ArtistOf::<Test>::hashed_key_for(artist_id),
+            &old_artist,
+        );
+        StorageVersion::new(0).put::<Artists>();
+
+        MigrateToV1::<Test>::on_runtime_upgrade();
+
+        let migrated = V1ArtistOf::<Test>::get(artist_id).unwrap();
+        assert_eq!(migrated.assets[0].kind, AssetType::Other);
+        assert_eq!(migrated.assets[0].hash, native_hash(&stored.assets[0].hash));
+        assert_eq!(migrated.deposit, stored.deposit);
+        assert_eq!(Artists::on_chain_storage_version(), 1);
+
+        // The migration doesn't run twice
+        MigrateToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(
+            V1ArtistOf::<Test>::get(artist_id).unwrap().encode(),
+            migrated.encode()
+        );
+    })
+}
+
+#[test]
+fn migrate_to_v2_works() {
+    use crate::migrations::v2::{ArtistOf as V2ArtistOf, MigrateToV2, OldArtist};
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // Store the artist back without its availability
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        let old_artist = OldArtist::<Test> {
+            owner: stored.owner.clone(),
+            registered_at: stored.registered_at,
+            verified_at: stored.verified_at,
+            kyc_evidence_hash: stored.kyc_evidence_hash,
+            main_name: stored.main_name.clone(),
+            alias: stored.aliases.first().cloned(),
+            genres: old_layout_genres(&stored),
+            description: old_layout_description(&stored),
+            assets: old_layout_assets(&stored),
+            contracts: stored.contracts.clone(),
+            contracts_frozen: stored.contracts_frozen,
+            deposit: stored.deposit,
+        };
+        frame_support::storage::unhashed::put(
+            &crate::ArtistOf::<Test>::hashed_key_for(artist_id),
+            &old_artist,
+        );
+        StorageVersion::new(1).put::<Artists>();
+
+        MigrateToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            V2ArtistOf::<Test>::get(artist_id).unwrap().encode(),
+            v3_layout_artist(&stored).encode()
+        );
+        assert_eq!(Artists::on_chain_storage_version(), 2);
+    })
+}
+
+/// The passed artist on the storage layout of the versions 2 and 3.
+fn v3_layout_artist(artist: &Artist<Test>) -> crate::migrations::v4::OldArtist<Test> {
+    crate::migrations::v4::OldArtist::<Test> {
+        owner: artist.owner,
+        registered_at: artist.registered_at,
+        verified_at: artist.verified_at,
+        kyc_evidence_hash: artist.kyc_evidence_hash,
+        main_name: artist.main_name.clone(),
+        alias: artist.aliases.first().cloned(),
+        genres: old_layout_genres(artist),
+        open_to: artist.open_to,
+        description: old_layout_description(artist),
+        assets: old_layout_assets(artist),
+        contracts: artist.contracts.clone(),
+        contracts_frozen: artist.contracts_frozen,
+        delegates: artist.delegates.clone(),
+        deposit: artist.deposit,
+    }
+}
+
+/// The passed artist on the storage layout of the versions 4 to 6.
+fn v6_layout_artist(artist: &Artist<Test>) -> crate::migrations::v7::OldArtist<Test> {
+    crate::migrations::v7::OldArtist::<Test> {
+        owner: artist.owner,
+        registered_at: artist.registered_at,
+        verified_at: artist.verified_at,
+        kyc_evidence_hash: artist.kyc_evidence_hash,
+        main_name: artist.main_name.clone(),
+        alias: artist.aliases.first().cloned(),
+        genres: old_layout_genres(artist),
+        open_to: artist.open_to,
+        description: old_layout_description(artist),
+        contact_hash: artist.contact_hash,
+        links: artist.links.clone(),
+        assets: old_layout_assets(artist),
+        contracts: artist.contracts.clone(),
+        contracts_frozen: artist.contracts_frozen,
+        delegates: artist.delegates.clone(),
+        deposit: artist.deposit,
+    }
+}
+
+/// The bare hash of a native fingerprint, as stored before the version 12.
+fn native_hash(fingerprint: &Fingerprint<H256>) -> H256 {
+    match fingerprint {
+        Fingerprint::Native(hash) => *hash,
+        _ => panic!("only native fingerprints exist before the version 12"),
+    }
+}
+
+/// The passed artist description fingerprint on the storage layout before the version 12.
+fn old_layout_description(artist: &Artist<Test>) -> Option<H256> {
+    artist.description.as_ref().map(native_hash)
+}
+
+/// The passed artist genres on the storage layout before the version 14.
+fn old_layout_genres(artist: &Artist<Test>) -> BoundedVec<MusicGenre, <Test as Config>::MaxGenres> {
+    artist
+        .genres
+        .iter()
+        .map(|genre| genre.genre().unwrap())
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap()
+}
+
+/// The passed artist assets on the storage layout before the version 12.
+fn old_layout_assets(
+    artist: &Artist<Test>,
+) -> BoundedVec<crate::migrations::v12::OldArtistAsset<Test>, <Test as Config>::MaxAssets> {
+    artist
+        .assets
+        .iter()
+        .map(|asset| crate::migrations::v12::OldArtistAsset::<Test> {
+            kind: asset.kind,
+            hash: native_hash(&asset.hash),
+            added_at: asset.added_at,
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap()
+}
+
+/// The passed artist on the storage layout of the version 15.
+fn v15_layout_artist(artist: &Artist<Test>) -> crate::migrations::v16::OldArtist<Test> {
+    crate::migrations::v16::OldArtist::<Test> {
+        owner: artist.owner,
+        registered_at: artist.registered_at,
+        last_updated_at: artist.last_updated_at,
+        update_nonce: artist.update_nonce,
+        verified_at: artist.verified_at,
+        kyc_evidence_hash: artist.kyc_evidence_hash,
+        status: artist.status,
+        main_name: artist.main_name.clone(),
+        alias: artist.aliases.first().cloned(),
+        genres: artist.genres.clone(),
+        open_to: artist.open_to,
+        description: artist.description.clone(),
+        raw_description: artist.raw_description.clone(),
+        contact_hash: artist.contact_hash,
+        links: artist.links.clone(),
+        assets: artist.assets.clone(),
+        contracts: artist.contracts.clone(),
+        contracts_frozen: artist.contracts_frozen,
+        delegates: artist.delegates.clone(),
+        kind: artist.kind,
+        members: artist.members.clone(),
+        royalty_splits: artist.royalty_splits.clone(),
+        deposit: artist.deposit,
+        depositor: artist.depositor,
+    }
+}
+
+/// The passed artist on the storage layout of the version 16.
+fn v16_layout_artist(artist: &Artist<Test>) -> crate::migrations::v17::OldArtist<Test> {
+    crate::migrations::v17::OldArtist::<Test> {
+        owner: artist.owner,
+        registered_at: artist.registered_at,
+        last_updated_at: artist.last_updated_at,
+        update_nonce: artist.update_nonce,
+        verified_at: artist.verified_at,
+        kyc_evidence_hash: artist.kyc_evidence_hash,
+        status: artist.status,
+        main_name: artist.main_name.clone(),
+        alias: artist.aliases.first().cloned(),
+        main_genre: artist.main_genre,
+        genres: artist.genres.clone(),
+        open_to: artist.open_to,
+        description: artist.description.clone(),
+        raw_description: artist.raw_description.clone(),
+        contact_hash: artist.contact_hash,
+        links: artist.links.clone(),
+        assets: artist.assets.clone(),
+        contracts: artist.contracts.clone(),
+        contracts_frozen: artist.contracts_frozen,
+        delegates: artist.delegates.clone(),
+        kind: artist.kind,
+        members: artist.members.clone(),
+        royalty_splits: artist.royalty_splits.clone(),
+        deposit: artist.deposit,
+        depositor: artist.depositor,
+    }
+}
+
+/// The passed artist on the storage layout of the version 17.
+fn v17_layout_artist(artist: &Artist<Test>) -> crate::migrations::v18::OldArtist<Test> {
+    crate::migrations::v18::OldArtist::<Test> {
+        owner: artist.owner,
+        registered_at: artist.registered_at,
+        last_updated_at: artist.last_updated_at,
+        update_nonce: artist.update_nonce,
+        verified_at: artist.verified_at,
+        kyc_evidence_hash: artist.kyc_evidence_hash,
+        status: artist.status,
+        main_name: artist.main_name.clone(),
+        aliases: artist.aliases.clone(),
+        main_genre: artist.main_genre,
+        genres: artist.genres.clone(),
+        open_to: artist.open_to,
+        description: artist.description.clone(),
+        raw_description: artist.raw_description.clone(),
+        contact_hash: artist.contact_hash,
+        links: artist.links.clone(),
+        assets: artist.assets.clone(),
+        contracts: artist.contracts.clone(),
+        contracts_frozen: artist.contracts_frozen,
+        delegates: artist.delegates.clone(),
+        kind: artist.kind,
+        members: artist.members.clone(),
+        royalty_splits: artist.royalty_splits.clone(),
+        deposit: artist.deposit,
+        depositor: artist.depositor,
+    }
+}
+
+/// The passed artist on the storage layout of the version 19.
+fn v19_layout_artist(artist: &Artist<Test>) -> crate::migrations::v20::OldArtist<Test> {
+    crate::migrations::v20::OldArtist::<Test> {
+        owner: artist.owner,
+        registered_at: artist.registered_at,
+        last_updated_at: artist.last_updated_at,
+        update_nonce: artist.update_nonce,
+        verified_at: artist.verified_at,
+        kyc_evidence_hash: artist.kyc_evidence_hash,
+        status: artist.status,
+        main_name: artist.main_name.clone(),
+        aliases: artist.aliases.clone(),
+        main_genre: artist.main_genre,
+        genres: artist.genres.clone(),
+        open_to: artist.open_to,
+        description: artist.description.clone(),
+        raw_description: artist.raw_description.clone(),
+        contact_hash: artist.contact_hash,
+        links: artist.links.clone(),
+        assets: artist.assets.clone(),
+        works: artist.works.clone(),
+        contracts: artist.contracts.clone(),
+        contracts_frozen: artist.contracts_frozen,
+        delegates: artist.delegates.clone(),
+        kind: artist.kind,
+        members: artist.members.clone(),
+        royalty_splits: artist.royalty_splits.clone(),
+        deposit: artist.deposit,
+        depositor: artist.depositor,
+    }
+}
+
+/// The passed artist on the storage layout of the version 13.
+fn v13_layout_artist(artist: &Artist<Test>) -> crate::migrations::v14::OldArtist<Test> {
+    crate::migrations::v14::OldArtist::<Test> {
+        owner: artist.owner,
+        registered_at: artist.registered_at,
+        last_updated_at: artist.last_updated_at,
+        update_nonce: artist.update_nonce,
+        verified_at: artist.verified_at,
+        kyc_evidence_hash: artist.kyc_evidence_hash,
+        status: artist.status,
+        main_name: artist.main_name.clone(),
+        alias: artist.aliases.first().cloned(),
+        genres: old_layout_genres(artist),
+        open_to: artist.open_to,
+        description: artist.description.clone(),
+        raw_description: artist.raw_description.clone(),
+        contact_hash: artist.contact_hash,
+        links: artist.links.clone(),
+        assets: artist.assets.clone(),
+        contracts: artist.contracts.clone(),
+        contracts_frozen: artist.contracts_frozen,
+        delegates: artist.delegates.clone(),
+        kind: artist.kind,
+        members: artist.members.clone(),
+        royalty_splits: artist.royalty_splits.clone(),
+        deposit: artist.deposit,
+        depositor: artist.depositor,
+    }
+}
+
+/// The passed artist on the storage layout of the version 12.
+fn v12_layout_artist(artist: &Artist<Test>) -> crate::migrations::v13::OldArtist<Test> {
+    crate::migrations::v13::OldArtist::<Test> {
+        owner: artist.owner,
+        registered_at: artist.registered_at,
+        verified_at: artist.verified_at,
+        kyc_evidence_hash: artist.kyc_evidence_hash,
+        status: artist.status,
+        main_name: artist.main_name.clone(),
+        alias: artist.aliases.first().cloned(),
+        genres: old_layout_genres(artist),
+        open_to: artist.open_to,
+        description: artist.description.clone(),
+        raw_description: artist.raw_description.clone(),
+        contact_hash: artist.contact_hash,
+        links: artist.links.clone(),
+        assets: artist.assets.clone(),
+        contracts: artist.contracts.clone(),
+        contracts_frozen: artist.contracts_frozen,
+        delegates: artist.delegates.clone(),
+        kind: artist.kind,
+        members: artist.members.clone(),
+        royalty_splits: artist.royalty_splits.clone(),
+        deposit: artist.deposit,
+        depositor: artist.depositor,
+    }
+}
+
+/// The passed artist on the storage layout of the version 11.
+fn v11_layout_artist(artist: &Artist<Test>) -> crate::migrations::v12::OldArtist<Test> {
+    crate::migrations::v12::OldArtist::<Test> {
+        owner: artist.owner,
+        registered_at: artist.registered_at,
+        verified_at: artist.verified_at,
+        kyc_evidence_hash: artist.kyc_evidence_hash,
+        status: artist.status,
+        main_name: artist.main_name.clone(),
+        alias: artist.aliases.first().cloned(),
+        genres: old_layout_genres(artist),
+        open_to: artist.open_to,
+        description: old_layout_description(artist),
+        raw_description: artist.raw_description.clone(),
+        contact_hash: artist.contact_hash,
+        links: artist.links.clone(),
+        assets: old_layout_assets(artist),
+        contracts: artist.contracts.clone(),
+        contracts_frozen: artist.contracts_frozen,
+        delegates: artist.delegates.clone(),
+        kind: artist.kind,
+        members: artist.members.clone(),
+        royalty_splits: artist.royalty_splits.clone(),
+        deposit: artist.deposit,
+        depositor: artist.depositor,
+    }
+}
+
+/// The passed artist on the storage layout of the version 10.
+fn v10_layout_artist(artist: &Artist<Test>) -> crate::migrations::v11::OldArtist<Test> {
+    crate::migrations::v11::OldArtist::<Test> {
+        owner: artist.owner,
+        registered_at: artist.registered_at,
+        verified_at: artist.verified_at,
+        kyc_evidence_hash: artist.kyc_evidence_hash,
+        status: artist.status,
+        main_name: artist.main_name.clone(),
+        alias: artist.aliases.first().cloned(),
+        genres: old_layout_genres(artist),
+        open_to: artist.open_to,
+        description: old_layout_description(artist),
+        raw_description: artist.raw_description.clone(),
+        contact_hash: artist.contact_hash,
+        links: artist.links.clone(),
+        assets: old_layout_assets(artist),
+        contracts: artist.contracts.clone(),
+        contracts_frozen: artist.contracts_frozen,
+        delegates: artist.delegates.clone(),
+        royalty_splits: artist.royalty_splits.clone(),
+        deposit: artist.deposit,
+        depositor: artist.depositor,
+    }
+}
+
+/// The passed artist on the storage layout of the version 9.
+fn v9_layout_artist(artist: &Artist<Test>) -> crate::migrations::v10::OldArtist<Test> {
+    crate::migrations::v10::OldArtist::<Test> {
+        owner: artist.owner,
+        registered_at: artist.registered_at,
+        verified_at: artist.verified_at,
+        kyc_evidence_hash: artist.kyc_evidence_hash,
+        status: artist.status,
+        main_name: artist.main_name.clone(),
+        alias: artist.aliases.first().cloned(),
+        genres: old_layout_genres(artist),
+        open_to: artist.open_to,
+        description: old_layout_description(artist),
+        raw_description: artist.raw_description.clone(),
+        contact_hash: artist.contact_hash,
+        links: artist.links.clone(),
+        assets: old_layout_assets(artist),
+        contracts: artist.contracts.clone(),
+        contracts_frozen: artist.contracts_frozen,
+        delegates: artist.delegates.clone(),
+        deposit: artist.deposit,
+        depositor: artist.depositor,
+    }
+}
+
+/// The passed artist on the storage layout of the version 8.
+fn v8_layout_artist(artist: &Artist<Test>) -> crate::migrations::v9::OldArtist<Test> {
+    crate::migrations::v9::OldArtist::<Test> {
+        owner: artist.owner,
+        registered_at: artist.registered_at,
+        verified_at: artist.verified_at,
+        kyc_evidence_hash: artist.kyc_evidence_hash,
+        status: artist.status,
+        main_name: artist.main_name.clone(),
+        alias: artist.aliases.first().cloned(),
+        genres: old_layout_genres(artist),
+        open_to: artist.open_to,
+        description: old_layout_description(artist),
+        contact_hash: artist.contact_hash,
+        links: artist.links.clone(),
+        assets: old_layout_assets(artist),
+        contracts: artist.contracts.clone(),
+        contracts_frozen: artist.contracts_frozen,
+        delegates: artist.delegates.clone(),
+        deposit: artist.deposit,
+        depositor: artist.depositor,
+    }
+}
+
+/// The passed artist on the storage layout of the version 7.
+fn v7_layout_artist(artist: &Artist<Test>) -> crate::migrations::v8::OldArtist<Test> {
+    crate::migrations::v8::OldArtist::<Test> {
+        owner: artist.owner,
+        registered_at: artist.registered_at,
+        verified_at: artist.verified_at,
+        kyc_evidence_hash: artist.kyc_evidence_hash,
+        status: artist.status,
+        main_name: artist.main_name.clone(),
+        alias: artist.aliases.first().cloned(),
+        genres: old_layout_genres(artist),
+        open_to: artist.open_to,
+        description: old_layout_description(artist),
+        contact_hash: artist.contact_hash,
+        links: artist.links.clone(),
+        assets: old_layout_assets(artist),
+        contracts: artist.contracts.clone(),
+        contracts_frozen: artist.contracts_frozen,
+        delegates: artist.delegates.clone(),
+        deposit: artist.deposit,
+    }
+}
+
+#[test]
+fn merge_artists_works() {
+    new_test_ext().execute_with(|| {
+        let mut artist = tester_artist::<Test>();
+        let primary = 1u64;
+        let duplicate = 2u64;
+        let contract = 42u64;
+
+        artist.assets = vec![(AssetType::Image, b"asset0".to_vec())]
+            .try_into()
+            .unwrap();
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(primary),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        artist.assets = vec![
+            (AssetType::Image, b"asset0".to_vec()),
+            (AssetType::Video, b"asset1".to_vec()),
+        ]
+        .try_into()
+        .unwrap();
+        artist.alias = tester_artist_of::<Test>(duplicate).alias;
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(duplicate),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert_ok!(Artists::link_contract(&duplicate, contract));
+
+        let add_link = |id: u64, link: &[u8]| {
+            assert_ok!(Artists::update(
+                RuntimeOrigin::signed(id),
+                UpdatableDataOf::<Test>::Links(UpdatableLinks::Add(
+                    LinkType::Website,
+                    link.to_vec()
+                )),
+            ));
+        };
+        for link in [&b"shared"[..], b"primary"] {
+            add_link(primary, link);
+        }
+        for link in [&b"shared"[..], b"duplicate0", b"duplicate1", b"duplicate2"] {
+            add_link(duplicate, link);
+        }
+
+        assert_noop!(
+            Artists::merge_artists(RuntimeOrigin::signed(primary), primary, duplicate),
+            BadOrigin
+        );
+
+        // The merged links can't exceed the bound of the primary artist
+        assert_noop!(
+            Artists::merge_artists(RuntimeOrigin::root(), primary, duplicate),
+            ArtistsError::<Test>::LinksFull
+        );
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(duplicate),
+            UpdatableDataOf::<Test>::Links(UpdatableLinks::Remove(
+                LinkType::Website,
+                b"duplicate2".to_vec()
+            )),
+        ));
+        assert_noop!(
+            Artists::merge_artists(RuntimeOrigin::root(), primary, primary),
+            ArtistsError::<Test>::MergeIntoSelf
+        );
+
+        assert_ok!(Artists::merge_artists(
+            RuntimeOrigin::root(),
+            primary,
+            duplicate
+        ));
+
+        // The missing data of the duplicate has been moved into the primary artist
+        let merged = Artists::get_artist_by_id(primary).unwrap();
+        assert_eq!(merged.assets().len(), 2);
+        assert_eq!(merged.assets()[1].kind(), &AssetType::Video);
+        assert_eq!(merged.contracts().to_vec(), vec![contract]);
+        // The links both artists share are kept once
+        assert_eq!(
+            merged
+                .links
+                .iter()
+                .map(|(_, link)| link.to_vec())
+                .collect::<Vec<_>>(),
+            vec![
+                b"shared".to_vec(),
+                b"primary".to_vec(),
+                b"duplicate0".to_vec(),
+                b"duplicate1".to_vec(),
+            ]
+        );
+        assert_eq!(Balances::total_balance_on_hold(&primary), *merged.deposit());
+
+        // The duplicate is refunded and tombstoned
+        assert!(Artists::get_artist_by_id(duplicate).is_none());
+        assert_eq!(Balances::free_balance(&duplicate), 500);
+        assert_eq!(Artists::get_merged_into(duplicate), Some(primary));
+        assert_noop!(
+            Artists::register(
+                RuntimeOrigin::signed(duplicate),
+                artist.main_name,
+                artist.alias,
+                artist.genres,
+                artist.description,
+                artist.assets,
+            ),
+            ArtistsError::<Test>::ArtistMerged
+        );
+    })
+}
+
+#[test]
+fn artist_rename_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let new_name: BoundedVec<u8, <Test as Config>::MaxNameLen> =
+            b"The New Tester".to_vec().try_into().unwrap();
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // An unverified artist is renamed right away, adjusting its name deposit
+        assert_ok!(Artists::rename(
+            RuntimeOrigin::signed(artist_id),
+            new_name.clone()
+        ));
+        let renamed = Artists::get_artist_by_id(artist_id).unwrap();
+        assert_eq!(renamed.main_name, new_name);
+        let name_diff = (new_name.encoded_size() - artist.main_name.encoded_size()) as u128;
+        assert_eq!(
+            Balances::total_balance_on_hold(&artist_id),
+            expected_artist_cost(&artist) + name_diff
+        );
+
+        // A verified artist needs the admin approval
+        assert_ok!(Artists::verify_artist(
+            RuntimeOrigin::root(),
+            artist_id,
+            None
+        ));
+
+        // Its requests are checked up front, as they would be on approval
+        let banned: <Test as Config>::Name = b"Banned".to_vec().try_into().unwrap();
+        let reserved: <Test as Config>::Name = b"Reserved".to_vec().try_into().unwrap();
+        assert_ok!(Artists::ban_name(RuntimeOrigin::root(), banned.clone()));
+        assert_ok!(Artists::reserve_name(
+            RuntimeOrigin::root(),
+            reserved.clone(),
+            Some(2)
+        ));
+        assert_noop!(
+            Artists::rename(RuntimeOrigin::signed(artist_id), banned),
+            ArtistsError::<Test>::BannedName
+        );
+        assert_noop!(
+            Artists::rename(RuntimeOrigin::signed(artist_id), reserved),
+            ArtistsError::<Test>::ReservedName
+        );
+
+        assert_ok!(Artists::rename(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone()
+        ));
+        assert_eq!(
+            Artists::get_artist_by_id(artist_id).unwrap().main_name,
+            new_name
+        );
+        assert_eq!(
+            Artists::get_pending_rename(artist_id),
+            Some(artist.main_name.clone())
+        );
+
+        // One rename is pending at most
+        assert_noop!(
+            Artists::rename(
+                RuntimeOrigin::signed(artist_id),
+                b"Another Tester".to_vec().try_into().unwrap()
+            ),
+            ArtistsError::<Test>::RenameAlreadyRequested
+        );
+
+        assert_noop!(
+            Artists::approve_rename(RuntimeOrigin::signed(artist_id), artist_id),
+            BadOrigin
+        );
+        assert_ok!(Artists::approve_rename(RuntimeOrigin::root(), artist_id));
+
+        // The verified names index follows the rename
+        assert!(Artists::get_artist_by_name(new_name.to_vec()).is_none());
+        assert_eq!(
+            Artists::get_artist_by_name(artist.main_name.to_vec())
+                .unwrap()
+                .owner(),
+            &artist_id
+        );
+        assert_eq!(
+            Balances::total_balance_on_hold(&artist_id),
+            expected_artist_cost(&artist)
+        );
+
+        // Nothing left to approve or reject
+        assert_noop!(
+            Artists::reject_rename(RuntimeOrigin::root(), artist_id),
+            ArtistsError::<Test>::RenameNotRequested
+        );
+    })
+}
+
+#[test]
+fn artist_invariants_validation_works() {
+    use frame_support::traits::fungible::MutateHold;
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_noop!(
+            Artists::validate_artist(&artist_id),
+            ArtistsError::<Test>::NotRegistered
+        );
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert_ok!(Artists::verify_artist(
+            RuntimeOrigin::root(),
+            artist_id,
+            None
+        ));
+
+        assert_ok!(Artists::validate_artist(&artist_id));
+        assert_ok!(Artists::do_try_state());
+
+        // A corrupted name index is caught
+        crate::ArtistNameOf::<Test>::remove(&artist.main_name);
+        assert!(Artists::validate_artist(&artist_id).is_err());
+        assert!(Artists::do_try_state().is_err());
+        crate::ArtistNameOf::<Test>::insert(&artist.main_name, artist_id);
+
+        // So is a corrupted alias index
+        crate::ArtistAliasIndex::<Test>::remove(artist.alias.as_ref().unwrap());
+        assert!(Artists::validate_artist(&artist_id).is_err());
+        crate::ArtistAliasIndex::<Test>::insert(artist.alias.as_ref().unwrap(), artist_id);
+        assert_ok!(Artists::do_try_state());
+
+        // And a deposit held without being tracked
+        assert_ok!(Balances::hold(
+            &RuntimeHoldReason::Artists(HoldReason::ArtistData),
+            &artist_id,
+            1
+        ));
+        assert!(Artists::validate_artist(&artist_id).is_ok());
+        assert!(Artists::do_try_state().is_err());
+    })
+}
+
+#[test]
+fn artist_legacy_holds_migration_works() {
+    use frame_support::traits::fungible::{InspectHold, MutateHold};
+    use frame_support::traits::tokens::Precision;
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        let base_deposit: BalanceOf<Test> = <Test as Config>::BaseDeposit::get();
+        let data_held = |id| {
+            Balances::balance_on_hold(&RuntimeHoldReason::Artists(HoldReason::ArtistData), &id)
+        };
+        let data_deposit = expected_artist_cost(&artist) - base_deposit;
+        assert_eq!(data_held(artist_id), data_deposit);
+
+        // Simulate a name deposit held under its legacy reason
+        let name_cost: BalanceOf<Test> = <Test as Config>::ByteDeposit::get()
+            .saturating_mul(artist.main_name.encoded_size() as u128);
+        assert_ok!(Balances::release(
+            &RuntimeHoldReason::Artists(HoldReason::ArtistData),
+            &artist_id,
+            name_cost,
+            Precision::Exact
+        ));
+        assert_ok!(Balances::hold(
+            &RuntimeHoldReason::Artists(HoldReason::ArtistName),
+            &artist_id,
+            name_cost
+        ));
+
+        // The next deposit change moves it under the data reason
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Description(None),
+        ));
+
+        let hash_cost: BalanceOf<Test> = <Test as Config>::ByteDeposit::get()
+            .saturating_mul(<Test as frame_system::Config>::Hash::max_encoded_len() as u128);
+        assert_eq!(
+            Balances::balance_on_hold(
+                &RuntimeHoldReason::Artists(HoldReason::ArtistName),
+                &artist_id
+            ),
+            0
+        );
+        assert_eq!(data_held(artist_id), data_deposit - hash_cost);
+        assert_eq!(
+            Balances::total_balance_on_hold(&artist_id),
+            *Artists::get_artist_by_id(artist_id).unwrap().deposit()
+        );
+    })
+}
+
+#[test]
+fn deposits_audit_works() {
+    use frame_support::traits::fungible::MutateHold;
+    use frame_support::traits::tokens::Precision;
+
+    new_test_ext().execute_with(|| {
+        for id in 1..=2u64 {
+            let artist = tester_artist_of::<Test>(id);
+            assert_ok!(Artists::register(
+                RuntimeOrigin::signed(id),
+                artist.main_name,
+                artist.alias,
+                artist.genres,
+                artist.description,
+                artist.assets,
+            ));
+        }
+
+        let deposit = |id| *Artists::get_artist_by_id(id).unwrap().deposit();
+        let tracked = deposit(1) + deposit(2);
+        assert_eq!(Artists::total_deposits_held(), (tracked, tracked));
+        assert!(Artists::artists_with_shortfall().is_empty());
+
+        // A hold released behind the registry back shows up as a shortfall
+        assert_ok!(Balances::release(
+            &RuntimeHoldReason::Artists(HoldReason::ArtistData),
+            &1,
+            3,
+            Precision::Exact
+        ));
+        assert_eq!(Artists::total_deposits_held(), (tracked, tracked - 3));
+        assert_eq!(
+            Artists::artists_with_shortfall(),
+            vec![(1, deposit(1), deposit(1) - 3)]
+        );
+        assert!(Artists::do_try_state().is_err());
+    })
+}
+
+#[test]
+fn artist_genre_capacity_works() {
+    new_test_ext().execute_with(|| {
+        let mut artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        MaxElectronicGenres::set(2);
+
+        artist.genres = vec![
+            MusicGenre::Electronic(Some(ElectronicSubtype::House)),
+            MusicGenre::Electronic(Some(ElectronicSubtype::Techno)),
+            MusicGenre::Electronic(Some(ElectronicSubtype::Trance)),
+        ]
+        .try_into()
+        .unwrap();
+        assert_noop!(
+            Artists::register(
+                RuntimeOrigin::signed(artist_id),
+                artist.main_name.clone(),
+                artist.alias.clone(),
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            ),
+            ArtistsError::<Test>::GenreCapacityExceeded
+        );
+
+        // Each top-level genre has its own capacity
+        artist.genres = vec![
+            MusicGenre::Electronic(Some(ElectronicSubtype::House)),
+            MusicGenre::Electronic(Some(ElectronicSubtype::Techno)),
+            MusicGenre::Classical(Some(ClassicalSubtype::Symphony)),
+        ]
+        .try_into()
+        .unwrap();
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        assert_noop!(
+            Artists::update(
+                RuntimeOrigin::signed(artist_id),
+                UpdatableDataOf::<Test>::Genres(UpdatableGenres::Add(MusicGenre::Classical(None))),
+            ),
+            ArtistsError::<Test>::GenreCapacityExceeded
+        );
+        assert_noop!(
+            Artists::update(
+                RuntimeOrigin::signed(artist_id),
+                UpdatableDataOf::<Test>::Genres(UpdatableGenres::Add(MusicGenre::Electronic(
+                    Some(ElectronicSubtype::Trance)
+                ))),
+            ),
+            ArtistsError::<Test>::GenreCapacityExceeded
+        );
+
+        MaxElectronicGenres::set(5);
+    })
+}
+
+#[test]
+fn artist_availability_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        System::set_block_number(1);
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert!(Artists::get_artist_by_id(artist_id)
+            .unwrap()
+            .open_to()
+            .0
+            .is_empty());
+
+        let open_to = AvailabilityFlags(Availability::Collaboration | Availability::Licensing);
+        let new_data = UpdatableDataOf::<Test>::OpenTo(open_to);
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            new_data.clone(),
+        ));
+        System::assert_last_event(
+            Event::<Test>::ArtistUpdated {
+                id: artist_id,
+                new_data,
+            }
+            .into(),
+        );
+
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        assert_eq!(stored.open_to(), &open_to);
+        assert!(stored.open_to().0.contains(Availability::Licensing));
+        assert!(!stored.open_to().0.contains(Availability::Booking));
+        // The availability has a fixed size, holding no more deposit
+        assert_eq!(stored.deposit(), &expected_artist_cost(&artist));
+    })
+}
+
+#[test]
+fn artist_delegates_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let delegate = 2u64;
+
+        System::set_block_number(1);
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        let new_data = UpdatableDataOf::<Test>::Assets(UpdatableAssets::Add(
+            AssetType::Image,
+            b"asset0".to_vec(),
+        ));
+        assert_noop!(
+            Artists::update_as_delegate(
+                RuntimeOrigin::signed(delegate),
+                artist_id,
+                new_data.clone()
+            ),
+            ArtistsError::<Test>::NotDelegate
+        );
+
+        assert_ok!(Artists::add_delegate(
+            RuntimeOrigin::signed(artist_id),
+            delegate
+        ));
+        assert_noop!(
+            Artists::add_delegate(RuntimeOrigin::signed(artist_id), delegate),
+            ArtistsError::<Test>::AlreadyDelegate
+        );
+        let delegate_cost: BalanceOf<Test> = <Test as Config>::ByteDeposit::get()
+            .saturating_mul(<Test as frame_system::Config>::AccountId::max_encoded_len() as u128);
+        let hash_cost: BalanceOf<Test> = <Test as Config>::ByteDeposit::get()
+            .saturating_mul(<Test as frame_system::Config>::Hash::max_encoded_len() as u128);
+        assert_eq!(
+            Balances::total_balance_on_hold(&artist_id),
+            expected_artist_cost(&artist) + delegate_cost
+        );
+
+        // The delegate updates on behalf of the artist, the deposit being held from the artist
+        assert_ok!(Artists::update_as_delegate(
+            RuntimeOrigin::signed(delegate),
+            artist_id,
+            new_data.clone()
+        ));
+        System::assert_last_event(
+            Event::<Test>::ArtistUpdatedByDelegate {
+                id: artist_id,
+                delegate,
+                new_data,
+            }
+            .into(),
+        );
+        assert_eq!(
+            Balances::total_balance_on_hold(&artist_id),
+            expected_artist_cost(&artist) + delegate_cost + hash_cost
+        );
+        assert_eq!(Balances::total_balance_on_hold(&delegate), 0);
+
+        // A delegate can't unregister the artist
+        assert_noop!(
+            Artists::request_unregister(RuntimeOrigin::signed(delegate)),
+            ArtistsError::<Test>::NotRegistered
+        );
+
+        assert_ok!(Artists::remove_delegate(
+            RuntimeOrigin::signed(artist_id),
+            delegate
+        ));
+        assert_noop!(
+            Artists::remove_delegate(RuntimeOrigin::signed(artist_id), delegate),
+            ArtistsError::<Test>::NotDelegate
+        );
+        assert_eq!(
+            Balances::total_balance_on_hold(&artist_id),
+            expected_artist_cost(&artist) + hash_cost
+        );
+    })
+}
+
+#[test]
+fn artist_works_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let featuring = 2u64;
+        let work = <Test as frame_system::Config>::Hashing::hash(b"song");
+
+        System::set_block_number(1);
+
+        assert_noop!(
+            Artists::claim_work(RuntimeOrigin::signed(artist_id), work),
+            ArtistsError::<Test>::NotRegistered
+        );
+        for id in [artist_id, featuring] {
+            assert_ok!(Artists::register(
+                RuntimeOrigin::signed(id),
+                artist.main_name.clone(),
+                tester_artist_of::<Test>(id).alias,
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            ));
+        }
+
+        assert_ok!(Artists::claim_work(RuntimeOrigin::signed(artist_id), work));
+        System::assert_last_event(
+            Event::<Test>::WorkClaimed {
+                id: artist_id,
+                work,
+            }
+            .into(),
+        );
+        assert_noop!(
+            Artists::claim_work(RuntimeOrigin::signed(artist_id), work),
+            ArtistsError::<Test>::WorkAlreadyClaimed
+        );
+        let hash_cost: BalanceOf<Test> = <Test as Config>::ByteDeposit::get()
+            .saturating_mul(<Test as frame_system::Config>::Hash::max_encoded_len() as u128);
+        let featuring_cost = Balances::total_balance_on_hold(&featuring);
+        assert_eq!(
+            Balances::total_balance_on_hold(&artist_id),
+            featuring_cost + hash_cost
+        );
+
+        // The performers of a featuring claim the same work
+        assert_ok!(Artists::claim_work(RuntimeOrigin::signed(featuring), work));
+        assert!(<Artists as ArtistsInspect<u64>>::claims_work(
+            &artist_id, &work
+        ));
+        assert!(<Artists as ArtistsInspect<u64>>::claims_work(
+            &featuring, &work
+        ));
+        assert_eq!(
+            <Artists as ArtistsInspect<u64>>::works_of(&artist_id),
+            vec![work]
+        );
+        assert_ok!(Artists::do_try_state());
+
+        assert_ok!(Artists::disclaim_work(
+            RuntimeOrigin::signed(featuring),
+            work
+        ));
+        System::assert_last_event(
+            Event::<Test>::WorkDisclaimed {
+                id: featuring,
+                work,
+            }
+            .into(),
+        );
+        assert_noop!(
+            Artists::disclaim_work(RuntimeOrigin::signed(featuring), work),
+            ArtistsError::<Test>::WorkNotClaimed
+        );
+        assert!(!<Artists as ArtistsInspect<u64>>::claims_work(
+            &featuring, &work
+        ));
+        assert_eq!(Balances::total_balance_on_hold(&featuring), featuring_cost);
+
+        // The claims are bounded by `MaxWorks`
+        let max_works: u32 = <Test as Config>::MaxWorks::get();
+        for i in 1..max_works {
+            assert_ok!(Artists::claim_work(
+                RuntimeOrigin::signed(artist_id),
+                <Test as frame_system::Config>::Hashing::hash(&i.to_le_bytes())
+            ));
+        }
+        assert_noop!(
+            Artists::claim_work(
+                RuntimeOrigin::signed(artist_id),
+                <Test as frame_system::Config>::Hashing::hash(b"one too many")
+            ),
+            ArtistsError::<Test>::WorksFull
+        );
+        assert_eq!(
+            Balances::total_balance_on_hold(&artist_id),
+            featuring_cost + hash_cost * max_works as u128
+        );
+        assert_eq!(
+            *Artists::get_artist_view(&artist_id)
+                .unwrap()
+                .deposits()
+                .works(),
+            hash_cost * max_works as u128
+        );
+    })
+}
+
+#[test]
+fn artists_genres_index_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let house = MusicGenre::Electronic(Some(ElectronicSubtype::House));
+        let symphony = MusicGenre::Classical(Some(ClassicalSubtype::Symphony));
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert!(crate::ArtistsOfGenre::<Test>::contains_key(
+            StoredGenre::from(house),
+            artist_id
+        ));
+
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Genres(UpdatableGenres::Add(symphony)),
+        ));
+        assert_eq!(Artists::get_artists_by_genre(symphony).len(), 1);
+
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Genres(UpdatableGenres::Remove(house)),
+        ));
+        assert!(Artists::get_artists_by_genre(house).is_empty());
+        assert_noop!(
+            Artists::update(
+                RuntimeOrigin::signed(artist_id),
+                UpdatableDataOf::<Test>::Genres(UpdatableGenres::Remove(house)),
+            ),
+            Error::<Test>::GenreNotFound
+        );
+        assert_ok!(Artists::do_try_state());
+
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Genres(UpdatableGenres::Add(house)),
+        ));
+        assert_ok!(Artists::request_unregister(RuntimeOrigin::signed(
+            artist_id
+        )));
+        let unregister_cd: u32 = <Test as Config>::UnregisterPeriod::get();
+        frame_system::Pallet::<Test>::set_block_number(unregister_cd.saturated_into());
+        assert_ok!(Artists::confirm_unregister(RuntimeOrigin::signed(
+            artist_id
+        )));
+
+        // The unregistered artist has left the index
+        assert_eq!(crate::ArtistsOfGenre::<Test>::iter_keys().count(), 0);
+    })
+}
+
+#[test]
+fn stored_genres_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let house = MusicGenre::Electronic(Some(ElectronicSubtype::House));
+        let classical = MusicGenre::Classical(None);
+
+        // Registry genres are stored as they read back
+        assert_eq!(StoredGenre::checked(house).unwrap().genre(), Some(house));
+        assert_eq!(StoredGenre::from(classical).subtag, None);
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // A genre unknown to the running registry still decodes with its artist
+        let unknown = StoredGenre {
+            tag: 200,
+            subtag: Some(3),
+        };
+        assert_eq!(unknown.genre(), None);
+        crate::ArtistOf::<Test>::mutate(artist_id, |maybe_artist| {
+            let artist = maybe_artist.as_mut().unwrap();
+            artist.genres.try_push(unknown).unwrap();
+        });
+        crate::ArtistsOfGenre::<Test>::insert(unknown, artist_id, ());
+        assert_eq!(
+            Artists::get_artist_by_id(artist_id)
+                .unwrap()
+                .genres
+                .to_vec(),
+            vec![StoredGenre::from(house), unknown]
+        );
+
+        // And doesn't prevent the artist from updating its known genres
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Genres(UpdatableGenres::Add(classical)),
+        ));
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Genres(UpdatableGenres::RemoveAt(1)),
+        ));
+        assert_eq!(
+            Artists::get_artist_by_id(artist_id)
+                .unwrap()
+                .genres
+                .to_vec(),
+            vec![StoredGenre::from(house), StoredGenre::from(classical)]
+        );
+        assert_ok!(Artists::do_try_state());
+    })
+}
+
+#[test]
+fn main_genre_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let house = MusicGenre::Electronic(Some(ElectronicSubtype::House));
+        let classical = MusicGenre::Classical(None);
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert_eq!(
+            <Artists as ArtistsInspect<u64>>::main_genre(&artist_id),
+            None
+        );
+
+        // The main genre can't be one of the secondary genres
+        assert_noop!(
+            Artists::update(
+                RuntimeOrigin::signed(artist_id),
+                UpdatableDataOf::<Test>::MainGenre(Some(house)),
+            ),
+            ArtistsError::<Test>::MainGenreDuplicated
+        );
+
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::MainGenre(Some(classical)),
+        ));
+        System::assert_has_event(
+            Event::<Test>::ArtistDataChanged {
+                id: artist_id,
+                old: ArtistField::MainGenre(None),
+                new: ArtistField::MainGenre(Some(StoredGenre::from(classical))),
+            }
+            .into(),
+        );
+        assert_eq!(
+            <Artists as ArtistsInspect<u64>>::main_genre(&artist_id),
+            Some(classical)
+        );
+        assert_eq!(
+            <Artists as ArtistsInspect<u64>>::secondary_genres(&artist_id),
+            vec![house]
+        );
+        assert_eq!(
+            Artists::get_artist_genres(&artist_id),
+            Some((Some(classical), vec![house]))
+        );
+
+        // The artist is listed under both its main and secondary genres
+        assert_eq!(Artists::get_artists_by_genre(classical).len(), 1);
+        assert_eq!(Artists::get_artists_by_genre(house).len(), 1);
+
+        // Nor can a secondary genre duplicate the main one
+        assert_noop!(
+            Artists::update(
+                RuntimeOrigin::signed(artist_id),
+                UpdatableDataOf::<Test>::Genres(UpdatableGenres::Add(classical)),
+            ),
+            ArtistsError::<Test>::MainGenreDuplicated
+        );
+
+        let record = Artists::get_artist_record(&artist_id, 2).unwrap();
+        let v2 = match record {
+            VersionedArtistRecord::V2(v2) => v2,
+            _ => panic!("a version 2 record is expected"),
+        };
+        assert_eq!(*v2.main_genre(), Some(StoredGenre::from(classical)));
+
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::MainGenre(None),
+        ));
+        assert!(Artists::get_artists_by_genre(classical).is_empty());
+        assert_ok!(Artists::do_try_state());
+    })
+}
+
+#[test]
+fn migrate_to_v3_works() {
+    use crate::migrations::v3::MigrateToV3;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // Store the artist back on its version 2 layout, without the index
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        frame_support::storage::unhashed::put(
+            &crate::ArtistOf::<Test>::hashed_key_for(artist_id),
+            &v3_layout_artist(&stored),
+        );
+        let _ = crate::ArtistsOfGenre::<Test>::clear(u32::MAX, None);
+        StorageVersion::new(2).put::<Artists>();
+
+        MigrateToV3::<Test>::on_runtime_upgrade();
+
+        assert!(
+            crate::migrations::v14::ArtistsOfGenre::<Test>::contains_key(
+                artist.genres[0],
+                artist_id
+            )
+        );
+        assert_eq!(Artists::on_chain_storage_version(), 3);
+    })
+}
+
+#[test]
+fn migrate_to_v4_works() {
+    use crate::migrations::v4::MigrateToV4;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // Store the artist back without its contact commitment
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        frame_support::storage::unhashed::put(
+            &crate::ArtistOf::<Test>::hashed_key_for(artist_id),
+            &v3_layout_artist(&stored),
+        );
+        StorageVersion::new(3).put::<Artists>();
+
+        MigrateToV4::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            crate::migrations::v7::ArtistOf::<Test>::get(artist_id)
+                .unwrap()
+                .encode(),
+            v6_layout_artist(&stored).encode()
+        );
+        assert_eq!(Artists::on_chain_storage_version(), 4);
+    })
+}
+
+#[test]
+fn artist_contact_hash_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        let contact_hash = <Test as frame_system::Config>::Hashing::hash(b"contact@tester.com");
+        let hash_cost: BalanceOf<Test> = <Test as Config>::ByteDeposit::get()
+            .saturating_mul(<Test as frame_system::Config>::Hash::max_encoded_len() as u128);
+        let stored_deposit = || *Artists::get_artist_by_id(artist_id).unwrap().deposit();
+
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::ContactHash(Some(contact_hash)),
+        ));
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        assert_eq!(stored.contact_hash(), &Some(contact_hash));
+        assert_eq!(stored_deposit(), expected_artist_cost(&artist) + hash_cost);
+
+        // Replacing the commitment doesn't hold more
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::ContactHash(Some(
+                <Test as frame_system::Config>::Hashing::hash(b"booking@tester.com")
+            )),
+        ));
+        assert_eq!(stored_deposit(), expected_artist_cost(&artist) + hash_cost);
+
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::ContactHash(None),
+        ));
+        assert!(Artists::get_artist_by_id(artist_id)
+            .unwrap()
+            .contact_hash()
+            .is_none());
+        assert_eq!(stored_deposit(), expected_artist_cost(&artist));
+        assert_eq!(
+            Balances::total_balance_on_hold(&artist_id),
+            stored_deposit()
+        );
+    })
+}
+
+#[test]
+fn artist_links_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        let link_cost = |kind: LinkType, link: &[u8]| -> BalanceOf<Test> {
+            <Test as Config>::ByteDeposit::get()
+                .saturating_mul((kind, link.to_vec()).encoded_size() as u128)
+        };
+        let add_link = |kind: LinkType, link: &[u8]| {
+            Artists::update(
+                RuntimeOrigin::signed(artist_id),
+                UpdatableDataOf::<Test>::Links(UpdatableLinks::Add(kind, link.to_vec())),
+            )
+        };
+        let stored_deposit = || *Artists::get_artist_by_id(artist_id).unwrap().deposit();
+
+        assert_ok!(add_link(LinkType::Spotify, b"4tZwfgrHOc3mvqYlEYSvVi"));
+        assert_ok!(add_link(LinkType::Website, b"https://tester.com"));
+        let links_cost = link_cost(LinkType::Spotify, b"4tZwfgrHOc3mvqYlEYSvVi")
+            + link_cost(LinkType::Website, b"https://tester.com");
+        assert_eq!(
+            Artists::get_artist_by_id(artist_id).unwrap().links().len(),
+            2
+        );
+        assert_eq!(stored_deposit(), expected_artist_cost(&artist) + links_cost);
+
+        // The same link can't be added twice, nor a link longer than `MaxLinkLen`
+        assert_noop!(
+            add_link(LinkType::Spotify, b"4tZwfgrHOc3mvqYlEYSvVi"),
+            ArtistsError::<Test>::NotUniqueLink
+        );
+        let max_len = <Test as Config>::MaxLinkLen::get() as usize;
+        assert_noop!(
+            add_link(LinkType::Website, &vec![b'a'; max_len + 1]),
+            ArtistsError::<Test>::LinkTooLong
+        );
+
+        // Links are removed by kind and value
+        assert_noop!(
+            Artists::update(
+                RuntimeOrigin::signed(artist_id),
+                UpdatableDataOf::<Test>::Links(UpdatableLinks::Remove(
+                    LinkType::Isni,
+                    b"4tZwfgrHOc3mvqYlEYSvVi".to_vec()
+                )),
+            ),
+            ArtistsError::<Test>::LinkNotFound
+        );
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Links(UpdatableLinks::Remove(
+                LinkType::Spotify,
+                b"4tZwfgrHOc3mvqYlEYSvVi".to_vec()
+            )),
+        ));
+        assert_eq!(
+            stored_deposit(),
+            expected_artist_cost(&artist) + link_cost(LinkType::Website, b"https://tester.com")
+        );
+
+        assert_ok!(add_link(LinkType::Isni, b"0000000121032683"));
+        assert_ok!(add_link(LinkType::Ipi, b"00052210040"));
+        assert_ok!(add_link(LinkType::Spotify, b"4tZwfgrHOc3mvqYlEYSvVi"));
+        assert_noop!(
+            add_link(LinkType::Website, b"https://tester.org"),
+            ArtistsError::<Test>::LinksFull
+        );
+
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Links(UpdatableLinks::Clear),
+        ));
+        assert!(Artists::get_artist_by_id(artist_id)
+            .unwrap()
+            .links()
+            .is_empty());
+        assert_eq!(stored_deposit(), expected_artist_cost(&artist));
+        assert_eq!(
+            Balances::total_balance_on_hold(&artist_id),
+            stored_deposit()
+        );
+    })
+}
+
+#[test]
+fn registry_stats_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let mut artist = tester_artist::<Test>();
+        artist.assets = vec![
+            (AssetType::Image, b"asset0".to_vec()),
+            (AssetType::Audio, b"asset1".to_vec()),
+            (AssetType::Image, b"asset2".to_vec()),
+        ]
+        .try_into()
+        .unwrap();
+
+        for artist_id in 1..=3u64 {
+            assert_ok!(Artists::register(
+                RuntimeOrigin::signed(artist_id),
+                artist.main_name.clone(),
+                tester_artist_of::<Test>(artist_id).alias,
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            ));
+        }
+        assert_ok!(Artists::verify_artist(RuntimeOrigin::root(), 1, None));
+
+        // The pass is resumed over the calls and only published once every artist is counted
+        assert_ok!(Artists::update_stats(RuntimeOrigin::signed(4), 1));
+        assert_ok!(Artists::update_stats(RuntimeOrigin::signed(5), 1));
+        assert_eq!(Artists::get_registry_stats(), RegistryStats::default());
+
+        System::set_block_number(2);
+        assert_ok!(Artists::update_stats(RuntimeOrigin::signed(4), 1));
+        let expected = RegistryStats {
+            total: 3,
+            verified: 1,
+            assets_per_type: [6, 3, 0, 0, 0],
+            updated_at: 2,
+        };
+        assert_eq!(Artists::get_registry_stats(), expected);
+        System::assert_last_event(
+            Event::<Test>::RegistryStatsUpdated {
+                total: 3,
+                verified: 1,
+            }
+            .into(),
+        );
+
+        // A new pass starts from scratch
+        System::set_block_number(3);
+        assert_ok!(Artists::force_unregister(
+            RuntimeOrigin::root(),
+            3,
+            DepositPolicy::Refund
+        ));
+        assert_ok!(Artists::update_stats(RuntimeOrigin::signed(4), 10));
+        assert_eq!(
+            Artists::get_registry_stats(),
+            RegistryStats {
+                total: 2,
+                verified: 1,
+                assets_per_type: [4, 2, 0, 0, 0],
+                updated_at: 3,
+            }
+        );
+    })
+}
+
+#[test]
+fn artists_inspect_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert!(!<Artists as ArtistsInspect<u64>>::is_artist(&artist_id));
+        assert_eq!(
+            <Artists as ArtistsInspect<u64>>::artist_name(&artist_id),
+            None
+        );
+        assert!(<Artists as ArtistsInspect<u64>>::contracts_of(&artist_id).is_empty());
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert_ok!(Artists::link_contract(&artist_id, 42));
+
+        assert!(<Artists as ArtistsInspect<u64>>::is_artist(&artist_id));
+        assert!(!<Artists as ArtistsInspect<u64>>::is_verified(&artist_id));
+        assert_eq!(
+            <Artists as ArtistsInspect<u64>>::artist_name(&artist_id),
+            Some(artist.main_name.to_vec())
+        );
+        assert_eq!(
+            <Artists as ArtistsInspect<u64>>::contracts_of(&artist_id),
+            vec![42]
+        );
+
+        assert_ok!(Artists::verify_artist(
+            RuntimeOrigin::root(),
+            artist_id,
+            None
+        ));
+        assert!(<Artists as ArtistsInspect<u64>>::is_verified(&artist_id));
+    })
+}
+
+#[test]
+fn artist_lifecycle_hooks_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+
+        for artist_id in 1..=2u64 {
+            assert_ok!(Artists::register(
+                RuntimeOrigin::signed(artist_id),
+                artist.main_name.clone(),
+                tester_artist_of::<Test>(artist_id).alias,
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            ));
+        }
+        assert_ok!(Artists::verify_artist(RuntimeOrigin::root(), 1, None));
+        assert_ok!(Artists::force_unregister(
+            RuntimeOrigin::root(),
+            2,
+            DepositPolicy::Refund
+        ));
+
+        // Failed calls don't reach the handlers
+        assert_noop!(
+            Artists::verify_artist(RuntimeOrigin::root(), 2, None),
+            ArtistsError::<Test>::NotRegistered
+        );
+
+        assert_eq!(
+            LifecycleCalls::get(),
+            vec![
+                LifecycleCall::Registered(1),
+                LifecycleCall::Registered(2),
+                LifecycleCall::Verified(1),
+                LifecycleCall::Unregistered(2),
+            ]
+        );
+
+        LifecycleCalls::set(Vec::new());
+    })
+}
+
+#[test]
+fn moderation_calls_are_fee_free() {
+    use frame_support::dispatch::{GetDispatchInfo, Pays};
+
+    let pays_fee =
+        |call: crate::Call<Test>| RuntimeCall::Artists(call).get_dispatch_info().pays_fee;
+
+    assert_eq!(
+        pays_fee(crate::Call::verify_artist {
+            id: 1,
+            kyc_evidence_hash: None
+        }),
+        Pays::No
+    );
+    assert_eq!(
+        pays_fee(crate::Call::force_unregister {
+            id: 1,
+            policy: DepositPolicy::Slash
+        }),
+        Pays::No
+    );
+    assert_eq!(
+        pays_fee(crate::Call::ban_name {
+            name: b"Tester".to_vec().try_into().unwrap()
+        }),
+        Pays::No
+    );
+    assert_eq!(
+        pays_fee(crate::Call::merge_artists {
+            primary: 1,
+            duplicate: 2
+        }),
+        Pays::No
+    );
+
+    assert_eq!(
+        pays_fee(crate::Call::suspend_artist { id: 1, ban: false }),
+        Pays::No
+    );
+    assert_eq!(pays_fee(crate::Call::reinstate_artist { id: 1 }), Pays::No);
+
+    // Artists keep paying for their own calls
+    assert_eq!(pays_fee(crate::Call::request_unregister {}), Pays::Yes);
+    assert_eq!(
+        pays_fee(crate::Call::update_stats { max_artists: 1 }),
+        Pays::Yes
+    );
+}
+
+#[test]
+#[should_panic(expected = "exceeds the `MaxArtistProofSize` budget")]
+fn integrity_test_checks_artist_proof_size() {
+    use frame_support::traits::Hooks;
+
+    // Not even fitting the `MaxContracts` contracts of the mock
+    MaxArtistProofSize::set(8 * 1024);
+    <Artists as Hooks<u64>>::integrity_test();
+}
+
+#[test]
+fn artist_contracts_paging_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert!(Artists::get_artist_contracts(&artist_id, 0, 10).is_empty());
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        for contract in 40..45u64 {
+            assert_ok!(Artists::link_contract(&artist_id, contract));
+        }
+
+        assert_eq!(
+            Artists::get_artist_contracts(&artist_id, 0, 2),
+            vec![40, 41]
+        );
+        assert_eq!(
+            Artists::get_artist_contracts(&artist_id, 2, 2),
+            vec![42, 43]
+        );
+        assert_eq!(Artists::get_artist_contracts(&artist_id, 4, 2), vec![44]);
+        assert!(Artists::get_artist_contracts(&artist_id, 5, 2).is_empty());
+    })
+}
+
+#[test]
+fn migrate_to_v5_works() {
+    use crate::migrations::v5::MigrateToV5;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+
+        for artist_id in 1..=2u64 {
+            assert_ok!(Artists::register(
+                RuntimeOrigin::signed(artist_id),
+                artist.main_name.clone(),
+                tester_artist_of::<Test>(artist_id).alias,
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            ));
+        }
+
+        // Drop the counter as if the artists were stored in a plain map
+        crate::CounterForArtistOf::<Test>::kill();
+        StorageVersion::new(4).put::<Artists>();
+        assert_eq!(Artists::artists_count(), 0);
+
+        MigrateToV5::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Artists::artists_count(), 2);
+        assert_eq!(Artists::on_chain_storage_version(), 5);
+        assert_ok!(Artists::do_try_state());
+    })
+}
+
+#[test]
+fn artists_paging_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+
+        assert_eq!(Artists::artists_count(), 0);
+        assert!(Artists::get_artists_page(None, 10).is_empty());
+
+        for artist_id in 1..=5u64 {
+            assert_ok!(Artists::register(
+                RuntimeOrigin::signed(artist_id),
+                artist.main_name.clone(),
+                tester_artist_of::<Test>(artist_id).alias,
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            ));
+        }
+        assert_ok!(Artists::force_unregister(
+            RuntimeOrigin::root(),
+            3,
+            DepositPolicy::Refund
+        ));
+        assert_eq!(Artists::artists_count(), 4);
+
+        // Paging through the registry yields every artist once
+        let mut paged = Vec::new();
+        let mut last = None;
+        loop {
+            let page = Artists::get_artists_page(last.as_ref(), 3);
+            if page.is_empty() {
+                break;
+            }
+            last = page.last().map(|artist| *artist.owner());
+            paged.extend(page.into_iter().map(|artist| *artist.owner()));
+        }
+        paged.sort();
+        assert_eq!(paged, vec![1, 2, 4, 5]);
+    })
+}
+
+#[test]
+fn bounded_name_like_works() {
+    type Name = <Test as Config>::Name;
+
+    let name = Name::try_from_bytes(b"Tester".to_vec()).unwrap();
+    assert_eq!(name.to_bytes(), b"Tester".to_vec());
+
+    let max_len = <Test as Config>::MaxNameLen::get() as usize;
+    assert!(Name::try_from_bytes(vec![b'X'; max_len]).is_some());
+    assert!(Name::try_from_bytes(vec![b'X'; max_len + 1]).is_none());
+
+    // Names are banned and reserved by the hash of their bytes
+    assert_eq!(
+        Artists::name_hash(&name.to_bytes()),
+        <Test as frame_system::Config>::Hashing::hash(b"Tester")
+    );
+}
+
+#[test]
+fn normalize_name_works() {
+    type Name = <Test as Config>::Name;
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // The off-chain normalization gives the stored name
+        let name = normalize_name::<Name>(artist.main_name.to_vec()).unwrap();
+        assert_eq!(
+            Artists::get_artist_by_id(artist_id).unwrap().main_name(),
+            &name
+        );
+        assert_eq!(
+            Artists::normalized_name(artist.main_name.to_vec()),
+            Ok(name.to_bytes())
+        );
+
+        let max_len = <Test as Config>::MaxNameLen::get() as usize;
+        assert_eq!(
+            normalize_name::<Name>(vec![b'X'; max_len + 1]),
+            Err(InvalidName)
+        );
+        assert_eq!(
+            Artists::normalized_name(vec![b'X'; max_len + 1]),
+            Err(ArtistsError::<Test>::InvalidName.into())
+        );
+    })
+}
+
+#[test]
+fn name_validation_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let impersonator = 2u64;
+        let name =
+            |raw: &str| -> <Test as Config>::Name { raw.as_bytes().to_vec().try_into().unwrap() };
+        let register = |id: u64, main_name: <Test as Config>::Name| {
+            Artists::register(
+                RuntimeOrigin::signed(id),
+                main_name,
+                tester_artist_of::<Test>(id).alias,
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            )
+        };
+
+        // Invisible characters and invalid UTF-8 are rejected
+        assert_noop!(
+            register(artist_id, name("Test\u{200B}er")),
+            ArtistsError::<Test>::InvalidName
+        );
+        assert_noop!(
+            register(artist_id, vec![b'T', 0xFF].try_into().unwrap()),
+            ArtistsError::<Test>::InvalidName
+        );
+        MinNameLen::set(3);
+        assert_noop!(
+            register(artist_id, name("  Te ")),
+            ArtistsError::<Test>::NameTooShort
+        );
+
+        // The name is stored trimmed
+        assert_eq!(
+            Artists::normalized_name(b" Tester ".to_vec()),
+            Ok(b"Tester".to_vec())
+        );
+        assert_ok!(register(artist_id, name(" Tester ")));
+        assert_eq!(
+            Artists::get_artist_by_id(artist_id).unwrap().main_name,
+            name("Tester")
+        );
+
+        // Case variants of an unverified name are allowed
+        assert_ok!(register(impersonator, name("TESTER")));
+        assert_ok!(Artists::force_unregister(
+            RuntimeOrigin::root(),
+            impersonator,
+            DepositPolicy::Refund
+        ));
+
+        // But not of a verified one, at the registration as well as the rename
+        assert_ok!(Artists::verify_artist(
+            RuntimeOrigin::root(),
+            artist_id,
+            None
+        ));
+        assert_noop!(
+            register(impersonator, name("TESTER")),
+            ArtistsError::<Test>::NameConfusable
+        );
+        assert_ok!(register(impersonator, name("Other")));
+        assert_noop!(
+            Artists::rename(RuntimeOrigin::signed(impersonator), name("tEsTeR")),
+            ArtistsError::<Test>::NameConfusable
+        );
+
+        // The aliases go through the same checks, updates only taking their canonical form
+        let update_alias = |alias: &str| {
+            Artists::update(
+                RuntimeOrigin::signed(impersonator),
+                UpdatableDataOf::<Test>::Aliases(UpdatableAliases::Add(name(alias))),
+            )
+        };
+        assert_noop!(update_alias("tEsTeR"), ArtistsError::<Test>::NameConfusable);
+        assert_noop!(
+            update_alias("Dark\u{200B}Singer"),
+            ArtistsError::<Test>::InvalidName
+        );
+        assert_noop!(
+            update_alias(" Dark Singer "),
+            ArtistsError::<Test>::InvalidName
+        );
+        assert_noop!(update_alias("Da"), ArtistsError::<Test>::NameTooShort);
+        assert_noop!(
+            Artists::update(
+                RuntimeOrigin::signed(impersonator),
+                UpdatableDataOf::<Test>::Alias(Some(name("TESTER")))
+            ),
+            ArtistsError::<Test>::NameConfusable
+        );
+        assert_ok!(update_alias("Dark Singer"));
+        assert_ok!(Artists::do_try_state());
+
+        // The name gets available along with the verified artist
+        assert_ok!(Artists::force_unregister(
+            RuntimeOrigin::root(),
+            artist_id,
+            DepositPolicy::Refund
+        ));
+        assert_ok!(Artists::rename(
+            RuntimeOrigin::signed(impersonator),
+            name("tEsTeR")
+        ));
+        assert_ok!(Artists::do_try_state());
+    })
+}
+
+#[test]
+fn artist_request_verification_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let artist = tester_artist::<Test>();
+        let escrow = <Test as Config>::VerificationDeposit::get();
+        let escrow_held = |id| {
+            Balances::balance_on_hold(
+                &RuntimeHoldReason::Artists(HoldReason::VerificationRequest),
+                &id,
+            )
+        };
+        let evidence: BoundedVec<_, <Test as Config>::MaxEvidence> =
+            vec![<Test as frame_system::Config>::Hashing::hash(
+                b"signed statement",
+            )]
+            .try_into()
+            .unwrap();
+
+        for id in [1u64, 2u64] {
+            assert_ok!(Artists::register(
+                RuntimeOrigin::signed(id),
+                artist.main_name.clone(),
+                tester_artist_of::<Test>(id).alias,
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            ));
+        }
+
+        // Nothing to approve or reject before a request
+        assert_noop!(
+            Artists::approve_verification(RuntimeOrigin::root(), 1, None),
+            ArtistsError::<Test>::VerificationNotRequested
+        );
+
+        assert_ok!(Artists::request_verification(
+            RuntimeOrigin::signed(1),
+            evidence.clone()
+        ));
+        System::assert_has_event(
+            Event::<Test>::VerificationRequested {
+                id: 1,
+                deposit: escrow,
+            }
+            .into(),
+        );
+        assert_eq!(escrow_held(1), escrow);
+        assert!(Artists::get_pending_verification(1).is_some());
+
+        assert_noop!(
+            Artists::request_verification(RuntimeOrigin::signed(1), evidence.clone()),
+            ArtistsError::<Test>::VerificationAlreadyRequested
+        );
+
+        // Approving refunds the escrow and verifies the artist
+        assert_noop!(
+            Artists::approve_verification(RuntimeOrigin::signed(2), 1, None),
+            BadOrigin
+        );
+        assert_ok!(Artists::approve_verification(
+            RuntimeOrigin::root(),
+            1,
+            None
+        ));
+        assert!(Artists::get_artist_by_id(1).unwrap().is_verified());
+        assert!(Artists::get_pending_verification(1).is_none());
+        assert_eq!(escrow_held(1), 0);
+        assert_eq!(
+            Balances::total_balance_on_hold(&1),
+            expected_artist_cost(&tester_artist_of::<Test>(1))
+        );
+
+        assert_noop!(
+            Artists::request_verification(RuntimeOrigin::signed(1), evidence.clone()),
+            ArtistsError::<Test>::AlreadyVerified
+        );
+
+        // Rejecting an abusive request slashes the escrow
+        let balance_before = Balances::total_balance(&2);
+        assert_ok!(Artists::request_verification(
+            RuntimeOrigin::signed(2),
+            evidence
+        ));
+        assert_ok!(Artists::reject_verification(
+            RuntimeOrigin::root(),
+            2,
+            DepositPolicy::Slash
+        ));
+        System::assert_last_event(
+            Event::<Test>::VerificationRejected {
+                id: 2,
+                policy: DepositPolicy::Slash,
+                deposit: escrow,
+            }
+            .into(),
+        );
+        assert_eq!(escrow_held(2), 0);
+        assert_eq!(Balances::total_balance(&2), balance_before - escrow);
+        assert!(Artists::get_pending_verification(2).is_none());
+        assert!(!Artists::get_artist_by_id(2).unwrap().is_verified());
+        assert_eq!(
+            Balances::total_balance_on_hold(&2),
+            expected_artist_cost(&tester_artist_of::<Test>(2))
+        );
+    })
+}
+
+#[test]
+fn artist_alias_index_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let alias = artist.alias.clone().unwrap();
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(1),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert_eq!(
+            Artists::get_artist_by_alias(alias.to_vec())
+                .unwrap()
+                .owner(),
+            &1
+        );
+
+        // The alias can't be used by another artist, as its alias or its main name
+        assert_noop!(
+            Artists::register(
+                RuntimeOrigin::signed(2),
+                artist.main_name.clone(),
+                artist.alias.clone(),
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            ),
+            ArtistsError::<Test>::AliasUnavailable
+        );
+        assert_noop!(
+            Artists::register(
+                RuntimeOrigin::signed(2),
+                alias.clone(),
+                None,
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            ),
+            ArtistsError::<Test>::NameUnavailable
+        );
+
+        // Nor be the verified main name of another artist
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(2),
+            to_bounded_alias(String::from("Verified")),
+            None,
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert_ok!(Artists::verify_artist(RuntimeOrigin::root(), 2, None));
+        assert_noop!(
+            Artists::update(
+                RuntimeOrigin::signed(1),
+                UpdatableDataOf::<Test>::Alias(Some(to_bounded_alias(String::from("Verified")))),
+            ),
+            ArtistsError::<Test>::AliasUnavailable
+        );
+
+        // Changing the alias frees the previous one
+        let new_alias = to_bounded_alias(String::from("Night Singer"));
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(1),
+            UpdatableDataOf::<Test>::Alias(Some(new_alias.clone())),
+        ));
+        assert!(Artists::get_artist_by_alias(alias.to_vec()).is_none());
+        assert_eq!(
+            Artists::get_artist_by_alias(new_alias.to_vec())
+                .unwrap()
+                .owner(),
+            &1
+        );
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(2),
+            UpdatableDataOf::<Test>::Alias(Some(alias.clone())),
+        ));
+        assert_ok!(Artists::do_try_state());
+
+        // Unregistering frees the alias
+        assert_ok!(Artists::force_unregister(
+            RuntimeOrigin::root(),
+            1,
+            DepositPolicy::Refund
+        ));
+        assert!(Artists::get_artist_by_alias(new_alias.to_vec()).is_none());
+        assert_ok!(Artists::do_try_state());
+    })
+}
+
+#[test]
+fn migrate_to_v6_works() {
+    use crate::migrations::{
+        v10::MigrateToV10, v11::MigrateToV11, v12::MigrateToV12, v13::MigrateToV13,
+        v6::MigrateToV6, v7::MigrateToV7, v8::MigrateToV8, v9::MigrateToV9,
+    };
+    use crate::migrations::{
+        v15::MigrateToV15, v16::MigrateToV16, v17::MigrateToV17, v18::MigrateToV18,
+        v19::MigrateToV19, v20::MigrateToV20, v21::MigrateToV21,
+    };
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+    use sp_runtime::traits::Identity;
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+
+        for artist_id in 1..=2u64 {
+            assert_ok!(Artists::register(
+                RuntimeOrigin::signed(artist_id),
+                artist.main_name.clone(),
+                tester_artist_of::<Test>(artist_id).alias,
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            ));
+        }
+
+        // Drop the index and store both artists back with the same alias, as allowed before
+        let _ = crate::ArtistAliasIndex::<Test>::clear(u32::MAX, None);
+        for artist_id in 1..=2u64 {
+            let mut stored = Artists::get_artist_by_id(artist_id).unwrap();
+            stored.aliases = tester_artist_of::<Test>(1)
+                .alias
+                .into_iter()
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap();
+            frame_support::storage::unhashed::put(
+                &crate::ArtistOf::<Test>::hashed_key_for(artist_id),
+                &v6_layout_artist(&stored),
+            );
+        }
+        StorageVersion::new(5).put::<Artists>();
+
+        MigrateToV6::<Test>::on_runtime_upgrade();
+        MigrateToV7::<Test>::on_runtime_upgrade();
+        MigrateToV8::<Test>::on_runtime_upgrade();
+        MigrateToV9::<Test>::on_runtime_upgrade();
+        MigrateToV10::<Test>::on_runtime_upgrade();
+        MigrateToV11::<Test>::on_runtime_upgrade();
+        MigrateToV12::<Test>::on_runtime_upgrade();
+        MigrateToV13::<Test>::on_runtime_upgrade();
+        MigrateToV14::<Test>::on_runtime_upgrade();
+        MigrateToV15::<Test, Balances, Identity>::on_runtime_upgrade();
+        MigrateToV16::<Test>::on_runtime_upgrade();
+        MigrateToV17::<Test>::on_runtime_upgrade();
+        MigrateToV18::<Test>::on_runtime_upgrade();
+        MigrateToV19::<Test>::on_runtime_upgrade();
+        MigrateToV20::<Test>::on_runtime_upgrade();
+        MigrateToV21::<Test>::on_runtime_upgrade();
+
+        // The duplicated alias is only indexed once
+        let alias = tester_artist_of::<Test>(1).alias.unwrap();
+        assert_eq!(
+            crate::ArtistAliasIndex::<Test>::iter()
+                .collect::<Vec<_>>()
+                .len(),
+            1
+        );
+        assert!(Artists::get_artist_by_alias(alias.to_vec()).is_some());
+        assert_eq!(Artists::on_chain_storage_version(), 21);
+
+        // The governance is left to rename the duplicate
+        assert!(Artists::do_try_state().is_err());
+        let duplicate = match crate::ArtistAliasIndex::<Test>::get(&alias) {
+            Some(1) => 2,
+            _ => 1,
+        };
+        assert_ok!(Artists::force_update(
+            RuntimeOrigin::root(),
+            duplicate,
+            UpdatableDataOf::<Test>::Alias(tester_artist_of::<Test>(duplicate).alias),
+        ));
+        assert_ok!(Artists::do_try_state());
+    })
+}
+
+#[test]
+fn name_prefix_search_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let names: [&[u8]; 4] = [b"Daemon", b"DAEDALUS", b"Dark", b"Da"];
+
+        for (artist_id, name) in (1u64..).zip(names) {
+            assert_ok!(Artists::register(
+                RuntimeOrigin::signed(artist_id),
+                name.to_vec().try_into().unwrap(),
+                None,
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            ));
+        }
+        let search = |prefix: &[u8], start_after: Option<&u64>, count: u32| {
+            Artists::get_artists_by_name_prefix(prefix.to_vec(), start_after, count)
+        };
+        let ids = |page: Result<(Vec<Artist<Test>>, Option<u64>), DispatchError>| {
+            let mut ids = page.unwrap().0.iter().map(|x| x.owner).collect::<Vec<_>>();
+            ids.sort();
+            ids
+        };
+
+        // Names are compared regardless of their case
+        assert_eq!(ids(search(b"dae", None, 10)), vec![1, 2]);
+        assert_eq!(ids(search(b"DAEM", None, 10)), vec![1]);
+        assert!(ids(search(b"xyz", None, 10)).is_empty());
+
+        // Prefixes shorter than the indexed ones are rejected
+        assert_eq!(
+            search(b"da", None, 10),
+            Err(ArtistsError::<Test>::PrefixTooShort.into())
+        );
+
+        // The bucket is paged through
+        let (first, cursor) = search(b"dae", None, 1).unwrap();
+        assert_eq!(first.len(), 1);
+        assert_eq!(cursor, Some(first[0].owner));
+        let (next, cursor) = search(b"dae", Some(&first[0].owner), 10).unwrap();
+        assert_eq!(next.len(), 1);
+        assert_ne!(next[0].owner, first[0].owner);
+        assert_eq!(cursor, None);
+
+        // Never examining more than `count` artists of the bucket, even without any match
+        let (mut found, mut cursor) = (Vec::new(), None);
+        loop {
+            let (page, next) = search(b"daem", cursor.as_ref(), 1).unwrap();
+            assert!(page.len() <= 1);
+            found.extend(page.iter().map(|x| x.owner));
+            match next {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+        assert_eq!(found, vec![1]);
+
+        // The index follows the renames and unregistrations
+        assert_ok!(Artists::rename(
+            RuntimeOrigin::signed(3),
+            b"Daedric".to_vec().try_into().unwrap()
+        ));
+        assert_eq!(ids(search(b"dae", None, 10)), vec![1, 2, 3]);
+        assert_ok!(Artists::force_unregister(
+            RuntimeOrigin::root(),
+            1,
+            DepositPolicy::Refund
+        ));
+        assert_eq!(ids(search(b"dae", None, 10)), vec![2, 3]);
+        assert_ok!(Artists::do_try_state());
+    })
+}
+
+#[test]
+fn poke_deposit_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        AbuseSurcharge::set(7);
+
+        assert_noop!(
+            Artists::poke_deposit(RuntimeOrigin::signed(2), artist_id),
+            ArtistsError::<Test>::NotRegistered
+        );
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        AbuseSurcharge::set(0);
+        let old_deposit = expected_artist_cost(&artist) + 7;
+        assert_eq!(
+            Artists::get_artist_by_id(artist_id).unwrap().deposit,
+            old_deposit
+        );
+
+        // Anyone can reprice the deposit, the surcharge being kept
+        BaseDeposit::set(20);
+        ByteDeposit::set(2);
+        let new_deposit = expected_artist_cost(&artist) + 7;
+        assert_ok!(Artists::poke_deposit(RuntimeOrigin::signed(2), artist_id));
+        System::assert_last_event(
+            Event::<Test>::DepositUpdated {
+                id: artist_id,
+                old_deposit,
+                new_deposit,
+            }
+            .into(),
+        );
+        assert_eq!(
+            Artists::get_artist_by_id(artist_id).unwrap().deposit,
+            new_deposit
+        );
+        assert_eq!(Balances::total_balance_on_hold(&artist_id), new_deposit);
+        assert_ok!(Artists::do_try_state());
+
+        // The base deposit gets released as well
+        BaseDeposit::set(1);
+        ByteDeposit::set(0);
+        assert_ok!(Artists::poke_deposit(RuntimeOrigin::signed(2), artist_id));
+        assert_eq!(Artists::get_artist_by_id(artist_id).unwrap().deposit, 8);
+        assert_eq!(Balances::total_balance_on_hold(&artist_id), 8);
+        assert_ok!(Artists::do_try_state());
+
+        // Not while a verification request escrows a deposit
+        assert_ok!(Artists::request_verification(
+            RuntimeOrigin::signed(artist_id),
+            Default::default()
+        ));
+        assert_noop!(
+            Artists::poke_deposit(RuntimeOrigin::signed(2), artist_id),
+            ArtistsError::<Test>::VerificationAlreadyRequested
+        );
+    })
+}
+
+#[cfg(feature = "evm-attestation")]
+#[test]
+fn reap_expired_works() {
+    use frame_support::traits::Hooks;
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let reaper = 2u64;
+
+        assert_noop!(
+            Artists::reap_expired(RuntimeOrigin::signed(reaper), artist_id),
+            ArtistsError::<Test>::NotRegistered
+        );
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert_eq!(Artists::get_unverified_expiry(&artist_id), Some(501));
+
+        System::set_block_number(500);
+        assert_noop!(
+            Artists::reap_expired(RuntimeOrigin::signed(reaper), artist_id),
+            ArtistsError::<Test>::NotExpired
+        );
+
+        // The artist stays unverified past its lifetime, anyone can reap it for a reward
+        System::set_block_number(501);
+        let deposit = expected_artist_cost(&artist);
+        let reward = deposit / 10;
+        let old_artist_balance = Balances::free_balance(&artist_id);
+        let old_reaper_balance = Balances::free_balance(&reaper);
+        assert_ok!(Artists::reap_expired(
+            RuntimeOrigin::signed(reaper),
+            artist_id
+        ));
+        System::assert_last_event(
+            Event::<Test>::ArtistReaped {
+                id: artist_id,
+                reaper,
+                reward,
+                deposit: deposit - reward,
+                depositor: artist_id,
+            }
+            .into(),
+        );
+        assert!(Artists::get_artist_by_id(artist_id).is_none());
+        assert_eq!(Balances::total_balance_on_hold(&artist_id), 0);
+        assert_eq!(
+            Balances::free_balance(&artist_id),
+            old_artist_balance + deposit - reward
+        );
+        assert_eq!(Balances::free_balance(&reaper), old_reaper_balance + reward);
+        assert_ok!(Artists::do_try_state());
+
+        // Verified artists don't expire
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert_ok!(Artists::verify_artist(
+            RuntimeOrigin::root(),
+            artist_id,
+            None
+        ));
+        System::set_block_number(2000);
+        assert_eq!(Artists::get_unverified_expiry(&artist_id), None);
+        assert_noop!(
+            Artists::reap_expired(RuntimeOrigin::signed(reaper), artist_id),
+            ArtistsError::<Test>::NotExpired
+        );
+
+        // Even once their verification expired, as they got verified within their lifetime
+        Artists::on_idle(System::block_number(), Weight::MAX);
+        System::assert_has_event(
+            Event::<Test>::VerificationExpired {
+                id: artist_id,
+                verified_at: 501,
+                requeued: true,
+            }
+            .into(),
+        );
+        assert_eq!(
+            Artists::get_artist_by_id(artist_id).unwrap().verified_at,
+            None
+        );
+        assert_eq!(Artists::get_unverified_expiry(&artist_id), None);
+        assert_noop!(
+            Artists::reap_expired(RuntimeOrigin::signed(reaper), artist_id),
+            ArtistsError::<Test>::NotExpired
+        );
+        assert_ok!(Artists::do_try_state());
+
+        // Nor do the artists of an instance without lifetime
+        assert_ok!(Producers::register(
+            RuntimeOrigin::signed(reaper),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        System::set_block_number(10_000);
+        assert_noop!(
+            Producers::reap_expired(RuntimeOrigin::signed(artist_id), reaper),
+            ArtistsError::<Test, frame_support::instances::Instance1>::NotExpired
+        );
+    });
+}
+
+#[test]
+fn verification_attestations_works() {
+    use crate::attestation::{VerificationAttestation, VerificationAttestations};
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(3);
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let kyc_evidence_hash = Some(<Test as frame_system::Config>::Hashing::hash(b"kyc"));
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert!(VerificationAttestations::<Test>::get(artist_id).is_none());
+
+        assert_ok!(Artists::verify_artist(
+            RuntimeOrigin::root(),
+            artist_id,
+            None
+        ));
+        assert_ok!(Artists::update_kyc_evidence(
+            RuntimeOrigin::root(),
+            artist_id,
+            kyc_evidence_hash
+        ));
+        assert_eq!(
+            VerificationAttestations::<Test>::get(artist_id),
+            Some(VerificationAttestation {
+                verified_at: 3,
+                kyc_evidence_hash,
+            })
+        );
+        assert_ok!(Artists::do_try_state());
+
+        // The attestation is revoked with the artist
+        assert_ok!(Artists::force_unregister(
+            RuntimeOrigin::root(),
+            artist_id,
+            DepositPolicy::Refund
+        ));
+        assert!(VerificationAttestations::<Test>::get(artist_id).is_none());
+    })
+}
+
+#[cfg(feature = "precompile")]
+#[test]
+fn precompile_encoding_works() {
+    use crate::precompile::{self, ArtistSummary, WORD_LEN};
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_eq!(
+            precompile::summary_of::<Test, ()>(&artist_id),
+            ArtistSummary::default()
+        );
+        assert_eq!(
+            precompile::is_artist::<Test, ()>(&artist_id),
+            vec![0u8; WORD_LEN]
+        );
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        let mut word = vec![0u8; WORD_LEN];
+        word[WORD_LEN - 1] = 1;
+        assert_eq!(precompile::is_artist::<Test, ()>(&artist_id), word);
+        assert_eq!(
+            precompile::is_verified::<Test, ()>(&artist_id),
+            vec![0u8; WORD_LEN]
+        );
+
+        // The offset of the name, its length and its bytes padded to a word
+        let main_name = precompile::main_name::<Test, ()>(&artist_id);
+        assert_eq!(main_name.len(), 3 * WORD_LEN);
+        assert_eq!(main_name[WORD_LEN - 1], WORD_LEN as u8);
+        assert_eq!(main_name[2 * WORD_LEN - 1], 6);
+        assert_eq!(&main_name[2 * WORD_LEN..2 * WORD_LEN + 6], b"Tester");
+        assert!(main_name[2 * WORD_LEN + 6..].iter().all(|x| *x == 0));
+
+        let summary = precompile::summary_of::<Test, ()>(&artist_id);
+        let house = StoredGenre::from(artist.genres[0]);
+        assert!(summary.is_artist);
+        assert_eq!(summary.main_name, b"Tester".to_vec());
+        assert_eq!(summary.genre_ids, vec![precompile::genre_id(&house)]);
+        assert_ne!(precompile::genre_id(&house), 0);
+
+        // 5 head words, the name in 2 words and the genres array in 2 words
+        assert_eq!(
+            precompile::artist_summary::<Test, ()>(&artist_id),
+            summary.abi_encode()
+        );
+        assert_eq!(summary.abi_encode().len(), 9 * WORD_LEN);
+    })
+}
+
+#[test]
+fn migrate_to_v7_works() {
+    use crate::migrations::v7::MigrateToV7;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // Store the artist back without its moderation status
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        frame_support::storage::unhashed::put(
+            &crate::ArtistOf::<Test>::hashed_key_for(artist_id),
+            &v6_layout_artist(&stored),
+        );
+        StorageVersion::new(6).put::<Artists>();
+
+        MigrateToV7::<Test>::on_runtime_upgrade();
+
+        let migrated = crate::migrations::v8::ArtistOf::<Test>::get(artist_id).unwrap();
+        assert_eq!(migrated.status, ArtistStatus::Active);
+        assert_eq!(migrated.encode(), v7_layout_artist(&stored).encode());
+        assert_eq!(Artists::on_chain_storage_version(), 7);
+    })
+}
+
+#[test]
+fn migrate_to_v8_works() {
+    use crate::migrations::v8::MigrateToV8;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // Store the artist back without its depositor
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        frame_support::storage::unhashed::put(
+            &crate::ArtistOf::<Test>::hashed_key_for(artist_id),
+            &v7_layout_artist(&stored),
+        );
+        StorageVersion::new(7).put::<Artists>();
+
+        MigrateToV8::<Test>::on_runtime_upgrade();
+
+        let migrated = crate::migrations::v9::ArtistOf::<Test>::get(artist_id).unwrap();
+        assert_eq!(migrated.depositor, artist_id);
+        assert_eq!(migrated.encode(), v8_layout_artist(&stored).encode());
+        assert_eq!(Artists::on_chain_storage_version(), 8);
+    })
+}
+
+#[test]
+fn migrate_to_v9_works() {
+    use crate::migrations::v9::MigrateToV9;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // Store the artist back without its raw description
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        frame_support::storage::unhashed::put(
+            &crate::ArtistOf::<Test>::hashed_key_for(artist_id),
+            &v8_layout_artist(&stored),
+        );
+        StorageVersion::new(8).put::<Artists>();
+
+        MigrateToV9::<Test>::on_runtime_upgrade();
+
+        let migrated = crate::migrations::v10::ArtistOf::<Test>::get(artist_id).unwrap();
+        assert!(migrated.raw_description.is_none());
+        assert_eq!(migrated.encode(), v9_layout_artist(&stored).encode());
+        assert_eq!(Artists::on_chain_storage_version(), 9);
+    })
+}
+
+#[test]
+fn migrate_to_v10_works() {
+    use crate::migrations::v10::MigrateToV10;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // Store the artist back without its royalty splits
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        frame_support::storage::unhashed::put(
+            &crate::ArtistOf::<Test>::hashed_key_for(artist_id),
+            &v9_layout_artist(&stored),
+        );
+        StorageVersion::new(9).put::<Artists>();
+
+        MigrateToV10::<Test>::on_runtime_upgrade();
+
+        let migrated = crate::migrations::v11::ArtistOf::<Test>::get(artist_id).unwrap();
+        assert!(migrated.royalty_splits.is_empty());
+        assert_eq!(migrated.encode(), v10_layout_artist(&stored).encode());
+        assert_eq!(Artists::on_chain_storage_version(), 10);
+    })
+}
+
+#[test]
+fn migrate_to_v11_works() {
+    use crate::migrations::v11::MigrateToV11;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // Store the artist back without its kind and members
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        frame_support::storage::unhashed::put(
+            &crate::ArtistOf::<Test>::hashed_key_for(artist_id),
+            &v10_layout_artist(&stored),
+        );
+        StorageVersion::new(10).put::<Artists>();
+
+        MigrateToV11::<Test>::on_runtime_upgrade();
+
+        let migrated = crate::migrations::v12::ArtistOf::<Test>::get(artist_id).unwrap();
+        assert_eq!(migrated.kind, ArtistKind::Solo);
+        assert!(migrated.members.is_empty());
+        assert_eq!(migrated.encode(), v11_layout_artist(&stored).encode());
+        assert_eq!(Artists::on_chain_storage_version(), 11);
+    })
+}
+
+#[test]
+fn migrate_to_v12_works() {
+    use crate::migrations::v12::MigrateToV12;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let mut artist = tester_artist::<Test>();
+        artist.assets = vec![
+            (AssetType::Image, b"asset0".to_vec()),
+            (AssetType::Audio, b"asset1".to_vec()),
+        ]
+        .try_into()
+        .unwrap();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // Store the artist back with its bare fingerprints
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        frame_support::storage::unhashed::put(
+            &crate::ArtistOf::<Test>::hashed_key_for(artist_id),
+            &v11_layout_artist(&stored),
+        );
+        StorageVersion::new(11).put::<Artists>();
+
+        MigrateToV12::<Test>::on_runtime_upgrade();
+
+        let migrated = crate::migrations::v13::ArtistOf::<Test>::get(artist_id).unwrap();
+        assert_eq!(
+            migrated.description,
+            Some(Fingerprint::Native(
+                <Test as frame_system::Config>::Hashing::hash(&artist.description.unwrap())
+            ))
+        );
+        assert_eq!(
+            migrated.assets[1].hash,
+            Fingerprint::Native(<Test as frame_system::Config>::Hashing::hash(b"asset1"))
+        );
+        assert_eq!(migrated.encode(), v12_layout_artist(&stored).encode());
+        assert_eq!(Artists::on_chain_storage_version(), 12);
+    })
+}
+
+#[test]
+fn migrate_to_v13_works() {
+    use crate::migrations::v13::MigrateToV13;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // Store the artist back without its updates tracking
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        frame_support::storage::unhashed::put(
+            &crate::ArtistOf::<Test>::hashed_key_for(artist_id),
+            &v12_layout_artist(&stored),
+        );
+        StorageVersion::new(12).put::<Artists>();
+        System::set_block_number(5);
+
+        MigrateToV13::<Test>::on_runtime_upgrade();
+
+        let migrated = crate::migrations::v14::ArtistOf::<Test>::get(artist_id).unwrap();
+        assert_eq!(migrated.last_updated_at, stored.registered_at);
+        assert_eq!(migrated.update_nonce, 0);
+        assert_eq!(migrated.encode(), v13_layout_artist(&stored).encode());
+        assert_eq!(Artists::on_chain_storage_version(), 13);
+    })
+}
+
+#[test]
+fn migrate_to_v14_works() {
+    use crate::migrations::v14::{ArtistsOfGenre as V13ArtistsOfGenre, MigrateToV14};
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // Store the artist back with its registry genres, indexed under them
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        frame_support::storage::unhashed::put(
+            &crate::ArtistOf::<Test>::hashed_key_for(artist_id),
+            &v13_layout_artist(&stored),
+        );
+        let _ = crate::ArtistsOfGenre::<Test>::clear(u32::MAX, None);
+        V13ArtistsOfGenre::<Test>::insert(artist.genres[0], artist_id, ());
+        StorageVersion::new(13).put::<Artists>();
+
+        MigrateToV14::<Test>::on_runtime_upgrade();
+
+        let migrated = crate::migrations::v16::ArtistOf::<Test>::get(artist_id).unwrap();
+        assert_eq!(migrated.genres[0].genre(), Some(artist.genres[0]));
+        assert_eq!(migrated.encode(), v15_layout_artist(&stored).encode());
+        assert!(crate::ArtistsOfGenre::<Test>::contains_key(
+            StoredGenre::from(artist.genres[0]),
+            artist_id
+        ));
+        assert_eq!(Artists::on_chain_storage_version(), 14);
+    })
+}
+
+#[test]
+fn migrate_to_v15_works() {
+    use crate::migrations::v15::MigrateToV15;
+    use frame_support::traits::fungible::{InspectHold, MutateHold};
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+    use sp_runtime::traits::Convert;
+
+    /// A deposit asset worth twice the native currency.
+    struct Halve;
+    impl Convert<u128, u128> for Halve {
+        fn convert(amount: u128) -> u128 {
+            amount / 2
+        }
+    }
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // Simulate a deposit still held under a legacy reason
+        let legacy_deposit = 10u128;
+        assert_ok!(Balances::hold(
+            &RuntimeHoldReason::Artists(HoldReason::ArtistName),
+            &artist_id,
+            legacy_deposit
+        ));
+        let mut stored = Artists::get_artist_by_id(artist_id).unwrap();
+        stored.deposit = stored.deposit.saturating_add(legacy_deposit);
+        frame_support::storage::unhashed::put(
+            &crate::ArtistOf::<Test>::hashed_key_for(artist_id),
+            &v15_layout_artist(&stored),
+        );
+        StorageVersion::new(14).put::<Artists>();
+
+        MigrateToV15::<Test, Balances, Halve>::on_runtime_upgrade();
+
+        let base_deposit: BalanceOf<Test> = <Test as Config>::BaseDeposit::get();
+        let data_deposit = expected_artist_cost(&artist) - base_deposit;
+        let migrated = crate::migrations::v16::ArtistOf::<Test>::get(artist_id).unwrap();
+        assert_eq!(
+            migrated.deposit,
+            base_deposit / 2 + data_deposit / 2 + legacy_deposit / 2
+        );
+        assert_eq!(
+            Balances::balance_on_hold(
+                &RuntimeHoldReason::Artists(HoldReason::ArtistName),
+                &artist_id
+            ),
+            0
+        );
+        assert_eq!(
+            Balances::total_balance_on_hold(&artist_id),
+            migrated.deposit
+        );
+        assert_eq!(Artists::on_chain_storage_version(), 15);
+    })
+}
+
+#[test]
+fn migrate_to_v16_works() {
+    use crate::migrations::v16::MigrateToV16;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // Store the artist back without its main genre
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        frame_support::storage::unhashed::put(
+            &crate::ArtistOf::<Test>::hashed_key_for(artist_id),
+            &v15_layout_artist(&stored),
+        );
+        StorageVersion::new(15).put::<Artists>();
+
+        MigrateToV16::<Test>::on_runtime_upgrade();
+
+        let migrated = crate::migrations::v17::ArtistOf::<Test>::get(artist_id).unwrap();
+        assert_eq!(migrated.main_genre, None);
+        assert_eq!(migrated.encode(), v16_layout_artist(&stored).encode());
+        assert_eq!(Artists::on_chain_storage_version(), 16);
+    })
+}
+
+#[test]
+fn migrate_to_v17_works() {
+    use crate::migrations::v17::MigrateToV17;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+
+        for artist_id in 1..=2u64 {
+            assert_ok!(Artists::register(
+                RuntimeOrigin::signed(artist_id),
+                artist.main_name.clone(),
+                if artist_id == 1 {
+                    artist.alias.clone()
+                } else {
+                    None
+                },
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            ));
+        }
+
+        // Store the artists back with their single optional alias
+        let stored = [1u64, 2].map(|id| Artists::get_artist_by_id(id).unwrap());
+        for (artist_id, artist) in (1u64..).zip(stored.iter()) {
+            frame_support::storage::unhashed::put(
+                &crate::ArtistOf::<Test>::hashed_key_for(artist_id),
+                &v16_layout_artist(artist),
+            );
+        }
+        StorageVersion::new(16).put::<Artists>();
+
+        MigrateToV17::<Test>::on_runtime_upgrade();
+
+        // The alias becomes the single one, holding the same deposit
+        let migrated = crate::migrations::v18::ArtistOf::<Test>::get(1).unwrap();
+        assert_eq!(
+            migrated.aliases.to_vec(),
+            vec![artist.alias.clone().unwrap()]
+        );
+        assert_eq!(migrated.encode(), v17_layout_artist(&stored[0]).encode());
+        let migrated = crate::migrations::v18::ArtistOf::<Test>::get(2).unwrap();
+        assert!(migrated.aliases.is_empty());
+        assert_eq!(migrated.encode(), v17_layout_artist(&stored[1]).encode());
+        assert_eq!(Artists::on_chain_storage_version(), 17);
+    })
+}
+
+#[test]
+fn migrate_to_v18_works() {
+    use crate::migrations::v18::MigrateToV18;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // Store the artist back without its claimed works
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        frame_support::storage::unhashed::put(
+            &crate::ArtistOf::<Test>::hashed_key_for(artist_id),
+            &v17_layout_artist(&stored),
+        );
+        StorageVersion::new(17).put::<Artists>();
+
+        MigrateToV18::<Test>::on_runtime_upgrade();
+
+        // The artist claims no work, holding the same deposit
+        let migrated = crate::migrations::v20::ArtistOf::<Test>::get(artist_id).unwrap();
+        assert!(migrated.works.is_empty());
+        assert_eq!(migrated.encode(), v19_layout_artist(&stored).encode());
+        assert_eq!(Artists::on_chain_storage_version(), 18);
+    })
+}
+
+#[test]
+fn migrate_to_v19_works() {
+    use crate::migrations::v19::MigrateToV19;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert_ok!(Artists::verify_artist(
+            RuntimeOrigin::root(),
+            artist_id,
+            None
+        ));
+
+        // Drop the confusable names index, as before the version 19
+        let _ = crate::ConfusableNameOf::<Test>::clear(u32::MAX, None);
+        StorageVersion::new(18).put::<Artists>();
+
+        MigrateToV19::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            crate::ConfusableNameOf::<Test>::iter_values().collect::<Vec<_>>(),
+            vec![artist_id]
+        );
+        assert_noop!(
+            Artists::register(
+                RuntimeOrigin::signed(2),
+                b"TESTER".to_vec().try_into().unwrap(),
+                None,
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            ),
+            ArtistsError::<Test>::NameConfusable
+        );
+        assert_eq!(Artists::on_chain_storage_version(), 19);
+        assert_ok!(Artists::do_try_state());
+    })
+}
+
+#[test]
+fn migrate_to_v20_works() {
+    use crate::migrations::v20::MigrateToV20;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        AbuseSurcharge::set(7);
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert_ok!(Artists::request_verification(
+            RuntimeOrigin::signed(artist_id),
+            vec![H256::repeat_byte(1)].try_into().unwrap()
+        ));
+
+        // Store the artist back without its tracked surcharge
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        assert_eq!(stored.surcharge, 7);
+        frame_support::storage::unhashed::put(
+            &crate::ArtistOf::<Test>::hashed_key_for(artist_id),
+            &v19_layout_artist(&stored),
+        );
+        StorageVersion::new(19).put::<Artists>();
+
+        MigrateToV20::<Test>::on_runtime_upgrade();
+
+        // The pending verification deposits aren't taken for a surcharge
+        let migrated = Artists::get_artist_by_id(artist_id).unwrap();
+        assert_eq!(migrated, stored);
+        assert_eq!(Artists::on_chain_storage_version(), 20);
+        assert_ok!(Artists::do_try_state());
+    })
+}
+
+#[test]
+fn migrate_to_v21_works() {
+    use crate::migrations::v21::MigrateToV21;
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+
+        for artist_id in 1..=2u64 {
+            assert_ok!(Artists::register(
+                RuntimeOrigin::signed(artist_id),
+                artist.main_name.clone(),
+                tester_artist_of::<Test>(artist_id).alias,
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            ));
+        }
+
+        // Drop the name prefix index, as before the version 21
+        let _ = crate::ArtistsOfNamePrefix::<Test>::clear(u32::MAX, None);
+        StorageVersion::new(20).put::<Artists>();
+        assert!(Artists::do_try_state().is_err());
+
+        MigrateToV21::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            Artists::get_artists_by_name_prefix(b"tes".to_vec(), None, 10)
+                .unwrap()
+                .0
+                .len(),
+            2
+        );
+        assert_eq!(Artists::on_chain_storage_version(), 21);
+        assert_ok!(Artists::do_try_state());
+    })
+}
+
+#[test]
+fn raw_description_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        let hash_cost = <Test as Config>::ByteDeposit::get()
+            .saturating_mul(<Test as frame_system::Config>::Hash::max_encoded_len() as u128);
+        let raw_cost = |description: &[u8]| -> BalanceOf<Test> {
+            <Test as Config>::ByteDeposit::get()
+                .saturating_mul(description.to_vec().encoded_size() as u128)
+        };
+        let set_raw = |description: Option<&[u8]>| {
+            Artists::update(
+                RuntimeOrigin::signed(artist_id),
+                UpdatableDataOf::<Test>::RawDescription(description.map(|x| x.to_vec())),
+            )
+        };
+        let stored = || Artists::get_artist_by_id(artist_id).unwrap();
+
+        // The raw description replaces the hashed one and its deposit
+        assert_ok!(set_raw(Some(b"A short on-chain bio.")));
+        assert!(stored().description().is_none());
+        assert_eq!(
+            stored().raw_description().as_ref().map(|x| x.to_vec()),
+            Some(b"A short on-chain bio.".to_vec())
+        );
+        assert_eq!(
+            *stored().deposit(),
+            expected_artist_cost(&artist) - hash_cost + raw_cost(b"A short on-chain bio.")
+        );
+        assert_ok!(Artists::do_try_state());
+
+        // Long texts keep using the hashed description
+        let max_len = <Test as Config>::MaxDescriptionLen::get() as usize;
+        assert_noop!(
+            set_raw(Some(&vec![b'a'; max_len + 1])),
+            ArtistsError::<Test>::DescriptionTooLong
+        );
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Description(Some(vec![b'a'; max_len + 1])),
+        ));
+        assert!(stored().raw_description().is_none());
+        assert!(stored().description().is_some());
+        assert_eq!(*stored().deposit(), expected_artist_cost(&artist));
+
+        // Up to the hashed descriptions bound
+        let max_hashed_len = <Test as Config>::MaxHashedDescriptionLen::get() as usize;
+        assert_noop!(
+            Artists::update(
+                RuntimeOrigin::signed(artist_id),
+                UpdatableDataOf::<Test>::Description(Some(vec![b'a'; max_hashed_len + 1])),
+            ),
+            ArtistsError::<Test>::DescriptionTooLong
+        );
+
+        // Removing the description releases its deposit whatever the way it was stored
+        assert_ok!(set_raw(None));
+        assert!(stored().description().is_none());
+        assert_eq!(
+            *stored().deposit(),
+            expected_artist_cost(&artist) - hash_cost
+        );
+        assert_ok!(Artists::do_try_state());
+    })
+}
+
+#[test]
+fn artist_suspension_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let delegate = 2u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert_ok!(Artists::add_delegate(
+            RuntimeOrigin::signed(artist_id),
+            delegate
+        ));
+
+        assert_noop!(
+            Artists::suspend_artist(RuntimeOrigin::signed(artist_id), artist_id, false),
+            BadOrigin
+        );
+        assert_ok!(Artists::suspend_artist(
+            RuntimeOrigin::root(),
+            artist_id,
+            false
+        ));
+        System::assert_last_event(
+            Event::<Test>::ArtistStatusChanged {
+                id: artist_id,
+                status: ArtistStatus::Suspended,
+            }
+            .into(),
+        );
+        assert!(<Artists as ArtistsInspect<u64>>::is_suspended(&artist_id));
+
+        // A suspended artist can't be updated nor get new contracts
+        assert_noop!(
+            Artists::update(
+                RuntimeOrigin::signed(artist_id),
+                UpdatableDataOf::<Test>::Alias(None),
+            ),
+            ArtistsError::<Test>::ArtistSuspended
+        );
+        assert_noop!(
+            Artists::update_as_delegate(
+                RuntimeOrigin::signed(delegate),
+                artist_id,
+                UpdatableDataOf::<Test>::Alias(None),
+            ),
+            ArtistsError::<Test>::ArtistSuspended
+        );
+        assert_noop!(
+            Artists::link_contract(&artist_id, 42),
+            ArtistsError::<Test>::ArtistSuspended
+        );
+
+        assert_ok!(Artists::reinstate_artist(RuntimeOrigin::root(), artist_id));
+        assert!(!<Artists as ArtistsInspect<u64>>::is_suspended(&artist_id));
+        assert_ok!(Artists::link_contract(&artist_id, 42));
+
+        // A banned artist can't be reinstated
+        assert_ok!(Artists::suspend_artist(
+            RuntimeOrigin::root(),
+            artist_id,
+            true
+        ));
+        assert!(<Artists as ArtistsInspect<u64>>::is_suspended(&artist_id));
+        assert_noop!(
+            Artists::reinstate_artist(RuntimeOrigin::root(), artist_id),
+            ArtistsError::<Test>::ArtistBanned
+        );
+    })
+}
+
+#[test]
+fn artist_export_and_restore_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+
+        assert_noop!(
+            Artists::export_artist(RuntimeOrigin::signed(artist_id), artist_id),
+            BadOrigin
+        );
+        assert_ok!(Artists::export_artist(RuntimeOrigin::root(), artist_id));
+        let record = ArtistRecord::new(stored.clone());
+        System::assert_last_event(
+            Event::<Test>::ArtistExported {
+                id: artist_id,
+                record: record.clone(),
+            }
+            .into(),
+        );
+
+        // A faulty migration wipes the artist genres, breaking the genres index
+        crate::ArtistOf::<Test>::mutate(artist_id, |maybe_artist| {
+            maybe_artist.as_mut().unwrap().genres = Default::default();
+        });
+        let _ = crate::ArtistsOfGenre::<Test>::clear(u32::MAX, None);
+
+        let mut stale_record = record.clone();
+        stale_record.storage_version -= 1;
+        assert_noop!(
+            Artists::force_restore_artist(RuntimeOrigin::root(), stale_record),
+            ArtistsError::<Test>::RecordVersionMismatch
+        );
+        assert_noop!(
+            Artists::force_restore_artist(RuntimeOrigin::signed(artist_id), record.clone()),
+            BadOrigin
+        );
+
+        assert_ok!(Artists::force_restore_artist(RuntimeOrigin::root(), record));
+        System::assert_last_event(Event::<Test>::ArtistRestored { id: artist_id }.into());
+        assert_eq!(Artists::get_artist_by_id(artist_id).unwrap(), stored);
+        assert_eq!(
+            Artists::get_artists_by_genre(stored.genres[0].genre().unwrap()),
+            vec![stored.clone()]
+        );
+        assert_ok!(Artists::do_try_state());
+    })
+}
+
+#[test]
+fn genesis_profile_claim_works() {
+    use sp_runtime::testing::TestSignature;
+
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(3, 500), (4, 500), (10, 500), (11, 500), (12, 500)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    crate::GenesisConfig::<Test> {
+        artists: vec![
+            (
+                10,
+                b"Founder".to_vec(),
+                Some(b"The Founder".to_vec()),
+                vec![MusicGenre::Electronic(Some(ElectronicSubtype::House))],
+                None,
+                vec![],
+                true,
+            ),
+            (11, b"Legend".to_vec(), None, vec![], None, vec![], false),
+            (12, b"Ghost".to_vec(), None, vec![], None, vec![], false),
+        ],
+        waive_deposits: false,
+        claimable: vec![10, 11, 12],
+        phantom: Default::default(),
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    sp_io::TestExternalities::from(t).execute_with(|| {
+        System::set_block_number(1);
+        let voucher = |profile: u64, claimant: u64| {
+            TestSignature(
+                ClaimAuthority::get(),
+                Artists::claim_voucher_payload(&profile, &claimant),
+            )
+        };
+        let deposit = *Artists::get_artist_by_id(10).unwrap().deposit();
+
+        // Only a voucher of the claim authority for this claimant is accepted
+        assert_noop!(
+            Artists::claim_profile(
+                RuntimeOrigin::signed(3),
+                10,
+                TestSignature(98, Artists::claim_voucher_payload(&10, &3)),
+            ),
+            ArtistsError::<Test>::InvalidVoucher
+        );
+        assert_noop!(
+            Artists::claim_profile(RuntimeOrigin::signed(3), 10, voucher(10, 4)),
+            ArtistsError::<Test>::InvalidVoucher
+        );
+        assert_noop!(
+            Artists::claim_profile(RuntimeOrigin::signed(3), 1, voucher(1, 3)),
+            ArtistsError::<Test>::NotClaimable
+        );
+
+        assert_ok!(Artists::claim_profile(
+            RuntimeOrigin::signed(3),
+            10,
+            voucher(10, 3)
+        ));
+        System::assert_last_event(
+            Event::<Test>::ProfileClaimed {
+                profile: 10,
+                claimant: 3,
+            }
+            .into(),
+        );
+
+        // The claimant took over the profile, its indexes and its deposit
+        assert!(Artists::get_artist_by_id(10).is_none());
+        let claimed = Artists::get_artist_by_id(3).unwrap();
+        assert_eq!(claimed.owner(), &3);
+        assert_eq!(
+            Artists::get_artist_by_name(b"Founder".to_vec())
+                .unwrap()
+                .owner(),
+            &3
+        );
+        assert_eq!(
+            Artists::get_artist_by_alias(b"The Founder".to_vec())
+                .unwrap()
+                .owner(),
+            &3
+        );
+        assert_eq!(Balances::total_balance_on_hold(&3), deposit);
+        assert_eq!(Balances::total_balance_on_hold(&10), 0);
+        assert!(!crate::ClaimableProfiles::<Test>::contains_key(10));
+        assert_noop!(
+            Artists::claim_profile(RuntimeOrigin::signed(4), 10, voucher(10, 4)),
+            ArtistsError::<Test>::NotClaimable
+        );
+
+        // The governance can hand a profile over too
+        assert_noop!(
+            Artists::approve_profile_claim(RuntimeOrigin::signed(4), 11, 4),
+            BadOrigin
+        );
+        assert_noop!(
+            Artists::approve_profile_claim(RuntimeOrigin::root(), 11, 3),
+            ArtistsError::<Test>::AlreadyRegistered
+        );
+        assert_ok!(Artists::approve_profile_claim(RuntimeOrigin::root(), 11, 4));
+        assert_eq!(Artists::get_artist_by_id(4).unwrap().owner(), &4);
+
+        // Unclaimed profiles are released once their claim window passed
+        assert_noop!(
+            Artists::release_expired_profile(RuntimeOrigin::signed(3), 12),
+            ArtistsError::<Test>::ClaimWindowOpen
+        );
+        System::set_block_number(<Test as Config>::ClaimWindow::get() + 1);
+        assert_noop!(
+            Artists::claim_profile(RuntimeOrigin::signed(5), 12, voucher(12, 5)),
+            ArtistsError::<Test>::ClaimExpired
+        );
+
+        let ghost_deposit = *Artists::get_artist_by_id(12).unwrap().deposit();
+        assert_ok!(Artists::release_expired_profile(
+            RuntimeOrigin::signed(3),
+            12
+        ));
+        System::assert_last_event(
+            Event::<Test>::ProfileReleased {
+                profile: 12,
+                deposit: ghost_deposit,
+            }
+            .into(),
+        );
+        assert!(Artists::get_artist_by_id(12).is_none());
+        assert_eq!(Balances::free_balance(&12), 500);
+        assert_ok!(Artists::do_try_state());
+    });
+}
+
+#[test]
+fn transfer_ownership_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let new_owner = 2u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(3),
+            b"Other".to_vec().try_into().unwrap(),
+            None,
+            Default::default(),
+            None,
+            Default::default(),
+        ));
+        let deposit = *Artists::get_artist_by_id(artist_id).unwrap().deposit();
+
+        // Only artists offer their profile, and never to another artist
+        assert_noop!(
+            Artists::transfer_ownership(RuntimeOrigin::signed(4), new_owner),
+            ArtistsError::<Test>::NotRegistered
+        );
+        assert_noop!(
+            Artists::transfer_ownership(RuntimeOrigin::signed(artist_id), 3),
+            ArtistsError::<Test>::AlreadyRegistered
+        );
+
+        assert_ok!(Artists::transfer_ownership(
+            RuntimeOrigin::signed(artist_id),
+            new_owner
+        ));
+        System::assert_last_event(
+            Event::<Test>::OwnershipOffered {
+                id: artist_id,
+                new_owner,
+            }
+            .into(),
+        );
+
+        // Only the offered account takes the profile over
+        assert_noop!(
+            Artists::accept_ownership(RuntimeOrigin::signed(4), artist_id),
+            ArtistsError::<Test>::NoOwnershipOffer
+        );
+        assert_ok!(Artists::accept_ownership(
+            RuntimeOrigin::signed(new_owner),
+            artist_id
+        ));
+        System::assert_last_event(
+            Event::<Test>::OwnershipTransferred {
+                from: artist_id,
+                to: new_owner,
+            }
+            .into(),
+        );
+
+        // The record, its indexes and its deposit moved to the new owner
+        assert!(Artists::get_artist_by_id(artist_id).is_none());
+        assert_eq!(
+            Artists::get_artist_by_id(new_owner).unwrap().owner(),
+            &new_owner
+        );
+        assert_eq!(
+            Artists::get_artist_by_alias(artist.alias.clone().unwrap().to_vec())
+                .unwrap()
+                .owner(),
+            &new_owner
+        );
+        assert_eq!(
+            Artists::get_artists_by_genre(artist.genres[0])
+                .iter()
+                .map(|artist| *artist.owner())
+                .collect::<Vec<_>>(),
+            vec![new_owner]
+        );
+        assert_eq!(Balances::total_balance_on_hold(&new_owner), deposit);
+        assert_eq!(Balances::total_balance_on_hold(&artist_id), 0);
+        assert!(Artists::get_ownership_offer(artist_id).is_none());
+        assert_noop!(
+            Artists::accept_ownership(RuntimeOrigin::signed(new_owner), artist_id),
+            ArtistsError::<Test>::NoOwnershipOffer
+        );
+        assert_ok!(Artists::do_try_state());
+    })
+}
+
+#[test]
+fn lifted_exemption_keeps_label_deposits() {
+    new_test_ext().execute_with(|| {
+        let label = Label::get();
+        let first = 3u64;
+        let second = 4u64;
+        let register = |target: u64| {
+            let artist = tester_artist_of::<Test>(target);
+            Artists::register_for(
+                RuntimeOrigin::signed(label),
+                target,
+                artist.main_name,
+                artist.alias,
+                artist.genres,
+                artist.description,
+                artist.assets,
+            )
+        };
+
+        // The first artist is registered for free while the label is exempt
+        assert_ok!(Artists::set_deposit_exemption(
+            RuntimeOrigin::root(),
+            label,
+            true
+        ));
+        assert_ok!(register(first));
+        assert_eq!(Artists::get_artist_by_id(first).unwrap().deposit, 0);
+
+        assert_ok!(Artists::set_deposit_exemption(
+            RuntimeOrigin::root(),
+            label,
+            false
+        ));
+        assert_ok!(register(second));
+        let second_deposit = Artists::get_artist_by_id(second).unwrap().deposit;
+        assert_eq!(Balances::total_balance_on_hold(&label), second_deposit);
+
+        // Removing the free data of the first artist doesn't release the second one deposit
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(first),
+            UpdatableDataOf::<Test>::Assets(UpdatableAssets::Clear),
+        ));
+        assert_eq!(Artists::get_artist_by_id(first).unwrap().deposit, 0);
+        assert_eq!(Balances::total_balance_on_hold(&label), second_deposit);
+        assert_ok!(Artists::do_try_state());
+    });
+}
+
+#[test]
+fn register_for_works() {
+    new_test_ext().execute_with(|| {
+        let label = Label::get();
+        let first = 3u64;
+        let second = 4u64;
+
+        // Neither a label nor a proxy of the target
+        assert_noop!(
+            Artists::register_for(
+                RuntimeOrigin::signed(2),
+                first,
+                tester_artist_of::<Test>(first).main_name,
+                tester_artist_of::<Test>(first).alias,
+                tester_artist_of::<Test>(first).genres,
+                tester_artist_of::<Test>(first).description,
+                tester_artist_of::<Test>(first).assets,
+            ),
+            ArtistsError::<Test>::NotProxy
+        );
+
+        for target in [first, second] {
+            let artist = tester_artist_of::<Test>(target);
+            assert_ok!(Artists::register_for(
+                RuntimeOrigin::signed(label),
+                target,
+                artist.main_name,
+                artist.alias,
+                artist.genres,
+                artist.description,
+                artist.assets,
+            ));
+        }
+
+        // The targets own their profile while the label pays for it
+        let first_artist = Artists::get_artist_by_id(first).unwrap();
+        let second_artist = Artists::get_artist_by_id(second).unwrap();
+        assert_eq!(first_artist.owner(), &first);
+        assert_eq!(first_artist.depositor(), &label);
+        assert_eq!(Balances::total_balance_on_hold(&first), 0);
+        assert_eq!(
+            Balances::total_balance_on_hold(&label),
+            first_artist.deposit + second_artist.deposit
+        );
+
+        // Only the deposit of the unregistered artist is released from the label
+        assert_ok!(Artists::request_unregister(RuntimeOrigin::signed(first)));
+        System::set_block_number(
+            System::block_number() + <Test as Config>::UnregisterPeriod::get() as u64,
+        );
+        assert_ok!(Artists::confirm_unregister(RuntimeOrigin::signed(first)));
+        System::assert_last_event(
+            Event::<Test>::ArtistUnregistered {
+                id: first,
+                deposit: first_artist.deposit,
+                depositor: label,
+            }
+            .into(),
+        );
+        assert_eq!(
+            Balances::total_balance_on_hold(&label),
+            second_artist.deposit
+        );
+
+        // A proxy of the target can register it too
+        ProxyPairs::set(vec![(first, 2)]);
+        let artist = tester_artist_of::<Test>(first);
+        assert_ok!(Artists::register_for(
+            RuntimeOrigin::signed(2),
+            first,
+            artist.main_name,
+            artist.alias,
+            artist.genres,
+            artist.description,
+            artist.assets,
+        ));
+        assert_eq!(Artists::get_artist_by_id(first).unwrap().depositor(), &2);
+        assert_ok!(Artists::do_try_state());
+    })
+}
+
+#[test]
+fn registered_artists_keep_the_deposits_they_hold_for_others() {
+    new_test_ext().execute_with(|| {
+        let depositor = 1u64;
+        let target = 3u64;
+        let register = |who: u64| {
+            let artist = tester_artist_of::<Test>(who);
+            assert_ok!(Artists::register(
+                RuntimeOrigin::signed(who),
+                artist.main_name,
+                artist.alias,
+                artist.genres,
+                artist.description,
+                artist.assets,
+            ));
+        };
+
+        register(depositor);
+        ProxyPairs::set(vec![(target, depositor)]);
+        let artist = tester_artist_of::<Test>(target);
+        assert_ok!(Artists::register_for(
+            RuntimeOrigin::signed(depositor),
+            target,
+            artist.main_name,
+            artist.alias,
+            artist.genres,
+            artist.description,
+            artist.assets,
+        ));
+        let target_deposit = Artists::get_artist_by_id(target).unwrap().deposit;
+
+        // Unregistering only releases the own deposit of the depositor
+        assert_ok!(Artists::request_unregister(RuntimeOrigin::signed(
+            depositor
+        )));
+        System::set_block_number(
+            System::block_number() + <Test as Config>::UnregisterPeriod::get() as u64,
+        );
+        assert_ok!(Artists::confirm_unregister(RuntimeOrigin::signed(
+            depositor
+        )));
+        assert_eq!(Balances::total_balance_on_hold(&depositor), target_deposit);
+        assert_ok!(Artists::do_try_state());
+
+        // Transferring only moves the own deposit of the depositor along
+        register(depositor);
+        assert_ok!(Artists::transfer_ownership(
+            RuntimeOrigin::signed(depositor),
+            4
+        ));
+        assert_ok!(Artists::accept_ownership(
+            RuntimeOrigin::signed(4),
+            depositor
+        ));
+        assert_eq!(Balances::total_balance_on_hold(&depositor), target_deposit);
+        assert_ok!(Artists::do_try_state());
+    })
+}
+
+#[test]
+fn asset_budget_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        AssetBudget::set(2 * ArtistAsset::<Test>::max_encoded_len() as u32);
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        let add_asset = |asset: &[u8]| {
+            Artists::update(
+                RuntimeOrigin::signed(artist_id),
+                UpdatableDataOf::<Test>::Assets(UpdatableAssets::Add(
+                    AssetType::Image,
+                    asset.to_vec(),
+                )),
+            )
+        };
+
+        // The registration assets aren't taken from the budget
+        assert_ok!(add_asset(b"first"));
+        assert_ok!(add_asset(b"second"));
+        assert_noop!(
+            add_asset(b"third"),
+            ArtistsError::<Test>::AssetBudgetExceeded
+        );
+
+        // The governance isn't bound by the budget
+        assert_ok!(Artists::force_update(
+            RuntimeOrigin::root(),
+            artist_id,
+            UpdatableDataOf::<Test>::Assets(UpdatableAssets::Add(
+                AssetType::Image,
+                b"third".to_vec()
+            )),
+        ));
+
+        // A new period starts once the previous one passed
+        System::set_block_number(1 + <Test as Config>::AssetBudgetPeriod::get());
+        assert_ok!(add_asset(b"fourth"));
+        assert_eq!(
+            crate::AssetBudgetUsage::<Test>::get(artist_id),
+            Some((
+                1 + <Test as Config>::AssetBudgetPeriod::get(),
+                ArtistAsset::<Test>::max_encoded_len() as u32
+            ))
+        );
+        assert_ok!(Artists::do_try_state());
+
+        AssetBudget::set(32 * 1024);
+    })
+}
+
+#[test]
+fn registrations_cap_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        MaxRegistrationsPerPeriod::set(2);
+
+        let register = |id: u64| {
+            let artist = tester_artist_of::<Test>(id);
+            Artists::register(
+                RuntimeOrigin::signed(id),
+                artist.main_name,
+                artist.alias,
+                artist.genres,
+                artist.description,
+                artist.assets,
+            )
+        };
+
+        assert_ok!(register(1));
+        assert_ok!(register(2));
+        assert_noop!(register(3), ArtistsError::<Test>::TooManyRegistrations);
+
+        // The governance isn't bound by the cap
+        let artist = tester_artist_of::<Test>(4);
+        assert_ok!(Artists::register_for(
+            RuntimeOrigin::root(),
+            4,
+            artist.main_name,
+            artist.alias,
+            artist.genres,
+            artist.description,
+            artist.assets,
+        ));
+        assert_eq!(crate::RegistrationsUsage::<Test>::get(), Some((1, 2)));
+        // Nor does it hold anything from the target, which didn't consent to it
+        assert_eq!(Balances::total_balance_on_hold(&4), 0);
+        assert_eq!(*Artists::get_artist_by_id(4).unwrap().deposit(), 0);
+        assert!(!crate::DepositExemptions::<Test>::contains_key(4));
+
+        // A new period starts once the previous one passed
+        System::set_block_number(1 + <Test as Config>::RegistrationPeriod::get());
+        assert_ok!(register(3));
+        assert_eq!(
+            crate::RegistrationsUsage::<Test>::get(),
+            Some((1 + <Test as Config>::RegistrationPeriod::get(), 1))
+        );
+        assert_ok!(Artists::do_try_state());
+
+        MaxRegistrationsPerPeriod::set(0);
+    })
+}
+
+#[test]
+fn artist_data_changed_event_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Assets(UpdatableAssets::Add(
+                AssetType::Image,
+                b"cover".to_vec()
+            )),
+        ));
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+
+        // Clearing carries the removed values
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Assets(UpdatableAssets::Clear),
+        ));
+        System::assert_has_event(
+            Event::<Test>::ArtistDataChanged {
+                id: artist_id,
+                old: ArtistField::Assets(stored.assets.clone()),
+                new: ArtistField::Assets(Default::default()),
+            }
+            .into(),
+        );
+
+        // Switching to a raw description shows both ways of storing it
+        assert_ok!(Artists::force_update(
+            RuntimeOrigin::root(),
+            artist_id,
+            UpdatableDataOf::<Test>::RawDescription(Some(b"Short bio.".to_vec())),
+        ));
+        System::assert_has_event(
+            Event::<Test>::ArtistDataChanged {
+                id: artist_id,
+                old: ArtistField::Description {
+                    hash: stored.description.clone(),
+                    raw: None,
+                },
+                new: ArtistField::Description {
+                    hash: None,
+                    raw: Some(b"Short bio.".to_vec().try_into().unwrap()),
+                },
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::<Test>::ArtistForceUpdated {
+                id: artist_id,
+                new_data: UpdatableDataOf::<Test>::RawDescription(Some(b"Short bio.".to_vec())),
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn abuse_filter_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        DeniedNames::set(vec![b"Spam".to_vec()]);
+        AbuseSurcharge::set(7);
+
+        assert_noop!(
+            Artists::register(
+                RuntimeOrigin::signed(artist_id),
+                b"Spam".to_vec().try_into().unwrap(),
+                artist.alias.clone(),
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            ),
+            ArtistsError::<Test>::SubmissionRejected
+        );
+
+        // The surcharge is held along with the artist deposit
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        System::assert_has_event(
+            Event::<Test>::SubmissionSurcharged {
+                id: artist_id,
+                amount: 7,
+            }
+            .into(),
+        );
+        let stored_deposit = || *Artists::get_artist_by_id(artist_id).unwrap().deposit();
+        assert_eq!(stored_deposit(), expected_artist_cost(&artist) + 7);
+
+        assert_noop!(
+            Artists::update(
+                RuntimeOrigin::signed(artist_id),
+                UpdatableDataOf::<Test>::Alias(Some(b"Spam".to_vec().try_into().unwrap())),
+            ),
+            ArtistsError::<Test>::SubmissionRejected
+        );
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::OpenTo(AvailabilityFlags(Availability::Booking.into())),
+        ));
+        assert_eq!(stored_deposit(), expected_artist_cost(&artist) + 14);
+
+        // The governance updates aren't scored
+        assert_ok!(Artists::force_update(
+            RuntimeOrigin::root(),
+            artist_id,
+            UpdatableDataOf::<Test>::OpenTo(Default::default()),
+        ));
+        assert_eq!(stored_deposit(), expected_artist_cost(&artist) + 14);
+        assert_ok!(Artists::do_try_state());
+
+        // The surcharges are refunded with the artist deposit
+        assert_ok!(Artists::force_unregister(
+            RuntimeOrigin::root(),
+            artist_id,
+            DepositPolicy::Refund
+        ));
+        System::assert_last_event(
+            Event::<Test>::ArtistForceUnregistered {
+                id: artist_id,
+                policy: DepositPolicy::Refund,
+                deposit: expected_artist_cost(&artist) + 14,
+                depositor: artist_id,
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn artist_fixture_works() {
+    use crate::fixtures::ArtistFixture;
+
+    new_test_ext().execute_with(|| {
+        let artist = ArtistFixture::<Test>::new()
+            .name("Fixture")
+            .alias("The Fixture")
+            .genre(MusicGenre::Electronic(Some(ElectronicSubtype::House)))
+            .verified()
+            .with_assets(3)
+            .insert(1);
+
+        assert_eq!(Artists::get_artist_by_id(1), Some(artist.clone()));
+        assert_eq!(
+            Artists::get_artist_by_name(b"Fixture".to_vec()),
+            Some(artist)
+        );
+        assert!(Artists::get_artist_by_alias(b"The Fixture".to_vec()).is_some());
+        assert_eq!(
+            Artists::get_artists_by_genre(MusicGenre::Electronic(Some(ElectronicSubtype::House)))
+                .len(),
+            1
+        );
+        assert_eq!(*Artists::get_artist_by_id(1).unwrap().deposit(), 0);
+
+        // The same fixture gives the same assets
+        let suspended = ArtistFixture::<Test>::new()
+            .name("Other")
+            .with_assets(3)
+            .status(ArtistStatus::Suspended)
+            .insert(2);
+        assert_eq!(
+            suspended
+                .assets()
+                .iter()
+                .map(|x| x.hash)
+                .collect::<Vec<_>>(),
+            Artists::get_artist_by_id(1)
+                .unwrap()
+                .assets()
+                .iter()
+                .map(|x| x.hash)
+                .collect::<Vec<_>>()
+        );
+        assert!(<Artists as ArtistsInspect<u64>>::is_suspended(&2));
+        assert_ok!(Artists::do_try_state());
+    })
+}
+
+#[test]
+fn remove_at_works() {
+    new_test_ext().execute_with(|| {
+        let mut artist = tester_artist::<Test>();
+        artist.genres = vec![
+            MusicGenre::Electronic(Some(ElectronicSubtype::House)),
+            MusicGenre::Electronic(Some(ElectronicSubtype::Techno)),
+        ]
+        .try_into()
+        .unwrap();
+        artist.assets = vec![
+            (AssetType::Image, b"cover".to_vec()),
+            (AssetType::Audio, b"single".to_vec()),
+        ]
+        .try_into()
+        .unwrap();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        let stored = || Artists::get_artist_by_id(artist_id).unwrap();
+        let update = |data| Artists::update(RuntimeOrigin::signed(artist_id), data);
+
+        assert_noop!(
+            update(UpdatableDataOf::<Test>::Genres(UpdatableGenres::RemoveAt(
+                2
+            ))),
+            ArtistsError::<Test>::IndexOutOfBounds
+        );
+        assert_ok!(update(UpdatableDataOf::<Test>::Genres(
+            UpdatableGenres::RemoveAt(0)
+        )));
+        assert_eq!(
+            stored().genres().to_vec(),
+            vec![StoredGenre::from(MusicGenre::Electronic(Some(
+                ElectronicSubtype::Techno
+            )))]
+        );
+        assert!(Artists::get_artists_by_genre(MusicGenre::Electronic(Some(
+            ElectronicSubtype::House
+        )))
+        .is_empty());
+
+        // The asset deposit is released as for a removal by value
+        let hash_cost = <Test as Config>::ByteDeposit::get()
+            .saturating_mul(<Test as frame_system::Config>::Hash::max_encoded_len() as u128);
+        let deposit = *stored().deposit();
+        assert_noop!(
+            update(UpdatableDataOf::<Test>::Assets(UpdatableAssets::RemoveAt(
+                2
+            ))),
+            ArtistsError::<Test>::IndexOutOfBounds
+        );
+        assert_ok!(update(UpdatableDataOf::<Test>::Assets(
+            UpdatableAssets::RemoveAt(1)
+        )));
+        assert_eq!(stored().assets().len(), 1);
+        assert_eq!(
+            stored().assets()[0].hash,
+            <Test as frame_system::Config>::Hashing::hash(b"cover")
+        );
+        assert_eq!(*stored().deposit(), deposit - hash_cost);
+        assert_ok!(Artists::do_try_state());
+    })
+}
+
+#[test]
+fn update_weight_refund_works() {
+    new_test_ext().execute_with(|| {
+        type Weights = <Test as Config>::WeightInfo;
+
+        let mut artist = tester_artist::<Test>();
+        artist.genres = vec![MusicGenre::Electronic(Some(ElectronicSubtype::House))]
+            .try_into()
+            .unwrap();
+        artist.assets = vec![
+            (AssetType::Image, b"cover".to_vec()),
+            (AssetType::Audio, b"single".to_vec()),
+        ]
+        .try_into()
+        .unwrap();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // the weight is refunded down to the existing genres
+        let info = Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Genres(UpdatableGenres::Add(MusicGenre::Electronic(Some(
+                ElectronicSubtype::Techno,
+            )))),
+        )
+        .unwrap();
+        assert_eq!(info.actual_weight, Some(Weights::update_add_genres(1)));
+        assert!(
+            Weights::update_add_genres(1).any_lt(Weights::update_add_genres(
+                <Test as Config>::MaxGenres::get()
+            ))
+        );
+
+        // and down to the existing assets
+        let info = Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Assets(UpdatableAssets::Remove(b"cover".to_vec())),
+        )
+        .unwrap();
+        assert_eq!(info.actual_weight, Some(Weights::update_remove_assets(2)));
+        // removing by position or fingerprint is charged less than re-sending the asset
+        assert!(Weights::update_remove_assets_at(2).any_lt(Weights::update_remove_assets(2)));
+        assert!(
+            Weights::update_remove_assets_fingerprint(2).any_lt(Weights::update_remove_assets(2))
+        );
+
+        // governance updates are refunded the same way
+        let info = Artists::force_update(
+            RuntimeOrigin::root(),
+            artist_id,
+            UpdatableDataOf::<Test>::Assets(UpdatableAssets::Clear),
+        )
+        .unwrap();
+        assert_eq!(info.actual_weight, Some(Weights::update_clear_assets(1)));
+        assert!(
+            Weights::update_clear_assets(1).any_lt(Weights::update_clear_assets(
+                <Test as Config>::MaxAssets::get()
+            ))
+        );
+
+        // the genres index cleanup is charged on its own, for the two cleared genres
+        let info = Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Genres(UpdatableGenres::Clear),
+        )
+        .unwrap();
+        assert_eq!(
+            info.actual_weight,
+            Some(Weights::update_clear_genres(0).saturating_add(Weights::clear_genre_index(2)))
+        );
+    });
+}
+
+#[test]
+fn royalty_splits_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_noop!(
+            Artists::clear_royalty_splits(RuntimeOrigin::signed(artist_id)),
+            ArtistsError::<Test>::NotRegistered
+        );
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        let held = Balances::total_balance_on_hold(&artist_id);
+        let splits = |splits: Vec<(u64, Perbill)>| -> crate::types::RoyaltySplitsOf<Test> {
+            splits.try_into().unwrap()
+        };
+        let set = |x| Artists::set_royalty_splits(RuntimeOrigin::signed(artist_id), x);
+
+        assert_noop!(
+            Artists::clear_royalty_splits(RuntimeOrigin::signed(artist_id)),
+            ArtistsError::<Test>::RoyaltySplitsNotFound
+        );
+        assert_noop!(
+            set(splits(vec![
+                (1, Perbill::from_percent(60)),
+                (2, Perbill::from_percent(30))
+            ])),
+            ArtistsError::<Test>::InvalidRoyaltySplits
+        );
+        assert_noop!(
+            set(splits(vec![
+                (1, Perbill::from_percent(100)),
+                (2, Perbill::zero())
+            ])),
+            ArtistsError::<Test>::InvalidRoyaltySplits
+        );
+        assert_noop!(
+            set(splits(vec![
+                (2, Perbill::from_percent(50)),
+                (2, Perbill::from_percent(50))
+            ])),
+            ArtistsError::<Test>::DuplicateBeneficiary
+        );
+
+        let two_way = splits(vec![
+            (1, Perbill::from_percent(70)),
+            (2, Perbill::from_percent(30)),
+        ]);
+        assert_ok!(set(two_way.clone()));
+        System::assert_last_event(
+            Event::<Test>::RoyaltySplitsSet {
+                id: artist_id,
+                splits: two_way.clone(),
+            }
+            .into(),
+        );
+        assert_eq!(
+            <Artists as ArtistsInspect<u64>>::royalty_splits(&artist_id),
+            two_way.to_vec()
+        );
+        let splits_cost = |x: &crate::types::RoyaltySplitsOf<Test>| {
+            <Test as Config>::ByteDeposit::get().saturating_mul(x.encoded_size().saturated_into())
+        };
+        assert_eq!(
+            Balances::total_balance_on_hold(&artist_id),
+            held + splits_cost(&two_way)
+        );
+
+        // Replacing the splits only holds the deposit difference
+        let three_way = splits(vec![
+            (1, Perbill::from_percent(50)),
+            (2, Perbill::from_percent(25)),
+            (3, Perbill::from_percent(25)),
+        ]);
+        assert_ok!(set(three_way.clone()));
+        assert_eq!(
+            Balances::total_balance_on_hold(&artist_id),
+            held + splits_cost(&three_way)
+        );
+        assert_ok!(Artists::do_try_state());
+
+        assert_ok!(Artists::clear_royalty_splits(RuntimeOrigin::signed(
+            artist_id
+        )));
+        System::assert_last_event(Event::<Test>::RoyaltySplitsCleared { id: artist_id }.into());
+        assert!(<Artists as ArtistsInspect<u64>>::royalty_splits(&artist_id).is_empty());
+        assert_eq!(Balances::total_balance_on_hold(&artist_id), held);
+    });
+}
+
+#[test]
+fn payout_router_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let contract = 42u64;
+        let target = |id| <Artists as PayoutRouter<u64>>::payout_target(&id);
+
+        assert_eq!(target(artist_id), None);
+        assert_noop!(
+            Artists::set_payout_route(RuntimeOrigin::signed(artist_id), None),
+            ArtistsError::<Test>::NotRegistered
+        );
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        // Paid directly without any split nor route
+        assert_eq!(target(artist_id), Some(PayoutTarget::Account(artist_id)));
+
+        let splits: crate::types::RoyaltySplitsOf<Test> = vec![
+            (1, Perbill::from_percent(70)),
+            (2, Perbill::from_percent(30)),
+        ]
+        .try_into()
+        .unwrap();
+        assert_ok!(Artists::set_royalty_splits(
+            RuntimeOrigin::signed(artist_id),
+            splits.clone()
+        ));
+        assert_eq!(
+            target(artist_id),
+            Some(PayoutTarget::SplitSet(splits.to_vec()))
+        );
+
+        // Only linked contracts can be routed to
+        assert_noop!(
+            Artists::set_payout_route(
+                RuntimeOrigin::signed(artist_id),
+                Some(PayoutRoute::Contract(contract))
+            ),
+            ArtistsError::<Test>::ContractNotLinked
+        );
+        assert_ok!(Artists::link_contract(&artist_id, contract));
+        assert_ok!(Artists::set_payout_route(
+            RuntimeOrigin::signed(artist_id),
+            Some(PayoutRoute::Contract(contract))
+        ));
+        System::assert_last_event(
+            Event::<Test>::PayoutRouteSet {
+                id: artist_id,
+                route: Some(PayoutRoute::Contract(contract)),
+            }
+            .into(),
+        );
+        assert_eq!(target(artist_id), Some(PayoutTarget::Contract(contract)));
+
+        // Unlinking the contract drops its route, back to the splits
+        assert_ok!(Artists::unlink_contract(&artist_id, &contract));
+        assert_eq!(Artists::get_payout_route(artist_id), None);
+        assert_eq!(
+            target(artist_id),
+            Some(PayoutTarget::SplitSet(splits.to_vec()))
+        );
+
+        assert_ok!(Artists::set_payout_route(
+            RuntimeOrigin::signed(artist_id),
+            Some(PayoutRoute::Account(3))
+        ));
+        assert_eq!(target(artist_id), Some(PayoutTarget::Account(3)));
+        assert_ok!(Artists::set_payout_route(
+            RuntimeOrigin::signed(artist_id),
+            None
+        ));
+        assert_eq!(
+            target(artist_id),
+            Some(PayoutTarget::SplitSet(splits.to_vec()))
+        );
+    });
+}
+
+#[test]
+fn simulate_register_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let assets: Vec<(AssetType, Vec<u8>)> = artist.assets.to_vec();
+        let simulate = |main_name: Vec<u8>| {
+            Artists::simulate_register(
+                artist_id,
+                main_name,
+                artist.alias.clone().map(|x| x.to_vec()),
+                artist.genres.to_vec(),
+                artist.description.clone().map(|x| x.to_vec()),
+                assets.clone(),
+            )
+        };
+
+        assert_eq!(
+            simulate(vec![b'X'; 65]),
+            Err(ArtistsError::<Test>::InvalidName.into())
+        );
+
+        // Nothing is kept from the simulation
+        let preview = simulate(artist.main_name.to_vec()).unwrap();
+        assert!(Artists::get_artist_by_id(artist_id).is_none());
+        assert!(Artists::get_artist_by_alias(artist.alias.clone().unwrap().to_vec()).is_none());
+        assert_eq!(Balances::total_balance_on_hold(&artist_id), 0);
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        assert_eq!(preview, stored);
+        assert_eq!(preview.deposit, Balances::total_balance_on_hold(&artist_id));
+
+        // The registration errors are returned
+        assert_eq!(
+            simulate(artist.main_name.to_vec()),
+            Err(ArtistsError::<Test>::AlreadyRegistered.into())
+        );
+    });
+}
+
+#[test]
+fn group_members_works() {
+    new_test_ext().execute_with(|| {
+        let group = 1u64;
+        let solo = 2u64;
+        let unregistered = 3u64;
+
+        for artist_id in [group, solo] {
+            let artist = tester_artist_of::<Test>(artist_id);
+            assert_ok!(Artists::register(
+                RuntimeOrigin::signed(artist_id),
+                artist.main_name.clone(),
+                artist.alias.clone(),
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            ));
+        }
+        let held = Balances::total_balance_on_hold(&group);
+        let member_cost = <Test as Config>::ByteDeposit::get()
+            .saturating_mul(u64::max_encoded_len().saturated_into());
+
+        assert_noop!(
+            Artists::add_member(RuntimeOrigin::signed(group), solo),
+            ArtistsError::<Test>::NotGroup
+        );
+        assert_ok!(Artists::set_artist_kind(
+            RuntimeOrigin::signed(group),
+            ArtistKind::Group
+        ));
+        System::assert_last_event(
+            Event::<Test>::ArtistKindChanged {
+                id: group,
+                kind: ArtistKind::Group,
+            }
+            .into(),
+        );
+
+        // The member has to accept the invitation
+        assert_ok!(Artists::add_member(RuntimeOrigin::signed(group), solo));
+        System::assert_last_event(
+            Event::<Test>::MemberInvited {
+                id: group,
+                member: solo,
+            }
+            .into(),
+        );
+        assert!(Artists::get_artist_by_id(group).unwrap().members.is_empty());
+        assert_noop!(
+            Artists::add_member(RuntimeOrigin::signed(group), solo),
+            ArtistsError::<Test>::AlreadyInvited
+        );
+        assert_noop!(
+            Artists::accept_membership(RuntimeOrigin::signed(unregistered), group),
+            ArtistsError::<Test>::NotInvited
+        );
+        assert_ok!(Artists::accept_membership(
+            RuntimeOrigin::signed(solo),
+            group
+        ));
+        System::assert_last_event(
+            Event::<Test>::MemberAdded {
+                id: group,
+                member: solo,
+            }
+            .into(),
+        );
+        assert_eq!(
+            <Artists as ArtistsInspect<u64>>::members_of(&group),
+            vec![solo]
+        );
+        assert!(crate::MemberInvitations::<Test>::get(group).is_empty());
+        assert_eq!(Balances::total_balance_on_hold(&group), held + member_cost);
+        assert_noop!(
+            Artists::add_member(RuntimeOrigin::signed(group), solo),
+            ArtistsError::<Test>::AlreadyMember
+        );
+
+        // The member is still a solo artist on its own
+        assert_eq!(
+            Artists::get_artist_by_id(solo).unwrap().kind,
+            ArtistKind::Solo
+        );
+        assert_noop!(
+            Artists::set_artist_kind(RuntimeOrigin::signed(group), ArtistKind::Solo),
+            ArtistsError::<Test>::GroupHasMembers
+        );
+
+        // Invitations can be declined
+        assert_ok!(Artists::add_member(
+            RuntimeOrigin::signed(group),
+            unregistered
+        ));
+        assert_ok!(Artists::leave_group(
+            RuntimeOrigin::signed(unregistered),
+            group
+        ));
+        assert!(crate::MemberInvitations::<Test>::get(group).is_empty());
+        assert_noop!(
+            Artists::remove_member(RuntimeOrigin::signed(group), unregistered),
+            ArtistsError::<Test>::NotMember
+        );
+
+        // Members and invitations are bounded together
+        let max_members = <Test as Config>::MaxMembers::get() as u64;
+        for member in 10..(10 + max_members - 1) {
+            assert_ok!(Artists::add_member(RuntimeOrigin::signed(group), member));
+        }
+        assert_noop!(
+            Artists::add_member(RuntimeOrigin::signed(group), 100),
+            ArtistsError::<Test>::MembersFull
+        );
+        assert_ok!(Artists::do_try_state());
+
+        assert_ok!(Artists::remove_member(RuntimeOrigin::signed(group), solo));
+        System::assert_last_event(
+            Event::<Test>::MemberRemoved {
+                id: group,
+                member: solo,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::total_balance_on_hold(&group), held);
+
+        // Turning solo drops the pending invitations
+        assert_ok!(Artists::set_artist_kind(
+            RuntimeOrigin::signed(group),
+            ArtistKind::Solo
+        ));
+        assert!(!crate::MemberInvitations::<Test>::contains_key(group));
+        assert_ok!(Artists::do_try_state());
+    });
+}
+
+#[test]
+fn artist_view_deposit_breakdown_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert!(Artists::get_artist_view(&artist_id).is_none());
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert_ok!(Artists::add_delegate(RuntimeOrigin::signed(artist_id), 2));
+
+        let byte_deposit: u128 = <Test as Config>::ByteDeposit::get();
+        let hash_cost =
+            byte_deposit * <Test as frame_system::Config>::Hash::max_encoded_len() as u128;
+        let view = Artists::get_artist_view(&artist_id).unwrap();
+        let deposits = view.deposits();
+
+        assert_eq!(
+            view.artist(),
+            &Artists::get_artist_by_id(artist_id).unwrap()
+        );
+        assert_eq!(*deposits.base(), <Test as Config>::BaseDeposit::get());
+        assert_eq!(
+            *deposits.names(),
+            byte_deposit * (artist.main_name.encoded_size() + artist.alias.encoded_size()) as u128
+        );
+        assert_eq!(*deposits.description(), hash_cost);
+        assert_eq!(*deposits.assets(), hash_cost * artist.assets.len() as u128);
+        assert_eq!(
+            *deposits.delegates(),
+            byte_deposit * u64::max_encoded_len() as u128
+        );
+        assert_eq!(*deposits.contracts(), 0);
+        assert_eq!(*deposits.other(), 0);
+
+        // Every category adds up to the held deposit
+        let total = deposits.base()
+            + deposits.names()
+            + deposits.description()
+            + deposits.contact_hash()
+            + deposits.links()
+            + deposits.assets()
+            + deposits.contracts()
+            + deposits.delegates()
+            + deposits.members()
+            + deposits.royalty_splits();
+        assert_eq!(total, view.artist().deposit);
+        assert_eq!(total, Balances::total_balance_on_hold(&artist_id));
+
+        // Surcharges are reported apart
+        AbuseSurcharge::set(5);
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Assets(UpdatableAssets::Clear),
+        ));
+        AbuseSurcharge::set(0);
+        let view = Artists::get_artist_view(&artist_id).unwrap();
+        assert_eq!(*view.deposits().assets(), 0);
+        assert_eq!(*view.deposits().other(), 5);
+    });
+}
+
+#[test]
+fn artist_record_versions_work() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_eq!(Artists::latest_record_version(), LATEST_RECORD_VERSION);
+        assert!(Artists::get_artist_record(&artist_id, 1).is_none());
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        let record = Artists::get_artist_record(&artist_id, 1).unwrap();
+        assert_eq!(record.version(), 1);
+        let v1 = match record {
+            VersionedArtistRecord::V1(v1) => v1,
+            _ => panic!("a version 1 record is expected"),
+        };
+        assert_eq!(*v1.id(), artist_id);
+        assert_eq!(*v1.main_name(), artist.main_name.to_vec());
+        assert_eq!(*v1.genres(), stored.genres().to_vec());
+        assert_eq!(v1.deposit(), stored.deposit());
+
+        // The versions not released yet aren't served
+        assert!(Artists::get_artist_record(&artist_id, 0).is_none());
+        assert!(Artists::get_artist_record(&artist_id, LATEST_RECORD_VERSION + 1).is_none());
+    })
+}
+
+#[test]
+fn register_v2_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let contact_hash = <Test as frame_system::Config>::Hashing::hash(b"contact@artist.com");
+        let open_to = AvailabilityFlags(Availability::Collaboration | Availability::Booking);
+
+        let mut data = ArtistRegistrationData::<Test>::new(
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        );
+        data.open_to = open_to;
+        data.contact_hash = Some(contact_hash);
+        data.links = vec![
+            (LinkType::Spotify, b"spotify:artist:1".to_vec()),
+            (LinkType::Website, b"https://artist.com".to_vec()),
+        ]
+        .try_into()
+        .unwrap();
+
+        // Duplicated links are refused as they would be through an update
+        let mut duplicated = data.clone();
+        duplicated.links[1] = duplicated.links[0].clone();
+        assert_noop!(
+            Artists::register_v2(RuntimeOrigin::signed(artist_id), duplicated),
+            ArtistsError::<Test>::NotUniqueLink
+        );
+
+        assert_ok!(Artists::register_v2(
+            RuntimeOrigin::signed(artist_id),
+            data.clone()
+        ));
+        System::assert_last_event(
+            Event::<Test>::ArtistRegistered {
+                id: artist_id,
+                name: artist.main_name.clone(),
+            }
+            .into(),
+        );
+
+        let stored = Artists::get_artist_by_id(artist_id).unwrap();
+        assert_eq!(stored.open_to, open_to);
+        assert_eq!(stored.contact_hash, Some(contact_hash));
+        assert_eq!(stored.links.len(), 2);
+
+        // The new fields are held for as they would be once updated
+        let view = Artists::get_artist_view(&artist_id).unwrap();
+        let byte_deposit: u128 = <Test as Config>::ByteDeposit::get();
+        assert_eq!(
+            *view.deposits().contact_hash(),
+            byte_deposit * <Test as frame_system::Config>::Hash::max_encoded_len() as u128
+        );
+        assert_eq!(
+            *view.deposits().links(),
+            stored
+                .links
+                .iter()
+                .map(|link| byte_deposit * link.encoded_size() as u128)
+                .sum::<u128>()
+        );
+        assert_eq!(stored.deposit, Balances::total_balance_on_hold(&artist_id));
+
+        assert_noop!(
+            Artists::register_v2(RuntimeOrigin::signed(artist_id), data),
+            ArtistsError::<Test>::AlreadyRegistered
+        );
+
+        // The deprecated call registers without the new fields
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(2),
+            to_bounded_alias("Other".to_string()),
+            None,
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        let legacy = Artists::get_artist_by_id(2).unwrap();
+        assert_eq!(legacy.open_to, AvailabilityFlags::default());
+        assert_eq!(legacy.contact_hash, None);
+        assert!(legacy.links.is_empty());
+    });
+}
+
+#[test]
+fn register_remote_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let artist = tester_artist::<Test>();
+        let derived_id = 3u64;
+        let origin_chain = OriginChain::Sibling(2000);
+        let data = ArtistRegistrationData::<Test>::new(
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        );
+
+        // Local accounts can't register as remote ones
+        assert_noop!(
+            Artists::register_remote(RuntimeOrigin::signed(derived_id), data.clone()),
+            BadOrigin
+        );
+
+        RemoteAccounts::set(vec![(derived_id, origin_chain)]);
+        assert_ok!(Artists::register_remote(
+            RuntimeOrigin::signed(derived_id),
+            data.clone()
+        ));
+        System::assert_last_event(
+            Event::<Test>::RemoteArtistRegistered {
+                id: derived_id,
+                origin_chain,
+            }
+            .into(),
+        );
+
+        // The derived account owns the profile and pays its deposit
+        let stored = Artists::get_artist_by_id(derived_id).unwrap();
+        assert_eq!(stored.owner, derived_id);
+        assert_eq!(stored.depositor, derived_id);
+        assert_eq!(
+            Balances::total_balance_on_hold(&derived_id),
+            expected_artist_cost(&artist)
+        );
+        assert_eq!(Artists::get_origin_chain(derived_id), Some(origin_chain));
+        assert_ok!(Artists::do_try_state());
+
+        // The tag goes away along with the profile
+        assert_ok!(Artists::force_unregister(
+            RuntimeOrigin::root(),
+            derived_id,
+            DepositPolicy::Refund
+        ));
+        assert_eq!(Artists::get_origin_chain(derived_id), None);
+        assert_ok!(Artists::do_try_state());
+
+        // Instances not accepting remote registrations refuse them
+        assert_noop!(
+            Producers::register_remote(RuntimeOrigin::signed(derived_id), data),
+            BadOrigin
+        );
+    });
+}
+
+#[cfg(feature = "remote-origin")]
+#[test]
+fn remote_origin_adapter_works() {
+    use crate::remote_origin::EnsureRemoteAccount;
+    use frame_support::traits::EnsureOrigin;
+    use xcm::latest::prelude::*;
+    use xcm_executor::traits::ConvertLocation;
+
+    /// The location of the passed account, under the chain it stands for.
+    fn location_of(who: u64) -> Location {
+        let account = AccountId32 {
+            network: None,
+            id: [who as u8; 32],
+        };
+        match who {
+            1 => Location::new(1, [Parachain(2000), account]),
+            2 => Location::new(1, [account]),
+            3 => Location::new(0, [account]),
+            4 => Location::new(1, [GlobalConsensus(NetworkId::Kusama), account]),
+            _ => Location::new(2, [GlobalConsensus(NetworkId::Kusama), account]),
+        }
+    }
+
+    /// Turns the signed origins into their location, as `pallet_xcm::EnsureXcm` does for the
+    /// XCM origins.
+    struct SignedAsLocation;
+
+    impl EnsureOrigin<RuntimeOrigin> for SignedAsLocation {
+        type Success = Location;
+
+        fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+            match o.clone().into() {
+                Ok(frame_system::RawOrigin::Signed(who)) => Ok(location_of(who)),
+                _ => Err(o),
+            }
+        }
+
+        #[cfg(feature = "runtime-benchmarks")]
+        fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+            Ok(RuntimeOrigin::signed(1))
+        }
+    }
+
+    /// Derives the accounts from the hash of their location, as `HashedDescription` does.
+    struct HashedLocation;
+
+    impl ConvertLocation<u64> for HashedLocation {
+        fn convert_location(location: &Location) -> Option<u64> {
+            Some(u64::from_le_bytes(sp_io::hashing::blake2_64(
+                &location.encode(),
+            )))
+        }
+    }
+
+    type RemoteAccount = EnsureRemoteAccount<SignedAsLocation, HashedLocation, u64>;
+    let derived = |who: u64| HashedLocation::convert_location(&location_of(who)).unwrap();
+
+    // The chain is derived from the location, the account from its conversion
+    assert_eq!(
+        RemoteAccount::try_origin(RuntimeOrigin::signed(1)).ok(),
+        Some((derived(1), OriginChain::Sibling(2000)))
+    );
+    assert_eq!(
+        RemoteAccount::try_origin(RuntimeOrigin::signed(2)).ok(),
+        Some((derived(2), OriginChain::Relay))
+    );
+
+    // The local locations and the ones of other consensus systems are rejected
+    for who in [3, 4, 5] {
+        assert!(RemoteAccount::try_origin(RuntimeOrigin::signed(who)).is_err());
+    }
+    assert!(RemoteAccount::try_origin(RuntimeOrigin::root()).is_err());
+}
+
+#[test]
+fn artist_lifecycle_hook_failure_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+
+        // Going over `MaxLifecycleHookWeight` exhausts the handler meter
+        LifecycleHookWeight::set(MaxLifecycleHookWeight::get().saturating_mul(2));
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(1),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert!(Artists::get_artist_by_id(1).is_some());
+        assert!(LifecycleCalls::get().is_empty());
+        System::assert_has_event(
+            Event::<Test>::LifecycleHookFailed {
+                id: 1,
+                error: DispatchError::Exhausted,
+            }
+            .into(),
+        );
+
+        LifecycleHookFailure::set(HookFailurePolicy::Revert);
+        assert_noop!(
+            Artists::register(
+                RuntimeOrigin::signed(2),
+                artist.main_name.clone(),
+                tester_artist_of::<Test>(2).alias,
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            ),
+            DispatchError::Exhausted
+        );
+        assert_noop!(
+            Artists::verify_artist(RuntimeOrigin::root(), 1, None),
+            DispatchError::Exhausted
+        );
+
+        // Within the limit, the handlers go through again
+        LifecycleHookWeight::set(MaxLifecycleHookWeight::get());
+        assert_ok!(Artists::verify_artist(RuntimeOrigin::root(), 1, None));
+        assert_eq!(LifecycleCalls::get(), vec![LifecycleCall::Verified(1)]);
+
+        LifecycleCalls::set(Vec::new());
+        LifecycleHookWeight::set(Weight::zero());
+        LifecycleHookFailure::set(HookFailurePolicy::Ignore);
+    })
+}
+
+#[test]
+fn artist_contract_notifications_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        for contract in [10, 11, 12] {
+            assert_ok!(Artists::link_contract(&artist_id, contract));
+        }
+
+        // A failing contract doesn't block the other notifications, nor the status change
+        FailingContracts::set(vec![11]);
+        assert_ok!(Artists::suspend_artist(
+            RuntimeOrigin::root(),
+            artist_id,
+            false
+        ));
+        let suspended = ContractNotice::StatusChanged(ArtistStatus::Suspended);
+        assert_eq!(
+            ContractNotices::get(),
+            vec![(artist_id, 10, suspended), (artist_id, 12, suspended)]
+        );
+        System::assert_has_event(
+            Event::<Test>::ContractNotificationFailed {
+                id: artist_id,
+                contract: 11,
+                error: DispatchError::Other("failing contract"),
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::<Test>::ArtistStatusChanged {
+                id: artist_id,
+                status: ArtistStatus::Suspended,
+            }
+            .into(),
+        );
+
+        // The notifications stop once `MaxContractNotificationWeight` is exhausted
+        ContractNotices::set(Vec::new());
+        FailingContracts::set(Vec::new());
+        ContractNotificationCost::set(Weight::from_parts(500_000, 512));
+        assert_ok!(Artists::reinstate_artist(RuntimeOrigin::root(), artist_id));
+        let active = ContractNotice::StatusChanged(ArtistStatus::Active);
+        assert_eq!(
+            ContractNotices::get(),
+            vec![(artist_id, 10, active), (artist_id, 11, active)]
+        );
+        System::assert_has_event(
+            Event::<Test>::ContractNotificationFailed {
+                id: artist_id,
+                contract: 12,
+                error: DispatchError::Exhausted,
+            }
+            .into(),
+        );
+
+        ContractNotices::set(Vec::new());
+        ContractNotificationCost::set(Weight::zero());
+        assert_ok!(Artists::force_unregister(
+            RuntimeOrigin::root(),
+            artist_id,
+            DepositPolicy::Refund
+        ));
+        assert_eq!(
+            ContractNotices::get(),
+            [10, 11, 12]
+                .map(|contract| (artist_id, contract, ContractNotice::Unregistered))
+                .to_vec()
+        );
+
+        ContractNotices::set(Vec::new());
+    })
+}
+
+#[test]
+fn artist_granular_update_events_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let house = MusicGenre::Electronic(Some(ElectronicSubtype::House));
+        let classical = MusicGenre::Classical(None);
+        let cover_hash =
+            Fingerprint::Native(<Test as frame_system::Config>::Hashing::hash(b"cover"));
+        let granular_events = || -> Vec<Event<Test>> {
+            System::events()
+                .into_iter()
+                .filter_map(|record| match record.event {
+                    RuntimeEvent::Artists(
+                        event @ (Event::GenreAdded { .. }
+                        | Event::GenreRemoved { .. }
+                        | Event::AssetAdded { .. }
+                        | Event::AssetRemoved { .. }
+                        | Event::AliasAdded { .. }
+                        | Event::AliasRemoved { .. }),
+                    ) => Some(event),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+
+        System::reset_events();
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Genres(UpdatableGenres::Add(classical)),
+        ));
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Genres(UpdatableGenres::Clear),
+        ));
+        assert_eq!(
+            granular_events(),
+            vec![
+                Event::<Test>::GenreAdded {
+                    id: artist_id,
+                    genre: classical.into(),
+                },
+                Event::<Test>::GenreRemoved {
+                    id: artist_id,
+                    genre: house.into(),
+                },
+                Event::<Test>::GenreRemoved {
+                    id: artist_id,
+                    genre: classical.into(),
+                },
+            ]
+        );
+
+        System::reset_events();
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Assets(UpdatableAssets::Add(
+                AssetType::Image,
+                b"cover".to_vec()
+            )),
+        ));
+        assert_ok!(Artists::force_update(
+            RuntimeOrigin::root(),
+            artist_id,
+            UpdatableDataOf::<Test>::Assets(UpdatableAssets::Remove(b"cover".to_vec())),
+        ));
+        assert_ok!(Artists::add_delegate(RuntimeOrigin::signed(artist_id), 2));
+        assert_ok!(Artists::update_as_delegate(
+            RuntimeOrigin::signed(2),
+            artist_id,
+            UpdatableDataOf::<Test>::Alias(None),
+        ));
+        assert_eq!(
+            granular_events(),
+            vec![
+                Event::<Test>::AssetAdded {
+                    id: artist_id,
+                    hash: cover_hash.clone(),
+                },
+                Event::<Test>::AssetRemoved {
+                    id: artist_id,
+                    hash: cover_hash,
+                },
+                Event::<Test>::AliasRemoved {
+                    id: artist_id,
+                    alias: artist.alias.clone().unwrap(),
+                },
+            ]
+        );
+
+        // Other fields only get the `ArtistUpdated` event
+        System::reset_events();
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Description(None),
+        ));
+        assert!(granular_events().is_empty());
+    })
+}
+
+#[test]
+fn artist_alias_capacity_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        // No alias until verified
+        UnverifiedAliases::set(0);
+        assert_noop!(
+            Artists::register(
+                RuntimeOrigin::signed(artist_id),
+                artist.main_name.clone(),
+                artist.alias.clone(),
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            ),
+            ArtistsError::<Test>::TooManyAliases
+        );
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            None,
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert_noop!(
+            Artists::update(
+                RuntimeOrigin::signed(artist_id),
+                UpdatableDataOf::<Test>::Alias(artist.alias.clone()),
+            ),
+            ArtistsError::<Test>::TooManyAliases
+        );
+        // Clearing the alias is always allowed
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Alias(None),
+        ));
+
+        assert_ok!(Artists::verify_artist(
+            RuntimeOrigin::root(),
+            artist_id,
+            None
+        ));
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Alias(artist.alias.clone()),
+        ));
+        assert_eq!(
+            Artists::get_artist_by_id(artist_id)
+                .unwrap()
+                .aliases
+                .first()
+                .cloned(),
+            artist.alias
+        );
+
+        UnverifiedAliases::set(5);
+    })
+}
+
+#[test]
+fn artist_aliases_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let alias = artist.alias.clone().unwrap();
+        let stage_name = to_bounded_alias("Stage Name".to_string());
+        let byte_deposit = <Test as Config>::ByteDeposit::get();
+        let aliases_update = |x| {
+            Artists::update(
+                RuntimeOrigin::signed(artist_id),
+                UpdatableDataOf::<Test>::Aliases(x),
+            )
+        };
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        let registered = Artists::get_artist_by_id(artist_id).unwrap();
+
+        // An added alias gets indexed, its bytes being held
+        assert_ok!(aliases_update(UpdatableAliases::Add(stage_name.clone())));
+        let updated = Artists::get_artist_by_id(artist_id).unwrap();
+        assert_eq!(
+            updated.aliases.to_vec(),
+            vec![alias.clone(), stage_name.clone()]
+        );
+        assert_eq!(
+            updated.deposit - registered.deposit,
+            byte_deposit * stage_name.encoded_size() as u128
+        );
+        assert_eq!(
+            Artists::get_artist_by_alias(stage_name.to_vec()),
+            Some(updated.clone())
+        );
+        assert_eq!(
+            Artists::get_artist_record(&artist_id, 3),
+            Some(VersionedArtistRecord::V3(ArtistRecordV3 {
+                id: artist_id,
+                owner: artist_id,
+                main_name: artist.main_name.to_vec(),
+                aliases: vec![alias.to_vec(), stage_name.to_vec()],
+                main_genre: None,
+                genres: updated.genres.to_vec(),
+                description: updated.description.clone(),
+                raw_description: None,
+                registered_at: updated.registered_at,
+                verified_at: None,
+                status: ArtistStatus::Active,
+                kind: ArtistKind::Solo,
+                members: Vec::new(),
+                contracts: Vec::new(),
+                deposit: updated.deposit,
+            }))
+        );
+        System::assert_has_event(
+            Event::<Test>::AliasAdded {
+                id: artist_id,
+                alias: stage_name.clone(),
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            aliases_update(UpdatableAliases::Add(stage_name.clone())),
+            ArtistsError::<Test>::NotUniqueAlias
+        );
+        assert_noop!(
+            aliases_update(UpdatableAliases::Remove(to_bounded_alias(
+                "Unknown".to_string()
+            ))),
+            ArtistsError::<Test>::AliasNotFound
+        );
+
+        // Another artist can't take any of the aliases
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(2),
+            artist.main_name.clone(),
+            None,
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert_noop!(
+            Artists::update(
+                RuntimeOrigin::signed(2),
+                UpdatableDataOf::<Test>::Aliases(UpdatableAliases::Add(stage_name.clone())),
+            ),
+            ArtistsError::<Test>::AliasUnavailable
+        );
+
+        // Up to `MaxAliases` aliases
+        for i in 2..<Test as Config>::MaxAliases::get() {
+            assert_ok!(aliases_update(UpdatableAliases::Add(to_bounded_alias(
+                format!("Alias {}", i)
+            ))));
+        }
+        assert_noop!(
+            aliases_update(UpdatableAliases::Add(to_bounded_alias(
+                "One Too Many".to_string()
+            ))),
+            ArtistsError::<Test>::TooManyAliases
+        );
+
+        // A removed alias is freed
+        assert_ok!(aliases_update(UpdatableAliases::Remove(alias.clone())));
+        assert!(Artists::get_artist_by_alias(alias.to_vec()).is_none());
+        assert!(!Artists::get_artist_by_id(artist_id)
+            .unwrap()
+            .aliases
+            .contains(&alias));
+
+        // Clearing the aliases frees all of them and gives their deposit back
+        assert_ok!(aliases_update(UpdatableAliases::Clear));
+        let cleared = Artists::get_artist_by_id(artist_id).unwrap();
+        assert!(cleared.aliases.is_empty());
+        assert_eq!(crate::ArtistAliasIndex::<Test>::iter().count(), 0);
+        assert_eq!(
+            registered.deposit - cleared.deposit,
+            byte_deposit * alias.encoded_size() as u128
+        );
+
+        // Setting a single alias replaces all of them
+        assert_ok!(aliases_update(UpdatableAliases::Add(stage_name.clone())));
+        assert_ok!(Artists::update(
+            RuntimeOrigin::signed(artist_id),
+            UpdatableDataOf::<Test>::Alias(Some(alias.clone())),
+        ));
+        assert_eq!(
+            Artists::get_artist_by_id(artist_id)
+                .unwrap()
+                .aliases
+                .to_vec(),
+            vec![alias.clone()]
+        );
+        assert!(Artists::get_artist_by_alias(stage_name.to_vec()).is_none());
+        assert_ok!(Artists::do_try_state());
+    })
+}
+
+#[test]
+fn artist_name_retention_works() {
+    new_test_ext().execute_with(|| {
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+        let name_hash = Artists::name_hash(&artist.main_name);
+        let register = |id: u64| {
+            Artists::register(
+                RuntimeOrigin::signed(id),
+                artist.main_name.clone(),
+                None,
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            )
+        };
+
+        assert_ok!(register(artist_id));
+        assert_ok!(Artists::request_unregister(RuntimeOrigin::signed(
+            artist_id
+        )));
+        System::set_block_number(<Test as Config>::UnregisterPeriod::get() as u64);
+        assert_ok!(Artists::confirm_unregister(RuntimeOrigin::signed(
+            artist_id
+        )));
+
+        let retired_until = System::block_number() + <Test as Config>::NameRetentionPeriod::get();
+        assert_eq!(
+            crate::RetiredNames::<Test>::get(name_hash),
+            Some((artist_id, retired_until))
+        );
+
+        // Only the previous owner can register the name back during the retention
+        System::set_block_number(retired_until);
+        assert_noop!(register(2), ArtistsError::<Test>::NameRetired);
+        assert_ok!(register(artist_id));
+        assert!(crate::RetiredNames::<Test>::get(name_hash).is_none());
+
+        // Anyone can register it once the retention passed
+        assert_ok!(Artists::request_unregister(RuntimeOrigin::signed(
+            artist_id
+        )));
+        System::set_block_number(
+            System::block_number() + <Test as Config>::UnregisterPeriod::get() as u64,
+        );
+        assert_ok!(Artists::confirm_unregister(RuntimeOrigin::signed(
+            artist_id
+        )));
+        System::set_block_number(
+            System::block_number() + <Test as Config>::NameRetentionPeriod::get() + 1,
+        );
+        assert_ok!(register(2));
+        assert!(crate::RetiredNames::<Test>::get(name_hash).is_none());
+    })
+}
+
+#[test]
+fn artist_stale_pruning_works() {
+    use frame_support::traits::Hooks;
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let artist = tester_artist::<Test>();
+        let escrow_held = |id| {
+            Balances::balance_on_hold(
+                &RuntimeHoldReason::Artists(HoldReason::VerificationRequest),
+                &id,
+            )
+        };
+        let evidence: BoundedVec<_, <Test as Config>::MaxEvidence> =
+            vec![<Test as frame_system::Config>::Hashing::hash(
+                b"signed statement",
+            )]
+            .try_into()
+            .unwrap();
+        let lifetime = <Test as Config>::VerificationRequestLifetime::get();
+
+        for id in 1..=2u64 {
+            assert_ok!(Artists::register(
+                RuntimeOrigin::signed(id),
+                artist.main_name.clone(),
+                None,
+                artist.genres.clone(),
+                artist.description.clone(),
+                artist.assets.clone(),
+            ));
+            assert_ok!(Artists::request_verification(
+                RuntimeOrigin::signed(id),
+                evidence.clone()
+            ));
+        }
+
+        let names: Vec<_> = (0..3u8)
+            .map(|i| <Test as frame_system::Config>::Hashing::hash(&[i]))
+            .collect();
+        for name_hash in names.iter() {
+            crate::RetiredNames::<Test>::insert(name_hash, (3u64, 1 + lifetime));
+        }
+
+        // Nothing expired yet
+        System::set_block_number(1 + lifetime);
+        Artists::on_idle(System::block_number(), Weight::MAX);
+        assert!(Artists::get_pending_verification(1).is_some());
+        assert!(names
+            .iter()
+            .all(|name_hash| crate::RetiredNames::<Test>::contains_key(name_hash)));
+
+        // The requests get pruned within `MaxCleanupPerBlock`, refunding their deposits
+        System::set_block_number(2 + lifetime);
+        assert_ok!(Artists::submit_verification_evidence(
+            RuntimeOrigin::signed(2),
+            evidence.clone()
+        ));
+        Artists::on_idle(System::block_number(), Weight::MAX);
+        System::assert_has_event(
+            Event::<Test>::StalePruned {
+                entry: StaleEntry::Verification(1),
+            }
+            .into(),
+        );
+        assert!(Artists::get_pending_verification(1).is_none());
+        assert_eq!(escrow_held(1), 0);
+        assert!(Artists::get_pending_verification(2).is_some());
+        assert_eq!(
+            crate::VerificationQueue::<Test>::get().into_inner(),
+            vec![2]
+        );
+
+        // The budget left examines a single retired name, after the ones examined before
+        let pruned_names = || {
+            names
+                .iter()
+                .filter(|name_hash| !crate::RetiredNames::<Test>::contains_key(name_hash))
+                .count()
+        };
+        assert_eq!(pruned_names(), 1);
+
+        // Past the last retired name, the cleanup starts over from the first one
+        Artists::on_idle(System::block_number(), Weight::MAX);
+        assert!(crate::RetiredNamesCursor::<Test>::get().is_none());
+        Artists::on_idle(System::block_number(), Weight::MAX);
+        assert_eq!(pruned_names(), 3);
+
+        // No weight left, nothing gets pruned
+        System::set_block_number(3 + 2 * lifetime);
+        Artists::on_idle(System::block_number(), Weight::zero());
+        assert!(Artists::get_pending_verification(2).is_some());
+        Artists::on_idle(System::block_number(), Weight::MAX);
+        assert!(Artists::get_pending_verification(2).is_none());
+        assert_eq!(escrow_held(2), 0);
+    })
+}
+
+#[test]
+fn verification_expiry_works() {
+    use frame_support::traits::Hooks;
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let validity = <Test as Config>::VerificationValidity::get();
+        let names: Vec<<Test as Config>::Name> = vec![
+            b"Tester".to_vec().try_into().unwrap(),
+            b"The New Tester".to_vec().try_into().unwrap(),
+        ];
+
+        for (id, name) in (1..=2u64).zip(names.iter()) {
+            assert_ok!(Artists::register(
+                RuntimeOrigin::signed(id),
+                name.clone(),
+                None,
+                Default::default(),
+                None,
+                Default::default(),
+            ));
+            assert_ok!(Artists::verify_artist(RuntimeOrigin::root(), id, None));
+        }
+
+        // Only the verifier can renew a verification, of a verified artist
+        assert_noop!(
+            Artists::renew_verification(RuntimeOrigin::signed(2), 2),
+            BadOrigin
+        );
+        assert_noop!(
+            Artists::renew_verification(RuntimeOrigin::root(), 3),
+            Error::<Test>::NotRegistered
+        );
+
+        System::set_block_number(1 + validity / 2);
+        assert_ok!(Artists::renew_verification(RuntimeOrigin::root(), 2));
+        System::assert_last_event(Event::<Test>::VerificationRenewed { id: 2 }.into());
+        assert_eq!(
+            Artists::get_artist_by_id(2).unwrap().verified_at,
+            Some(1 + validity / 2)
+        );
+
+        // Nothing expired yet
+        System::set_block_number(validity);
+        Artists::on_idle(System::block_number(), Weight::MAX);
+        assert!(Artists::get_artist_by_id(1).unwrap().is_verified());
+
+        // The first verification expires, freeing its name, while the renewed one still holds
+        System::set_block_number(1 + validity);
+        Artists::on_idle(System::block_number(), Weight::MAX);
+        System::assert_has_event(
+            Event::<Test>::VerificationExpired {
+                id: 1,
+                verified_at: 1,
+                requeued: true,
+            }
+            .into(),
+        );
+        let expired = Artists::get_artist_by_id(1).unwrap();
+        assert!(!expired.is_verified());
+        assert!(Artists::get_pending_verification(1).is_some());
+        assert!(Artists::get_artist_by_name(names[0].to_vec()).is_none());
+        assert!(Artists::get_artist_by_name(names[1].to_vec()).is_some());
+
+        // The expired artist has to be verified again, it can't be renewed
+        assert_noop!(
+            Artists::renew_verification(RuntimeOrigin::root(), 1),
+            Error::<Test>::NotVerified
+        );
+        assert_ok!(Artists::verify_artist(RuntimeOrigin::root(), 1, None));
+
+        // A lapsed verification stops counting before the cleanup gets to it
+        System::set_block_number(1 + validity / 2 + validity);
+        Artists::on_idle(System::block_number(), Weight::zero());
+        let lapsed = Artists::get_artist_by_id(2).unwrap();
+        assert!(!lapsed.is_verified());
+        assert_eq!(lapsed.verified_at, Some(1 + validity / 2));
+        assert!(!<Artists as ArtistsInspect<u64>>::is_verified(&2));
+        assert!(Artists::get_artist_by_name(names[1].to_vec()).is_none());
+        assert_noop!(
+            Artists::renew_verification(RuntimeOrigin::root(), 2),
+            Error::<Test>::NotVerified
+        );
+        assert_ok!(Artists::do_try_state());
+
+        // The cleanup then drops it from the names index
+        Artists::on_idle(System::block_number(), Weight::MAX);
+        assert_eq!(Artists::get_artist_by_id(2).unwrap().verified_at, None);
+        assert!(Artists::get_artist_by_id(1).unwrap().is_verified());
+        assert_ok!(Artists::do_try_state());
+
+        // A lapsed artist gets verified again without waiting for the cleanup
+        System::set_block_number(1 + 2 * validity);
+        assert!(!Artists::get_artist_by_id(1).unwrap().is_verified());
+        assert_ok!(Artists::verify_artist(RuntimeOrigin::root(), 1, None));
+        System::assert_has_event(
+            Event::<Test>::VerificationExpired {
+                id: 1,
+                verified_at: 1 + validity,
+                requeued: true,
+            }
+            .into(),
+        );
+        assert!(Artists::get_artist_by_id(1).unwrap().is_verified());
+        assert!(Artists::get_artist_by_name(names[0].to_vec()).is_some());
+
+        assert_ok!(Artists::do_try_state());
+    })
+}
+
+#[test]
+fn expired_verifications_get_queued_back() {
+    use frame_support::traits::Hooks;
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let validity = <Test as Config>::VerificationValidity::get();
+        let escrow = <Test as Config>::VerificationDeposit::get();
+
+        for id in 1..=4u64 {
+            let artist = tester_artist_of::<Test>(id);
+            assert_ok!(Artists::register(
+                RuntimeOrigin::signed(id),
+                artist.main_name,
+                artist.alias,
+                artist.genres,
+                artist.description,
+                artist.assets,
+            ));
+        }
+        assert_ok!(Artists::verify_artist(RuntimeOrigin::root(), 1, None));
+        assert_ok!(Artists::link_contract(&1, 10));
+        let held = Balances::total_balance_on_hold(&1);
+
+        // The expired artist escrows a new deposit to be verified again, its contracts notified
+        System::set_block_number(1 + validity);
+        Artists::on_idle(System::block_number(), Weight::MAX);
+        System::assert_has_event(
+            Event::<Test>::VerificationExpired {
+                id: 1,
+                verified_at: 1,
+                requeued: true,
+            }
+            .into(),
+        );
+        assert!(Artists::get_pending_verification(1).is_some());
+        assert_eq!(Balances::total_balance_on_hold(&1), held + escrow);
+        assert_eq!(
+            ContractNotices::get(),
+            vec![(1, 10, ContractNotice::Unverified)]
+        );
+        assert_ok!(Artists::do_try_state());
+
+        assert_ok!(Artists::approve_verification(
+            RuntimeOrigin::root(),
+            1,
+            None
+        ));
+        assert!(Artists::get_artist_by_id(1).unwrap().is_verified());
+        assert_eq!(Balances::total_balance_on_hold(&1), held);
+
+        // A full queue leaves the expired artist unverified, without evicting the requests
+        assert_ok!(Artists::verify_artist(RuntimeOrigin::root(), 2, None));
+        for id in [3, 4] {
+            assert_ok!(Artists::request_verification(
+                RuntimeOrigin::signed(id),
+                Default::default()
+            ));
+        }
+        System::set_block_number(1 + 2 * validity);
+        Artists::on_idle(System::block_number(), Weight::MAX);
+        System::assert_has_event(
+            Event::<Test>::VerificationExpired {
+                id: 2,
+                verified_at: 1 + validity,
+                requeued: false,
+            }
+            .into(),
+        );
+        assert!(!Artists::get_artist_by_id(2).unwrap().is_verified());
+        assert!(Artists::get_pending_verification(2).is_none());
+        assert_eq!(
+            crate::VerificationQueue::<Test>::get().into_inner(),
+            vec![3, 4]
+        );
+        assert_ok!(Artists::do_try_state());
+
+        ContractNotices::set(Vec::new());
+    })
+}
+
+#[test]
+fn group_co_ownership_works() {
+    new_test_ext().execute_with(|| {
+        let group = 1u64;
+        let (co_owner, member) = (2u64, 3u64);
+        let share = 50u128;
+        let artist = tester_artist_of::<Test>(group);
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(group),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert_ok!(Artists::set_artist_kind(
+            RuntimeOrigin::signed(group),
+            ArtistKind::Group
+        ));
+        for account in [co_owner, member] {
+            assert_ok!(Artists::add_member(RuntimeOrigin::signed(group), account));
+            assert_ok!(Artists::accept_membership(
+                RuntimeOrigin::signed(account),
+                group
+            ));
+        }
+
+        assert_noop!(
+            Artists::join_ownership(RuntimeOrigin::signed(4), group, share),
+            ArtistsError::<Test>::NotMember
+        );
+        assert_noop!(
+            Artists::offer_co_ownership(RuntimeOrigin::signed(group), 4, share),
+            ArtistsError::<Test>::NotMember
+        );
+        assert_noop!(
+            Artists::offer_co_ownership(RuntimeOrigin::signed(group), co_owner, 0),
+            ArtistsError::<Test>::ZeroShare
+        );
+        assert_noop!(
+            Artists::offer_co_ownership(RuntimeOrigin::signed(group), co_owner, 9),
+            ArtistsError::<Test>::ShareTooLow
+        );
+
+        // Members can't promote themselves without an offer of the group
+        assert_noop!(
+            Artists::join_ownership(RuntimeOrigin::signed(co_owner), group, share),
+            ArtistsError::<Test>::NoCoOwnershipOffer
+        );
+        assert_noop!(
+            Artists::offer_co_ownership(RuntimeOrigin::signed(co_owner), co_owner, share),
+            ArtistsError::<Test>::NotRegistered
+        );
+
+        assert_ok!(Artists::offer_co_ownership(
+            RuntimeOrigin::signed(group),
+            co_owner,
+            share
+        ));
+        System::assert_last_event(
+            Event::<Test>::CoOwnershipOffered {
+                id: group,
+                member: co_owner,
+                share,
+            }
+            .into(),
+        );
+        // Nor pick another share than the offered one
+        assert_noop!(
+            Artists::join_ownership(RuntimeOrigin::signed(co_owner), group, share - 1),
+            ArtistsError::<Test>::NoCoOwnershipOffer
+        );
+        assert_ok!(Artists::join_ownership(
+            RuntimeOrigin::signed(co_owner),
+            group,
+            share
+        ));
+        System::assert_last_event(
+            Event::<Test>::CoOwnerJoined {
+                id: group,
+                member: co_owner,
+                share,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::total_balance_on_hold(&co_owner), share);
+        assert!(!crate::CoOwnershipOffers::<Test>::contains_key(
+            group, co_owner
+        ));
+        assert_noop!(
+            Artists::offer_co_ownership(RuntimeOrigin::signed(group), co_owner, share),
+            ArtistsError::<Test>::AlreadyCoOwner
+        );
+
+        // Co-owners manage the group, plain members don't
+        let new_data = UpdatableDataOf::<Test>::Description(Some(b"Our band".to_vec()));
+        assert_ok!(Artists::update_as_delegate(
+            RuntimeOrigin::signed(co_owner),
+            group,
+            new_data.clone()
+        ));
+        assert_noop!(
+            Artists::update_as_delegate(RuntimeOrigin::signed(member), group, new_data.clone()),
+            ArtistsError::<Test>::NotDelegate
+        );
+
+        // Leaving the group settles the share and the management rights at once
+        assert_ok!(Artists::leave_group(RuntimeOrigin::signed(co_owner), group));
+        System::assert_has_event(
+            Event::<Test>::CoOwnerExited {
+                id: group,
+                member: co_owner,
+                policy: DepositPolicy::Refund,
+                share,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::total_balance_on_hold(&co_owner), 0);
+        assert!(!crate::CoOwners::<Test>::contains_key(group, co_owner));
+        assert_noop!(
+            Artists::update_as_delegate(RuntimeOrigin::signed(co_owner), group, new_data),
+            ArtistsError::<Test>::NotDelegate
+        );
+
+        // The shares follow the group removal policy
+        assert_ok!(Artists::offer_co_ownership(
+            RuntimeOrigin::signed(group),
+            member,
+            share
+        ));
+        assert_ok!(Artists::join_ownership(
+            RuntimeOrigin::signed(member),
+            group,
+            share
+        ));
+        let balance = Balances::total_balance(&member);
+        assert_ok!(Artists::force_unregister(
+            RuntimeOrigin::root(),
+            group,
+            DepositPolicy::Slash
+        ));
+        System::assert_has_event(
+            Event::<Test>::CoOwnerExited {
+                id: group,
+                member,
+                policy: DepositPolicy::Slash,
+                share,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::total_balance(&member), balance - share);
+        assert_eq!(crate::CoOwners::<Test>::iter_prefix(group).count(), 0);
+    })
+}
+
+#[test]
+fn management_call_filter_works() {
+    use frame_support::traits::InstanceFilter;
+
+    let update = crate::Call::<Test>::update {
+        data: UpdatableDataOf::<Test>::Description(None),
+    };
+    let transfer = crate::Call::<Test>::transfer_ownership { new_owner: 2 };
+    let unregister = crate::Call::<Test>::request_unregister {};
+    let splits = crate::Call::<Test>::clear_royalty_splits {};
+
+    assert!(Artists::is_management_call(&update));
+    assert!(!Artists::is_management_call(&transfer));
+    assert!(!Artists::is_management_call(&unregister));
+    assert!(!Artists::is_management_call(&splits));
+
+    // A manager proxy only gets the management calls
+    let manager = ArtistCallGroup::ManagementCall;
+    assert!(InstanceFilter::<crate::Call<Test>>::filter(
+        &manager, &update
+    ));
+    assert!(!InstanceFilter::<crate::Call<Test>>::filter(
+        &manager, &transfer
+    ));
+    assert!(InstanceFilter::<crate::Call<Test>>::filter(
+        &ArtistCallGroup::Any,
+        &transfer
+    ));
+    assert!(InstanceFilter::<crate::Call<Test>>::is_superset(
+        &ArtistCallGroup::Any,
+        &manager
+    ));
+    assert!(!InstanceFilter::<crate::Call<Test>>::is_superset(
+        &manager,
+        &ArtistCallGroup::Any
+    ));
+}
+
+#[test]
+fn instances_keep_separate_registries() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let artist = tester_artist::<Test>();
+        let artist_id = 1u64;
+
+        assert_ok!(Artists::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        assert!(Producers::get_artist_by_id(&artist_id).is_none());
+        let artist_held = Balances::total_balance_on_hold(&artist_id);
+
+        // The same account can register under the same names in the producers registry
+        assert_ok!(Producers::register(
+            RuntimeOrigin::signed(artist_id),
+            artist.main_name.clone(),
+            artist.alias.clone(),
+            artist.genres.clone(),
+            artist.description.clone(),
+            artist.assets.clone(),
+        ));
+        System::assert_last_event(RuntimeEvent::Producers(Event::ArtistRegistered {
+            id: artist_id,
+            name: artist.main_name.clone(),
+        }));
+        assert_eq!(Artists::artists_count(), 1);
+        assert_eq!(Producers::artists_count(), 1);
+
+        // Each registry holds its own deposit
+        assert_eq!(
+            Balances::balance_on_hold(
+                &RuntimeHoldReason::Producers(HoldReason::ArtistRegistration),
+                &artist_id
+            ),
+            5
+        );
+        assert_eq!(Balances::total_balance_on_hold(&artist_id), artist_held * 2);
+
+        // Unregistering the producer leaves the artist untouched
+        assert_ok!(Producers::force_unregister(
+            RuntimeOrigin::root(),
+            artist_id,
+            DepositPolicy::Refund
         ));
+        assert!(Producers::get_artist_by_id(&artist_id).is_none());
+        assert!(Artists::get_artist_by_id(&artist_id).is_some());
+        assert_eq!(Balances::total_balance_on_hold(&artist_id), artist_held);
     })
 }
@@ -15,42 +15,149 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{Config, Error, HoldReason};
+use crate::{Call, Config, DepositExemptions, Error, HoldReason, Pallet};
 use codec::{Decode, Encode, MaxEncodedLen};
 use derive_getters::Getters;
+use enumflags2::{bitflags, BitFlags};
 use frame_support::dispatch::{DispatchErrorWithPostInfo, DispatchResultWithPostInfo};
-use frame_support::pallet_prelude::Get;
+use frame_support::pallet_prelude::{ConstU32, DispatchResult, Get};
+use frame_support::traits::fungible::BalancedHold;
 use frame_support::traits::fungible::Inspect;
 use frame_support::traits::fungible::MutateHold;
 use frame_support::traits::tokens::fungible::hold::Inspect as InspectHold;
 use frame_support::traits::tokens::Precision;
+use frame_support::traits::{Imbalance, InstanceFilter, OnUnbalanced};
+use frame_support::weights::WeightMeter;
 use frame_support::BoundedVec;
 use frame_system::pallet_prelude::BlockNumberFor;
 use genres_registry::MusicGenre;
-use scale_info::TypeInfo;
-use sp_runtime::traits::Hash;
-use sp_runtime::{RuntimeDebug, SaturatedConversion, Saturating};
+use scale_info::{build::Fields, meta_type, Path, Type, TypeInfo, TypeParameter};
+use sp_runtime::traits::{Hash, IdentifyAccount, Verify, Zero};
+use sp_runtime::{Perbill, RuntimeDebug, SaturatedConversion, Saturating};
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::prelude::Vec;
 
 pub(super) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
-pub(super) type BalanceOf<T> =
-    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
-pub(super) type ArtistAliasOf<T> = BoundedVec<u8, <T as Config>::MaxNameLen>;
+pub(super) type BalanceOf<T, I = ()> =
+    <<T as Config<I>>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+pub(super) type ArtistAliasOf<T, I = ()> = <T as Config<I>>::Name;
+pub(super) type UpdatableDataOf<T, I = ()> =
+    UpdatableData<ArtistAliasOf<T, I>, <T as frame_system::Config>::Hash>;
+pub(super) type FingerprintOf<T> = Fingerprint<<T as frame_system::Config>::Hash>;
+pub(super) type RoyaltySplitsOf<T, I = ()> =
+    BoundedVec<(AccountIdOf<T>, Perbill), <T as Config<I>>::MaxRoyaltyBeneficiaries>;
+pub(super) type ClaimSignerOf<T, I = ()> =
+    <<<T as Config<I>>::ClaimSignature as Verify>::Signer as IdentifyAccount>::AccountId;
+
+/// The context prefixed to the profile claim vouchers payload, so the `ClaimAuthority` signatures
+/// can't be replayed elsewhere.
+pub const CLAIM_VOUCHER_CONTEXT: &[u8] = b"allfeat/artists/claim";
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
-pub enum UpdatableData<ArtistAlias> {
+pub enum UpdatableData<ArtistAlias, Hash> {
+    /// Replace every alias of the artist by the passed one, if any.
     Alias(Option<ArtistAlias>),
     Genres(UpdatableGenres),
     Description(Option<Vec<u8>>),
-    Assets(UpdatableAssets),
+    /// A short description to store on-chain as is, replacing the hashed one if any.
+    RawDescription(Option<Vec<u8>>),
+    /// The fingerprint of the description computed off-chain, replacing the description if any.
+    DescriptionFingerprint(Option<Fingerprint<Hash>>),
+    Assets(UpdatableAssets<Hash>),
+    OpenTo(AvailabilityFlags),
+    /// The fingerprint of the off-chain contact record to commit to, if any.
+    ContactHash(Option<Hash>),
+    Links(UpdatableLinks),
+    /// The headline genre, which can't be one of the secondary genres.
+    MainGenre(Option<MusicGenre>),
+    Aliases(UpdatableAliases<ArtistAlias>),
+}
+
+impl<ArtistAlias, Hash> UpdatableData<ArtistAlias, Hash> {
+    /// The alias the update gives to the artist, if any, to check against the aliases index.
+    pub(crate) fn added_alias(&self) -> Option<&ArtistAlias> {
+        match self {
+            UpdatableData::Alias(Some(x)) | UpdatableData::Aliases(UpdatableAliases::Add(x)) => {
+                Some(x)
+            }
+            _ => None,
+        }
+    }
 }
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
-pub enum UpdatableAssets {
-    Add(Vec<u8>),
+pub enum UpdatableAssets<Hash> {
+    Add(AssetType, Vec<u8>),
+    /// add an asset from its fingerprint computed off-chain (e.g: its IPFS CID)
+    AddFingerprint(AssetType, Fingerprint<Hash>),
     /// lookup into the existing value if the content exist and try to remove it
     Remove(Vec<u8>),
+    /// lookup into the existing value if the fingerprint exist and try to remove it
+    RemoveFingerprint(Fingerprint<Hash>),
+    /// remove the asset at the passed position of the existing list
+    RemoveAt(u32),
+    Clear,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum UpdatableLinks {
+    Add(LinkType, Vec<u8>),
+    /// lookup into the existing value if the link exist and try to remove it
+    Remove(LinkType, Vec<u8>),
+    Clear,
+}
+
+/// The data an artist registers with through `register_v2`, bundled so the registration can get
+/// new fields without new calls.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T, I))]
+pub struct ArtistRegistrationData<T: Config<I>, I: 'static = ()> {
+    /// The main name of the artist, fixed once registered.
+    pub main_name: T::Name,
+    /// An alias to the main name.
+    pub alias: Option<T::Name>,
+    /// The main music genres of the artist.
+    pub genres: BoundedVec<MusicGenre, T::MaxGenres>,
+    /// The description of the artist, stored as a hash.
+    pub description: Option<BoundedVec<u8, T::MaxHashedDescriptionLen>>,
+    /// The assets officially representing the artist, stored as hashes.
+    pub assets: BoundedVec<(AssetType, Vec<u8>), T::MaxAssets>,
+    /// What the artist is open to (collaborations, bookings...).
+    pub open_to: AvailabilityFlags,
+    /// The fingerprint of an off-chain contact record of the artist.
+    pub contact_hash: Option<T::Hash>,
+    /// External identifiers and links of the artist.
+    pub links: BoundedVec<(LinkType, Vec<u8>), T::MaxLinks>,
+}
+
+impl<T: Config<I>, I: 'static> ArtistRegistrationData<T, I> {
+    /// The registration data of the historical `register` arguments, without any of the later
+    /// fields.
+    pub fn new(
+        main_name: T::Name,
+        alias: Option<T::Name>,
+        genres: BoundedVec<MusicGenre, T::MaxGenres>,
+        description: Option<BoundedVec<u8, T::MaxHashedDescriptionLen>>,
+        assets: BoundedVec<(AssetType, Vec<u8>), T::MaxAssets>,
+    ) -> Self {
+        ArtistRegistrationData {
+            main_name,
+            alias,
+            genres,
+            description,
+            assets,
+            open_to: Default::default(),
+            contact_hash: None,
+            links: Default::default(),
+        }
+    }
+}
+
+#[derive(Encode, MaxEncodedLen, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum UpdatableAliases<ArtistAlias> {
+    Add(ArtistAlias),
+    /// lookup into the existing value if the alias exist and try to remove it
+    Remove(ArtistAlias),
     Clear,
 }
 
@@ -59,268 +166,2258 @@ pub enum UpdatableGenres {
     Add(MusicGenre),
     /// lookup into the existing value if the content exist and try to remove it
     Remove(MusicGenre),
+    /// remove the genre at the passed position of the existing list
+    RemoveAt(u32),
     Clear,
 }
 
-/// How an Artist is designed to be stored on-chain.
+/// A music genre as stored on-chain, by the tag of its top-level genre and the optional subtag of
+/// its subgenre rather than as the registry enum, so stored artists keep decoding whatever genres
+/// get added to the registry.
+#[derive(
+    Encode,
+    MaxEncodedLen,
+    Decode,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    RuntimeDebug,
+    TypeInfo,
+    Getters,
+)]
+pub struct StoredGenre {
+    /// The top-level genre, its variant index in the registry.
+    pub(crate) tag: u16,
+    /// The subgenre, its variant index under the top-level genre in the registry.
+    pub(crate) subtag: Option<u16>,
+}
+
+impl StoredGenre {
+    /// The stored form of the passed genre, only if it reads back as the same genre.
+    pub fn checked(genre: MusicGenre) -> Option<Self> {
+        let stored = Self::from(genre);
+        (stored.genre() == Some(genre)).then_some(stored)
+    }
+
+    /// The registry genre, `None` if the registry running doesn't know it.
+    pub fn genre(&self) -> Option<MusicGenre> {
+        let tag = u8::try_from(self.tag).ok()?;
+        let subtag = self.subtag.map(u8::try_from).transpose().ok()?;
+
+        MusicGenre::decode(&mut &(tag, subtag).encode()[..]).ok()
+    }
+}
+
+impl From<MusicGenre> for StoredGenre {
+    fn from(genre: MusicGenre) -> Self {
+        // Registry genres encode as their variant index, followed by the optional variant index
+        // of their subgenre.
+        let encoded = genre.encode();
+
+        StoredGenre {
+            tag: encoded[0].into(),
+            subtag: match encoded.get(1) {
+                Some(1) => encoded.get(2).map(|x| (*x).into()),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// The kind of content an artist asset fingerprint refers to.
+#[derive(Encode, MaxEncodedLen, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum AssetType {
+    Image,
+    Audio,
+    Video,
+    Document,
+    Other,
+}
+
+impl AssetType {
+    /// The amount of asset types.
+    pub const COUNT: usize = 5;
+}
+
+/// The fingerprint of some artist data (description, asset...) along with the algorithm it got
+/// computed with, so artists can declare the fingerprints their content is already known by.
+#[derive(Encode, MaxEncodedLen, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum Fingerprint<Hash> {
+    /// The data hashed on-chain with the runtime hashing.
+    Native(Hash),
+    /// The SHA-256 digest of the data.
+    Sha256([u8; 32]),
+    /// The IPFS content identifier of the data, in its binary form.
+    IpfsCid(BoundedVec<u8, ConstU32<64>>),
+}
+
+impl<Hash: AsRef<[u8]>> Fingerprint<Hash> {
+    /// The binary prefix of the CIDv1 of raw data hashed with SHA-256, followed by the digest.
+    const RAW_SHA256_CID_PREFIX: [u8; 4] = [0x01, 0x55, 0x12, 0x20];
+
+    /// The stored bytes of the fingerprint, each of them being covered by the deposit.
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Fingerprint::Native(hash) => hash.as_ref(),
+            Fingerprint::Sha256(digest) => &digest[..],
+            Fingerprint::IpfsCid(cid) => &cid[..],
+        }
+    }
+
+    /// The digest the fingerprint refers to, the CID of raw data hashed with SHA-256 being reduced
+    /// to its SHA-256 digest.
+    pub fn digest(&self) -> &[u8] {
+        match self {
+            Fingerprint::IpfsCid(cid)
+                if cid.len() == 36 && cid.starts_with(&Self::RAW_SHA256_CID_PREFIX) =>
+            {
+                &cid[4..]
+            }
+            _ => self.as_bytes(),
+        }
+    }
+
+    /// Whether both fingerprints refer to the same digest, whatever the variant declaring it.
+    pub fn same_digest(&self, other: &Self) -> bool {
+        self.digest() == other.digest()
+    }
+}
+
+/// The kind of external identifier or link of an artist.
+#[derive(Encode, MaxEncodedLen, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum LinkType {
+    /// The artist ID on Spotify.
+    Spotify,
+    /// The International Standard Name Identifier of the artist.
+    Isni,
+    /// The Interested Parties Information code of the artist.
+    Ipi,
+    /// The website URL of the artist.
+    Website,
+}
+
+/// A digital asset officially representing an artist.
 #[derive(Encode, MaxEncodedLen, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Getters)]
 #[scale_info(skip_type_params(T))]
-pub struct Artist<T>
+pub struct ArtistAsset<T>
 where
-    T: frame_system::Config + Config,
+    T: frame_system::Config,
 {
-    // Main data
-    /// The artist's identifier. While the predominant mapping employs AccountId => Artist,
-    /// it's essential to include this in the artist's data since verified artists can be retrieved by their name as well.
-    pub(crate) owner: AccountIdOf<T>,
-    /// When the artist got registered on-chain.
-    pub(crate) registered_at: BlockNumberFor<T>,
-    /// When the artist got verified.
-    verified_at: Option<BlockNumberFor<T>>,
-    // Metadata
-    /// The name of the artist.
-    /// This is generally the main name of how we usually call the artist (e.g: 'The Weeknd')
-    /// This is fixed and can't be changed after the registration.
-    pub(crate) main_name: BoundedVec<u8, T::MaxNameLen>,
-    /// An alias to the main name.
-    /// This name can be changed compared to the 'nickname'
-    pub(crate) alias: Option<ArtistAliasOf<T>>,
-    /// The main music genres of the artists.
-    genres: BoundedVec<MusicGenre, T::MaxGenres>,
-    // Metadata Fingerprint
-    // Given the significant size of certain data associated with an artist,
-    // we choose to store a digital fingerprint (hash) of this data rather than
-    // the raw data itself. This fingerprint acts as a unique digital reference,
-    // and services can use it to compare and validate the artist's data, ensuring
-    // that it has been approved and recorded on the blockchain by the artist themselves.
-    /// The digital fingerprint (hash) of the artist's description.
-    pub(crate) description: Option<T::Hash>,
-    /// Digital assets (such as photos, profile pictures, banners, videos, etc.)
-    /// that officially represent the artist. These fingerprints allow for the
-    /// verification of the authenticity of these assets.
-    assets: BoundedVec<T::Hash, T::MaxAssets>,
-    // Linked chain logic data
-    /// Associated smart-contracts deployed by dApps for the artist (e.g: royalties contracts)
-    contracts: BoundedVec<AccountIdOf<T>, T::MaxContracts>,
+    /// What kind of content the asset is (e.g: an image for a profile picture).
+    pub(crate) kind: AssetType,
+    /// The fingerprint of the asset content.
+    pub(crate) hash: FingerprintOf<T>,
+    /// When the asset got added to the artist.
+    pub(crate) added_at: BlockNumberFor<T>,
 }
 
-impl<T> Artist<T>
-where
-    T: frame_system::Config + Config,
-{
-    pub(super) fn new(
-        owner: AccountIdOf<T>,
-        main_name: BoundedVec<u8, T::MaxNameLen>,
-        alias: Option<ArtistAliasOf<T>>,
-        genres: BoundedVec<MusicGenre, T::MaxGenres>,
-        description: Option<Vec<u8>>,
-        assets: BoundedVec<Vec<u8>, T::MaxAssets>,
-    ) -> Result<Self, DispatchErrorWithPostInfo> {
-        let current_block = <frame_system::Pallet<T>>::block_number();
-
-        let mut new_artist = Artist {
-            owner,
-            registered_at: current_block,
-            verified_at: None,
-            main_name: main_name.clone(),
-            alias: Default::default(),
-            // need to set later with the checked fn
-            genres: Default::default(),
-            description: Default::default(),
-            assets: Default::default(),
-            contracts: Default::default(),
-        };
+/// Aggregated statistics of the registry, refreshed by `update_stats` so dashboards can get
+/// them from a single read.
+#[derive(Encode, MaxEncodedLen, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+pub struct RegistryStats<BlockNumber> {
+    /// The amount of registered artists.
+    pub total: u32,
+    /// The amount of verified artists.
+    pub verified: u32,
+    /// The amount of artist assets of each [`AssetType`], in their declaration order.
+    pub assets_per_type: [u32; AssetType::COUNT],
+    /// When these statistics got fully refreshed.
+    pub updated_at: BlockNumber,
+}
 
-        let name_len: BalanceOf<T> = main_name.encoded_size().saturated_into();
-        T::Currency::hold(
-            &HoldReason::ArtistName.into(),
-            &new_artist.owner,
-            T::ByteDeposit::get().saturating_mul(name_len),
-        )?;
+impl<BlockNumber> RegistryStats<BlockNumber> {
+    /// Add the passed artist to the statistics.
+    pub(super) fn count<T: Config<I>, I: 'static>(&mut self, artist: &Artist<T, I>) {
+        self.total.saturating_inc();
+        if artist.is_verified() {
+            self.verified.saturating_inc();
+        }
+        for asset in artist.assets.iter() {
+            self.assets_per_type[asset.kind as usize].saturating_inc();
+        }
+    }
+}
 
-        new_artist.set_alias(alias)?;
-        new_artist.set_checked_genres(genres)?;
-        new_artist.set_description(description)?;
-        assets
-            .iter()
-            .try_for_each(|asset| new_artist.add_checked_asset(asset).map(|_| ()))?;
+/// What to do with the held deposit of an artist removed by force.
+#[derive(Encode, MaxEncodedLen, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum DepositPolicy {
+    /// The deposit is released back to the artist.
+    Refund,
+    /// The deposit is slashed and handed to `T::Slash`.
+    Slash,
+    /// The passed part of the deposit is slashed and handed to `T::Slash`, the rest being
+    /// released back to the artist.
+    SlashPart(Perbill),
+}
 
-        Ok(new_artist)
+impl DepositPolicy {
+    /// The part of the deposit slashed by the policy.
+    pub(crate) fn slashed_part(&self) -> Perbill {
+        match self {
+            DepositPolicy::Refund => Perbill::zero(),
+            DepositPolicy::Slash => Perbill::one(),
+            DepositPolicy::SlashPart(part) => *part,
+        }
     }
+}
 
-    /// Set the genres of the artist while verifying that there is not the same genre multiple times.
-    pub(super) fn set_checked_genres(
-        &mut self,
-        genres: BoundedVec<MusicGenre, T::MaxGenres>,
-    ) -> DispatchResultWithPostInfo {
-        let mut seen = BTreeSet::new();
+/// The moderation status of an artist.
+#[derive(
+    Encode, MaxEncodedLen, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, TypeInfo,
+)]
+pub enum ArtistStatus {
+    /// The artist profile is publicly listed and can be updated.
+    #[default]
+    Active,
+    /// The artist profile is hidden by a moderation, it can't be updated nor get new contracts
+    /// until reinstated.
+    Suspended,
+    /// The artist profile is permanently hidden, it can't be reinstated.
+    Banned,
+}
 
-        for genre in genres.clone() {
-            if !seen.insert(genre.clone()) {
-                return Err(Error::<T>::NotUniqueGenre.into());
-            }
+/// Whether an artist performs alone or as a group (e.g: a band).
+#[derive(
+    Encode, MaxEncodedLen, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, TypeInfo,
+)]
+pub enum ArtistKind {
+    /// A single performer.
+    #[default]
+    Solo,
+    /// A band or a collective, listing its members.
+    Group,
+}
+
+/// The groups of artist calls a proxy can be restricted to, for runtimes to build their proxy
+/// types from, such as an "ArtistManager" proxy only filtering `ManagementCall`.
+#[derive(
+    Encode,
+    MaxEncodedLen,
+    Decode,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    RuntimeDebug,
+    TypeInfo,
+)]
+pub enum ArtistCallGroup {
+    /// Every call of the pallet.
+    #[default]
+    Any,
+    /// The calls managing the profile of an artist, never unregistering it, transferring its
+    /// ownership nor routing its royalties, see [`Pallet::is_management_call`].
+    ManagementCall,
+}
+
+impl<T: Config<I>, I: 'static> InstanceFilter<Call<T, I>> for ArtistCallGroup {
+    fn filter(&self, call: &Call<T, I>) -> bool {
+        match self {
+            ArtistCallGroup::Any => true,
+            ArtistCallGroup::ManagementCall => Pallet::<T, I>::is_management_call(call),
         }
+    }
 
-        self.genres = genres;
+    fn is_superset(&self, other: &Self) -> bool {
+        match (self, other) {
+            (x, y) if x == y => true,
+            (ArtistCallGroup::Any, _) => true,
+            _ => false,
+        }
+    }
+}
 
-        Ok(().into())
+/// What to do with a new verification request when the pending queue is full.
+#[derive(Encode, MaxEncodedLen, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum QueueOverflowPolicy {
+    /// The new request is rejected.
+    Reject,
+    /// The oldest pending request is evicted, refunding its deposit, to make room for the new one.
+    EvictOldest,
+}
+
+/// A stale entry pruned from the storage by the idle cleanup.
+#[derive(Encode, MaxEncodedLen, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum StaleEntry<AccountId, Hash> {
+    /// The pending verification of the artist, past its `VerificationRequestLifetime`.
+    Verification(AccountId),
+    /// The fingerprint of a retired name, past its `NameRetentionPeriod`.
+    RetiredName(Hash),
+}
+
+/// What an artist can be open to, for discovery dApps to filter artists on.
+#[bitflags]
+#[repr(u8)]
+#[derive(Encode, MaxEncodedLen, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum Availability {
+    /// Collaborating with other artists.
+    Collaboration = 1 << 0,
+    /// Being booked for live performances.
+    Booking = 1 << 1,
+    /// Licensing its works.
+    Licensing = 1 << 2,
+}
+
+/// The set of [`Availability`] an artist is currently open to.
+#[derive(Clone, Copy, PartialEq, Default, RuntimeDebug)]
+pub struct AvailabilityFlags(pub BitFlags<Availability>);
+
+impl Eq for AvailabilityFlags {}
+
+impl MaxEncodedLen for AvailabilityFlags {
+    fn max_encoded_len() -> usize {
+        u8::max_encoded_len()
     }
+}
 
-    fn add_checked_genres(&mut self, genre: MusicGenre) -> DispatchResultWithPostInfo {
-        let mut actual_genres = self.genres.clone();
-        actual_genres
-            .try_push(genre)
-            .map_err(|_| Error::<T>::Full)?;
+impl Encode for AvailabilityFlags {
+    fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+        self.0.bits().using_encoded(f)
+    }
+}
 
-        self.set_checked_genres(actual_genres)
+impl Decode for AvailabilityFlags {
+    fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+        let bits = u8::decode(input)?;
+        Ok(Self(
+            BitFlags::<Availability>::from_bits(bits).map_err(|_| "invalid availability flags")?,
+        ))
     }
+}
 
-    pub(super) fn update(
-        &mut self,
-        field: UpdatableData<BoundedVec<u8, T::MaxNameLen>>,
-    ) -> DispatchResultWithPostInfo {
-        match field {
-            UpdatableData::Alias(x) => self.set_alias(x)?,
-            UpdatableData::Genres(UpdatableGenres::Add(x)) => return self.add_checked_genres(x),
-            UpdatableData::Genres(UpdatableGenres::Remove(x)) => return self.remove_genre(x),
-            UpdatableData::Genres(UpdatableGenres::Clear) => self.genres = Default::default(),
-            UpdatableData::Description(x) => self.set_description(x)?,
-            UpdatableData::Assets(UpdatableAssets::Add(x)) => return self.add_checked_asset(&x),
-            UpdatableData::Assets(UpdatableAssets::Remove(x)) => return self.remove_asset(&x),
-            UpdatableData::Assets(UpdatableAssets::Clear) => self.clear_assets()?,
-        }
+impl TypeInfo for AvailabilityFlags {
+    type Identity = Self;
 
-        Ok(().into())
+    fn type_info() -> Type {
+        Type::builder()
+            .path(Path::new("BitFlags", module_path!()))
+            .type_params(sp_std::vec![TypeParameter::new(
+                "T",
+                Some(meta_type::<Availability>())
+            )])
+            .composite(Fields::unnamed().field(|f| f.ty::<u8>().type_name("Availability")))
     }
-    /// Return true if the artist have a 'verified_at" timestamp which mean he's verified
-    pub(super) fn is_verified(&self) -> bool {
-        self.verified_at.is_some()
+}
+
+/// What the registry needs from the artists names, so runtimes can plug their own name type.
+pub trait NameLike: Sized {
+    /// Build a name from its raw bytes, if they form a valid name.
+    fn try_from_bytes(bytes: Vec<u8>) -> Option<Self>;
+
+    /// The raw bytes of the name, whose hash bans or reserves it.
+    fn to_bytes(&self) -> Vec<u8>;
+}
+
+impl<S: Get<u32>> NameLike for BoundedVec<u8, S> {
+    fn try_from_bytes(bytes: Vec<u8>) -> Option<Self> {
+        bytes.try_into().ok()
     }
 
-    fn set_alias(
-        &mut self,
-        alias: Option<BoundedVec<u8, T::MaxNameLen>>,
-    ) -> Result<(), DispatchErrorWithPostInfo> {
-        let alias_len = alias.encoded_size();
-        let alias_cost = T::ByteDeposit::get().saturating_mul(alias_len.saturated_into());
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_vec()
+    }
+}
 
-        let old_deposit =
-            T::Currency::balance_on_hold(&HoldReason::ArtistAlias.into(), &self.owner);
+/// Checks the main names the artists register or rename to, and tells apart the names confusable
+/// with each other, so runtimes can plug their own naming rules.
+pub trait NameValidator {
+    /// Check the raw bytes of a name, returning the canonical bytes it gets stored under.
+    fn validate(raw: &[u8]) -> Result<Vec<u8>, InvalidName>;
 
-        if alias_cost > old_deposit {
-            T::Currency::hold(
-                &HoldReason::ArtistAlias.into(),
-                &self.owner,
-                alias_cost - old_deposit,
-            )?;
-        }
-        if alias_cost < old_deposit {
-            T::Currency::release(
-                &HoldReason::ArtistAlias.into(),
-                &self.owner,
-                old_deposit - alias_cost,
-                Precision::Exact,
-            )?;
-        }
+    /// The key shared by the names confusable with each other, such as their lowercase form.
+    fn confusable_key(name: &[u8]) -> Vec<u8>;
+}
 
-        self.alias = alias;
+/// Any bytes form a name, only confusable with the very same bytes.
+impl NameValidator for () {
+    fn validate(raw: &[u8]) -> Result<Vec<u8>, InvalidName> {
+        Ok(raw.to_vec())
+    }
 
-        Ok(())
+    fn confusable_key(name: &[u8]) -> Vec<u8> {
+        name.to_vec()
     }
+}
 
-    fn set_description(
-        &mut self,
-        raw_description: Option<Vec<u8>>,
-    ) -> Result<(), DispatchErrorWithPostInfo> {
-        // Clean any existent deposit
-        self.unreserve_deposit_hash(HoldReason::ArtistDescription)?;
+/// Names are UTF-8 strings trimmed of their surrounding whitespace, without any control or
+/// invisible character (such as a zero-width space), confusable with each other regardless of
+/// their case.
+pub struct Utf8NameValidator;
 
-        match raw_description {
-            Some(x) => {
-                self.reserve_deposit_hash(HoldReason::ArtistDescription)?;
-                self.description = Some(T::Hashing::hash(&x));
-            }
-            None => self.description = None,
+impl Utf8NameValidator {
+    /// Whether the passed character renders as nothing, letting a name impersonate another one.
+    fn is_invisible(c: char) -> bool {
+        matches!(
+            c,
+            '\u{00AD}'
+                | '\u{034F}'
+                | '\u{180E}'
+                | '\u{200B}'..='\u{200F}'
+                | '\u{202A}'..='\u{202E}'
+                | '\u{2060}'..='\u{2064}'
+                | '\u{2066}'..='\u{2069}'
+                | '\u{FEFF}'
+        )
+    }
+}
+
+impl NameValidator for Utf8NameValidator {
+    fn validate(raw: &[u8]) -> Result<Vec<u8>, InvalidName> {
+        let name = core::str::from_utf8(raw).map_err(|_| InvalidName)?.trim();
+        if name
+            .chars()
+            .any(|c| c.is_control() || Self::is_invisible(c))
+        {
+            return Err(InvalidName);
         }
 
-        Ok(())
+        Ok(name.as_bytes().to_vec())
     }
 
-    fn add_checked_asset(&mut self, asset: &Vec<u8>) -> DispatchResultWithPostInfo {
-        let hash = T::Hashing::hash(asset);
+    fn confusable_key(name: &[u8]) -> Vec<u8> {
+        let Ok(name) = core::str::from_utf8(name) else {
+            return name.to_vec();
+        };
 
-        match self.assets.contains(&hash) {
-            false => {
-                self.assets.try_push(hash).map_err(|_| Error::<T>::Full)?;
+        let mut key = Vec::with_capacity(name.len());
+        for c in name
+            .chars()
+            .filter(|c| !Self::is_invisible(*c))
+            .flat_map(char::to_lowercase)
+        {
+            key.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+        }
+        key
+    }
+}
 
-                // hold storage deposit
-                self.reserve_deposit_hash(HoldReason::ArtistAssets)?;
+/// The raw bytes passed to [`normalize_name`] don't form a valid name.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub struct InvalidName;
 
-                Ok(().into())
-            }
-            true => Err(Error::<T>::NotUniqueAsset.into()),
-        }
+/// Normalize raw bytes into the canonical name the registry stores, indexes, bans and reserves
+/// them under.
+///
+/// This is the exact routine the registry runs on the raw names it receives, so explorers and SDKs
+/// compute byte-identical keys when checking a name availability. The main names are further
+/// checked by the `NameValidator` of the registry, as `Pallet::normalized_name` does.
+pub fn normalize_name<Name: NameLike>(raw: Vec<u8>) -> Result<Name, InvalidName> {
+    Name::try_from_bytes(raw).ok_or(InvalidName)
+}
+
+/// Bounds the genres an artist can tag under the same top-level genre, to keep genre tagging
+/// curated rather than exhaustive.
+pub trait GenreCapacity {
+    /// The maximum number of genres an artist can have under the top-level genre of `genre`,
+    /// counting the top-level genre itself and its subgenres.
+    fn max_per_genre(genre: &MusicGenre) -> u32;
+}
+
+/// No limit per top-level genre, only `T::MaxGenres` applying.
+impl GenreCapacity for () {
+    fn max_per_genre(_: &MusicGenre) -> u32 {
+        u32::MAX
     }
+}
 
-    fn remove_asset(&mut self, asset: &Vec<u8>) -> DispatchResultWithPostInfo {
-        let hash = T::Hashing::hash(asset);
+/// What to do when an `OnArtistLifecycle` handler fails, or goes over its weight limit.
+#[derive(Encode, MaxEncodedLen, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum HookFailurePolicy {
+    /// The changes of the handler are dropped and its failure reported by a `LifecycleHookFailed`
+    /// event, the artist call going through.
+    Ignore,
+    /// The artist call fails with the error of the handler.
+    Revert,
+}
 
-        if let Some(pos) = self.assets.iter().position(|&x| x == hash) {
-            // refund storage deposit
-            self.unreserve_deposit_hash(HoldReason::ArtistAssets)?;
+/// Bounds the aliases an artist can hold depending on its verification level, such as keeping
+/// the aliases to the verified artists.
+pub trait AliasCapacity {
+    /// The maximum number of aliases an artist can hold, depending on whether it is verified.
+    fn max_aliases(verified: bool) -> u32;
+}
+
+/// No limit per verification level, an artist holding up to a single alias.
+impl AliasCapacity for () {
+    fn max_aliases(_: bool) -> u32 {
+        u32::MAX
+    }
+}
 
-            self.assets.remove(pos);
+/// Called once an account got registered as an artist.
+///
+/// The handlers consume their weight from the passed meter, limited to `T::MaxLifecycleHookWeight`,
+/// and fail once it is exhausted.
+pub trait OnArtistRegistered<AccountId> {
+    fn on_artist_registered(who: &AccountId, meter: &mut WeightMeter) -> DispatchResult;
+}
 
-            Ok(().into())
-        } else {
-            Err(Error::<T>::NotFound.into())
-        }
+impl<AccountId> OnArtistRegistered<AccountId> for () {
+    fn on_artist_registered(_: &AccountId, _: &mut WeightMeter) -> DispatchResult {
+        Ok(())
     }
+}
 
-    fn clear_assets(&mut self) -> Result<(), DispatchErrorWithPostInfo> {
-        let actual_deposit =
-            T::Currency::balance_on_hold(&HoldReason::ArtistAssets.into(), &self.owner);
-        T::Currency::release(
-            &HoldReason::ArtistAssets.into(),
-            &self.owner,
-            actual_deposit,
-            Precision::BestEffort,
-        )?;
+/// Called once an artist got removed from the registry, whether unregistered by itself, by force
+/// or merged into another artist.
+pub trait OnArtistUnregistered<AccountId> {
+    fn on_artist_unregistered(who: &AccountId, meter: &mut WeightMeter) -> DispatchResult;
+}
 
-        self.assets = Default::default();
+impl<AccountId> OnArtistUnregistered<AccountId> for () {
+    fn on_artist_unregistered(_: &AccountId, _: &mut WeightMeter) -> DispatchResult {
+        Ok(())
+    }
+}
+
+/// Called once an artist got verified.
+pub trait OnArtistVerified<AccountId> {
+    fn on_artist_verified(who: &AccountId, meter: &mut WeightMeter) -> DispatchResult;
+}
 
+impl<AccountId> OnArtistVerified<AccountId> for () {
+    fn on_artist_verified(_: &AccountId, _: &mut WeightMeter) -> DispatchResult {
         Ok(())
     }
+}
 
-    fn remove_genre(&mut self, genre: MusicGenre) -> DispatchResultWithPostInfo {
-        if let Some(pos) = self.genres.iter().position(|&x| x == genre) {
-            self.genres.remove(pos);
-            Ok(().into())
-        } else {
-            Err(Error::<T>::NotFound.into())
-        }
+/// Tells whether an account can act for another one, such as a proxy registered in
+/// `pallet-proxy`.
+pub trait ProxyCheck<AccountId> {
+    fn is_proxy(delegator: &AccountId, delegate: &AccountId) -> bool;
+}
+
+impl<AccountId> ProxyCheck<AccountId> for () {
+    fn is_proxy(_: &AccountId, _: &AccountId) -> bool {
+        false
+    }
+}
+
+/// A status change of an artist, notified to its linked contracts.
+#[derive(Encode, MaxEncodedLen, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum ContractNotice {
+    /// The moderation status of the artist changed (suspended, banned or reinstated).
+    StatusChanged(ArtistStatus),
+    /// The artist got removed from the registry.
+    Unregistered,
+    /// The verification of the artist expired, the artist being unverified until verified again.
+    Unverified,
+}
+
+/// Notifies the contracts linked to an artist of its status changes, such as by calling a
+/// standardized entry point of the contracts through `pallet-contracts`, so royalties contracts
+/// can pause their payouts.
+pub trait NotifyContract<AccountId> {
+    /// Notify `contract`, linked to `artist`, of the passed status change, consuming its weight
+    /// from `meter`.
+    fn notify(
+        artist: &AccountId,
+        contract: &AccountId,
+        notice: ContractNotice,
+        meter: &mut WeightMeter,
+    ) -> DispatchResult;
+}
+
+impl<AccountId> NotifyContract<AccountId> for () {
+    fn notify(
+        _: &AccountId,
+        _: &AccountId,
+        _: ContractNotice,
+        _: &mut WeightMeter,
+    ) -> DispatchResult {
+        Ok(())
     }
+}
+
+/// The verdict of the [`ScoreRegistration`] filter on a registration or an update.
+#[derive(Encode, MaxEncodedLen, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum AbuseVerdict<Balance> {
+    /// The submission goes through as usual.
+    Accept,
+    /// The submission goes through, holding the passed extra deposit along with the artist one.
+    Surcharge(Balance),
+    /// The submission is rejected.
+    Reject,
+}
+
+/// Scores the candidate registrations and updates, letting the runtime inject its own anti-abuse
+/// heuristics (name deny-lists, rate limits, oracle verdicts...).
+///
+/// It runs within the `register`, `register_for`, `update` and `update_as_delegate` calls, so it
+/// must stay cheap enough to be covered by their weight. The `AdminOrigin` updates aren't scored.
+pub trait ScoreRegistration<T: Config<I>, I: 'static = ()> {
+    /// Score the registration of `who` with the passed data.
+    fn score_registration(
+        who: &T::AccountId,
+        main_name: &T::Name,
+        alias: Option<&T::Name>,
+        genres: &[MusicGenre],
+        description: Option<&[u8]>,
+        assets: &[(AssetType, Vec<u8>)],
+    ) -> AbuseVerdict<BalanceOf<T, I>>;
 
-    fn reserve_deposit_hash(&self, reason: HoldReason) -> Result<(), DispatchErrorWithPostInfo> {
-        let hash_size = T::Hash::max_encoded_len();
-        let hash_cost = T::ByteDeposit::get().saturating_mul(hash_size.saturated_into());
+    /// Score the passed update of the data of the artist `who`.
+    fn score_update(
+        who: &T::AccountId,
+        data: &UpdatableData<T::Name, T::Hash>,
+    ) -> AbuseVerdict<BalanceOf<T, I>>;
+}
 
-        T::Currency::hold(&reason.into(), &self.owner, hash_cost).map_err(|e| e.into())
+/// Accepts every submission.
+impl<T: Config<I>, I: 'static> ScoreRegistration<T, I> for () {
+    fn score_registration(
+        _: &T::AccountId,
+        _: &T::Name,
+        _: Option<&T::Name>,
+        _: &[MusicGenre],
+        _: Option<&[u8]>,
+        _: &[(AssetType, Vec<u8>)],
+    ) -> AbuseVerdict<BalanceOf<T, I>> {
+        AbuseVerdict::Accept
     }
 
-    fn unreserve_deposit_hash(
-        &self,
-        reason: HoldReason,
-    ) -> Result<BalanceOf<T>, DispatchErrorWithPostInfo> {
-        let hash_size = T::Hash::max_encoded_len();
-        let hash_cost = T::ByteDeposit::get().saturating_mul(hash_size.saturated_into());
+    fn score_update(
+        _: &T::AccountId,
+        _: &UpdatableData<T::Name, T::Hash>,
+    ) -> AbuseVerdict<BalanceOf<T, I>> {
+        AbuseVerdict::Accept
+    }
+}
 
-        T::Currency::release(
-            &reason.into(),
-            &self.owner,
-            hash_cost,
-            Precision::BestEffort,
-        )
-        .map_err(|e| e.into())
+/// Signs for the `ClaimAuthority` in the benchmarks, whose key the pallet doesn't know.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<Signature> {
+    /// Sign the passed profile claim voucher payload as the `ClaimAuthority`.
+    fn sign_claim_voucher(payload: &[u8]) -> Signature;
+}
+
+/// Read access to the registry for other pallets (royalties, releases...), so they can depend on
+/// it rather than on its storage.
+pub trait ArtistsInspect<AccountId> {
+    /// The fingerprint of the works (songs, albums...) the artists claim.
+    type Work;
+
+    /// Whether the account is registered as an artist.
+    fn is_artist(who: &AccountId) -> bool;
+
+    /// Whether the account is registered as a verified artist.
+    fn is_verified(who: &AccountId) -> bool;
+
+    /// Whether the artist registered with the account is suspended or banned, dApps being
+    /// expected to hide its profile.
+    fn is_suspended(who: &AccountId) -> bool;
+
+    /// The main name of the artist registered with the account, if any.
+    fn artist_name(who: &AccountId) -> Option<Vec<u8>>;
+
+    /// The contracts linked to the artist registered with the account, empty if there is none.
+    fn contracts_of(who: &AccountId) -> Vec<AccountId>;
+
+    /// The royalty beneficiaries of the artist registered with the account with their share,
+    /// summing to 100%, empty if the artist didn't set any.
+    fn royalty_splits(who: &AccountId) -> Vec<(AccountId, Perbill)>;
+
+    /// The members of the group artist registered with the account, empty for solo artists.
+    fn members_of(who: &AccountId) -> Vec<AccountId>;
+
+    /// The headline genre of the artist registered with the account, if any.
+    fn main_genre(who: &AccountId) -> Option<MusicGenre>;
+
+    /// The secondary genres of the artist registered with the account, empty if there is none.
+    fn secondary_genres(who: &AccountId) -> Vec<MusicGenre>;
+
+    /// The works claimed by the artist registered with the account, empty if it claims none.
+    fn works_of(who: &AccountId) -> Vec<Self::Work>;
+
+    /// Whether the artist registered with the account claims the passed work, for the releases
+    /// to be cross-checked against it.
+    fn claims_work(who: &AccountId, work: &Self::Work) -> bool;
+}
+
+/// The payout destination an artist chose, rather than its royalty beneficiaries.
+#[derive(Encode, MaxEncodedLen, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum PayoutRoute<AccountId> {
+    /// Pay the passed account, such as the treasury of the artist.
+    Account(AccountId),
+    /// Pay the passed contract linked to the artist, such as its royalties contract.
+    Contract(AccountId),
+}
+
+/// The chain an artist registered from with `register_remote`, as told by the
+/// `RemoteRegistrationOrigin`.
+#[derive(Encode, MaxEncodedLen, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum OriginChain {
+    /// The relay chain.
+    Relay,
+    /// The sibling parachain with the passed id.
+    Sibling(u32),
+}
+
+/// Where the payouts of an artist go, as resolved by a [`PayoutRouter`].
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum PayoutTarget<AccountId> {
+    /// The whole payout goes to the account.
+    Account(AccountId),
+    /// The payout is split between the royalty beneficiaries along their share, summing to 100%.
+    SplitSet(Vec<(AccountId, Perbill)>),
+    /// The whole payout goes to the royalties contract linked to the artist, distributing it on
+    /// its own.
+    Contract(AccountId),
+}
+
+/// Resolves where the payouts of an artist go, the single integration point of the royalties
+/// distribution pallets.
+pub trait PayoutRouter<AccountId> {
+    /// The payout destination of the artist registered with the account: its `PayoutRoute` if
+    /// it set one, else its royalty splits if it set any, else the artist account itself. `None`
+    /// if the account isn't registered as an artist.
+    fn payout_target(artist: &AccountId) -> Option<PayoutTarget<AccountId>>;
+}
+
+/// Why an account can't confirm its unregistration yet.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum UnregisterBlocker {
+    /// The account isn't registered as an artist.
+    NotRegistered,
+    /// The artist is verified, verified artists can't unregister.
+    Verified,
+    /// The artist didn't request its unregistration with `request_unregister`.
+    NotRequested,
+    /// The `UnregisterPeriod` of the request isn't elapsed, for the passed amount of blocks.
+    PeriodRemaining(u32),
+}
+
+/// How an Artist is designed to be stored on-chain.
+#[derive(Encode, MaxEncodedLen, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Getters)]
+#[scale_info(skip_type_params(T, I))]
+pub struct Artist<T, I: 'static = ()>
+where
+    T: frame_system::Config + Config<I>,
+{
+    // Main data
+    /// The artist's identifier. While the predominant mapping employs AccountId => Artist,
+    /// it's essential to include this in the artist's data since verified artists can be retrieved by their name as well.
+    pub(crate) owner: AccountIdOf<T>,
+    /// When the artist got registered on-chain.
+    pub(crate) registered_at: BlockNumberFor<T>,
+    /// When the artist data got last updated, its registration block if never updated.
+    pub(crate) last_updated_at: BlockNumberFor<T>,
+    /// The number of successful updates of the artist data, so off-chain caches can detect
+    /// staleness and clients can check the profile they edit wasn't updated meanwhile.
+    pub(crate) update_nonce: u32,
+    /// When the artist got verified.
+    pub(crate) verified_at: Option<BlockNumberFor<T>>,
+    /// The fingerprint (hash) of the KYC evidence attached by the verifier.
+    /// The raw evidence is never stored on-chain, it stays with the verifying entity which can
+    /// audit the verification against this fingerprint.
+    pub(crate) kyc_evidence_hash: Option<T::Hash>,
+    /// The moderation status of the artist.
+    pub(crate) status: ArtistStatus,
+    // Metadata
+    /// The name of the artist.
+    /// This is generally the main name of how we usually call the artist (e.g: 'The Weeknd')
+    /// This is fixed and can't be changed after the registration.
+    pub(crate) main_name: T::Name,
+    /// The aliases to the main name.
+    /// These names can be changed compared to the 'nickname'
+    pub(crate) aliases: BoundedVec<ArtistAliasOf<T, I>, T::MaxAliases>,
+    /// The headline music genre of the artist, apart from its secondary `genres`.
+    pub(crate) main_genre: Option<StoredGenre>,
+    /// The secondary music genres of the artists.
+    pub(crate) genres: BoundedVec<StoredGenre, T::MaxGenres>,
+    /// What the artist is currently open to (collaborations, bookings...).
+    pub(crate) open_to: AvailabilityFlags,
+    // Metadata Fingerprint
+    // Given the significant size of certain data associated with an artist,
+    // we choose to store a digital fingerprint (hash) of this data rather than
+    // the raw data itself. This fingerprint acts as a unique digital reference,
+    // and services can use it to compare and validate the artist's data, ensuring
+    // that it has been approved and recorded on the blockchain by the artist themselves.
+    /// The digital fingerprint of the artist's description.
+    pub(crate) description: Option<FingerprintOf<T>>,
+    /// A short description of the artist stored on-chain as is, each of its bytes being covered
+    /// by the deposit. Only one of the hashed and the raw description is set at once.
+    pub(crate) raw_description: Option<BoundedVec<u8, T::MaxDescriptionLen>>,
+    /// The digital fingerprint (hash) of an off-chain contact record of the artist (e.g: its
+    /// email address), never stored in plaintext. Platforms can check they contacted the
+    /// address the artist committed to.
+    pub(crate) contact_hash: Option<T::Hash>,
+    /// External identifiers and links of the artist (e.g: its Spotify ID or ISNI), stored in
+    /// plain so platforms can match the artist with their own catalog.
+    pub(crate) links: BoundedVec<(LinkType, BoundedVec<u8, T::MaxLinkLen>), T::MaxLinks>,
+    /// Digital assets (such as photos, profile pictures, banners, videos, etc.)
+    /// that officially represent the artist. These fingerprints allow for the
+    /// verification of the authenticity of these assets.
+    pub(crate) assets: BoundedVec<ArtistAsset<T>, T::MaxAssets>,
+    /// The fingerprints (hashes) of the works (songs, albums...) the artist claims, for the
+    /// releases to be cross-checked against them. A work can be claimed by several artists, such
+    /// as the performers of a featuring.
+    pub(crate) works: BoundedVec<T::Hash, T::MaxWorks>,
+    // Linked chain logic data
+    /// Associated smart-contracts deployed by dApps for the artist (e.g: royalties contracts)
+    pub(crate) contracts: BoundedVec<AccountIdOf<T>, T::MaxContracts>,
+    /// Whether linking or unlinking contracts is blocked by a moderation freeze
+    /// (e.g: during a royalty dispute).
+    pub(crate) contracts_frozen: bool,
+    /// Accounts allowed to update the artist data on behalf of the owner (e.g: its label or
+    /// manager).
+    pub(crate) delegates: BoundedVec<AccountIdOf<T>, T::MaxDelegates>,
+    /// Whether the artist is a solo artist or a group.
+    pub(crate) kind: ArtistKind,
+    /// The accounts of the group members, each of them having accepted to join it. Members can
+    /// be artists on their own as well.
+    pub(crate) members: BoundedVec<AccountIdOf<T>, T::MaxMembers>,
+    /// The beneficiaries of the artist royalties with their share, summing to 100%, for the
+    /// payment pallets to split them. Empty if the artist didn't set any.
+    pub(crate) royalty_splits: RoyaltySplitsOf<T, I>,
+    // Deposit
+    /// The total amount currently held from the owner for this artist, including the base
+    /// registration deposit and every per-byte deposit of the stored data.
+    pub(crate) deposit: BalanceOf<T, I>,
+    /// The account the deposit is held from, the artist itself unless registered by a label.
+    pub(crate) depositor: AccountIdOf<T>,
+    /// The part of the deposit held as `T::AbuseFilter` surcharges, kept as is when the deposit
+    /// gets repriced.
+    pub(crate) surcharge: BalanceOf<T, I>,
+}
+
+/// The stored value of the artist field targeted by an [`UpdatableData`], so indexers can follow
+/// its transitions.
+#[derive(Encode, MaxEncodedLen, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T, I))]
+pub enum ArtistField<T, I: 'static = ()>
+where
+    T: frame_system::Config + Config<I>,
+{
+    Aliases(BoundedVec<ArtistAliasOf<T, I>, T::MaxAliases>),
+    Genres(BoundedVec<StoredGenre, T::MaxGenres>),
+    /// Both the hashed and the raw description, only one of them being set at once.
+    Description {
+        hash: Option<FingerprintOf<T>>,
+        raw: Option<BoundedVec<u8, T::MaxDescriptionLen>>,
+    },
+    Assets(BoundedVec<ArtistAsset<T>, T::MaxAssets>),
+    OpenTo(AvailabilityFlags),
+    ContactHash(Option<T::Hash>),
+    Links(BoundedVec<(LinkType, BoundedVec<u8, T::MaxLinkLen>), T::MaxLinks>),
+    MainGenre(Option<StoredGenre>),
+}
+
+/// A portable snapshot of an artist, exported and restored by the root for disaster recovery.
+#[derive(Encode, MaxEncodedLen, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Getters)]
+#[scale_info(skip_type_params(T, I))]
+pub struct ArtistRecord<T, I: 'static = ()>
+where
+    T: frame_system::Config + Config<I>,
+{
+    /// The storage version of the artist layout, a record only being restorable under the same
+    /// version.
+    pub(crate) storage_version: u16,
+    /// The exported artist.
+    pub(crate) artist: Artist<T, I>,
+}
+
+impl<T, I: 'static> ArtistRecord<T, I>
+where
+    T: frame_system::Config + Config<I>,
+{
+    pub(super) fn new(artist: Artist<T, I>) -> Self {
+        ArtistRecord {
+            storage_version: crate::migrations::STORAGE_VERSION_NUMBER,
+            artist,
+        }
+    }
+}
+
+/// The deposit held for an artist, split per category of stored data, so artists know what
+/// trimming their profile would give back.
+///
+/// The categories are what their data costs to store, an artist of an exempted depositor holding
+/// less than their sum.
+#[derive(
+    Encode, MaxEncodedLen, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, Getters,
+)]
+pub struct DepositBreakdown<Balance> {
+    /// The base registration deposit.
+    pub(crate) base: Balance,
+    /// The bytes of the main name and of the aliases.
+    pub(crate) names: Balance,
+    /// The hashed or the raw description.
+    pub(crate) description: Balance,
+    /// The contact record fingerprint.
+    pub(crate) contact_hash: Balance,
+    /// The external links.
+    pub(crate) links: Balance,
+    /// The asset fingerprints, the same amount being held for every asset.
+    pub(crate) assets: Balance,
+    /// The claimed work fingerprints, the same amount being held for every work.
+    pub(crate) works: Balance,
+    /// The linked contracts, the same amount being held for every contract.
+    pub(crate) contracts: Balance,
+    /// The delegates.
+    pub(crate) delegates: Balance,
+    /// The group members.
+    pub(crate) members: Balance,
+    /// The royalty beneficiaries.
+    pub(crate) royalty_splits: Balance,
+    /// Anything else held for the artist, such as the `AbuseFilter` surcharges or a pending
+    /// verification escrow.
+    pub(crate) other: Balance,
+}
+
+/// The public view of an artist, along with the breakdown of its deposit.
+#[derive(Encode, MaxEncodedLen, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Getters)]
+#[scale_info(skip_type_params(T, I))]
+pub struct ArtistView<T, I: 'static = ()>
+where
+    T: frame_system::Config + Config<I>,
+{
+    /// The artist as stored.
+    pub(crate) artist: Artist<T, I>,
+    /// What its deposit is held for.
+    pub(crate) deposits: DepositBreakdown<BalanceOf<T, I>>,
+}
+
+impl<T, I: 'static> ArtistView<T, I>
+where
+    T: frame_system::Config + Config<I>,
+{
+    pub(super) fn new(artist: Artist<T, I>) -> Self {
+        ArtistView {
+            deposits: artist.deposit_breakdown(),
+            artist,
+        }
+    }
+}
+
+/// The latest version of the client-facing artist records, see [`VersionedArtistRecord`].
+pub const LATEST_RECORD_VERSION: u32 = 3;
+
+/// The first version of the client-facing record of an artist.
+///
+/// Unlike the stored [`Artist`], its layout never changes once released: schema evolutions add a
+/// new version instead, so older clients keep decoding the one they know.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Getters)]
+pub struct ArtistRecordV1<AccountId, BlockNumber, Hash, Balance> {
+    /// The account the artist is registered with.
+    pub(crate) id: AccountId,
+    /// The owner of the profile.
+    pub(crate) owner: AccountId,
+    /// The bytes of the main name.
+    pub(crate) main_name: Vec<u8>,
+    /// The bytes of the alias, if any.
+    pub(crate) alias: Option<Vec<u8>>,
+    /// The genres, by their registry tag and subtag.
+    pub(crate) genres: Vec<StoredGenre>,
+    /// The fingerprint of the description, if hashed.
+    pub(crate) description: Option<Fingerprint<Hash>>,
+    /// The raw description, if stored in clear.
+    pub(crate) raw_description: Option<Vec<u8>>,
+    /// When the artist got registered.
+    pub(crate) registered_at: BlockNumber,
+    /// When the artist got verified, if verified.
+    pub(crate) verified_at: Option<BlockNumber>,
+    /// The moderation status.
+    pub(crate) status: ArtistStatus,
+    /// Whether the artist is a solo artist or a group.
+    pub(crate) kind: ArtistKind,
+    /// The accounts of the group members.
+    pub(crate) members: Vec<AccountId>,
+    /// The linked contracts.
+    pub(crate) contracts: Vec<AccountId>,
+    /// The deposit held for the artist.
+    pub(crate) deposit: Balance,
+}
+
+/// The second version of the client-facing record of an artist, splitting its headline genre from
+/// the secondary ones.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Getters)]
+pub struct ArtistRecordV2<AccountId, BlockNumber, Hash, Balance> {
+    /// The account the artist is registered with.
+    pub(crate) id: AccountId,
+    /// The owner of the profile.
+    pub(crate) owner: AccountId,
+    /// The bytes of the main name.
+    pub(crate) main_name: Vec<u8>,
+    /// The bytes of the alias, if any.
+    pub(crate) alias: Option<Vec<u8>>,
+    /// The headline genre, if any.
+    pub(crate) main_genre: Option<StoredGenre>,
+    /// The secondary genres, by their registry tag and subtag.
+    pub(crate) genres: Vec<StoredGenre>,
+    /// The fingerprint of the description, if hashed.
+    pub(crate) description: Option<Fingerprint<Hash>>,
+    /// The raw description, if stored in clear.
+    pub(crate) raw_description: Option<Vec<u8>>,
+    /// When the artist got registered.
+    pub(crate) registered_at: BlockNumber,
+    /// When the artist got verified, if verified.
+    pub(crate) verified_at: Option<BlockNumber>,
+    /// The moderation status.
+    pub(crate) status: ArtistStatus,
+    /// Whether the artist is a solo artist or a group.
+    pub(crate) kind: ArtistKind,
+    /// The accounts of the group members.
+    pub(crate) members: Vec<AccountId>,
+    /// The linked contracts.
+    pub(crate) contracts: Vec<AccountId>,
+    /// The deposit held for the artist.
+    pub(crate) deposit: Balance,
+}
+
+/// The third version of the client-facing record of an artist, listing all of its aliases.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Getters)]
+pub struct ArtistRecordV3<AccountId, BlockNumber, Hash, Balance> {
+    /// The account the artist is registered with.
+    pub(crate) id: AccountId,
+    /// The owner of the profile.
+    pub(crate) owner: AccountId,
+    /// The bytes of the main name.
+    pub(crate) main_name: Vec<u8>,
+    /// The bytes of every alias.
+    pub(crate) aliases: Vec<Vec<u8>>,
+    /// The headline genre, if any.
+    pub(crate) main_genre: Option<StoredGenre>,
+    /// The secondary genres, by their registry tag and subtag.
+    pub(crate) genres: Vec<StoredGenre>,
+    /// The fingerprint of the description, if hashed.
+    pub(crate) description: Option<Fingerprint<Hash>>,
+    /// The raw description, if stored in clear.
+    pub(crate) raw_description: Option<Vec<u8>>,
+    /// When the artist got registered.
+    pub(crate) registered_at: BlockNumber,
+    /// When the artist got verified, if verified.
+    pub(crate) verified_at: Option<BlockNumber>,
+    /// The moderation status.
+    pub(crate) status: ArtistStatus,
+    /// Whether the artist is a solo artist or a group.
+    pub(crate) kind: ArtistKind,
+    /// The accounts of the group members.
+    pub(crate) members: Vec<AccountId>,
+    /// The linked contracts.
+    pub(crate) contracts: Vec<AccountId>,
+    /// The deposit held for the artist.
+    pub(crate) deposit: Balance,
+}
+
+/// A client-facing record of an artist, in the version negotiated by the client.
+///
+/// Clients ask for the highest version they support up to the [`LATEST_RECORD_VERSION`], every
+/// version ever released staying served.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum VersionedArtistRecord<AccountId, BlockNumber, Hash, Balance> {
+    #[codec(index = 1)]
+    V1(ArtistRecordV1<AccountId, BlockNumber, Hash, Balance>),
+    #[codec(index = 2)]
+    V2(ArtistRecordV2<AccountId, BlockNumber, Hash, Balance>),
+    #[codec(index = 3)]
+    V3(ArtistRecordV3<AccountId, BlockNumber, Hash, Balance>),
+}
+
+impl<AccountId, BlockNumber, Hash, Balance>
+    VersionedArtistRecord<AccountId, BlockNumber, Hash, Balance>
+{
+    /// The version of the record.
+    pub fn version(&self) -> u32 {
+        match self {
+            VersionedArtistRecord::V1(_) => 1,
+            VersionedArtistRecord::V2(_) => 2,
+            VersionedArtistRecord::V3(_) => 3,
+        }
+    }
+}
+
+pub(super) type VersionedArtistRecordOf<T, I = ()> = VersionedArtistRecord<
+    AccountIdOf<T>,
+    BlockNumberFor<T>,
+    <T as frame_system::Config>::Hash,
+    BalanceOf<T, I>,
+>;
+
+impl<T, I: 'static> Artist<T, I>
+where
+    T: frame_system::Config + Config<I>,
+{
+    /// The record of the artist registered with the passed account in the passed version, if
+    /// released.
+    pub(super) fn to_record(
+        &self,
+        id: AccountIdOf<T>,
+        version: u32,
+    ) -> Option<VersionedArtistRecordOf<T, I>> {
+        match version {
+            1 => Some(VersionedArtistRecord::V1(ArtistRecordV1 {
+                id,
+                owner: self.owner.clone(),
+                main_name: self.main_name.to_bytes(),
+                alias: self.aliases.first().map(|alias| alias.to_bytes()),
+                genres: self.genres.to_vec(),
+                description: self.description.clone(),
+                raw_description: self.raw_description.as_ref().map(|raw| raw.to_vec()),
+                registered_at: self.registered_at,
+                verified_at: self.verified_at,
+                status: self.status,
+                kind: self.kind,
+                members: self.members.to_vec(),
+                contracts: self.contracts.to_vec(),
+                deposit: self.deposit,
+            })),
+            2 => Some(VersionedArtistRecord::V2(ArtistRecordV2 {
+                id,
+                owner: self.owner.clone(),
+                main_name: self.main_name.to_bytes(),
+                alias: self.aliases.first().map(|alias| alias.to_bytes()),
+                main_genre: self.main_genre,
+                genres: self.genres.to_vec(),
+                description: self.description.clone(),
+                raw_description: self.raw_description.as_ref().map(|raw| raw.to_vec()),
+                registered_at: self.registered_at,
+                verified_at: self.verified_at,
+                status: self.status,
+                kind: self.kind,
+                members: self.members.to_vec(),
+                contracts: self.contracts.to_vec(),
+                deposit: self.deposit,
+            })),
+            3 => Some(VersionedArtistRecord::V3(ArtistRecordV3 {
+                id,
+                owner: self.owner.clone(),
+                main_name: self.main_name.to_bytes(),
+                aliases: self.aliases.iter().map(|alias| alias.to_bytes()).collect(),
+                main_genre: self.main_genre,
+                genres: self.genres.to_vec(),
+                description: self.description.clone(),
+                raw_description: self.raw_description.as_ref().map(|raw| raw.to_vec()),
+                registered_at: self.registered_at,
+                verified_at: self.verified_at,
+                status: self.status,
+                kind: self.kind,
+                members: self.members.to_vec(),
+                contracts: self.contracts.to_vec(),
+                deposit: self.deposit,
+            })),
+            _ => None,
+        }
+    }
+}
+
+/// A verification request of an artist, waiting for the verifier decision.
+#[derive(Encode, MaxEncodedLen, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Getters)]
+#[scale_info(skip_type_params(T, I))]
+pub struct VerificationRequest<T, I: 'static = ()>
+where
+    T: frame_system::Config + Config<I>,
+{
+    /// Fingerprints (hashes) of the evidence supporting the request (social posts, signed
+    /// statements...), the raw evidence stays off-chain.
+    evidence: BoundedVec<T::Hash, T::MaxEvidence>,
+    /// When the evidence got submitted.
+    submitted_at: BlockNumberFor<T>,
+}
+
+impl<T, I: 'static> VerificationRequest<T, I>
+where
+    T: frame_system::Config + Config<I>,
+{
+    pub(super) fn new(evidence: BoundedVec<T::Hash, T::MaxEvidence>) -> Self {
+        VerificationRequest {
+            evidence,
+            submitted_at: <frame_system::Pallet<T>>::block_number(),
+        }
+    }
+}
+
+impl<T, I: 'static> Artist<T, I>
+where
+    T: frame_system::Config + Config<I>,
+{
+    pub(super) fn new(
+        owner: AccountIdOf<T>,
+        depositor: AccountIdOf<T>,
+        main_name: T::Name,
+        alias: Option<ArtistAliasOf<T, I>>,
+        genres: BoundedVec<MusicGenre, T::MaxGenres>,
+        description: Option<Vec<u8>>,
+        assets: BoundedVec<(AssetType, Vec<u8>), T::MaxAssets>,
+    ) -> Result<Self, DispatchErrorWithPostInfo> {
+        let current_block = <frame_system::Pallet<T>>::block_number();
+
+        let mut new_artist = Artist {
+            owner,
+            registered_at: current_block,
+            last_updated_at: current_block,
+            update_nonce: 0,
+            verified_at: None,
+            kyc_evidence_hash: None,
+            status: ArtistStatus::Active,
+            main_name: main_name.clone(),
+            aliases: Default::default(),
+            main_genre: None,
+            // need to set later with the checked fn
+            genres: Default::default(),
+            open_to: Default::default(),
+            description: Default::default(),
+            raw_description: None,
+            contact_hash: None,
+            links: Default::default(),
+            assets: Default::default(),
+            works: Default::default(),
+            contracts: Default::default(),
+            contracts_frozen: false,
+            delegates: Default::default(),
+            kind: ArtistKind::Solo,
+            members: Default::default(),
+            royalty_splits: Default::default(),
+            deposit: Default::default(),
+            depositor,
+            surcharge: Default::default(),
+        };
+        let aliases: BoundedVec<ArtistAliasOf<T, I>, T::MaxAliases> = alias
+            .into_iter()
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| Error::<T, I>::TooManyAliases)?;
+        new_artist.check_alias_capacity(aliases.len())?;
+
+        // held amount for base artist data registration
+        new_artist.hold_deposit(HoldReason::ArtistRegistration, T::BaseDeposit::get())?;
+
+        // held amount for the names, the aliases length prefix being held even without alias
+        let names_cost = Self::bytes_cost(main_name.encoded_size())
+            .saturating_add(Self::bytes_cost(aliases.encoded_size()));
+        new_artist.hold_deposit(HoldReason::ArtistData, names_cost)?;
+        new_artist.aliases = aliases;
+
+        new_artist.set_checked_genres(Self::checked_genres(genres)?)?;
+        new_artist.set_description(description)?;
+        assets
+            .iter()
+            .try_for_each(|(kind, asset)| new_artist.add_checked_asset(*kind, asset).map(|_| ()))?;
+
+        Ok(new_artist)
+    }
+
+    /// The deposit held for registering an artist with the passed names, amount of assets and
+    /// description, as [`Artist::new`] holds it, exemptions and surcharges aside.
+    pub(super) fn registration_deposit(
+        main_name: &T::Name,
+        alias: &Option<ArtistAliasOf<T, I>>,
+        assets: u32,
+        with_description: bool,
+    ) -> BalanceOf<T, I> {
+        let hash_cost = Self::bytes_cost(T::Hash::max_encoded_len());
+        let description_cost = if with_description {
+            hash_cost
+        } else {
+            Zero::zero()
+        };
+
+        // an optional alias encodes to as many bytes as the aliases registered with it
+        T::BaseDeposit::get()
+            .saturating_add(Self::bytes_cost(main_name.encoded_size()))
+            .saturating_add(Self::bytes_cost(alias.encoded_size()))
+            .saturating_add(description_cost)
+            .saturating_add(hash_cost.saturating_mul(assets.into()))
+    }
+
+    /// The stored form of the passed genres, failing on a genre that can't be read back.
+    fn checked_genres(
+        genres: BoundedVec<MusicGenre, T::MaxGenres>,
+    ) -> Result<BoundedVec<StoredGenre, T::MaxGenres>, Error<T, I>> {
+        let stored = genres
+            .into_iter()
+            .map(|genre| StoredGenre::checked(genre).ok_or(Error::<T, I>::UnsupportedGenre))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(stored
+            .try_into()
+            .expect("the genres bound is unchanged; qed"))
+    }
+
+    /// Set the genres of the artist while verifying that there is not the same genre multiple times
+    /// and that every top-level genre stays within its `T::GenreCapacity`.
+    ///
+    /// Genres unknown to the running registry only count against the capacity of known ones.
+    pub(super) fn set_checked_genres(
+        &mut self,
+        genres: BoundedVec<StoredGenre, T::MaxGenres>,
+    ) -> DispatchResultWithPostInfo {
+        let mut seen = BTreeSet::new();
+
+        for genre in genres.iter() {
+            if !seen.insert(genre) {
+                return Err(Error::<T, I>::NotUniqueGenre.into());
+            }
+        }
+        if self.main_genre.map_or(false, |main| seen.contains(&main)) {
+            return Err(Error::<T, I>::MainGenreDuplicated.into());
+        }
+
+        for genre in genres.iter() {
+            let count = genres.iter().filter(|x| x.tag == genre.tag).count();
+            let capacity = genre
+                .genre()
+                .map_or(u32::MAX, |x| T::GenreCapacity::max_per_genre(&x));
+
+            if count > capacity as usize {
+                return Err(Error::<T, I>::GenreCapacityExceeded.into());
+            }
+        }
+
+        self.genres = genres;
+
+        Ok(().into())
+    }
+
+    fn add_checked_genres(&mut self, genre: MusicGenre) -> DispatchResultWithPostInfo {
+        let genre = StoredGenre::checked(genre).ok_or(Error::<T, I>::UnsupportedGenre)?;
+        let mut actual_genres = self.genres.clone();
+        actual_genres
+            .try_push(genre)
+            .map_err(|_| Error::<T, I>::GenresFull)?;
+
+        self.set_checked_genres(actual_genres)
+    }
+
+    pub(super) fn update(&mut self, field: UpdatableDataOf<T, I>) -> DispatchResultWithPostInfo {
+        match field {
+            UpdatableData::Alias(x) => self.set_alias(x)?,
+            UpdatableData::Genres(UpdatableGenres::Add(x)) => return self.add_checked_genres(x),
+            UpdatableData::Genres(UpdatableGenres::Remove(x)) => return self.remove_genre(x),
+            UpdatableData::Genres(UpdatableGenres::RemoveAt(x)) => return self.remove_genre_at(x),
+            UpdatableData::Genres(UpdatableGenres::Clear) => self.genres = Default::default(),
+            UpdatableData::Description(x) => self.set_description(x)?,
+            UpdatableData::RawDescription(x) => self.set_raw_description(x)?,
+            UpdatableData::DescriptionFingerprint(x) => self.set_description_fingerprint(x)?,
+            UpdatableData::Assets(UpdatableAssets::Add(kind, x)) => {
+                return self.add_checked_asset(kind, &x)
+            }
+            UpdatableData::Assets(UpdatableAssets::AddFingerprint(kind, x)) => {
+                return self.add_checked_fingerprint(kind, x)
+            }
+            UpdatableData::Assets(UpdatableAssets::Remove(x)) => return self.remove_asset(&x),
+            UpdatableData::Assets(UpdatableAssets::RemoveFingerprint(x)) => {
+                return self.remove_fingerprint(&x)
+            }
+            UpdatableData::Assets(UpdatableAssets::RemoveAt(x)) => return self.remove_asset_at(x),
+            UpdatableData::Assets(UpdatableAssets::Clear) => self.clear_assets()?,
+            UpdatableData::OpenTo(x) => self.open_to = x,
+            UpdatableData::ContactHash(x) => self.set_contact_hash(x)?,
+            UpdatableData::Links(UpdatableLinks::Add(kind, x)) => {
+                return self.add_checked_link(kind, x)
+            }
+            UpdatableData::Links(UpdatableLinks::Remove(kind, x)) => {
+                return self.remove_link(kind, &x)
+            }
+            UpdatableData::Links(UpdatableLinks::Clear) => self.clear_links()?,
+            UpdatableData::MainGenre(x) => self.set_main_genre(x)?,
+            UpdatableData::Aliases(UpdatableAliases::Add(x)) => self.add_alias(x)?,
+            UpdatableData::Aliases(UpdatableAliases::Remove(x)) => self.remove_alias(&x)?,
+            UpdatableData::Aliases(UpdatableAliases::Clear) => {
+                self.set_aliases(Default::default())?
+            }
+        }
+
+        Ok(().into())
+    }
+
+    /// Set the headline genre of the artist, which can't be one of its secondary genres.
+    fn set_main_genre(&mut self, genre: Option<MusicGenre>) -> DispatchResultWithPostInfo {
+        let genre = genre
+            .map(|x| StoredGenre::checked(x).ok_or(Error::<T, I>::UnsupportedGenre))
+            .transpose()?;
+        if genre.map_or(false, |x| self.genres.contains(&x)) {
+            return Err(Error::<T, I>::MainGenreDuplicated.into());
+        }
+        self.main_genre = genre;
+
+        Ok(().into())
+    }
+
+    /// Every genre the artist is indexed under, its main genre first if any.
+    pub(super) fn indexed_genres(&self) -> Vec<StoredGenre> {
+        self.main_genre
+            .into_iter()
+            .chain(self.genres.iter().copied())
+            .collect()
+    }
+
+    /// Record a successful update of the artist data at the current block.
+    pub(super) fn note_update(&mut self) {
+        self.last_updated_at = <frame_system::Pallet<T>>::block_number();
+        self.update_nonce = self.update_nonce.wrapping_add(1);
+    }
+
+    /// The current value of the field targeted by the passed data.
+    pub(super) fn field(&self, data: &UpdatableDataOf<T, I>) -> ArtistField<T, I> {
+        match data {
+            UpdatableData::Alias(_) | UpdatableData::Aliases(_) => {
+                ArtistField::Aliases(self.aliases.clone())
+            }
+            UpdatableData::Genres(_) => ArtistField::Genres(self.genres.clone()),
+            UpdatableData::Description(_)
+            | UpdatableData::RawDescription(_)
+            | UpdatableData::DescriptionFingerprint(_) => ArtistField::Description {
+                hash: self.description.clone(),
+                raw: self.raw_description.clone(),
+            },
+            UpdatableData::Assets(_) => ArtistField::Assets(self.assets.clone()),
+            UpdatableData::OpenTo(_) => ArtistField::OpenTo(self.open_to),
+            UpdatableData::ContactHash(_) => ArtistField::ContactHash(self.contact_hash),
+            UpdatableData::Links(_) => ArtistField::Links(self.links.clone()),
+            UpdatableData::MainGenre(_) => ArtistField::MainGenre(self.main_genre),
+        }
+    }
+    /// Return true if the artist got verified and its verification didn't lapse past the
+    /// `T::VerificationValidity` yet.
+    pub(super) fn is_verified(&self) -> bool {
+        self.verified_at.map_or(false, |verified_at| {
+            let validity = T::VerificationValidity::get();
+            validity.is_zero()
+                || verified_at.saturating_add(validity) > frame_system::Pallet::<T>::block_number()
+        })
+    }
+
+    /// Return true if the artist holds a verification, even a lapsed one, its main name staying
+    /// in the verified names index until the verification gets expired.
+    pub(super) fn holds_verification(&self) -> bool {
+        self.verified_at.is_some()
+    }
+
+    /// Return true if the artist is neither suspended nor banned.
+    pub(super) fn is_active(&self) -> bool {
+        self.status == ArtistStatus::Active
+    }
+
+    /// The fingerprints of the artist assets.
+    pub(super) fn asset_hashes(&self) -> Vec<FingerprintOf<T>> {
+        self.assets.iter().map(|asset| asset.hash.clone()).collect()
+    }
+
+    /// Mark the artist as verified at the current block with the passed KYC evidence fingerprint.
+    pub(super) fn verify(&mut self, kyc_evidence_hash: Option<T::Hash>) {
+        self.verified_at = Some(<frame_system::Pallet<T>>::block_number());
+        self.kyc_evidence_hash = kyc_evidence_hash;
+    }
+
+    /// Replace the main name of the artist, adjusting its held name deposit.
+    pub(super) fn set_main_name(&mut self, main_name: T::Name) -> DispatchResultWithPostInfo {
+        self.adjust_data_deposit(
+            Self::bytes_cost(self.main_name.encoded_size()),
+            Self::bytes_cost(main_name.encoded_size()),
+        )?;
+
+        self.main_name = main_name;
+
+        Ok(().into())
+    }
+
+    fn set_alias(&mut self, alias: Option<T::Name>) -> Result<(), DispatchErrorWithPostInfo> {
+        let aliases = alias
+            .into_iter()
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| Error::<T, I>::TooManyAliases)?;
+
+        self.set_aliases(aliases)
+    }
+
+    fn add_alias(&mut self, alias: T::Name) -> Result<(), DispatchErrorWithPostInfo> {
+        let mut aliases = self.aliases.clone();
+        aliases
+            .try_push(alias)
+            .map_err(|_| Error::<T, I>::TooManyAliases)?;
+
+        self.set_aliases(aliases)
+    }
+
+    fn remove_alias(&mut self, alias: &T::Name) -> Result<(), DispatchErrorWithPostInfo> {
+        let mut aliases = self.aliases.clone();
+        let index = aliases
+            .iter()
+            .position(|x| x == alias)
+            .ok_or(Error::<T, I>::AliasNotFound)?;
+        aliases.remove(index);
+
+        self.set_aliases(aliases)
+    }
+
+    /// Replace the aliases of the artist while verifying that there is not the same alias
+    /// multiple times, adjusting its held name deposit.
+    fn set_aliases(
+        &mut self,
+        aliases: BoundedVec<ArtistAliasOf<T, I>, T::MaxAliases>,
+    ) -> Result<(), DispatchErrorWithPostInfo> {
+        for (i, alias) in aliases.iter().enumerate() {
+            if aliases[..i].contains(alias) {
+                return Err(Error::<T, I>::NotUniqueAlias.into());
+            }
+        }
+        self.check_alias_capacity(aliases.len())?;
+        self.adjust_data_deposit(
+            Self::bytes_cost(self.aliases.encoded_size()),
+            Self::bytes_cost(aliases.encoded_size()),
+        )?;
+
+        self.aliases = aliases;
+
+        Ok(())
+    }
+
+    /// Check the artist can hold the passed amount of aliases under `T::AliasCapacity` at its
+    /// verification level.
+    fn check_alias_capacity(&self, count: usize) -> Result<(), DispatchErrorWithPostInfo> {
+        if count as u32 > T::AliasCapacity::max_aliases(self.is_verified()) {
+            Err(Error::<T, I>::TooManyAliases.into())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn set_description(
+        &mut self,
+        raw_description: Option<Vec<u8>>,
+    ) -> Result<(), DispatchErrorWithPostInfo> {
+        if raw_description.as_ref().map_or(false, |x| {
+            x.len() as u32 > T::MaxHashedDescriptionLen::get()
+        }) {
+            return Err(Error::<T, I>::DescriptionTooLong.into());
+        }
+
+        self.set_description_fingerprint(
+            raw_description.map(|x| Fingerprint::Native(T::Hashing::hash(&x))),
+        )
+    }
+
+    fn set_description_fingerprint(
+        &mut self,
+        description: Option<FingerprintOf<T>>,
+    ) -> Result<(), DispatchErrorWithPostInfo> {
+        // Clean any existent deposit
+        self.clear_description()?;
+
+        if let Some(x) = description {
+            self.hold_deposit(HoldReason::ArtistData, Self::fingerprint_cost(&x))?;
+            self.description = Some(x);
+        }
+
+        Ok(())
+    }
+
+    fn set_raw_description(
+        &mut self,
+        raw_description: Option<Vec<u8>>,
+    ) -> Result<(), DispatchErrorWithPostInfo> {
+        let raw_description: Option<BoundedVec<u8, T::MaxDescriptionLen>> = raw_description
+            .map(|x| x.try_into().map_err(|_| Error::<T, I>::DescriptionTooLong))
+            .transpose()?;
+
+        // Clean any existent deposit
+        self.clear_description()?;
+
+        if let Some(x) = raw_description {
+            // hold storage deposit for every byte of the description
+            self.hold_deposit(HoldReason::ArtistData, Self::bytes_cost(x.encoded_size()))?;
+            self.raw_description = Some(x);
+        }
+
+        Ok(())
+    }
+
+    /// Remove both the hashed and the raw description of the artist, releasing their deposit.
+    fn clear_description(&mut self) -> Result<(), DispatchErrorWithPostInfo> {
+        if let Some(x) = self.description.take() {
+            self.release_deposit(HoldReason::ArtistData, Self::fingerprint_cost(&x))?;
+        }
+        if let Some(x) = self.raw_description.take() {
+            self.release_deposit(HoldReason::ArtistData, Self::bytes_cost(x.encoded_size()))?;
+        }
+
+        Ok(())
+    }
+
+    fn set_contact_hash(
+        &mut self,
+        contact_hash: Option<T::Hash>,
+    ) -> Result<(), DispatchErrorWithPostInfo> {
+        match (self.contact_hash.is_some(), contact_hash.is_some()) {
+            (false, true) => self.reserve_deposit_hash()?,
+            (true, false) => self.unreserve_deposit_hash().map(|_| ())?,
+            _ => (),
+        }
+
+        self.contact_hash = contact_hash;
+
+        Ok(())
+    }
+
+    fn add_checked_link(&mut self, kind: LinkType, link: Vec<u8>) -> DispatchResultWithPostInfo {
+        let link: BoundedVec<u8, T::MaxLinkLen> =
+            link.try_into().map_err(|_| Error::<T, I>::LinkTooLong)?;
+        let link = (kind, link);
+
+        if self.links.contains(&link) {
+            return Err(Error::<T, I>::NotUniqueLink.into());
+        }
+
+        // hold storage deposit for every byte of the link
+        let link_cost = Self::bytes_cost(link.encoded_size());
+        self.links
+            .try_push(link)
+            .map_err(|_| Error::<T, I>::LinksFull)?;
+        self.hold_deposit(HoldReason::ArtistData, link_cost)?;
+
+        Ok(().into())
+    }
+
+    fn remove_link(&mut self, kind: LinkType, link: &Vec<u8>) -> DispatchResultWithPostInfo {
+        if let Some(pos) = self
+            .links
+            .iter()
+            .position(|(x_kind, x)| *x_kind == kind && x[..] == link[..])
+        {
+            // refund storage deposit
+            let link_cost = Self::bytes_cost(self.links.remove(pos).encoded_size());
+            self.release_deposit(HoldReason::ArtistData, link_cost)?;
+
+            Ok(().into())
+        } else {
+            Err(Error::<T, I>::LinkNotFound.into())
+        }
+    }
+
+    fn clear_links(&mut self) -> Result<(), DispatchErrorWithPostInfo> {
+        let links_cost = self
+            .links
+            .iter()
+            .fold(BalanceOf::<T, I>::zero(), |acc, link| {
+                acc.saturating_add(Self::bytes_cost(link.encoded_size()))
+            });
+        self.release_deposit(HoldReason::ArtistData, links_cost)?;
+
+        self.links = Default::default();
+
+        Ok(())
+    }
+
+    fn add_checked_asset(
+        &mut self,
+        kind: AssetType,
+        asset: &Vec<u8>,
+    ) -> DispatchResultWithPostInfo {
+        self.add_checked_fingerprint(kind, Fingerprint::Native(T::Hashing::hash(asset)))
+    }
+
+    /// Add an asset from its fingerprint, rejecting it if an asset already has the same digest
+    /// under any fingerprint variant.
+    fn add_checked_fingerprint(
+        &mut self,
+        kind: AssetType,
+        hash: FingerprintOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        match self.assets.iter().any(|x| x.hash.same_digest(&hash)) {
+            false => {
+                let asset_cost = Self::fingerprint_cost(&hash);
+                let asset = ArtistAsset {
+                    kind,
+                    hash,
+                    added_at: <frame_system::Pallet<T>>::block_number(),
+                };
+                self.assets
+                    .try_push(asset)
+                    .map_err(|_| Error::<T, I>::AssetsFull)?;
+
+                // hold storage deposit
+                self.hold_deposit(HoldReason::ArtistData, asset_cost)?;
+
+                Ok(().into())
+            }
+            true => Err(Error::<T, I>::NotUniqueAsset.into()),
+        }
+    }
+
+    fn remove_asset(&mut self, asset: &Vec<u8>) -> DispatchResultWithPostInfo {
+        self.remove_fingerprint(&Fingerprint::Native(T::Hashing::hash(asset)))
+    }
+
+    fn remove_fingerprint(&mut self, hash: &FingerprintOf<T>) -> DispatchResultWithPostInfo {
+        if let Some(pos) = self.assets.iter().position(|x| x.hash.same_digest(hash)) {
+            self.remove_asset_at(pos as u32)
+        } else {
+            Err(Error::<T, I>::AssetNotFound.into())
+        }
+    }
+
+    fn remove_asset_at(&mut self, index: u32) -> DispatchResultWithPostInfo {
+        let index = index as usize;
+
+        if index < self.assets.len() {
+            let asset = self.assets.remove(index);
+
+            // refund storage deposit
+            self.release_deposit(HoldReason::ArtistData, Self::fingerprint_cost(&asset.hash))?;
+
+            Ok(().into())
+        } else {
+            Err(Error::<T, I>::IndexOutOfBounds.into())
+        }
+    }
+
+    fn clear_assets(&mut self) -> Result<(), DispatchErrorWithPostInfo> {
+        self.release_deposit(HoldReason::ArtistData, Self::assets_cost(&self.assets))?;
+
+        self.assets = Default::default();
+
+        Ok(())
+    }
+
+    fn remove_genre(&mut self, genre: MusicGenre) -> DispatchResultWithPostInfo {
+        let genre = StoredGenre::from(genre);
+        if let Some(pos) = self.genres.iter().position(|&x| x == genre) {
+            self.genres.remove(pos);
+            Ok(().into())
+        } else {
+            Err(Error::<T, I>::GenreNotFound.into())
+        }
+    }
+
+    fn remove_genre_at(&mut self, index: u32) -> DispatchResultWithPostInfo {
+        let index = index as usize;
+
+        if index < self.genres.len() {
+            self.genres.remove(index);
+            Ok(().into())
+        } else {
+            Err(Error::<T, I>::IndexOutOfBounds.into())
+        }
+    }
+
+    /// Link the passed contract to the artist, holding its storage deposit.
+    pub(super) fn add_contract(&mut self, contract: AccountIdOf<T>) -> DispatchResultWithPostInfo {
+        if self.contracts_frozen {
+            return Err(Error::<T, I>::ContractsFrozen.into());
+        }
+        if self.contracts.contains(&contract) {
+            return Err(Error::<T, I>::NotUniqueContract.into());
+        }
+
+        self.contracts
+            .try_push(contract)
+            .map_err(|_| Error::<T, I>::ContractsFull)?;
+
+        let contract_cost = Self::bytes_cost(AccountIdOf::<T>::max_encoded_len());
+        self.hold_deposit(HoldReason::ArtistData, contract_cost)?;
+
+        Ok(().into())
+    }
+
+    /// Unlink the passed contract from the artist, releasing its storage deposit.
+    pub(super) fn remove_contract(
+        &mut self,
+        contract: &AccountIdOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        if self.contracts_frozen {
+            return Err(Error::<T, I>::ContractsFrozen.into());
+        }
+
+        if let Some(pos) = self.contracts.iter().position(|x| x == contract) {
+            self.contracts.remove(pos);
+
+            let contract_cost = Self::bytes_cost(AccountIdOf::<T>::max_encoded_len());
+            self.release_deposit(HoldReason::ArtistData, contract_cost)?;
+
+            Ok(().into())
+        } else {
+            Err(Error::<T, I>::ContractNotFound.into())
+        }
+    }
+
+    /// Adjust the held evidence deposit of the artist from `old_count` to `new_count` evidence
+    /// fingerprints.
+    pub(super) fn set_evidence_deposit(
+        &mut self,
+        old_count: u32,
+        new_count: u32,
+    ) -> DispatchResultWithPostInfo {
+        let hash_cost = Self::bytes_cost(T::Hash::max_encoded_len());
+
+        self.adjust_data_deposit(
+            hash_cost.saturating_mul(old_count.saturated_into()),
+            hash_cost.saturating_mul(new_count.saturated_into()),
+        )?;
+
+        Ok(().into())
+    }
+
+    /// Hold the `T::VerificationDeposit` escrow of a verification request of the artist.
+    pub(super) fn hold_verification_deposit(&mut self) -> DispatchResultWithPostInfo {
+        self.hold_deposit(
+            HoldReason::VerificationRequest,
+            T::VerificationDeposit::get(),
+        )?;
+
+        Ok(().into())
+    }
+
+    /// Refund or slash the verification request escrow of the artist, returning the slashed
+    /// amount, or the refunded one with a refund `policy`.
+    pub(super) fn settle_verification_deposit(
+        &mut self,
+        policy: DepositPolicy,
+    ) -> Result<BalanceOf<T, I>, DispatchErrorWithPostInfo> {
+        let reason = HoldReason::<I>::VerificationRequest;
+        // The depositor holds the escrows of all the artists it registered under the same reason
+        let held = T::Currency::balance_on_hold(&reason.into(), &self.depositor)
+            .min(T::VerificationDeposit::get())
+            .min(self.deposit);
+
+        match policy {
+            DepositPolicy::Refund => self.release_deposit(reason, held),
+            DepositPolicy::Slash | DepositPolicy::SlashPart(_) => {
+                let (credit, _) = <T::Currency as BalancedHold<AccountIdOf<T>>>::slash(
+                    &reason.into(),
+                    &self.depositor,
+                    policy.slashed_part().mul_floor(held),
+                );
+                let slashed = credit.peek();
+                if !slashed.is_zero() {
+                    T::Slash::on_unbalanced(credit);
+                }
+                self.deposit = self.deposit.saturating_sub(slashed);
+
+                let rest = held.saturating_sub(slashed);
+                if !rest.is_zero() {
+                    self.release_deposit(reason, rest)?;
+                }
+
+                Ok(slashed)
+            }
+        }
+    }
+
+    /// Hold the surcharge of an [`AbuseVerdict`] from the depositor, along with the artist deposit.
+    pub(super) fn hold_surcharge(&mut self, amount: BalanceOf<T, I>) -> DispatchResultWithPostInfo {
+        let before = self.deposit;
+        self.hold_deposit(HoldReason::ArtistData, amount)?;
+        // Nothing is held from the exempted depositors
+        self.surcharge = self
+            .surcharge
+            .saturating_add(self.deposit.saturating_sub(before));
+
+        Ok(().into())
+    }
+
+    /// Return true if the passed account is allowed to update the artist on behalf of the owner.
+    pub(super) fn is_delegate(&self, account: &AccountIdOf<T>) -> bool {
+        self.delegates.contains(account)
+    }
+
+    /// Allow the passed account to update the artist, holding its storage deposit.
+    pub(super) fn add_delegate(&mut self, delegate: AccountIdOf<T>) -> DispatchResultWithPostInfo {
+        if self.delegates.contains(&delegate) {
+            return Err(Error::<T, I>::AlreadyDelegate.into());
+        }
+
+        self.delegates
+            .try_push(delegate)
+            .map_err(|_| Error::<T, I>::DelegatesFull)?;
+
+        let delegate_cost = Self::bytes_cost(AccountIdOf::<T>::max_encoded_len());
+        self.hold_deposit(HoldReason::ArtistData, delegate_cost)?;
+
+        Ok(().into())
+    }
+
+    /// Revoke the passed delegate of the artist, releasing its storage deposit.
+    pub(super) fn remove_delegate(
+        &mut self,
+        delegate: &AccountIdOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        let pos = self
+            .delegates
+            .iter()
+            .position(|x| x == delegate)
+            .ok_or(Error::<T, I>::NotDelegate)?;
+        self.delegates.remove(pos);
+
+        let delegate_cost = Self::bytes_cost(AccountIdOf::<T>::max_encoded_len());
+        self.release_deposit(HoldReason::ArtistData, delegate_cost)?;
+
+        Ok(().into())
+    }
+
+    /// Claim the passed work for the artist, holding the storage deposit of its fingerprint.
+    pub(super) fn claim_work(&mut self, work: T::Hash) -> DispatchResultWithPostInfo {
+        if self.works.contains(&work) {
+            return Err(Error::<T, I>::WorkAlreadyClaimed.into());
+        }
+
+        self.works
+            .try_push(work)
+            .map_err(|_| Error::<T, I>::WorksFull)?;
+        self.reserve_deposit_hash()?;
+
+        Ok(().into())
+    }
+
+    /// Disclaim the passed work of the artist, releasing the storage deposit of its fingerprint.
+    pub(super) fn disclaim_work(&mut self, work: &T::Hash) -> DispatchResultWithPostInfo {
+        let pos = self
+            .works
+            .iter()
+            .position(|x| x == work)
+            .ok_or(Error::<T, I>::WorkNotClaimed)?;
+        self.works.remove(pos);
+        self.unreserve_deposit_hash()?;
+
+        Ok(().into())
+    }
+
+    /// Return true if the passed account is a member of the group.
+    pub(super) fn is_member(&self, account: &AccountIdOf<T>) -> bool {
+        self.members.contains(account)
+    }
+
+    /// Turn the artist into a solo artist or a group, a group having to remove its members
+    /// before turning solo.
+    pub(super) fn set_kind(&mut self, kind: ArtistKind) -> DispatchResultWithPostInfo {
+        if kind == ArtistKind::Solo && !self.members.is_empty() {
+            return Err(Error::<T, I>::GroupHasMembers.into());
+        }
+
+        self.kind = kind;
+
+        Ok(().into())
+    }
+
+    /// Add the passed account to the group members, holding its storage deposit.
+    pub(super) fn add_member(&mut self, member: AccountIdOf<T>) -> DispatchResultWithPostInfo {
+        if self.kind != ArtistKind::Group {
+            return Err(Error::<T, I>::NotGroup.into());
+        }
+        if self.members.contains(&member) {
+            return Err(Error::<T, I>::AlreadyMember.into());
+        }
+
+        self.members
+            .try_push(member)
+            .map_err(|_| Error::<T, I>::MembersFull)?;
+
+        let member_cost = Self::bytes_cost(AccountIdOf::<T>::max_encoded_len());
+        self.hold_deposit(HoldReason::ArtistData, member_cost)?;
+
+        Ok(().into())
+    }
+
+    /// Remove the passed member from the group, releasing its storage deposit.
+    pub(super) fn remove_member(&mut self, member: &AccountIdOf<T>) -> DispatchResultWithPostInfo {
+        let pos = self
+            .members
+            .iter()
+            .position(|x| x == member)
+            .ok_or(Error::<T, I>::NotMember)?;
+        self.members.remove(pos);
+
+        let member_cost = Self::bytes_cost(AccountIdOf::<T>::max_encoded_len());
+        self.release_deposit(HoldReason::ArtistData, member_cost)?;
+
+        Ok(().into())
+    }
+
+    /// Replace the royalty beneficiaries of the artist, holding the storage deposit difference.
+    ///
+    /// The shares must all be positive, sum to 100% and go to distinct beneficiaries.
+    pub(super) fn set_royalty_splits(
+        &mut self,
+        splits: RoyaltySplitsOf<T, I>,
+    ) -> DispatchResultWithPostInfo {
+        let mut beneficiaries = BTreeSet::new();
+        let mut total = 0u64;
+        for (beneficiary, share) in splits.iter() {
+            if share.is_zero() {
+                return Err(Error::<T, I>::InvalidRoyaltySplits.into());
+            }
+            if !beneficiaries.insert(beneficiary) {
+                return Err(Error::<T, I>::DuplicateBeneficiary.into());
+            }
+            total = total.saturating_add(share.deconstruct().into());
+        }
+        if total != Perbill::one().deconstruct().into() {
+            return Err(Error::<T, I>::InvalidRoyaltySplits.into());
+        }
+
+        let old_cost = Self::royalty_splits_cost(&self.royalty_splits);
+        self.adjust_data_deposit(old_cost, Self::royalty_splits_cost(&splits))?;
+        self.royalty_splits = splits;
+
+        Ok(().into())
+    }
+
+    /// Remove the royalty beneficiaries of the artist, releasing their storage deposit.
+    pub(super) fn clear_royalty_splits(&mut self) -> DispatchResultWithPostInfo {
+        if self.royalty_splits.is_empty() {
+            return Err(Error::<T, I>::RoyaltySplitsNotFound.into());
+        }
+
+        let splits = sp_std::mem::take(&mut self.royalty_splits);
+        self.release_deposit(HoldReason::ArtistData, Self::royalty_splits_cost(&splits))?;
+
+        Ok(().into())
+    }
+
+    /// The storage deposit of the passed royalty beneficiaries, nothing being held without any.
+    fn royalty_splits_cost(splits: &RoyaltySplitsOf<T, I>) -> BalanceOf<T, I> {
+        if splits.is_empty() {
+            return Zero::zero();
+        }
+        Self::bytes_cost(splits.encoded_size())
+    }
+
+    /// Move the assets, links and contracts of the passed duplicate artist into this one, holding
+    /// their storage deposit from this artist depositor. Items the artist already has are skipped.
+    pub(super) fn absorb(&mut self, duplicate: &Artist<T, I>) -> DispatchResultWithPostInfo {
+        for asset in duplicate.assets.iter() {
+            if self.assets.iter().any(|x| x.hash.same_digest(&asset.hash)) {
+                continue;
+            }
+
+            self.assets
+                .try_push(asset.clone())
+                .map_err(|_| Error::<T, I>::AssetsFull)?;
+            self.hold_deposit(HoldReason::ArtistData, Self::fingerprint_cost(&asset.hash))?;
+        }
+
+        for link in duplicate.links.iter() {
+            if self.links.contains(link) {
+                continue;
+            }
+
+            let link_cost = Self::bytes_cost(link.encoded_size());
+            self.links
+                .try_push(link.clone())
+                .map_err(|_| Error::<T, I>::LinksFull)?;
+            self.hold_deposit(HoldReason::ArtistData, link_cost)?;
+        }
+
+        for contract in duplicate.contracts.iter() {
+            if self.contracts.contains(contract) {
+                continue;
+            }
+
+            self.add_contract(contract.clone())?;
+        }
+
+        Ok(().into())
+    }
+
+    /// Freeze or unfreeze the contracts list of the artist.
+    pub(super) fn set_contracts_frozen(&mut self, frozen: bool) -> DispatchResultWithPostInfo {
+        match (self.contracts_frozen, frozen) {
+            (true, true) => Err(Error::<T, I>::ContractsFrozen.into()),
+            (false, false) => Err(Error::<T, I>::ContractsNotFrozen.into()),
+            _ => {
+                self.contracts_frozen = frozen;
+                Ok(().into())
+            }
+        }
+    }
+
+    fn reserve_deposit_hash(&mut self) -> Result<(), DispatchErrorWithPostInfo> {
+        let hash_cost = Self::bytes_cost(T::Hash::max_encoded_len());
+
+        self.hold_deposit(HoldReason::ArtistData, hash_cost)
+    }
+
+    fn unreserve_deposit_hash(&mut self) -> Result<BalanceOf<T, I>, DispatchErrorWithPostInfo> {
+        let hash_cost = Self::bytes_cost(T::Hash::max_encoded_len());
+
+        self.release_deposit(HoldReason::ArtistData, hash_cost)
+    }
+
+    /// Split the artist deposit per category of stored data, the rest of the tracked deposit
+    /// being reported as `other`.
+    pub(super) fn deposit_breakdown(&self) -> DepositBreakdown<BalanceOf<T, I>> {
+        let hash_cost = Self::bytes_cost(T::Hash::max_encoded_len());
+        let account_cost = Self::bytes_cost(AccountIdOf::<T>::max_encoded_len());
+
+        let description = match (&self.description, &self.raw_description) {
+            (Some(x), _) => Self::fingerprint_cost(x),
+            (None, Some(x)) => Self::bytes_cost(x.encoded_size()),
+            (None, None) => Zero::zero(),
+        };
+        let mut breakdown = DepositBreakdown {
+            base: T::BaseDeposit::get(),
+            names: Self::bytes_cost(self.main_name.encoded_size())
+                .saturating_add(Self::bytes_cost(self.aliases.encoded_size())),
+            description,
+            contact_hash: if self.contact_hash.is_some() {
+                hash_cost
+            } else {
+                Zero::zero()
+            },
+            links: self.links.iter().fold(Zero::zero(), |acc, link| {
+                acc.saturating_add(Self::bytes_cost(link.encoded_size()))
+            }),
+            assets: Self::assets_cost(&self.assets),
+            works: hash_cost.saturating_mul(self.works.len().saturated_into()),
+            contracts: account_cost.saturating_mul(self.contracts.len().saturated_into()),
+            delegates: account_cost.saturating_mul(self.delegates.len().saturated_into()),
+            members: account_cost.saturating_mul(self.members.len().saturated_into()),
+            royalty_splits: Self::royalty_splits_cost(&self.royalty_splits),
+            other: Zero::zero(),
+        };
+
+        breakdown.other = self.deposit.saturating_sub(Self::data_deposit(&breakdown));
+
+        breakdown
+    }
+
+    /// The sum of the passed breakdown, apart from its `other` part.
+    fn data_deposit(breakdown: &DepositBreakdown<BalanceOf<T, I>>) -> BalanceOf<T, I> {
+        [
+            breakdown.base,
+            breakdown.names,
+            breakdown.description,
+            breakdown.contact_hash,
+            breakdown.links,
+            breakdown.assets,
+            breakdown.works,
+            breakdown.contracts,
+            breakdown.delegates,
+            breakdown.members,
+            breakdown.royalty_splits,
+        ]
+        .into_iter()
+        .fold(BalanceOf::<T, I>::zero(), |acc, x| acc.saturating_add(x))
+    }
+
+    /// The deposit the artist requires at the current `T::BaseDeposit` and `T::ByteDeposit`,
+    /// along with its surcharges.
+    ///
+    /// The escrow and evidence deposits of a pending verification request aren't accounted for.
+    pub(super) fn required_deposit(&self) -> BalanceOf<T, I> {
+        Self::data_deposit(&self.deposit_breakdown()).saturating_add(self.surcharge)
+    }
+
+    /// Hold or release the difference between the tracked deposit of the artist and its
+    /// [`Self::required_deposit`].
+    ///
+    /// The base deposit being held under its own reason, what can't be released from the data
+    /// deposit is released from it.
+    pub(super) fn reprice_deposit(&mut self) -> Result<(), DispatchErrorWithPostInfo> {
+        let required = self.required_deposit();
+
+        if required > self.deposit {
+            self.hold_deposit(HoldReason::ArtistData, required - self.deposit)?;
+        }
+        if required < self.deposit {
+            let excess = self.deposit - required;
+            let released = self.release_deposit(HoldReason::ArtistData, excess)?;
+            let rest = excess.saturating_sub(released);
+            if !rest.is_zero() {
+                self.release_deposit(HoldReason::ArtistRegistration, rest)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The deposit for storing the passed fingerprint, the same as for a hash for the native ones.
+    fn fingerprint_cost(fingerprint: &FingerprintOf<T>) -> BalanceOf<T, I> {
+        Self::bytes_cost(fingerprint.as_bytes().len())
+    }
+
+    /// The deposit for storing the fingerprints of the passed assets.
+    fn assets_cost(assets: &[ArtistAsset<T>]) -> BalanceOf<T, I> {
+        assets.iter().fold(Zero::zero(), |acc, asset| {
+            acc.saturating_add(Self::fingerprint_cost(&asset.hash))
+        })
+    }
+
+    /// The deposit for storing `len` bytes.
+    fn bytes_cost(len: usize) -> BalanceOf<T, I> {
+        T::ByteDeposit::get().saturating_mul(len.saturated_into())
+    }
+
+    /// Hold or release the data deposit difference between the `old_cost` and the `new_cost`.
+    fn adjust_data_deposit(
+        &mut self,
+        old_cost: BalanceOf<T, I>,
+        new_cost: BalanceOf<T, I>,
+    ) -> Result<(), DispatchErrorWithPostInfo> {
+        if new_cost > old_cost {
+            self.hold_deposit(HoldReason::ArtistData, new_cost - old_cost)?;
+        }
+        if new_cost < old_cost {
+            self.release_deposit(HoldReason::ArtistData, old_cost - new_cost)?;
+        }
+
+        Ok(())
+    }
+
+    /// Move the funds still held from the depositor under a legacy per-field reason to the
+    /// [`HoldReason::ArtistData`] one.
+    ///
+    /// The tracked artist deposit is unchanged as the funds stay held.
+    pub(super) fn migrate_legacy_holds(&self) -> Result<(), DispatchErrorWithPostInfo> {
+        for reason in HoldReason::<I>::legacy_data_reasons() {
+            let held = T::Currency::balance_on_hold(&reason.into(), &self.depositor);
+            if held.is_zero() {
+                continue;
+            }
+
+            T::Currency::release(&reason.into(), &self.depositor, held, Precision::Exact)?;
+            T::Currency::hold(&HoldReason::<I>::ArtistData.into(), &self.depositor, held)?;
+        }
+
+        Ok(())
+    }
+
+    /// Hold `amount` from the depositor for the passed reason and track it in the artist deposit.
+    ///
+    /// Nothing is held if the depositor is exempted from deposits.
+    fn hold_deposit(
+        &mut self,
+        reason: HoldReason<I>,
+        amount: BalanceOf<T, I>,
+    ) -> Result<(), DispatchErrorWithPostInfo> {
+        if DepositExemptions::<T, I>::contains_key(&self.depositor) {
+            return Ok(());
+        }
+
+        self.migrate_legacy_holds()?;
+        T::Currency::hold(&reason.into(), &self.depositor, amount)?;
+        self.deposit = self.deposit.saturating_add(amount);
+
+        Ok(())
+    }
+
+    /// Release up to `amount` held from the depositor for the passed reason and untrack it from
+    /// the artist deposit, returning the amount effectively released.
+    ///
+    /// Never more than the tracked deposit is released, the depositor possibly holding under the
+    /// same reason for other data, such as the other artists of a label, or for data stored while
+    /// it was exempt.
+    fn release_deposit(
+        &mut self,
+        reason: HoldReason<I>,
+        amount: BalanceOf<T, I>,
+    ) -> Result<BalanceOf<T, I>, DispatchErrorWithPostInfo> {
+        self.migrate_legacy_holds()?;
+        let released = T::Currency::release(
+            &reason.into(),
+            &self.depositor,
+            amount.min(self.deposit),
+            Precision::BestEffort,
+        )?;
+        self.deposit = self.deposit.saturating_sub(released);
+
+        Ok(released)
     }
 }
@@ -20,6 +20,9 @@
 // --output=artists.rs
 // --template=./.maintain/frame-weight-template.hbs
 
+// The functions marked as estimated or adjusted by hand are placeholders, added or changed
+// since the run above, until the weights get generated again.
+
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -30,203 +33,965 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for pallet_artists.
 pub trait WeightInfo {
-    fn register(n: u32, g: u32, a: u32, ) -> Weight;
+    fn register(n: u32, g: u32, a: u32, d: u32, ) -> Weight;
     fn force_unregister(n: u32, g: u32, a: u32, ) -> Weight;
-    fn unregister(n: u32, g: u32, a: u32, ) -> Weight;
+    fn confirm_unregister(n: u32, g: u32, a: u32, ) -> Weight;
     fn update_alias(n: u32, x: u32, ) -> Weight;
     fn update_add_genres(n: u32, ) -> Weight;
     fn update_remove_genres(n: u32, ) -> Weight;
+    fn update_remove_genres_at(n: u32, ) -> Weight;
     fn update_clear_genres(n: u32, ) -> Weight;
-    fn update_description() -> Weight;
+    fn clear_genre_index(n: u32, ) -> Weight;
+    fn update_description(n: u32, ) -> Weight;
+    fn update_raw_description(n: u32, ) -> Weight;
+    fn update_open_to() -> Weight;
+    fn update_main_genre() -> Weight;
+    fn update_contact_hash() -> Weight;
     fn update_add_assets(n: u32, ) -> Weight;
     fn update_remove_assets(n: u32, ) -> Weight;
+    fn update_remove_assets_at(n: u32, ) -> Weight;
+    fn update_remove_assets_fingerprint(n: u32, ) -> Weight;
     fn update_clear_assets(n: u32, ) -> Weight;
+    fn update_add_links(n: u32, ) -> Weight;
+    fn update_remove_links(n: u32, ) -> Weight;
+    fn update_clear_links(n: u32, ) -> Weight;
+    fn update_add_aliases(n: u32, ) -> Weight;
+    fn update_remove_aliases(n: u32, ) -> Weight;
+    fn update_clear_aliases(n: u32, ) -> Weight;
+    fn verify_artist() -> Weight;
+    fn update_kyc_evidence() -> Weight;
+    fn set_deposit_exemption() -> Weight;
+    fn freeze_contracts() -> Weight;
+    fn unfreeze_contracts() -> Weight;
+    fn reserve_name() -> Weight;
+    fn unreserve_name() -> Weight;
+    fn ban_name() -> Weight;
+    fn unban_name() -> Weight;
+    fn submit_verification_evidence(e: u32, ) -> Weight;
+    fn merge_artists(a: u32, l: u32, c: u32, ) -> Weight;
+    fn rename(n: u32, ) -> Weight;
+    fn approve_rename(n: u32, ) -> Weight;
+    fn reject_rename() -> Weight;
+    fn request_unregister() -> Weight;
+    fn cancel_unregister() -> Weight;
+    fn add_delegate(d: u32, ) -> Weight;
+    fn remove_delegate(d: u32, ) -> Weight;
+    fn update_stats(n: u32, ) -> Weight;
+    fn request_verification(e: u32, ) -> Weight;
+    fn approve_verification() -> Weight;
+    fn reject_verification() -> Weight;
+    fn suspend_artist() -> Weight;
+    fn reinstate_artist() -> Weight;
+    fn export_artist() -> Weight;
+    fn force_restore_artist(g: u32, ) -> Weight;
+    fn claim_profile() -> Weight;
+    fn approve_profile_claim() -> Weight;
+    fn release_expired_profile() -> Weight;
+    fn register_for(n: u32, g: u32, a: u32, d: u32, ) -> Weight;
+    fn set_royalty_splits(b: u32, ) -> Weight;
+    fn clear_royalty_splits(b: u32, ) -> Weight;
+    fn set_artist_kind() -> Weight;
+    fn add_member(m: u32, ) -> Weight;
+    fn accept_membership(m: u32, ) -> Weight;
+    fn remove_member(m: u32, ) -> Weight;
+    fn leave_group(m: u32, ) -> Weight;
+    fn register_v2(n: u32, g: u32, a: u32, l: u32, d: u32, ) -> Weight;
+    fn join_ownership() -> Weight;
+    fn offer_co_ownership() -> Weight;
+    fn prune_pending_verification() -> Weight;
+    fn prune_retired_name() -> Weight;
+    fn transfer_ownership() -> Weight;
+    fn accept_ownership() -> Weight;
+    fn set_evidence_delegate() -> Weight;
+    fn submit_verification_evidence_as_delegate(e: u32, ) -> Weight;
+    fn set_payout_route() -> Weight;
+    fn renew_verification() -> Weight;
+    fn expire_verification() -> Weight;
+    fn claim_work(w: u32, ) -> Weight;
+    fn disclaim_work(w: u32, ) -> Weight;
+    fn poke_deposit() -> Weight;
+    fn reap_expired(n: u32, g: u32, a: u32, ) -> Weight;
+    fn register_remote(n: u32, g: u32, a: u32, l: u32, d: u32, ) -> Weight;
 }
 
 /// For backwards compatibility and tests
 impl WeightInfo for () {
+    /// Adjusted by hand from the last benchmark run, not benchmarked again yet.
     /// Storage: `Artists::ArtistOf` (r:1 w:1)
-    /// Proof: `Artists::ArtistOf` (`max_values`: None, `max_size`: Some(12659), added: 15134, mode: `MaxEncodedLen`)
+    /// Storage: `Artists::BannedNames` (r:1 w:0)
+    /// Storage: `Artists::ReservedNames` (r:1 w:0)
+    /// Storage: `Artists::ArtistNameOf` (r:1 w:0)
     /// Storage: `Balances::Holds` (r:1 w:1)
-    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(937), added: 3412, mode: `MaxEncodedLen`)
+    /// Storage: `Artists::ArtistsOfGenre` (r:0 w:5)
+    /// Storage: `Artists::CounterForArtistOf` (r:1 w:1)
+    /// Storage: `Artists::ArtistAliasIndex` (r:1 w:1)
+    /// Storage: `Artists::RegistrationsUsage` (r:1 w:1)
     /// The range of component `n` is `[1, 128]`.
     /// The range of component `g` is `[0, 5]`.
     /// The range of component `a` is `[0, 64]`.
-    fn register(n: u32, g: u32, a: u32, ) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `76`
-        //  Estimated: `16124`
-        // Minimum execution time: 165_000_000 picoseconds.
+    /// The range of component `d` is `[0, 1024]`.
+    fn register(n: u32, g: u32, a: u32, d: u32, ) -> Weight {
         Weight::from_parts(5_895_691, 16124)
-            // Standard Error: 75_465
             .saturating_add(Weight::from_parts(1_282_433, 0).saturating_mul(n.into()))
-            // Standard Error: 1_724_768
             .saturating_add(Weight::from_parts(8_934_318, 0).saturating_mul(g.into()))
-            // Standard Error: 149_054
             .saturating_add(Weight::from_parts(31_929_514, 0).saturating_mul(a.into()))
-            .saturating_add(RocksDbWeight::get().reads(2_u64))
-            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(Weight::from_parts(2_861, 0).saturating_mul(d.into()))
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(g.into())))
     }
+    /// Adjusted by hand from the last benchmark run, not benchmarked again yet.
     /// Storage: `Balances::Holds` (r:1 w:1)
-    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(937), added: 3412, mode: `MaxEncodedLen`)
     /// Storage: `Artists::ArtistOf` (r:0 w:1)
-    /// Proof: `Artists::ArtistOf` (`max_values`: None, `max_size`: Some(12659), added: 15134, mode: `MaxEncodedLen`)
+    /// Storage: `Artists::ArtistsOfGenre` (r:0 w:5)
+    /// Storage: `Artists::CounterForArtistOf` (r:1 w:1)
+    /// Storage: `Artists::ArtistAliasIndex` (r:1 w:1)
     /// The range of component `n` is `[1, 128]`.
     /// The range of component `g` is `[0, 5]`.
     /// The range of component `a` is `[0, 64]`.
     fn force_unregister(n: u32, g: u32, a: u32, ) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `118`
-        //  Estimated: `4402`
-        // Minimum execution time: 92_000_000 picoseconds.
         Weight::from_parts(92_911_331, 4402)
-            // Standard Error: 947
             .saturating_add(Weight::from_parts(4_111, 0).saturating_mul(n.into()))
-            // Standard Error: 21_652
             .saturating_add(Weight::from_parts(110_423, 0).saturating_mul(g.into()))
-            // Standard Error: 1_871
             .saturating_add(Weight::from_parts(5_196, 0).saturating_mul(a.into()))
-            .saturating_add(RocksDbWeight::get().reads(1_u64))
-            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(g.into())))
     }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
     /// Storage: `Artists::ArtistOf` (r:1 w:1)
-    /// Proof: `Artists::ArtistOf` (`max_values`: None, `max_size`: Some(12659), added: 15134, mode: `MaxEncodedLen`)
+    /// Storage: `Artists::UnregisterRequests` (r:1 w:1)
     /// Storage: `Balances::Holds` (r:1 w:1)
-    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(937), added: 3412, mode: `MaxEncodedLen`)
+    /// Storage: `Artists::ArtistsOfGenre` (r:0 w:5)
+    /// Storage: `Artists::CounterForArtistOf` (r:1 w:1)
+    /// Storage: `Artists::ArtistAliasIndex` (r:1 w:1)
     /// The range of component `n` is `[1, 128]`.
     /// The range of component `g` is `[0, 5]`.
     /// The range of component `a` is `[0, 64]`.
-    fn unregister(_n: u32, g: u32, a: u32, ) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `314 + a * (32 ±0) + g * (3 ±0) + n * (2 ±0)`
-        //  Estimated: `16124`
-        // Minimum execution time: 130_000_000 picoseconds.
+    fn confirm_unregister(_n: u32, g: u32, a: u32, ) -> Weight {
         Weight::from_parts(141_218_178, 16124)
-            // Standard Error: 24_917
             .saturating_add(Weight::from_parts(71_438, 0).saturating_mul(g.into()))
-            // Standard Error: 2_153
             .saturating_add(Weight::from_parts(11_973, 0).saturating_mul(a.into()))
-            .saturating_add(RocksDbWeight::get().reads(2_u64))
-            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(g.into())))
     }
+    /// Adjusted by hand from the last benchmark run, not benchmarked again yet.
     /// Storage: `Artists::ArtistOf` (r:1 w:1)
-    /// Proof: `Artists::ArtistOf` (`max_values`: None, `max_size`: Some(12659), added: 15134, mode: `MaxEncodedLen`)
     /// Storage: `Balances::Holds` (r:1 w:0)
-    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(937), added: 3412, mode: `MaxEncodedLen`)
+    /// Storage: `Artists::ArtistAliasIndex` (r:2 w:2)
+    /// Storage: `Artists::ArtistNameOf` (r:1 w:0)
     /// The range of component `n` is `[1, 128]`.
     /// The range of component `x` is `[1, 128]`.
     fn update_alias(_n: u32, x: u32, ) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `295 + n * (2 ±0)`
-        //  Estimated: `16124`
-        // Minimum execution time: 14_000_000 picoseconds.
         Weight::from_parts(47_930_120, 16124)
-            // Standard Error: 4_071
             .saturating_add(Weight::from_parts(15_145, 0).saturating_mul(x.into()))
-            .saturating_add(RocksDbWeight::get().reads(2_u64))
-            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
     }
+    /// Adjusted by hand from the last benchmark run, not benchmarked again yet.
     /// Storage: `Artists::ArtistOf` (r:1 w:1)
-    /// Proof: `Artists::ArtistOf` (`max_values`: None, `max_size`: Some(12659), added: 15134, mode: `MaxEncodedLen`)
+    /// Storage: `Artists::ArtistsOfGenre` (r:0 w:1)
     /// The range of component `n` is `[0, 4]`.
     fn update_add_genres(n: u32, ) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `198 + n * (3 ±0)`
-        //  Estimated: `16124`
-        // Minimum execution time: 11_000_000 picoseconds.
         Weight::from_parts(11_578_271, 16124)
-            // Standard Error: 14_289
             .saturating_add(Weight::from_parts(302_453, 0).saturating_mul(n.into()))
             .saturating_add(RocksDbWeight::get().reads(1_u64))
-            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
     }
+    /// Adjusted by hand from the last benchmark run, not benchmarked again yet.
     /// Storage: `Artists::ArtistOf` (r:1 w:1)
-    /// Proof: `Artists::ArtistOf` (`max_values`: None, `max_size`: Some(12659), added: 15134, mode: `MaxEncodedLen`)
+    /// Storage: `Artists::ArtistsOfGenre` (r:0 w:1)
     /// The range of component `n` is `[1, 5]`.
     fn update_remove_genres(n: u32, ) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `198 + n * (3 ±0)`
-        //  Estimated: `16124`
-        // Minimum execution time: 11_000_000 picoseconds.
         Weight::from_parts(12_224_824, 16124)
-            // Standard Error: 31_119
             .saturating_add(Weight::from_parts(86_974, 0).saturating_mul(n.into()))
             .saturating_add(RocksDbWeight::get().reads(1_u64))
-            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
     }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
     /// Storage: `Artists::ArtistOf` (r:1 w:1)
-    /// Proof: `Artists::ArtistOf` (`max_values`: None, `max_size`: Some(12659), added: 15134, mode: `MaxEncodedLen`)
+    /// Storage: `Artists::ArtistsOfGenre` (r:0 w:1)
+    /// The range of component `n` is `[1, 5]`.
+    fn update_remove_genres_at(n: u32, ) -> Weight {
+        Weight::from_parts(11_853_112, 16124)
+            .saturating_add(Weight::from_parts(71_338, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Adjusted by hand from the last benchmark run, not benchmarked again yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Artists::ArtistsOfGenre` (r:0 w:5)
     /// The range of component `n` is `[0, 5]`.
     fn update_clear_genres(n: u32, ) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `198 + n * (3 ±0)`
-        //  Estimated: `16124`
-        // Minimum execution time: 10_000_000 picoseconds.
         Weight::from_parts(11_122_480, 16124)
-            // Standard Error: 12_695
             .saturating_add(Weight::from_parts(50_257, 0).saturating_mul(n.into()))
             .saturating_add(RocksDbWeight::get().reads(1_u64))
             .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistsOfGenre` (r:0 w:5)
+    /// The range of component `n` is `[0, 5]`.
+    fn clear_genre_index(n: u32, ) -> Weight {
+        Weight::from_parts(1_245_306, 0)
+            .saturating_add(Weight::from_parts(1_103_522, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+    }
+    /// Adjusted by hand from the last benchmark run, not benchmarked again yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// The range of component `n` is `[0, 1024]`.
+    fn update_description(n: u32, ) -> Weight {
+        Weight::from_parts(69_284_113, 16124)
+            .saturating_add(Weight::from_parts(2_846, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// The range of component `n` is `[1, 256]`.
+    fn update_raw_description(n: u32, ) -> Weight {
+        Weight::from_parts(67_418_250, 16124)
+            .saturating_add(Weight::from_parts(3_127, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    fn update_open_to() -> Weight {
+        Weight::from_parts(15_000_000, 16125)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Artists::ArtistsOfGenre` (r:0 w:2)
+    fn update_main_genre() -> Weight {
+        Weight::from_parts(13_000_000, 16131)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
     /// Storage: `Artists::ArtistOf` (r:1 w:1)
-    /// Proof: `Artists::ArtistOf` (`max_values`: None, `max_size`: Some(12659), added: 15134, mode: `MaxEncodedLen`)
     /// Storage: `Balances::Holds` (r:1 w:1)
-    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(937), added: 3412, mode: `MaxEncodedLen`)
-    fn update_description() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `298`
-        //  Estimated: `16124`
-        // Minimum execution time: 68_000_000 picoseconds.
-        Weight::from_parts(70_000_000, 16124)
+    fn update_contact_hash() -> Weight {
+        Weight::from_parts(68_000_000, 16222)
             .saturating_add(RocksDbWeight::get().reads(2_u64))
             .saturating_add(RocksDbWeight::get().writes(2_u64))
     }
+    /// Adjusted by hand from the last benchmark run, not benchmarked again yet.
     /// Storage: `Artists::ArtistOf` (r:1 w:1)
-    /// Proof: `Artists::ArtistOf` (`max_values`: None, `max_size`: Some(12659), added: 15134, mode: `MaxEncodedLen`)
     /// Storage: `Balances::Holds` (r:1 w:1)
-    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(937), added: 3412, mode: `MaxEncodedLen`)
+    /// Storage: `Artists::AssetBudgetUsage` (r:1 w:1)
     /// The range of component `n` is `[0, 63]`.
     fn update_add_assets(n: u32, ) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `316 + n * (32 ±0)`
-        //  Estimated: `16124`
-        // Minimum execution time: 45_000_000 picoseconds.
-        Weight::from_parts(46_039_569, 16124)
-            // Standard Error: 3_787
+        Weight::from_parts(50_211_304, 16124)
             .saturating_add(Weight::from_parts(162_626, 0).saturating_mul(n.into()))
-            .saturating_add(RocksDbWeight::get().reads(2_u64))
-            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
     }
+    /// Adjusted by hand from the last benchmark run, not benchmarked again yet.
     /// Storage: `Artists::ArtistOf` (r:1 w:1)
-    /// Proof: `Artists::ArtistOf` (`max_values`: None, `max_size`: Some(12659), added: 15134, mode: `MaxEncodedLen`)
     /// Storage: `Balances::Holds` (r:1 w:1)
-    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(937), added: 3412, mode: `MaxEncodedLen`)
     /// The range of component `n` is `[1, 64]`.
     fn update_remove_assets(n: u32, ) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `316 + n * (32 ±0)`
-        //  Estimated: `16124`
-        // Minimum execution time: 38_000_000 picoseconds.
         Weight::from_parts(39_473_860, 16124)
-            // Standard Error: 4_385
             .saturating_add(Weight::from_parts(138_221, 0).saturating_mul(n.into()))
             .saturating_add(RocksDbWeight::get().reads(2_u64))
             .saturating_add(RocksDbWeight::get().writes(2_u64))
     }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// The range of component `n` is `[1, 64]`.
+    fn update_remove_assets_at(n: u32, ) -> Weight {
+        Weight::from_parts(37_112_409, 16124)
+            .saturating_add(Weight::from_parts(41_587, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// The range of component `n` is `[1, 64]`.
+    fn update_remove_assets_fingerprint(n: u32, ) -> Weight {
+        Weight::from_parts(37_540_218, 16124)
+            .saturating_add(Weight::from_parts(112_306, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Adjusted by hand from the last benchmark run, not benchmarked again yet.
     /// Storage: `Artists::ArtistOf` (r:1 w:1)
-    /// Proof: `Artists::ArtistOf` (`max_values`: None, `max_size`: Some(12659), added: 15134, mode: `MaxEncodedLen`)
     /// Storage: `Balances::Holds` (r:1 w:1)
-    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(937), added: 3412, mode: `MaxEncodedLen`)
     /// The range of component `n` is `[0, 64]`.
     fn update_clear_assets(n: u32, ) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `316 + n * (32 ±0)`
-        //  Estimated: `16124`
-        // Minimum execution time: 28_000_000 picoseconds.
         Weight::from_parts(40_107_255, 16124)
-            // Standard Error: 4_516
             .saturating_add(Weight::from_parts(13_602, 0).saturating_mul(n.into()))
             .saturating_add(RocksDbWeight::get().reads(2_u64))
             .saturating_add(RocksDbWeight::get().writes(2_u64))
     }
-}
\ No newline at end of file
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// The range of component `n` is `[0, 3]`.
+    fn update_add_links(n: u32, ) -> Weight {
+        Weight::from_parts(42_617_330, 16648)
+            .saturating_add(Weight::from_parts(205_148, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// The range of component `n` is `[1, 4]`.
+    fn update_remove_links(n: u32, ) -> Weight {
+        Weight::from_parts(39_904_112, 16648)
+            .saturating_add(Weight::from_parts(187_503, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// The range of component `n` is `[0, 4]`.
+    fn update_clear_links(n: u32, ) -> Weight {
+        Weight::from_parts(40_512_086, 16648)
+            .saturating_add(Weight::from_parts(24_931, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Artists::ArtistAliasIndex` (r:1 w:1)
+    /// Storage: `Artists::ArtistNameOf` (r:1 w:0)
+    /// The range of component `n` is `[0, 4]`.
+    fn update_add_aliases(n: u32, ) -> Weight {
+        Weight::from_parts(46_804_512, 17164)
+            .saturating_add(Weight::from_parts(231_640, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Artists::ArtistAliasIndex` (r:1 w:1)
+    /// The range of component `n` is `[1, 5]`.
+    fn update_remove_aliases(n: u32, ) -> Weight {
+        Weight::from_parts(42_355_108, 17164)
+            .saturating_add(Weight::from_parts(264_415, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Artists::ArtistAliasIndex` (r:5 w:5)
+    /// The range of component `n` is `[0, 5]`.
+    fn update_clear_aliases(n: u32, ) -> Weight {
+        Weight::from_parts(30_118_962, 17164)
+            .saturating_add(Weight::from_parts(3_912_554, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2628).saturating_mul(n.into()))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Artists::PendingVerifications` (r:1 w:1)
+    /// Storage: `Artists::VerificationQueue` (r:1 w:1)
+    /// Storage: `Artists::ArtistAliasIndex` (r:1 w:0)
+    fn verify_artist() -> Weight {
+        Weight::from_parts(28_000_000, 16124)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    fn update_kyc_evidence() -> Weight {
+        Weight::from_parts(13_000_000, 16124)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::DepositExemptions` (r:0 w:1)
+    fn set_deposit_exemption() -> Weight {
+        Weight::from_parts(7_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    fn freeze_contracts() -> Weight {
+        Weight::from_parts(12_000_000, 16124)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    fn unfreeze_contracts() -> Weight {
+        Weight::from_parts(12_000_000, 16124)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ReservedNames` (r:0 w:1)
+    fn reserve_name() -> Weight {
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ReservedNames` (r:1 w:1)
+    fn unreserve_name() -> Weight {
+        Weight::from_parts(8_000_000, 3539)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::BannedNames` (r:0 w:1)
+    fn ban_name() -> Weight {
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::BannedNames` (r:1 w:1)
+    fn unban_name() -> Weight {
+        Weight::from_parts(8_000_000, 3539)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:2 w:2)
+    /// Storage: `Balances::Holds` (r:2 w:2)
+    /// Storage: `Artists::PendingVerifications` (r:1 w:2)
+    /// Storage: `Artists::VerificationQueue` (r:1 w:1)
+    /// The range of component `e` is `[1, 8]`.
+    fn submit_verification_evidence(e: u32, ) -> Weight {
+        Weight::from_parts(42_398_512, 31258)
+            .saturating_add(Weight::from_parts(52_911, 0).saturating_mul(e.into()))
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:2 w:2)
+    /// Storage: `Balances::Holds` (r:2 w:2)
+    /// Storage: `Artists::PendingVerifications` (r:1 w:1)
+    /// Storage: `Artists::MergedInto` (r:0 w:1)
+    /// Storage: `Artists::ArtistsOfGenre` (r:0 w:5)
+    /// Storage: `Artists::CounterForArtistOf` (r:1 w:1)
+    /// Storage: `Artists::ArtistAliasIndex` (r:1 w:1)
+    /// The range of component `a` is `[0, 32]`.
+    /// The range of component `l` is `[0, 5]`.
+    /// The range of component `c` is `[0, 2048]`.
+    fn merge_artists(a: u32, l: u32, c: u32, ) -> Weight {
+        Weight::from_parts(53_226_782, 31258)
+            .saturating_add(Weight::from_parts(1_131_611, 0).saturating_mul(a.into()))
+            .saturating_add(Weight::from_parts(2_425_870, 0).saturating_mul(l.into()))
+            .saturating_add(Weight::from_parts(232_094, 0).saturating_mul(c.into()))
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(13_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Artists::BannedNames` (r:1 w:0)
+    /// Storage: `Artists::ReservedNames` (r:1 w:0)
+    /// Storage: `Artists::ArtistNameOf` (r:1 w:0)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Artists::ArtistAliasIndex` (r:1 w:0)
+    /// The range of component `n` is `[1, 64]`.
+    fn rename(n: u32, ) -> Weight {
+        Weight::from_parts(32_187_418, 16124)
+            .saturating_add(Weight::from_parts(11_209, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::PendingRenames` (r:1 w:1)
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Artists::BannedNames` (r:1 w:0)
+    /// Storage: `Artists::ReservedNames` (r:1 w:0)
+    /// Storage: `Artists::ArtistNameOf` (r:1 w:2)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Artists::ArtistAliasIndex` (r:1 w:0)
+    /// The range of component `n` is `[1, 64]`.
+    fn approve_rename(n: u32, ) -> Weight {
+        Weight::from_parts(40_552_981, 16124)
+            .saturating_add(Weight::from_parts(12_784, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::PendingRenames` (r:1 w:1)
+    fn reject_rename() -> Weight {
+        Weight::from_parts(10_000_000, 3554)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:0)
+    /// Storage: `Artists::UnregisterRequests` (r:1 w:1)
+    fn request_unregister() -> Weight {
+        Weight::from_parts(15_000_000, 16124)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::UnregisterRequests` (r:1 w:1)
+    fn cancel_unregister() -> Weight {
+        Weight::from_parts(9_000_000, 3493)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// The range of component `d` is `[0, 1]`.
+    fn add_delegate(d: u32, ) -> Weight {
+        Weight::from_parts(45_356_120, 16189)
+            .saturating_add(Weight::from_parts(118_904, 0).saturating_mul(d.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// The range of component `d` is `[1, 2]`.
+    fn remove_delegate(d: u32, ) -> Weight {
+        Weight::from_parts(43_102_457, 16189)
+            .saturating_add(Weight::from_parts(121_330, 0).saturating_mul(d.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::PendingStats` (r:1 w:1)
+    /// Storage: `Artists::ArtistOf` (r:1001 w:0)
+    /// Storage: `Artists::Stats` (r:0 w:1)
+    /// The range of component `n` is `[0, 1000]`.
+    fn update_stats(n: u32, ) -> Weight {
+        Weight::from_parts(6_412_000, 1551)
+            .saturating_add(Weight::from_parts(6_583_271, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(Weight::from_parts(0, 15658).saturating_mul(n.into()))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Artists::PendingVerifications` (r:1 w:1)
+    /// Storage: `Artists::VerificationQueue` (r:1 w:1)
+    /// The range of component `e` is `[1, 8]`.
+    fn request_verification(e: u32, ) -> Weight {
+        Weight::from_parts(46_127_903, 16124)
+            .saturating_add(Weight::from_parts(49_202, 0).saturating_mul(e.into()))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::PendingVerifications` (r:2 w:1)
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Artists::ArtistNameOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Artists::VerificationQueue` (r:1 w:1)
+    /// Storage: `Artists::ArtistAliasIndex` (r:1 w:0)
+    fn approve_verification() -> Weight {
+        Weight::from_parts(39_000_000, 16124)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::PendingVerifications` (r:2 w:1)
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Artists::VerificationQueue` (r:1 w:1)
+    fn reject_verification() -> Weight {
+        Weight::from_parts(37_000_000, 16124)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    fn suspend_artist() -> Weight {
+        Weight::from_parts(12_000_000, 16124)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    fn reinstate_artist() -> Weight {
+        Weight::from_parts(12_000_000, 16124)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:0)
+    fn export_artist() -> Weight {
+        Weight::from_parts(11_000_000, 16124)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistNameOf` (r:2 w:2)
+    /// Storage: `Artists::ArtistAliasIndex` (r:3 w:2)
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Artists::CounterForArtistOf` (r:1 w:1)
+    /// Storage: `Artists::ArtistsOfGenre` (r:0 w:10)
+    /// The range of component `g` is `[0, 5]`.
+    fn force_restore_artist(g: u32, ) -> Weight {
+        Weight::from_parts(32_104_000, 16124)
+            .saturating_add(Weight::from_parts(1_412_338, 0).saturating_mul(g.into()))
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(g.into())))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ClaimableProfiles` (r:1 w:1)
+    /// Storage: `Artists::ArtistOf` (r:2 w:2)
+    /// Storage: `Artists::MergedInto` (r:1 w:0)
+    /// Storage: `Artists::PendingVerifications` (r:1 w:0)
+    /// Storage: `Balances::Holds` (r:2 w:2)
+    /// Storage: `Artists::ArtistNameOf` (r:0 w:1)
+    /// Storage: `Artists::ArtistAliasIndex` (r:1 w:2)
+    /// Storage: `Artists::ArtistsOfGenre` (r:0 w:10)
+    /// Storage: `Artists::UnregisterRequests` (r:0 w:1)
+    fn claim_profile() -> Weight {
+        Weight::from_parts(101_000_000, 31260)
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().writes(20_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ClaimableProfiles` (r:1 w:1)
+    /// Storage: `Artists::ArtistOf` (r:2 w:2)
+    /// Storage: `Artists::MergedInto` (r:1 w:0)
+    /// Storage: `Artists::PendingVerifications` (r:1 w:0)
+    /// Storage: `Balances::Holds` (r:2 w:2)
+    /// Storage: `Artists::ArtistNameOf` (r:0 w:1)
+    /// Storage: `Artists::ArtistAliasIndex` (r:1 w:2)
+    /// Storage: `Artists::ArtistsOfGenre` (r:0 w:10)
+    /// Storage: `Artists::UnregisterRequests` (r:0 w:1)
+    fn approve_profile_claim() -> Weight {
+        Weight::from_parts(73_000_000, 31260)
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().writes(20_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ClaimableProfiles` (r:1 w:1)
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Artists::ArtistAliasIndex` (r:1 w:1)
+    /// Storage: `Artists::PendingVerifications` (r:1 w:0)
+    /// Storage: `Artists::CounterForArtistOf` (r:1 w:1)
+    /// Storage: `Artists::ArtistsOfGenre` (r:0 w:5)
+    /// Storage: `Artists::UnregisterRequests` (r:0 w:1)
+    fn release_expired_profile() -> Weight {
+        Weight::from_parts(54_000_000, 16124)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(11_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Artists::BannedNames` (r:1 w:0)
+    /// Storage: `Artists::ReservedNames` (r:1 w:0)
+    /// Storage: `Artists::ArtistNameOf` (r:1 w:0)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Artists::ArtistsOfGenre` (r:0 w:5)
+    /// Storage: `Artists::CounterForArtistOf` (r:1 w:1)
+    /// Storage: `Artists::ArtistAliasIndex` (r:1 w:1)
+    /// Storage: `Artists::RegistrationsUsage` (r:1 w:1)
+    /// The range of component `n` is `[1, 128]`.
+    /// The range of component `g` is `[0, 5]`.
+    /// The range of component `a` is `[0, 64]`.
+    /// The range of component `d` is `[0, 1024]`.
+    fn register_for(n: u32, g: u32, a: u32, d: u32, ) -> Weight {
+        Weight::from_parts(6_120_337, 16124)
+            .saturating_add(Weight::from_parts(1_282_433, 0).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(8_934_318, 0).saturating_mul(g.into()))
+            .saturating_add(Weight::from_parts(31_929_514, 0).saturating_mul(a.into()))
+            .saturating_add(Weight::from_parts(2_861, 0).saturating_mul(d.into()))
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(g.into())))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// The range of component `b` is `[1, 8]`.
+    fn set_royalty_splits(b: u32, ) -> Weight {
+        Weight::from_parts(45_891_204, 16513)
+            .saturating_add(Weight::from_parts(236_418, 0).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// The range of component `b` is `[1, 8]`.
+    fn clear_royalty_splits(b: u32, ) -> Weight {
+        Weight::from_parts(42_207_663, 16513)
+            .saturating_add(Weight::from_parts(98_115, 0).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Artists::MemberInvitations` (r:0 w:1)
+    fn set_artist_kind() -> Weight {
+        Weight::from_parts(17_000_000, 16563)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:0)
+    /// Storage: `Artists::MemberInvitations` (r:1 w:1)
+    /// The range of component `m` is `[0, 5]`.
+    fn add_member(m: u32, ) -> Weight {
+        Weight::from_parts(19_204_117, 16563)
+            .saturating_add(Weight::from_parts(103_880, 0).saturating_mul(m.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::MemberInvitations` (r:1 w:1)
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// The range of component `m` is `[0, 5]`.
+    fn accept_membership(m: u32, ) -> Weight {
+        Weight::from_parts(48_317_052, 16563)
+            .saturating_add(Weight::from_parts(124_306, 0).saturating_mul(m.into()))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::MemberInvitations` (r:1 w:0)
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Artists::CoOwners` (r:1 w:1)
+    /// The range of component `m` is `[1, 6]`.
+    fn remove_member(m: u32, ) -> Weight {
+        Weight::from_parts(45_118_630, 16563)
+            .saturating_add(Weight::from_parts(119_552, 0).saturating_mul(m.into()))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::MemberInvitations` (r:1 w:0)
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Artists::CoOwners` (r:1 w:1)
+    /// The range of component `m` is `[1, 6]`.
+    fn leave_group(m: u32, ) -> Weight {
+        Weight::from_parts(45_086_214, 16563)
+            .saturating_add(Weight::from_parts(120_007, 0).saturating_mul(m.into()))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Artists::BannedNames` (r:1 w:0)
+    /// Storage: `Artists::ReservedNames` (r:1 w:0)
+    /// Storage: `Artists::ArtistNameOf` (r:1 w:0)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Artists::ArtistsOfGenre` (r:0 w:5)
+    /// Storage: `Artists::CounterForArtistOf` (r:1 w:1)
+    /// Storage: `Artists::ArtistAliasIndex` (r:1 w:1)
+    /// Storage: `Artists::RegistrationsUsage` (r:1 w:1)
+    /// The range of component `n` is `[1, 128]`.
+    /// The range of component `g` is `[0, 5]`.
+    /// The range of component `a` is `[0, 64]`.
+    /// The range of component `l` is `[0, 5]`.
+    /// The range of component `d` is `[0, 1024]`.
+    fn register_v2(n: u32, g: u32, a: u32, l: u32, d: u32, ) -> Weight {
+        Weight::from_parts(8_412_305, 16648)
+            .saturating_add(Weight::from_parts(1_290_877, 0).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(8_951_660, 0).saturating_mul(g.into()))
+            .saturating_add(Weight::from_parts(31_974_208, 0).saturating_mul(a.into()))
+            .saturating_add(Weight::from_parts(2_418_093, 0).saturating_mul(l.into()))
+            .saturating_add(Weight::from_parts(2_874, 0).saturating_mul(d.into()))
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(g.into())))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:0)
+    /// Storage: `Artists::CoOwners` (r:1 w:1)
+    /// Storage: `Artists::CoOwnershipOffers` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    fn join_ownership() -> Weight {
+        Weight::from_parts(42_000_000, 16648)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:0)
+    /// Storage: `Artists::CoOwners` (r:1 w:0)
+    /// Storage: `Artists::CoOwnershipOffers` (r:0 w:1)
+    fn offer_co_ownership() -> Weight {
+        Weight::from_parts(22_000_000, 16648)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::PendingVerifications` (r:1 w:1)
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    fn prune_pending_verification() -> Weight {
+        Weight::from_parts(39_000_000, 16648)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::RetiredNames` (r:1 w:1)
+    /// Storage: `Artists::RetiredNamesCursor` (r:0 w:1)
+    fn prune_retired_name() -> Weight {
+        Weight::from_parts(10_000_000, 3533)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:2 w:0)
+    /// Storage: `Artists::MergedInto` (r:1 w:0)
+    /// Storage: `Artists::OwnershipOffers` (r:0 w:1)
+    fn transfer_ownership() -> Weight {
+        Weight::from_parts(28_000_000, 32306)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::OwnershipOffers` (r:1 w:1)
+    /// Storage: `Artists::ArtistOf` (r:2 w:2)
+    /// Storage: `Artists::MergedInto` (r:1 w:0)
+    /// Storage: `Artists::PendingVerifications` (r:1 w:0)
+    /// Storage: `Balances::Holds` (r:2 w:2)
+    /// Storage: `Artists::ArtistNameOf` (r:0 w:1)
+    /// Storage: `Artists::ArtistAliasIndex` (r:1 w:2)
+    /// Storage: `Artists::ArtistsOfGenre` (r:0 w:10)
+    /// Storage: `Artists::UnregisterRequests` (r:0 w:1)
+    fn accept_ownership() -> Weight {
+        Weight::from_parts(102_000_000, 31260)
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().writes(20_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:0)
+    /// Storage: `Artists::EvidenceDelegates` (r:0 w:1)
+    fn set_evidence_delegate() -> Weight {
+        Weight::from_parts(18_000_000, 16648)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::EvidenceDelegates` (r:1 w:0)
+    /// Storage: `Artists::ArtistOf` (r:2 w:2)
+    /// Storage: `Balances::Holds` (r:2 w:2)
+    /// Storage: `Artists::PendingVerifications` (r:1 w:2)
+    /// Storage: `Artists::VerificationQueue` (r:1 w:1)
+    /// The range of component `e` is `[1, 8]`.
+    fn submit_verification_evidence_as_delegate(e: u32, ) -> Weight {
+        Weight::from_parts(45_207_139, 31724)
+            .saturating_add(Weight::from_parts(54_360, 0).saturating_mul(e.into()))
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:0)
+    /// Storage: `Artists::PayoutRoutes` (r:0 w:1)
+    fn set_payout_route() -> Weight {
+        Weight::from_parts(19_000_000, 16648)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    fn renew_verification() -> Weight {
+        Weight::from_parts(15_000_000, 16648)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:2 w:1)
+    /// Storage: `Artists::ArtistNameOf` (r:1 w:1)
+    /// Storage: `Artists::VerificationExpiryCursor` (r:1 w:1)
+    /// Storage: `Artists::LapsedVerifications` (r:0 w:1)
+    /// Storage: `Artists::PendingVerifications` (r:1 w:1)
+    /// Storage: `Artists::VerificationQueue` (r:1 w:1)
+    /// Storage: `Artists::DepositExemptions` (r:1 w:0)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    fn expire_verification() -> Weight {
+        Weight::from_parts(48_000_000, 38804)
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// The range of component `w` is `[0, 9]`.
+    fn claim_work(w: u32, ) -> Weight {
+        Weight::from_parts(44_208_315, 16968)
+            .saturating_add(Weight::from_parts(96_412, 0).saturating_mul(w.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// The range of component `w` is `[1, 10]`.
+    fn disclaim_work(w: u32, ) -> Weight {
+        Weight::from_parts(42_519_870, 16968)
+            .saturating_add(Weight::from_parts(101_738, 0).saturating_mul(w.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::PendingVerifications` (r:1 w:0)
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Artists::DepositExemptions` (r:1 w:0)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    fn poke_deposit() -> Weight {
+        Weight::from_parts(53_000_000, 16984)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Artists::PendingVerifications` (r:1 w:0)
+    /// Storage: `Artists::ClaimableProfiles` (r:1 w:0)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `Artists::ArtistsOfGenre` (r:0 w:5)
+    /// Storage: `Artists::CounterForArtistOf` (r:1 w:1)
+    /// Storage: `Artists::RetiredNames` (r:0 w:1)
+    /// The range of component `n` is `[1, 128]`.
+    /// The range of component `g` is `[0, 5]`.
+    /// The range of component `a` is `[0, 64]`.
+    fn reap_expired(_n: u32, g: u32, a: u32, ) -> Weight {
+        Weight::from_parts(163_504_211, 16984)
+            .saturating_add(Weight::from_parts(72_215, 0).saturating_mul(g.into()))
+            .saturating_add(Weight::from_parts(12_048, 0).saturating_mul(a.into()))
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(g.into())))
+    }
+    /// Estimated by hand from the storage accesses below, not benchmarked yet.
+    /// Storage: `Artists::ArtistOf` (r:1 w:1)
+    /// Storage: `Artists::RegistrationsUsage` (r:1 w:1)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Storage: `Artists::RemoteOriginOf` (r:0 w:1)
+    /// The range of component `n` is `[1, 128]`.
+    /// The range of component `g` is `[0, 5]`.
+    /// The range of component `a` is `[0, 64]`.
+    /// The range of component `l` is `[0, 5]`.
+    /// The range of component `d` is `[0, 1024]`.
+    fn register_remote(n: u32, g: u32, a: u32, l: u32, d: u32, ) -> Weight {
+        Weight::from_parts(9_136_482, 16984)
+            .saturating_add(Weight::from_parts(1_294_113, 0).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(8_978_402, 0).saturating_mul(g.into()))
+            .saturating_add(Weight::from_parts(32_011_645, 0).saturating_mul(a.into()))
+            .saturating_add(Weight::from_parts(2_425_870, 0).saturating_mul(l.into()))
+            .saturating_add(Weight::from_parts(2_881, 0).saturating_mul(d.into()))
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(g.into())))
+    }
+}